@@ -0,0 +1,11 @@
+#![no_main]
+
+use cardano_serialization_lib::arbitrary::check_bytes_round_trip;
+use cardano_serialization_lib::FixedTransaction;
+use libfuzzer_sys::fuzz_target;
+
+// a fixed transaction must encode to exactly the bytes it was decoded from
+fuzz_target!(|data: &[u8]| {
+    let result = check_bytes_round_trip::<FixedTransaction>(data);
+    assert!(!result.is_failure(), "{:?}", result);
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use cardano_serialization_lib::arbitrary::check_bytes_round_trip;
+use cardano_serialization_lib::PlutusData;
+use libfuzzer_sys::fuzz_target;
+
+// plutus data keeps its original encoding because datum hashes are computed over it
+fuzz_target!(|data: &[u8]| {
+    let result = check_bytes_round_trip::<PlutusData>(data);
+    assert!(!result.is_failure(), "{:?}", result);
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use cardano_serialization_lib::arbitrary::check_decoded_round_trip;
+use cardano_serialization_lib::Transaction;
+use libfuzzer_sys::fuzz_target;
+
+// a decoded transaction must encode to bytes that decode to the same transaction
+fuzz_target!(|data: &[u8]| {
+    let result = check_decoded_round_trip::<Transaction>(data);
+    assert!(!result.is_failure(), "{:?}", result);
+});
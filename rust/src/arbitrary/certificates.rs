@@ -0,0 +1,154 @@
+use super::*;
+use crate::*;
+use quickcheck::{Arbitrary, Gen};
+
+fn arbitrary_dns_name<G: Gen>(g: &mut G) -> String {
+    let len = 1 + choose(g, 64);
+    (0..len).map(|_| char::from(b'a' + choose(g, 26) as u8)).collect()
+}
+
+impl Arbitrary for Relay {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let port = arbitrary_option(g, |g| g.next_u32() as u16);
+        match choose(g, 3) {
+            0 => {
+                let ipv4 = arbitrary_option(g, |g| Ipv4::new(arbitrary_bytes(g, 4)).unwrap());
+                let ipv6 = arbitrary_option(g, |g| Ipv6::new(arbitrary_bytes(g, 16)).unwrap());
+                Relay::new_single_host_addr(&SingleHostAddr::new(port, ipv4, ipv6))
+            }
+            1 => {
+                let dns_name = DNSRecordAorAAAA::new(arbitrary_dns_name(g)).unwrap();
+                Relay::new_single_host_name(&SingleHostName::new(port, &dns_name))
+            }
+            _ => {
+                let dns_name = DNSRecordSRV::new(arbitrary_dns_name(g)).unwrap();
+                Relay::new_multi_host_name(&MultiHostName::new(&dns_name))
+            }
+        }
+    }
+}
+
+impl Arbitrary for Relays {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let mut relays = Relays::new();
+        for relay in arbitrary_vec(g, 0, 3, Relay::arbitrary) {
+            relays.add(&relay);
+        }
+        relays
+    }
+}
+
+impl Arbitrary for PoolMetadata {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        PoolMetadata::new(&URL::arbitrary(g), &PoolMetadataHash::arbitrary(g))
+    }
+}
+
+impl Arbitrary for PoolParams {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        PoolParams::new(
+            &Ed25519KeyHash::arbitrary(g),
+            &VRFKeyHash::arbitrary(g),
+            &BigNum::arbitrary(g),
+            &BigNum::arbitrary(g),
+            &UnitInterval::arbitrary(g),
+            &RewardAddress::arbitrary(g),
+            &Ed25519KeyHashes::arbitrary(g),
+            &Relays::arbitrary(g),
+            arbitrary_option(g, PoolMetadata::arbitrary),
+        )
+    }
+}
+
+impl Arbitrary for MIRPot {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        if coin_flip(g) {
+            MIRPot::Reserves
+        } else {
+            MIRPot::Treasury
+        }
+    }
+}
+
+impl Arbitrary for MoveInstantaneousReward {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let pot = MIRPot::arbitrary(g);
+        if coin_flip(g) {
+            return MoveInstantaneousReward::new_to_other_pot(pot, &BigNum::arbitrary(g));
+        }
+        let mut amounts = MIRToStakeCredentials::new();
+        for credential in arbitrary_vec(g, 1, 3, Credential::arbitrary) {
+            amounts.insert(&credential, &Int::arbitrary(g));
+        }
+        MoveInstantaneousReward::new_to_stake_creds(pot, &amounts)
+    }
+}
+
+impl Arbitrary for Certificate {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let credential = Credential::arbitrary(g);
+        let pool = Ed25519KeyHash::arbitrary(g);
+        let coin = BigNum::arbitrary(g);
+        let drep = DRep::arbitrary(g);
+        let anchor = arbitrary_option(g, Anchor::arbitrary);
+        match choose(g, 19) {
+            0 => Certificate::new_stake_registration(&StakeRegistration::new(&credential)),
+            1 => Certificate::new_reg_cert(&StakeRegistration::new_with_explicit_deposit(&credential, &coin)).unwrap(),
+            2 => Certificate::new_stake_deregistration(&StakeDeregistration::new(&credential)),
+            3 => Certificate::new_unreg_cert(&StakeDeregistration::new_with_explicit_refund(&credential, &coin)).unwrap(),
+            4 => Certificate::new_stake_delegation(&StakeDelegation::new(&credential, &pool)),
+            5 => Certificate::new_pool_registration(&PoolRegistration::new(&PoolParams::arbitrary(g))),
+            6 => Certificate::new_pool_retirement(&PoolRetirement::new(&pool, arbitrary_u32(g))),
+            7 => Certificate::new_genesis_key_delegation(&GenesisKeyDelegation::new(
+                &GenesisHash::arbitrary(g),
+                &GenesisDelegateHash::arbitrary(g),
+                &VRFKeyHash::arbitrary(g),
+            )),
+            8 => Certificate::new_move_instantaneous_rewards_cert(&MoveInstantaneousRewardsCert::new(
+                &MoveInstantaneousReward::arbitrary(g),
+            )),
+            9 => Certificate::new_committee_hot_auth(&CommitteeHotAuth::new(&credential, &Credential::arbitrary(g))),
+            10 => Certificate::new_committee_cold_resign(&match anchor {
+                Some(anchor) => CommitteeColdResign::new_with_anchor(&credential, &anchor),
+                None => CommitteeColdResign::new(&credential),
+            }),
+            11 => Certificate::new_drep_deregistration(&DRepDeregistration::new(&credential, &coin)),
+            12 => Certificate::new_drep_registration(&match anchor {
+                Some(anchor) => DRepRegistration::new_with_anchor(&credential, &coin, &anchor),
+                None => DRepRegistration::new(&credential, &coin),
+            }),
+            13 => Certificate::new_drep_update(&match anchor {
+                Some(anchor) => DRepUpdate::new_with_anchor(&credential, &anchor),
+                None => DRepUpdate::new(&credential),
+            }),
+            14 => Certificate::new_stake_and_vote_delegation(&StakeAndVoteDelegation::new(&credential, &pool, &drep)),
+            15 => Certificate::new_stake_registration_and_delegation(&StakeRegistrationAndDelegation::new(
+                &credential,
+                &pool,
+                &coin,
+            )),
+            16 => Certificate::new_stake_vote_registration_and_delegation(&StakeVoteRegistrationAndDelegation::new(
+                &credential,
+                &pool,
+                &drep,
+                &coin,
+            )),
+            17 => Certificate::new_vote_delegation(&VoteDelegation::new(&credential, &drep)),
+            _ => Certificate::new_vote_registration_and_delegation(&VoteRegistrationAndDelegation::new(
+                &credential,
+                &drep,
+                &coin,
+            )),
+        }
+    }
+}
+
+impl Arbitrary for Certificates {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let mut certificates = Certificates::new();
+        for certificate in arbitrary_vec(g, 1, 3, Certificate::arbitrary) {
+            certificates.add(&certificate);
+        }
+        certificates
+    }
+}
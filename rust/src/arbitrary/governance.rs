@@ -0,0 +1,297 @@
+use super::*;
+use crate::*;
+use quickcheck::{Arbitrary, Gen};
+
+impl Arbitrary for DRep {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        match choose(g, 4) {
+            0 => DRep::new_key_hash(&Ed25519KeyHash::arbitrary(g)),
+            1 => DRep::new_script_hash(&ScriptHash::arbitrary(g)),
+            2 => DRep::new_always_abstain(),
+            _ => DRep::new_always_no_confidence(),
+        }
+    }
+}
+
+impl Arbitrary for Voter {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        match choose(g, 3) {
+            0 => Voter::new_constitutional_committee_hot_credential(&Credential::arbitrary(g)),
+            1 => Voter::new_drep_credential(&Credential::arbitrary(g)),
+            _ => Voter::new_stake_pool_key_hash(&Ed25519KeyHash::arbitrary(g)),
+        }
+    }
+}
+
+impl Arbitrary for GovernanceActionId {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        GovernanceActionId::new(&TransactionHash::arbitrary(g), arbitrary_u32(g))
+    }
+}
+
+impl Arbitrary for VotingProcedure {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let vote = match choose(g, 3) {
+            0 => VoteKind::No,
+            1 => VoteKind::Yes,
+            _ => VoteKind::Abstain,
+        };
+        match arbitrary_option(g, Anchor::arbitrary) {
+            Some(anchor) => VotingProcedure::new_with_anchor(vote, &anchor),
+            None => VotingProcedure::new(vote),
+        }
+    }
+}
+
+impl Arbitrary for VotingProcedures {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let mut procedures = VotingProcedures::new();
+        for voter in arbitrary_vec(g, 1, 3, Voter::arbitrary) {
+            for action_id in arbitrary_vec(g, 1, 2, GovernanceActionId::arbitrary) {
+                procedures.insert(&voter, &action_id, &VotingProcedure::arbitrary(g));
+            }
+        }
+        procedures
+    }
+}
+
+impl Arbitrary for ExUnitPrices {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        ExUnitPrices::new(&UnitInterval::arbitrary(g), &UnitInterval::arbitrary(g))
+    }
+}
+
+impl Arbitrary for PoolVotingThresholds {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let thresholds: Vec<UnitInterval> = (0..5).map(|_| UnitInterval::arbitrary(g)).collect();
+        PoolVotingThresholds::new(&thresholds[0], &thresholds[1], &thresholds[2], &thresholds[3], &thresholds[4])
+    }
+}
+
+impl Arbitrary for DRepVotingThresholds {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let thresholds: Vec<UnitInterval> = (0..10).map(|_| UnitInterval::arbitrary(g)).collect();
+        DRepVotingThresholds::new(
+            &thresholds[0],
+            &thresholds[1],
+            &thresholds[2],
+            &thresholds[3],
+            &thresholds[4],
+            &thresholds[5],
+            &thresholds[6],
+            &thresholds[7],
+            &thresholds[8],
+            &thresholds[9],
+        )
+    }
+}
+
+impl Arbitrary for ProtocolParamUpdate {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let mut update = ProtocolParamUpdate::new();
+        // every parameter is set in about a third of the updates
+        let set = |g: &mut G| choose(g, 3) == 0;
+        if set(g) {
+            update.set_minfee_a(&BigNum::arbitrary(g));
+        }
+        if set(g) {
+            update.set_minfee_b(&BigNum::arbitrary(g));
+        }
+        if set(g) {
+            update.set_max_block_body_size(arbitrary_u32(g));
+        }
+        if set(g) {
+            update.set_max_tx_size(arbitrary_u32(g));
+        }
+        if set(g) {
+            update.set_max_block_header_size(arbitrary_u32(g));
+        }
+        if set(g) {
+            update.set_key_deposit(&BigNum::arbitrary(g));
+        }
+        if set(g) {
+            update.set_pool_deposit(&BigNum::arbitrary(g));
+        }
+        if set(g) {
+            update.set_max_epoch(arbitrary_u32(g));
+        }
+        if set(g) {
+            update.set_n_opt(arbitrary_u32(g));
+        }
+        if set(g) {
+            update.set_pool_pledge_influence(&UnitInterval::arbitrary(g));
+        }
+        if set(g) {
+            update.set_expansion_rate(&UnitInterval::arbitrary(g));
+        }
+        if set(g) {
+            update.set_treasury_growth_rate(&UnitInterval::arbitrary(g));
+        }
+        if set(g) {
+            update.set_min_pool_cost(&BigNum::arbitrary(g));
+        }
+        if set(g) {
+            update.set_ada_per_utxo_byte(&BigNum::arbitrary(g));
+        }
+        if set(g) {
+            update.set_cost_models(&Costmdls::arbitrary(g));
+        }
+        if set(g) {
+            update.set_execution_costs(&ExUnitPrices::arbitrary(g));
+        }
+        if set(g) {
+            update.set_max_tx_ex_units(&ExUnits::arbitrary(g));
+        }
+        if set(g) {
+            update.set_max_block_ex_units(&ExUnits::arbitrary(g));
+        }
+        if set(g) {
+            update.set_max_value_size(arbitrary_u32(g));
+        }
+        if set(g) {
+            update.set_collateral_percentage(arbitrary_u32(g));
+        }
+        if set(g) {
+            update.set_max_collateral_inputs(arbitrary_u32(g));
+        }
+        if set(g) {
+            update.set_pool_voting_thresholds(&PoolVotingThresholds::arbitrary(g));
+        }
+        if set(g) {
+            update.set_drep_voting_thresholds(&DRepVotingThresholds::arbitrary(g));
+        }
+        if set(g) {
+            update.set_min_committee_size(arbitrary_u32(g));
+        }
+        if set(g) {
+            update.set_committee_term_limit(arbitrary_u32(g));
+        }
+        if set(g) {
+            update.set_governance_action_validity_period(arbitrary_u32(g));
+        }
+        if set(g) {
+            update.set_governance_action_deposit(&BigNum::arbitrary(g));
+        }
+        if set(g) {
+            update.set_drep_deposit(&BigNum::arbitrary(g));
+        }
+        if set(g) {
+            update.set_drep_inactivity_period(arbitrary_u32(g));
+        }
+        if set(g) {
+            update.set_ref_script_coins_per_byte(&UnitInterval::arbitrary(g));
+        }
+        update
+    }
+}
+
+impl Arbitrary for Committee {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let mut committee = Committee::new(&UnitInterval::arbitrary(g));
+        for member in arbitrary_vec(g, 0, 3, Credential::arbitrary) {
+            committee.add_member(&member, arbitrary_u32(g));
+        }
+        committee
+    }
+}
+
+impl Arbitrary for Constitution {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let anchor = Anchor::arbitrary(g);
+        match arbitrary_option(g, ScriptHash::arbitrary) {
+            Some(script_hash) => Constitution::new_with_script_hash(&anchor, &script_hash),
+            None => Constitution::new(&anchor),
+        }
+    }
+}
+
+impl Arbitrary for TreasuryWithdrawals {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let mut withdrawals = TreasuryWithdrawals::new();
+        for address in arbitrary_vec(g, 1, 3, RewardAddress::arbitrary) {
+            withdrawals.insert(&address, &BigNum::arbitrary(g));
+        }
+        withdrawals
+    }
+}
+
+impl Arbitrary for GovernanceAction {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let action_id = arbitrary_option(g, GovernanceActionId::arbitrary);
+        let policy_hash = arbitrary_option(g, ScriptHash::arbitrary);
+        match choose(g, 7) {
+            0 => {
+                let update = ProtocolParamUpdate::arbitrary(g);
+                let action = match (&action_id, &policy_hash) {
+                    (Some(id), Some(hash)) => ParameterChangeAction::new_with_policy_hash_and_action_id(id, &update, hash),
+                    (Some(id), None) => ParameterChangeAction::new_with_action_id(id, &update),
+                    (None, Some(hash)) => ParameterChangeAction::new_with_policy_hash(&update, hash),
+                    (None, None) => ParameterChangeAction::new(&update),
+                };
+                GovernanceAction::new_parameter_change_action(&action)
+            }
+            1 => {
+                let version = ProtocolVersion::arbitrary(g);
+                let action = match &action_id {
+                    Some(id) => HardForkInitiationAction::new_with_action_id(id, &version),
+                    None => HardForkInitiationAction::new(&version),
+                };
+                GovernanceAction::new_hard_fork_initiation_action(&action)
+            }
+            2 => {
+                let withdrawals = TreasuryWithdrawals::arbitrary(g);
+                let action = match &policy_hash {
+                    Some(hash) => TreasuryWithdrawalsAction::new_with_policy_hash(&withdrawals, hash),
+                    None => TreasuryWithdrawalsAction::new(&withdrawals),
+                };
+                GovernanceAction::new_treasury_withdrawals_action(&action)
+            }
+            3 => {
+                let action = match &action_id {
+                    Some(id) => NoConfidenceAction::new_with_action_id(id),
+                    None => NoConfidenceAction::new(),
+                };
+                GovernanceAction::new_no_confidence_action(&action)
+            }
+            4 => {
+                let committee = Committee::arbitrary(g);
+                let members_to_remove = Credentials::arbitrary(g);
+                let action = match &action_id {
+                    Some(id) => UpdateCommitteeAction::new_with_action_id(id, &committee, &members_to_remove),
+                    None => UpdateCommitteeAction::new(&committee, &members_to_remove),
+                };
+                GovernanceAction::new_new_committee_action(&action)
+            }
+            5 => {
+                let constitution = Constitution::arbitrary(g);
+                let action = match &action_id {
+                    Some(id) => NewConstitutionAction::new_with_action_id(id, &constitution),
+                    None => NewConstitutionAction::new(&constitution),
+                };
+                GovernanceAction::new_new_constitution_action(&action)
+            }
+            _ => GovernanceAction::new_info_action(&InfoAction::new()),
+        }
+    }
+}
+
+impl Arbitrary for VotingProposal {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        VotingProposal::new(
+            &GovernanceAction::arbitrary(g),
+            &Anchor::arbitrary(g),
+            &RewardAddress::arbitrary(g),
+            &BigNum::arbitrary(g),
+        )
+    }
+}
+
+impl Arbitrary for VotingProposals {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let mut proposals = VotingProposals::new();
+        for proposal in arbitrary_vec(g, 1, 3, VotingProposal::arbitrary) {
+            proposals.add(&proposal);
+        }
+        proposals
+    }
+}
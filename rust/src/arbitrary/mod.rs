@@ -0,0 +1,57 @@
+//! quickcheck generators for the ledger types and the round-trip checks using them.
+//! Every generated value is valid for the Conway CDDL, so a failed check is a serialization bug.
+
+mod certificates;
+mod governance;
+mod primitives;
+mod round_trip;
+mod scripts;
+mod transaction;
+
+pub use round_trip::*;
+
+use quickcheck::Gen;
+
+// PlutusData, native scripts and metadata don't get deeper than this
+const MAX_DEPTH: usize = 3;
+
+fn choose<G: Gen>(g: &mut G, n: usize) -> usize {
+    g.next_u32() as usize % n
+}
+
+fn coin_flip<G: Gen>(g: &mut G) -> bool {
+    g.next_u32() & 1 == 0
+}
+
+fn arbitrary_bytes<G: Gen>(g: &mut G, len: usize) -> Vec<u8> {
+    let mut bytes = vec![0u8; len];
+    g.fill_bytes(&mut bytes);
+    bytes
+}
+
+// small values are the most likely, but every CBOR argument size is covered
+fn arbitrary_u64<G: Gen>(g: &mut G) -> u64 {
+    match choose(g, 4) {
+        0 => g.next_u64() % 24,
+        1 => g.next_u64() % 0x1_0000,
+        2 => g.next_u32() as u64,
+        _ => g.next_u64(),
+    }
+}
+
+fn arbitrary_u32<G: Gen>(g: &mut G) -> u32 {
+    arbitrary_u64(g) as u32
+}
+
+fn arbitrary_vec<G: Gen, T>(g: &mut G, min: usize, max: usize, mut element: impl FnMut(&mut G) -> T) -> Vec<T> {
+    let len = min + choose(g, max - min + 1);
+    (0..len).map(|_| element(g)).collect()
+}
+
+fn arbitrary_option<G: Gen, T>(g: &mut G, value: impl FnOnce(&mut G) -> T) -> Option<T> {
+    if coin_flip(g) {
+        Some(value(g))
+    } else {
+        None
+    }
+}
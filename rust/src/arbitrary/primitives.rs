@@ -0,0 +1,259 @@
+use super::*;
+use crate::*;
+use quickcheck::{Arbitrary, Gen};
+
+macro_rules! impl_arbitrary_hash {
+    ($($name:ident: $byte_count:expr),*) => {
+        $(
+            impl Arbitrary for $name {
+                fn arbitrary<G: Gen>(g: &mut G) -> Self {
+                    let mut bytes = [0u8; $byte_count];
+                    g.fill_bytes(&mut bytes);
+                    $name(bytes)
+                }
+            }
+        )*
+    };
+}
+
+impl_arbitrary_hash!(
+    Ed25519KeyHash: 28,
+    ScriptHash: 28,
+    AnchorDataHash: 32,
+    TransactionHash: 32,
+    GenesisDelegateHash: 28,
+    GenesisHash: 28,
+    AuxiliaryDataHash: 32,
+    PoolMetadataHash: 32,
+    VRFKeyHash: 32,
+    BlockHash: 32,
+    DataHash: 32,
+    ScriptDataHash: 32,
+    VRFVKey: 32,
+    KESVKey: 32
+);
+
+impl Arbitrary for BigNum {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        BigNum(arbitrary_u64(g))
+    }
+}
+
+impl Arbitrary for Int {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let value = BigNum::arbitrary(g);
+        if coin_flip(g) {
+            Int::new(&value)
+        } else {
+            Int::new_negative(&value.checked_add(&BigNum::one()).unwrap_or(value))
+        }
+    }
+}
+
+impl Arbitrary for BigInt {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let digits: String = arbitrary_vec(g, 1, 40, |g| char::from(b'0' + choose(g, 10) as u8)).into_iter().collect();
+        let sign = if coin_flip(g) { "-" } else { "" };
+        BigInt::from_str(&format!("{}{}", sign, digits)).unwrap()
+    }
+}
+
+impl Arbitrary for UnitInterval {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let denominator = BigNum(1 + arbitrary_u64(g) % u32::MAX as u64);
+        let numerator = BigNum(arbitrary_u64(g) % (denominator.0 + 1));
+        UnitInterval::new(&numerator, &denominator)
+    }
+}
+
+impl Arbitrary for ExUnits {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        ExUnits::new(&BigNum::arbitrary(g), &BigNum::arbitrary(g))
+    }
+}
+
+impl Arbitrary for Ed25519Signature {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let bytes = arbitrary_bytes(g, 64);
+        Ed25519Signature(chain_crypto::Signature::from_binary(&bytes).unwrap())
+    }
+}
+
+impl Arbitrary for PublicKey {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let bytes = arbitrary_bytes(g, 32);
+        PublicKey(chain_crypto::PublicKey::from_binary(&bytes).unwrap())
+    }
+}
+
+impl Arbitrary for Vkey {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        Vkey::new(&PublicKey::arbitrary(g))
+    }
+}
+
+impl Arbitrary for Credential {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        if coin_flip(g) {
+            Credential::from_keyhash(&Ed25519KeyHash::arbitrary(g))
+        } else {
+            Credential::from_scripthash(&ScriptHash::arbitrary(g))
+        }
+    }
+}
+
+impl Arbitrary for Credentials {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let mut credentials = Credentials::new();
+        for credential in arbitrary_vec(g, 0, 3, Credential::arbitrary) {
+            credentials.add(&credential);
+        }
+        credentials
+    }
+}
+
+impl Arbitrary for Ed25519KeyHashes {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let mut hashes = Ed25519KeyHashes::new();
+        for hash in arbitrary_vec(g, 1, 3, Ed25519KeyHash::arbitrary) {
+            hashes.add(&hash);
+        }
+        hashes
+    }
+}
+
+fn arbitrary_network<G: Gen>(g: &mut G) -> u8 {
+    choose(g, 16) as u8
+}
+
+impl Arbitrary for RewardAddress {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        RewardAddress::new(arbitrary_network(g), &Credential::arbitrary(g))
+    }
+}
+
+impl Arbitrary for Address {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let network = arbitrary_network(g);
+        let payment = Credential::arbitrary(g);
+        match choose(g, 4) {
+            0 => BaseAddress::new(network, &payment, &Credential::arbitrary(g)).to_address(),
+            1 => EnterpriseAddress::new(network, &payment).to_address(),
+            2 => RewardAddress::new(network, &payment).to_address(),
+            _ => {
+                // out of range pointers are decoded as (0, 0, 0) like the ledger does
+                let pointer = Pointer::new_pointer(
+                    &BigNum(arbitrary_u32(g) as u64),
+                    &BigNum(arbitrary_u32(g) as u16 as u64),
+                    &BigNum(arbitrary_u32(g) as u16 as u64),
+                );
+                PointerAddress::new(network, &payment, &pointer).to_address()
+            }
+        }
+    }
+}
+
+impl Arbitrary for TransactionInput {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        TransactionInput::new(&TransactionHash::arbitrary(g), arbitrary_u32(g))
+    }
+}
+
+impl Arbitrary for TransactionInputs {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let mut inputs = TransactionInputs::new();
+        for input in arbitrary_vec(g, 1, 3, TransactionInput::arbitrary) {
+            inputs.add(&input);
+        }
+        inputs
+    }
+}
+
+impl Arbitrary for AssetName {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let len = choose(g, 33);
+        AssetName::new(arbitrary_bytes(g, len)).unwrap()
+    }
+}
+
+impl Arbitrary for MultiAsset {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let mut multiasset = MultiAsset::new();
+        for policy_id in arbitrary_vec(g, 1, 3, PolicyID::arbitrary) {
+            let mut assets = Assets::new();
+            for name in arbitrary_vec(g, 1, 3, AssetName::arbitrary) {
+                assets.insert(&name, &BigNum(1 + arbitrary_u64(g) % (u64::MAX - 1)));
+            }
+            multiasset.insert(&policy_id, &assets);
+        }
+        multiasset
+    }
+}
+
+impl Arbitrary for Value {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let coin = BigNum::arbitrary(g);
+        if coin_flip(g) {
+            Value::new(&coin)
+        } else {
+            Value::new_with_assets(&coin, &MultiAsset::arbitrary(g))
+        }
+    }
+}
+
+impl Arbitrary for Mint {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let mut mint = Mint::new();
+        for policy_id in arbitrary_vec(g, 1, 3, PolicyID::arbitrary) {
+            let mut assets = MintAssets::new();
+            for name in arbitrary_vec(g, 1, 3, AssetName::arbitrary) {
+                // mint quantities are non-zero
+                let quantity = BigNum(1 + arbitrary_u64(g) % (i64::MAX as u64));
+                let quantity = if coin_flip(g) { Int::new(&quantity) } else { Int::new_negative(&quantity) };
+                assets.insert(&name, &quantity).unwrap();
+            }
+            mint.insert(&policy_id, &assets);
+        }
+        mint
+    }
+}
+
+impl Arbitrary for Withdrawals {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let mut withdrawals = Withdrawals::new();
+        for address in arbitrary_vec(g, 1, 3, RewardAddress::arbitrary) {
+            withdrawals.insert(&address, &BigNum::arbitrary(g));
+        }
+        withdrawals
+    }
+}
+
+impl Arbitrary for URL {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let len = choose(g, 64);
+        let path: String = (0..len).map(|_| char::from(b'a' + choose(g, 26) as u8)).collect();
+        URL::new(format!("https://{}", path)).unwrap()
+    }
+}
+
+impl Arbitrary for Anchor {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        Anchor::new(&URL::arbitrary(g), &AnchorDataHash::arbitrary(g))
+    }
+}
+
+impl Arbitrary for ProtocolVersion {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        ProtocolVersion::new(arbitrary_u32(g), arbitrary_u32(g))
+    }
+}
+
+impl Arbitrary for NetworkId {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        if coin_flip(g) {
+            NetworkId::mainnet()
+        } else {
+            NetworkId::testnet()
+        }
+    }
+}
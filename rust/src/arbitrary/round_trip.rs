@@ -0,0 +1,101 @@
+use crate::*;
+use quickcheck::TestResult;
+use std::fmt::Debug;
+use std::io::Cursor;
+
+/// decode(encode(value)) must be equal to the value and encode the same way again
+pub fn check_round_trip<T: Serialize + Deserialize + PartialEq + Debug>(value: &T) -> TestResult {
+    let bytes = encode(value);
+    let decoded = match decode_all::<T>(&bytes) {
+        Ok(decoded) => decoded,
+        Err(e) => return TestResult::error(format!("{:?} doesn't decode from {}: {}", value, hex::encode(&bytes), e)),
+    };
+    if &decoded != value {
+        return TestResult::error(format!("{:?} decodes as {:?} from {}", value, decoded, hex::encode(&bytes)));
+    }
+    let encoded_again = encode(&decoded);
+    if encoded_again != bytes {
+        return TestResult::error(format!(
+            "{:?} encodes as {} and then as {}",
+            value,
+            hex::encode(&bytes),
+            hex::encode(&encoded_again)
+        ));
+    }
+    TestResult::passed()
+}
+
+/// encode(decode(bytes)) must give back the decoded bytes for types that keep their original encoding.
+/// Bytes that aren't well-formed CBOR or don't decode are discarded.
+pub fn check_bytes_round_trip<T: Serialize + Deserialize>(bytes: &[u8]) -> TestResult {
+    // cbor_event allocates the announced length of byte strings before reading them
+    if !is_well_formed(bytes) {
+        return TestResult::discard();
+    }
+    let mut raw = Deserializer::from(Cursor::new(bytes));
+    let decoded = match T::deserialize(&mut raw) {
+        Ok(decoded) => decoded,
+        Err(_) => return TestResult::discard(),
+    };
+    let consumed = raw.as_mut_ref().stream_position().unwrap() as usize;
+    let encoded = encode(&decoded);
+    if encoded != bytes[..consumed] {
+        return TestResult::error(format!(
+            "{} encodes as {} after decoding",
+            hex::encode(&bytes[..consumed]),
+            hex::encode(&encoded)
+        ));
+    }
+    TestResult::passed()
+}
+
+/// Bytes that decode as T must round trip like values built with the constructors.
+/// Bytes that aren't well-formed CBOR or don't decode are discarded.
+pub fn check_decoded_round_trip<T: Serialize + Deserialize + PartialEq + Debug>(bytes: &[u8]) -> TestResult {
+    if !is_well_formed(bytes) {
+        return TestResult::discard();
+    }
+    let mut raw = Deserializer::from(Cursor::new(bytes));
+    match T::deserialize(&mut raw) {
+        Ok(decoded) => check_round_trip(&decoded),
+        Err(_) => TestResult::discard(),
+    }
+}
+
+// the first item of the bytes can be read, trailing bytes are left to the decoders
+fn is_well_formed(bytes: &[u8]) -> bool {
+    let length = match CborExplorer::explore(bytes) {
+        Ok(root) => root.length,
+        Err(_) => return false,
+    };
+    match CborExplorer::explore(&bytes[..length.min(bytes.len())]) {
+        Ok(item) => item.error.is_none() && has_no_errors(&item),
+        Err(_) => false,
+    }
+}
+
+fn has_no_errors(node: &CborNode) -> bool {
+    node.children.iter().all(|child| {
+        let key_has_no_errors = match &child.key {
+            Some(key) => key.error.is_none() && has_no_errors(key),
+            None => true,
+        };
+        key_has_no_errors && child.error.is_none() && has_no_errors(child)
+    })
+}
+
+fn decode_all<T: Deserialize>(bytes: &[u8]) -> Result<T, DeserializeError> {
+    let mut raw = Deserializer::from(Cursor::new(bytes));
+    let value = T::deserialize(&mut raw)?;
+    let consumed = raw.as_mut_ref().stream_position().unwrap() as usize;
+    if consumed != bytes.len() {
+        return Err(DeserializeFailure::CustomError(format!("{} trailing bytes", bytes.len() - consumed)).into());
+    }
+    Ok(value)
+}
+
+fn encode<T: Serialize>(value: &T) -> Vec<u8> {
+    let mut serializer = Serializer::new_vec();
+    value.serialize(&mut serializer).unwrap();
+    serializer.finalize()
+}
@@ -0,0 +1,180 @@
+use super::*;
+use crate::*;
+use quickcheck::{Arbitrary, Gen};
+
+fn arbitrary_native_script<G: Gen>(g: &mut G, depth: usize) -> NativeScript {
+    let kinds = if depth < MAX_DEPTH { 6 } else { 3 };
+    match choose(g, kinds) {
+        0 => NativeScript::new_script_pubkey(&ScriptPubkey::new(&Ed25519KeyHash::arbitrary(g))),
+        1 => NativeScript::new_timelock_start(&TimelockStart::new_timelockstart(&BigNum::arbitrary(g))),
+        2 => NativeScript::new_timelock_expiry(&TimelockExpiry::new_timelockexpiry(&BigNum::arbitrary(g))),
+        kind => {
+            let mut scripts = NativeScripts::new();
+            for script in arbitrary_vec(g, 0, 3, |g| arbitrary_native_script(g, depth + 1)) {
+                scripts.add(&script);
+            }
+            match kind {
+                3 => NativeScript::new_script_all(&ScriptAll::new(&scripts)),
+                4 => NativeScript::new_script_any(&ScriptAny::new(&scripts)),
+                _ => NativeScript::new_script_n_of_k(&ScriptNOfK::new(arbitrary_u32(g), &scripts)),
+            }
+        }
+    }
+}
+
+impl Arbitrary for NativeScript {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        arbitrary_native_script(g, 0)
+    }
+}
+
+impl Arbitrary for NativeScripts {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let mut scripts = NativeScripts::new();
+        for script in arbitrary_vec(g, 1, 3, NativeScript::arbitrary) {
+            scripts.add(&script);
+        }
+        scripts
+    }
+}
+
+impl Arbitrary for Language {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        match choose(g, 3) {
+            0 => Language::new_plutus_v1(),
+            1 => Language::new_plutus_v2(),
+            _ => Language::new_plutus_v3(),
+        }
+    }
+}
+
+impl Arbitrary for PlutusScript {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let len = choose(g, 100);
+        PlutusScript::new_with_version(arbitrary_bytes(g, len), &Language::arbitrary(g))
+    }
+}
+
+impl Arbitrary for ScriptRef {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        if coin_flip(g) {
+            ScriptRef::new_native_script(&NativeScript::arbitrary(g))
+        } else {
+            ScriptRef::new_plutus_script(&PlutusScript::arbitrary(g))
+        }
+    }
+}
+
+fn arbitrary_plutus_list<G: Gen>(g: &mut G, depth: usize) -> PlutusList {
+    let mut list = PlutusList::new();
+    for data in arbitrary_vec(g, 0, 3, |g| arbitrary_plutus_data(g, depth + 1)) {
+        list.add(&data);
+    }
+    list
+}
+
+fn arbitrary_plutus_data<G: Gen>(g: &mut G, depth: usize) -> PlutusData {
+    let kinds = if depth < MAX_DEPTH { 5 } else { 2 };
+    match choose(g, kinds) {
+        0 => PlutusData::new_integer(&BigInt::arbitrary(g)),
+        // bytes longer than 64 are written in chunks
+        1 => {
+            let len = choose(g, 100);
+            PlutusData::new_bytes(arbitrary_bytes(g, len))
+        }
+        2 => {
+            // covers the compact constructor tags and the general form
+            let alternative = match choose(g, 3) {
+                0 => BigNum(arbitrary_u64(g) % 7),
+                1 => BigNum(7 + arbitrary_u64(g) % 121),
+                _ => BigNum::arbitrary(g),
+            };
+            let fields = arbitrary_plutus_list(g, depth);
+            PlutusData::new_constr_plutus_data(&ConstrPlutusData::new(&alternative, &fields))
+        }
+        3 => PlutusData::new_list(&arbitrary_plutus_list(g, depth)),
+        _ => {
+            let mut map = PlutusMap::new();
+            for _ in 0..choose(g, 3) {
+                let key = arbitrary_plutus_data(g, depth + 1);
+                let mut values = PlutusMapValues::new();
+                values.add(&arbitrary_plutus_data(g, depth + 1));
+                map.insert(&key, &values);
+            }
+            PlutusData::new_map(&map)
+        }
+    }
+}
+
+impl Arbitrary for PlutusData {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        arbitrary_plutus_data(g, 0)
+    }
+}
+
+impl Arbitrary for PlutusList {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        arbitrary_plutus_list(g, 0)
+    }
+}
+
+impl Arbitrary for RedeemerTag {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        match choose(g, 6) {
+            0 => RedeemerTag::new_spend(),
+            1 => RedeemerTag::new_mint(),
+            2 => RedeemerTag::new_cert(),
+            3 => RedeemerTag::new_reward(),
+            4 => RedeemerTag::new_vote(),
+            _ => RedeemerTag::new_voting_proposal(),
+        }
+    }
+}
+
+impl Arbitrary for Redeemer {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        Redeemer::new(
+            &RedeemerTag::arbitrary(g),
+            &BigNum(arbitrary_u32(g) as u64),
+            &PlutusData::arbitrary(g),
+            &ExUnits::arbitrary(g),
+        )
+    }
+}
+
+impl Arbitrary for Redeemers {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let redeemers = arbitrary_vec(g, 1, 3, Redeemer::arbitrary);
+        if coin_flip(g) {
+            return Redeemers::new_with_serialization_format(redeemers, CborContainerType::Array);
+        }
+        // the map form is keyed by tag and index
+        let mut keyed: Vec<Redeemer> = Vec::new();
+        for redeemer in redeemers {
+            if !keyed.iter().any(|other| other.tag == redeemer.tag && other.index == redeemer.index) {
+                keyed.push(redeemer);
+            }
+        }
+        Redeemers::new_with_serialization_format(keyed, CborContainerType::Map)
+    }
+}
+
+impl Arbitrary for CostModel {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let mut cost_model = CostModel::new();
+        for (operation, cost) in arbitrary_vec(g, 0, 10, Int::arbitrary).iter().enumerate() {
+            cost_model.set(operation, cost).unwrap();
+        }
+        cost_model
+    }
+}
+
+impl Arbitrary for Costmdls {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let mut cost_models = Costmdls::new();
+        for language in arbitrary_vec(g, 1, 3, Language::arbitrary) {
+            cost_models.insert(&language, &CostModel::arbitrary(g));
+        }
+        cost_models
+    }
+}
@@ -0,0 +1,235 @@
+use super::*;
+use crate::*;
+use quickcheck::{Arbitrary, Gen};
+
+impl Arbitrary for TransactionOutput {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let mut output = TransactionOutput::new(&Address::arbitrary(g), &Value::arbitrary(g));
+        // every datum option with and without a script ref
+        match choose(g, 3) {
+            0 => {}
+            1 => output.set_data_hash(&DataHash::arbitrary(g)),
+            _ => output.set_plutus_data(&PlutusData::arbitrary(g)),
+        }
+        if coin_flip(g) {
+            output.set_script_ref(&ScriptRef::arbitrary(g));
+        }
+        output
+    }
+}
+
+impl Arbitrary for TransactionOutputs {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let mut outputs = TransactionOutputs::new();
+        for output in arbitrary_vec(g, 0, 3, TransactionOutput::arbitrary) {
+            outputs.add(&output);
+        }
+        outputs
+    }
+}
+
+impl Arbitrary for TransactionBody {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let mut body = TransactionBody::new_tx_body(
+            &TransactionInputs::arbitrary(g),
+            &TransactionOutputs::arbitrary(g),
+            &BigNum::arbitrary(g),
+        );
+        if coin_flip(g) {
+            body.set_ttl(&BigNum::arbitrary(g));
+        }
+        if coin_flip(g) {
+            body.set_certs(&Certificates::arbitrary(g));
+        }
+        if coin_flip(g) {
+            body.set_withdrawals(&Withdrawals::arbitrary(g));
+        }
+        if coin_flip(g) {
+            body.set_auxiliary_data_hash(&AuxiliaryDataHash::arbitrary(g));
+        }
+        if coin_flip(g) {
+            body.set_validity_start_interval_bignum(&BigNum::arbitrary(g));
+        }
+        if coin_flip(g) {
+            body.set_mint(&Mint::arbitrary(g));
+        }
+        if coin_flip(g) {
+            body.set_script_data_hash(&ScriptDataHash::arbitrary(g));
+        }
+        if coin_flip(g) {
+            body.set_collateral(&TransactionInputs::arbitrary(g));
+        }
+        if coin_flip(g) {
+            body.set_required_signers(&Ed25519KeyHashes::arbitrary(g));
+        }
+        if coin_flip(g) {
+            body.set_network_id(&NetworkId::arbitrary(g));
+        }
+        if coin_flip(g) {
+            body.set_collateral_return(&TransactionOutput::arbitrary(g));
+        }
+        if coin_flip(g) {
+            body.set_total_collateral(&BigNum::arbitrary(g));
+        }
+        if coin_flip(g) {
+            body.set_reference_inputs(&TransactionInputs::arbitrary(g));
+        }
+        if coin_flip(g) {
+            body.set_voting_procedures(&VotingProcedures::arbitrary(g));
+        }
+        if coin_flip(g) {
+            body.set_voting_proposals(&VotingProposals::arbitrary(g));
+        }
+        if coin_flip(g) {
+            body.set_current_treasury_value(&BigNum::arbitrary(g));
+        }
+        if coin_flip(g) {
+            // donations are positive
+            body.set_donation(&BigNum(1 + arbitrary_u64(g) % (u64::MAX - 1)));
+        }
+        body
+    }
+}
+
+impl Arbitrary for Vkeywitness {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        Vkeywitness::new(&Vkey::arbitrary(g), &Ed25519Signature::arbitrary(g))
+    }
+}
+
+impl Arbitrary for BootstrapWitness {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let attributes_len = choose(g, 40);
+        BootstrapWitness::new(
+            &Vkey::arbitrary(g),
+            &Ed25519Signature::arbitrary(g),
+            arbitrary_bytes(g, 32),
+            arbitrary_bytes(g, attributes_len),
+        )
+    }
+}
+
+impl Arbitrary for TransactionWitnessSet {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let mut witness_set = TransactionWitnessSet::new();
+        if coin_flip(g) {
+            let mut vkeys = Vkeywitnesses::new();
+            for witness in arbitrary_vec(g, 1, 3, Vkeywitness::arbitrary) {
+                vkeys.add(&witness);
+            }
+            witness_set.set_vkeys(&vkeys);
+        }
+        if coin_flip(g) {
+            witness_set.set_native_scripts(&NativeScripts::arbitrary(g));
+        }
+        if coin_flip(g) {
+            let mut bootstraps = BootstrapWitnesses::new();
+            for witness in arbitrary_vec(g, 1, 3, BootstrapWitness::arbitrary) {
+                bootstraps.add(&witness);
+            }
+            witness_set.set_bootstraps(&bootstraps);
+        }
+        if coin_flip(g) {
+            witness_set.set_plutus_scripts(&PlutusScripts::arbitrary(g));
+        }
+        if coin_flip(g) {
+            let mut plutus_data = PlutusList::new();
+            for data in arbitrary_vec(g, 1, 3, PlutusData::arbitrary) {
+                plutus_data.add(&data);
+            }
+            witness_set.set_plutus_data(&plutus_data);
+        }
+        if coin_flip(g) {
+            witness_set.set_redeemers(&Redeemers::arbitrary(g));
+        }
+        witness_set
+    }
+}
+
+impl Arbitrary for PlutusScripts {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        // the encoding groups the scripts by language
+        let mut generated = arbitrary_vec(g, 1, 3, PlutusScript::arbitrary);
+        generated.sort_by_key(|script| script.language_version());
+        let mut scripts = PlutusScripts::new();
+        for script in generated {
+            scripts.add(&script);
+        }
+        scripts
+    }
+}
+
+fn arbitrary_metadatum<G: Gen>(g: &mut G, depth: usize) -> TransactionMetadatum {
+    let kinds = if depth < MAX_DEPTH { 5 } else { 3 };
+    match choose(g, kinds) {
+        0 => TransactionMetadatum::new_int(&Int::arbitrary(g)),
+        1 => {
+            let len = choose(g, 65);
+            TransactionMetadatum::new_bytes(arbitrary_bytes(g, len)).unwrap()
+        }
+        2 => {
+            let len = choose(g, 65);
+            let text = (0..len).map(|_| char::from(b' ' + choose(g, 95) as u8)).collect();
+            TransactionMetadatum::new_text(text).unwrap()
+        }
+        3 => {
+            let mut list = MetadataList::new();
+            for metadatum in arbitrary_vec(g, 0, 3, |g| arbitrary_metadatum(g, depth + 1)) {
+                list.add(&metadatum);
+            }
+            TransactionMetadatum::new_list(&list)
+        }
+        _ => {
+            let mut map = MetadataMap::new();
+            for _ in 0..choose(g, 3) {
+                map.insert(&arbitrary_metadatum(g, depth + 1), &arbitrary_metadatum(g, depth + 1));
+            }
+            TransactionMetadatum::new_map(&map)
+        }
+    }
+}
+
+impl Arbitrary for TransactionMetadatum {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        arbitrary_metadatum(g, 0)
+    }
+}
+
+impl Arbitrary for GeneralTransactionMetadata {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let mut metadata = GeneralTransactionMetadata::new();
+        for label in arbitrary_vec(g, 1, 3, BigNum::arbitrary) {
+            metadata.insert(&label, &TransactionMetadatum::arbitrary(g));
+        }
+        metadata
+    }
+}
+
+impl Arbitrary for AuxiliaryData {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let mut auxiliary_data = AuxiliaryData::new();
+        if coin_flip(g) {
+            auxiliary_data.set_metadata(&GeneralTransactionMetadata::arbitrary(g));
+        }
+        if coin_flip(g) {
+            auxiliary_data.set_native_scripts(&NativeScripts::arbitrary(g));
+        }
+        if coin_flip(g) {
+            auxiliary_data.set_plutus_scripts(&PlutusScripts::arbitrary(g));
+        }
+        auxiliary_data.set_prefer_alonzo_format(coin_flip(g));
+        auxiliary_data
+    }
+}
+
+impl Arbitrary for Transaction {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let mut transaction = Transaction::new(
+            &TransactionBody::arbitrary(g),
+            &TransactionWitnessSet::arbitrary(g),
+            arbitrary_option(g, AuxiliaryData::arbitrary),
+        );
+        transaction.set_is_valid(coin_flip(g));
+        transaction
+    }
+}
@@ -1,16 +1,22 @@
 use hashlink::LinkedHashMap;
 use crate::*;
+use std::collections::HashSet;
 
 #[wasm_bindgen]
 #[derive(Clone, Debug)]
 pub struct CertificatesBuilder {
     certs: LinkedHashMap<Certificate, Option<ScriptWitnessType>>,
+    // registrations of pools that are already registered, which take no deposit
+    pool_updates: HashSet<Certificate>,
 }
 
 #[wasm_bindgen]
 impl CertificatesBuilder {
     pub fn new() -> Self {
-        Self { certs: LinkedHashMap::new() }
+        Self {
+            certs: LinkedHashMap::new(),
+            pool_updates: HashSet::new(),
+        }
     }
 
     pub fn add(&mut self, cert: &Certificate) -> Result<(), JsError> {
@@ -29,6 +35,15 @@ impl CertificatesBuilder {
         Ok(())
     }
 
+    /// Registration certificate of an already registered pool, which updates its parameters
+    /// and so is accounted without the pool deposit
+    pub fn add_pool_update(&mut self, pool_registration: &PoolRegistration) -> Result<(), JsError> {
+        let cert = Certificate::new_pool_registration(pool_registration);
+        self.add(&cert)?;
+        self.pool_updates.insert(cert);
+        Ok(())
+    }
+
     pub fn add_with_plutus_witness(
         &mut self,
         cert: &Certificate,
@@ -176,31 +191,12 @@ impl CertificatesBuilder {
     ) -> Result<Coin, JsError> {
         let mut deposit = Coin::zero();
         for (cert, _) in &self.certs {
-            match &cert.0 {
-                CertificateEnum::PoolRegistration(_) => {
-                    deposit = deposit.checked_add(&pool_deposit)?;
-                }
-                CertificateEnum::StakeRegistration(cert) => {
-                    if let Some(coin) = cert.coin {
-                        deposit = deposit.checked_add(&coin)?;
-                    } else {
-                        deposit = deposit.checked_add(&key_deposit)?;
-                    }
-                }
-                CertificateEnum::DRepRegistration(cert) => {
-                    deposit = deposit.checked_add(&cert.coin)?;
-                }
-                CertificateEnum::StakeRegistrationAndDelegation(cert) => {
-                    deposit = deposit.checked_add(&cert.coin)?;
-                }
-                CertificateEnum::VoteRegistrationAndDelegation(cert) => {
-                    deposit = deposit.checked_add(&cert.coin)?;
-                }
-                CertificateEnum::StakeVoteRegistrationAndDelegation(cert) => {
-                    deposit = deposit.checked_add(&cert.coin)?;
-                }
-                _ => {}
-            }
+            deposit = deposit.checked_add(&certificate_deposit(
+                cert,
+                pool_deposit,
+                key_deposit,
+                self.pool_updates.contains(cert),
+            ))?;
         }
         Ok(deposit)
     }
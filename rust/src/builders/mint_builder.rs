@@ -119,6 +119,46 @@ impl MintBuilder {
         Ok(())
     }
 
+    /// Mints a CIP-68 token pair: one reference NFT (label 100) and `user_token_amount` tokens
+    /// with `user_token_label` (222, 333, 444), both named `asset_name_body` after the label prefix.
+    /// Returns an output to `reference_address` that holds the reference NFT with the datum inlined
+    /// and the minimal required ada, it should be added to the transaction outputs.
+    pub fn add_cip68_assets(
+        &mut self,
+        mint: &MintWitness,
+        asset_name_body: Vec<u8>,
+        user_token_label: &Cip67Label,
+        user_token_amount: &BigNum,
+        datum: &Cip68Datum,
+        reference_address: &Address,
+        data_cost: &DataCost,
+    ) -> Result<TransactionOutput, JsError> {
+        if *user_token_label == Cip67Label::reference_nft() {
+            return Err(JsError::from_str(
+                "User token can't use the reference NFT label",
+            ));
+        }
+        if user_token_amount.is_zero() {
+            return Err(JsError::from_str("Mint cannot be zero."));
+        }
+        let reference_name = Cip67Label::reference_nft().asset_name(asset_name_body.clone())?;
+        let user_name = user_token_label.asset_name(asset_name_body)?;
+        let user_amount = Int::new(user_token_amount);
+        self.add_asset(mint, &reference_name, &Int::new_i32(1))?;
+        self.add_asset(mint, &user_name, &user_amount)?;
+
+        let mut assets = Assets::new();
+        assets.insert(&reference_name, &BigNum::one());
+        let mut multiasset = MultiAsset::new();
+        multiasset.insert(&mint.script_hash(), &assets);
+        TransactionOutputBuilder::new()
+            .with_address(reference_address)
+            .with_plutus_data(&datum.to_plutus_data())
+            .next()?
+            .with_asset_and_min_required_coin_by_utxo_cost(&multiasset, data_cost)?
+            .build()
+    }
+
     fn update_mint_value(
         &mut self,
         mint_witness: &MintWitness,
@@ -3,6 +3,9 @@ pub(crate) mod batch_tools;
 mod certificates_builder;
 pub use certificates_builder::*;
 
+mod pool_registration_builder;
+pub use pool_registration_builder::*;
+
 mod mint_builder;
 pub use mint_builder::*;
 
@@ -0,0 +1,239 @@
+use crate::*;
+use serde_json::Value;
+
+// limits of the metadata and relays checked by cardano-cli
+const METADATA_MAX_BYTES: usize = 512;
+const NAME_MAX_LEN: usize = 50;
+const DESCRIPTION_MAX_LEN: usize = 255;
+const TICKER_MIN_LEN: usize = 3;
+const TICKER_MAX_LEN: usize = 5;
+const HOMEPAGE_MAX_LEN: usize = 64;
+const METADATA_URL_MAX_LEN: usize = 64;
+const RELAY_DNS_NAME_MAX_LEN: usize = 64;
+const DNS_LABEL_MAX_LEN: usize = 63;
+
+/// The metadata file of a stake pool, whose hash is registered with the pool.
+/// The hash is the one of the file bytes as published, so they're kept as given.
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolMetadataJson {
+    name: String,
+    ticker: String,
+    description: String,
+    homepage: String,
+    json: String,
+}
+
+#[wasm_bindgen]
+impl PoolMetadataJson {
+    /// Checks the fields of the standard schema and their lengths
+    pub fn from_json(json: &str) -> Result<PoolMetadataJson, JsError> {
+        if json.len() > METADATA_MAX_BYTES {
+            return Err(JsError::from_str(&format!(
+                "Pool metadata must be at most {} bytes, found {}",
+                METADATA_MAX_BYTES,
+                json.len()
+            )));
+        }
+        let object = match serde_json::from_str(json) {
+            Ok(Value::Object(object)) => object,
+            Ok(_) => return Err(JsError::from_str("Pool metadata must be a JSON object")),
+            Err(e) => {
+                return Err(JsError::from_str(&format!(
+                    "Invalid pool metadata JSON: {}",
+                    e
+                )))
+            }
+        };
+        let field = |key: &str, min: usize, max: usize| -> Result<String, JsError> {
+            let value = object
+                .get(key)
+                .and_then(Value::as_str)
+                .ok_or_else(|| {
+                    JsError::from_str(&format!("Pool metadata field \"{}\" must be a string", key))
+                })?;
+            let len = value.chars().count();
+            if len < min || len > max {
+                return Err(JsError::from_str(&format!(
+                    "Pool metadata field \"{}\" must have between {} and {} characters, found {}",
+                    key, min, max, len
+                )));
+            }
+            Ok(value.to_string())
+        };
+        Ok(Self {
+            name: field("name", 0, NAME_MAX_LEN)?,
+            ticker: field("ticker", TICKER_MIN_LEN, TICKER_MAX_LEN)?,
+            description: field("description", 0, DESCRIPTION_MAX_LEN)?,
+            homepage: field("homepage", 0, HOMEPAGE_MAX_LEN)?,
+            json: json.to_string(),
+        })
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn ticker(&self) -> String {
+        self.ticker.clone()
+    }
+
+    pub fn description(&self) -> String {
+        self.description.clone()
+    }
+
+    pub fn homepage(&self) -> String {
+        self.homepage.clone()
+    }
+
+    pub fn to_json(&self) -> String {
+        self.json.clone()
+    }
+
+    pub fn hash(&self) -> PoolMetadataHash {
+        PoolMetadataHash::from(blake2b256(self.json.as_bytes()))
+    }
+}
+
+/// Builder of the registration certificate of a stake pool, also used to update
+/// the parameters of a registered pool
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct PoolRegistrationBuilder {
+    operator: Ed25519KeyHash,
+    vrf_keyhash: VRFKeyHash,
+    pledge: Coin,
+    cost: Coin,
+    margin: UnitInterval,
+    reward_account: RewardAddress,
+    pool_owners: Ed25519KeyHashes,
+    relays: Relays,
+    pool_metadata: Option<PoolMetadata>,
+}
+
+#[wasm_bindgen]
+impl PoolRegistrationBuilder {
+    pub fn new(
+        operator: &Ed25519KeyHash,
+        vrf_keyhash: &VRFKeyHash,
+        pledge: &Coin,
+        cost: &Coin,
+        margin: &UnitInterval,
+        reward_account: &RewardAddress,
+    ) -> Result<PoolRegistrationBuilder, JsError> {
+        if margin.denominator.is_zero() || margin.numerator > margin.denominator {
+            return Err(JsError::from_str("Pool margin must be between 0 and 1"));
+        }
+        Ok(Self {
+            operator: operator.clone(),
+            vrf_keyhash: vrf_keyhash.clone(),
+            pledge: *pledge,
+            cost: *cost,
+            margin: margin.clone(),
+            reward_account: reward_account.clone(),
+            pool_owners: Ed25519KeyHashes::new(),
+            relays: Relays::new(),
+            pool_metadata: None,
+        })
+    }
+
+    /// Owners sign the certificate and their stake counts towards the pledge
+    pub fn add_owner(&mut self, owner: &Ed25519KeyHash) -> Result<(), JsError> {
+        if !self.pool_owners.add(owner) {
+            return Err(JsError::from_str("Pool owner already exists"));
+        }
+        Ok(())
+    }
+
+    /// Checks that an address relay has an IP and that the DNS names are valid host names
+    pub fn add_relay(&mut self, relay: &Relay) -> Result<(), JsError> {
+        match &relay.0 {
+            RelayEnum::SingleHostAddr(addr) => {
+                if addr.ipv4.is_none() && addr.ipv6.is_none() {
+                    return Err(JsError::from_str(
+                        "Single host address relay must have an IPv4 or IPv6 address",
+                    ));
+                }
+            }
+            RelayEnum::SingleHostName(name) => check_dns_name(&name.dns_name.0, false)?,
+            RelayEnum::MultiHostName(name) => check_dns_name(&name.dns_name.0, true)?,
+        }
+        if self.relays.0.contains(relay) {
+            return Err(JsError::from_str("Pool relay already exists"));
+        }
+        self.relays.add(relay);
+        Ok(())
+    }
+
+    /// The metadata published at `url`, registered with its hash
+    pub fn set_metadata(&mut self, url: &URL, metadata: &PoolMetadataJson) -> Result<(), JsError> {
+        if url.0.len() > METADATA_URL_MAX_LEN {
+            return Err(JsError::from_str(&format!(
+                "Pool metadata URL must be at most {} bytes, found {}",
+                METADATA_URL_MAX_LEN,
+                url.0.len()
+            )));
+        }
+        self.pool_metadata = Some(PoolMetadata::new(url, &metadata.hash()));
+        Ok(())
+    }
+
+    pub fn build(&self) -> PoolRegistration {
+        PoolRegistration::new(&PoolParams::new(
+            &self.operator,
+            &self.vrf_keyhash,
+            &self.pledge,
+            &self.cost,
+            &self.margin,
+            &self.reward_account,
+            &self.pool_owners,
+            &self.relays,
+            self.pool_metadata.clone(),
+        ))
+    }
+
+    /// Certificates with the registration, which the transaction builder accounts
+    /// with the pool deposit and the signatures of the operator and owners
+    pub fn build_certificates(&self) -> Result<CertificatesBuilder, JsError> {
+        let mut certificates = CertificatesBuilder::new();
+        certificates.add(&Certificate::new_pool_registration(&self.build()))?;
+        Ok(certificates)
+    }
+
+    /// Certificates with the registration of an already registered pool, updating its parameters.
+    /// Updates take no pool deposit, the one of the first registration stays locked
+    pub fn build_certificates_for_update(&self) -> Result<CertificatesBuilder, JsError> {
+        let mut certificates = CertificatesBuilder::new();
+        certificates.add_pool_update(&self.build())?;
+        Ok(certificates)
+    }
+}
+
+// host names of letters, digits and hyphens, SRV records starting with underscored labels
+fn check_dns_name(name: &str, srv: bool) -> Result<(), JsError> {
+    if name.is_empty() || name.len() > RELAY_DNS_NAME_MAX_LEN {
+        return Err(JsError::from_str(&format!(
+            "Relay DNS name must have between 1 and {} bytes, found {}",
+            RELAY_DNS_NAME_MAX_LEN,
+            name.len()
+        )));
+    }
+    let valid_label = |label: &str| {
+        let label = match label.strip_prefix('_') {
+            Some(service) if srv => service,
+            _ => label,
+        };
+        !label.is_empty()
+            && label.len() <= DNS_LABEL_MAX_LEN
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    };
+    if !name.trim_end_matches('.').split('.').all(valid_label) {
+        return Err(JsError::from_str(&format!(
+            "Relay DNS name \"{}\" isn't a valid host name",
+            name
+        )));
+    }
+    Ok(())
+}
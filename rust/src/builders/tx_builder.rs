@@ -224,6 +224,29 @@ impl TransactionBuilderConfigBuilder {
         }
     }
 
+    /// Config with the initial protocol parameters of the genesis files.
+    /// The reference script fee is only set when the Conway genesis is passed.
+    pub fn from_genesis(
+        shelley: &ShelleyGenesis,
+        alonzo: &AlonzoGenesis,
+        conway: Option<&ConwayGenesis>,
+    ) -> Self {
+        let params = &shelley.protocol_params;
+        let mut cfg = Self::new();
+        if let (Some(coefficient), Some(constant)) = (params.minfee_a, params.minfee_b) {
+            cfg.fee_algo = Some(fees::LinearFee::new(&coefficient, &constant));
+        }
+        cfg.pool_deposit = params.pool_deposit;
+        cfg.key_deposit = params.key_deposit;
+        cfg.max_tx_size = params.max_tx_size;
+        cfg.max_value_size = Some(alonzo.max_value_size);
+        cfg.data_cost = Some(DataCost::new_coins_per_byte(&alonzo.coins_per_utxo_byte()));
+        cfg.ex_unit_prices = Some(alonzo.execution_prices.clone());
+        cfg.ref_script_coins_per_byte =
+            conway.map(|conway| conway.min_fee_ref_script_cost_per_byte.clone());
+        cfg
+    }
+
     pub fn fee_algo(&self, fee_algo: &fees::LinearFee) -> Self {
         let mut cfg = self.clone();
         cfg.fee_algo = Some(fee_algo.clone());
@@ -1185,6 +1208,27 @@ impl TransactionBuilder {
         self.validity_start_interval = Some(validity_start_interval.clone())
     }
 
+    /// Sets the ttl to the slot containing the POSIX time in milliseconds,
+    /// so the transaction is only valid in the slots starting before that time.
+    pub fn set_ttl_from_time(
+        &mut self,
+        posix_millis: &BigNum,
+        era_history: &EraHistory,
+    ) -> Result<(), JsError> {
+        self.ttl = Some(era_history.posix_millis_to_slot(posix_millis)?);
+        Ok(())
+    }
+
+    /// Sets the validity start to the first slot starting at or after the POSIX time in milliseconds
+    pub fn set_validity_start_interval_from_time(
+        &mut self,
+        posix_millis: &BigNum,
+        era_history: &EraHistory,
+    ) -> Result<(), JsError> {
+        self.validity_start_interval = Some(era_history.posix_millis_to_slot_ceil(posix_millis)?);
+        Ok(())
+    }
+
     pub fn remove_validity_start_interval(&mut self) {
         self.validity_start_interval = None;
     }
@@ -1311,6 +1355,30 @@ impl TransactionBuilder {
         Ok(())
     }
 
+    /// Add a CIP-20 message under the label 674
+    /// It will replace any existing message in this builder
+    pub fn add_message(&mut self, message: &Cip20Message) -> Result<(), JsError> {
+        let metadatum = message.to_metadatum()?;
+        self.add_metadatum(&BigNum::from(CIP20_METADATA_LABEL), &metadatum);
+        Ok(())
+    }
+
+    /// Add a CIP-83 encrypted message under the label 674
+    /// It will replace any existing message in this builder
+    pub fn add_encrypted_message(&mut self, message: &Cip83EncryptedMessage) -> Result<(), JsError> {
+        let metadatum = message.to_metadatum()?;
+        self.add_metadatum(&BigNum::from(CIP20_METADATA_LABEL), &metadatum);
+        Ok(())
+    }
+
+    /// Returns the CIP-20 message of this builder if there is one
+    pub fn get_message(&self) -> Result<Option<Cip20Message>, JsError> {
+        match &self.auxiliary_data {
+            Some(aux) => Cip20Message::from_auxiliary_data(aux),
+            None => Ok(None),
+        }
+    }
+
     pub fn set_mint_builder(&mut self, mint_builder: &MintBuilder) {
         self.mint = Some(mint_builder.clone());
     }
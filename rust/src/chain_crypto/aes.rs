@@ -0,0 +1,65 @@
+//! AES-256 in CBC mode with PKCS#7 padding, the cipher of `openssl enc -aes-256-cbc`.
+//! The block cipher comes from the `aes` crate, which is constant time.
+
+use aes::cipher::block_padding::Pkcs7;
+use aes::cipher::{BlockDecryptMut, BlockEncryptMut, KeyIvInit};
+
+pub const KEY_SIZE: usize = 32;
+pub const IV_SIZE: usize = 16;
+pub const BLOCK_SIZE: usize = 16;
+
+type Encryptor = cbc::Encryptor<aes::Aes256>;
+type Decryptor = cbc::Decryptor<aes::Aes256>;
+
+pub fn encrypt_cbc(key: &[u8; KEY_SIZE], iv: &[u8; IV_SIZE], data: &[u8]) -> Vec<u8> {
+    Encryptor::new(key.into(), iv.into()).encrypt_padded_vec_mut::<Pkcs7>(data)
+}
+
+/// None when the data isn't made of whole blocks or the padding is invalid, as happens
+/// with a wrong key
+pub fn decrypt_cbc(key: &[u8; KEY_SIZE], iv: &[u8; IV_SIZE], data: &[u8]) -> Option<Vec<u8>> {
+    Decryptor::new(key.into(), iv.into())
+        .decrypt_padded_vec_mut::<Pkcs7>(data)
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryInto;
+
+    // FIPS-197 C.3, the first block with a zero IV is encrypted like a single block
+    #[test]
+    fn fips_197_block() {
+        let key: [u8; KEY_SIZE] = core::array::from_fn(|i| i as u8);
+        let block = hex::decode("00112233445566778899aabbccddeeff").unwrap();
+        let ciphertext = encrypt_cbc(&key, &[0; IV_SIZE], &block);
+        assert_eq!(
+            hex::encode(&ciphertext[..BLOCK_SIZE]),
+            "8ea2b7ca516745bfeafc49904b496089"
+        );
+        assert_eq!(decrypt_cbc(&key, &[0; IV_SIZE], &ciphertext).unwrap(), block);
+    }
+
+    // NIST SP 800-38A F.2.5, followed by a block of padding
+    #[test]
+    fn sp_800_38a_cbc() {
+        let key: [u8; KEY_SIZE] =
+            hex::decode("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        let iv: [u8; IV_SIZE] = core::array::from_fn(|i| i as u8);
+        let plaintext =
+            hex::decode("6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51")
+                .unwrap();
+        let ciphertext = encrypt_cbc(&key, &iv, &plaintext);
+        assert_eq!(ciphertext.len(), 3 * BLOCK_SIZE);
+        assert_eq!(
+            hex::encode(&ciphertext[..2 * BLOCK_SIZE]),
+            "f58c4c04d6e5f1ba779eabfb5f7bfbd69cfc4e967edb808d679f777bc6702c7d"
+        );
+        assert_eq!(decrypt_cbc(&key, &iv, &ciphertext).unwrap(), plaintext);
+        assert!(decrypt_cbc(&key, &iv, &ciphertext[..BLOCK_SIZE + 1]).is_none());
+    }
+}
@@ -11,11 +11,13 @@ use cryptoxide::mac::Mac;
 use cryptoxide::sha2::Sha512;
 
 use super::ed25519 as ei;
+use cryptoxide::curve25519::Scalar;
 use cryptoxide::ed25519;
 use ed25519_bip32::{XPrv, XPub, XPRV_SIZE, XPUB_SIZE};
 use rand_os::rand_core::{CryptoRng, RngCore};
 
 const CHAIN_CODE_SIZE: usize = 32;
+const HARDENED_INDEX: u32 = 0x8000_0000;
 const SEED_SIZE: usize = 64;
 
 /// Legacy Daedalus algorithm
@@ -48,6 +50,66 @@ impl LegacyPriv {
         buf.clone_from_slice(&self.0.as_ref()[ed25519::EXTENDED_KEY_LENGTH..XPRV_SIZE]);
         buf
     }
+
+    /// Child key with the V1 derivation scheme of cardano-sl, used by the legacy Daedalus wallets.
+    /// It differs from BIP32-Ed25519 (V2) in the index encoding (big endian),
+    /// the left half being added modulo the group order and the right half added without carries.
+    pub fn derive_v1(&self, index: u32) -> Self {
+        let ekey = self.inner_key();
+        let chaincode = self.chaincode();
+        let seri = index.to_be_bytes();
+        let mut zmac = Hmac::new(Sha512::new(), &chaincode);
+        let mut imac = Hmac::new(Sha512::new(), &chaincode);
+        if index >= HARDENED_INDEX {
+            zmac.input(&[0x0]);
+            zmac.input(&ekey);
+            imac.input(&[0x1]);
+            imac.input(&ekey);
+        } else {
+            let pk = ed25519::extended_to_public(&ekey);
+            zmac.input(&[0x2]);
+            zmac.input(&pk);
+            imac.input(&[0x3]);
+            imac.input(&pk);
+        }
+        zmac.input(&seri);
+        imac.input(&seri);
+        let mut z = [0u8; 64];
+        zmac.raw_result(&mut z);
+        let mut i = [0u8; 64];
+        imac.raw_result(&mut i);
+
+        let mut out = [0u8; XPRV_SIZE];
+        out[0..32].clone_from_slice(&add_mod_order(&multiply8_v1(&z[0..32]), &ekey[0..32]));
+        for (byte, (left, right)) in out[32..64].iter_mut().zip(z[32..64].iter().zip(ekey[32..64].iter())) {
+            *byte = left.wrapping_add(*right);
+        }
+        out[64..XPRV_SIZE].clone_from_slice(&i[32..64]);
+        LegacyPriv(out)
+    }
+}
+
+// 8 * z as computed by cardano-sl, which drops the bits shifted out of each byte
+// (its carry is masked with 0x8 while only 3 bits are carried)
+fn multiply8_v1(z: &[u8]) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    for (byte, z) in out.iter_mut().zip(z.iter()) {
+        *byte = z << 3;
+    }
+    out
+}
+
+// (x + y) mod the order of the ed25519 group
+fn add_mod_order(x: &[u8], y: &[u8]) -> [u8; 32] {
+    let mut wide = [0u8; 64];
+    let mut carry = 0u16;
+    for (byte, (x, y)) in wide.iter_mut().zip(x.iter().zip(y.iter())) {
+        let sum = *x as u16 + *y as u16 + carry;
+        *byte = sum as u8;
+        carry = sum >> 8;
+    }
+    wide[32] = carry as u8;
+    Scalar::reduce_from_wide_bytes(&wide).to_bytes()
 }
 
 impl AsymmetricPublicKey for LegacyDaedalus {
@@ -176,4 +238,30 @@ mod test {
     fn sign_ko(input: (KeyPair<LegacyDaedalus>, KeyPair<LegacyDaedalus>, Vec<u8>)) -> bool {
         keypair_signing_ko(input)
     }
+
+    #[test]
+    fn add_mod_order_wraps() {
+        // the order of the ed25519 group minus 1, little endian
+        let mut order_minus_1 = [0u8; 32];
+        order_minus_1[..16].copy_from_slice(&[
+            0xec, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9,
+            0xde, 0x14,
+        ]);
+        order_minus_1[31] = 0x10;
+        let mut two = [0u8; 32];
+        two[0] = 2;
+        let mut one = [0u8; 32];
+        one[0] = 1;
+        assert_eq!(add_mod_order(&order_minus_1, &two), one);
+        assert_eq!(add_mod_order(&one, &one), two);
+    }
+
+    #[test]
+    fn multiply8_v1_drops_the_carries() {
+        let mut z = [0u8; 32];
+        z[0] = 0xff;
+        z[1] = 0x01;
+        let product = multiply8_v1(&z);
+        assert_eq!(product[..3], [0xf8, 0x08, 0x00]);
+    }
 }
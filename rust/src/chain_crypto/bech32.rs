@@ -16,22 +16,125 @@ pub trait Bech32 {
 }
 
 pub fn to_bech32_from_bytes<B: Bech32>(bytes: &[u8]) -> String {
-    bech32::encode(B::BECH32_HRP, bytes.to_base32())
+    to_bech32_with_hrp(B::BECH32_HRP, bytes)
+}
+
+pub fn to_bech32_with_hrp(hrp: &str, bytes: &[u8]) -> String {
+    bech32::encode(hrp, bytes.to_base32())
         .unwrap_or_else(|e| panic!("Failed to build bech32: {}", e))
         .to_string()
 }
 
 pub fn try_from_bech32_to_bytes<B: Bech32>(bech32_str: &str) -> Result<Vec<u8>> {
+    try_from_bech32_with_hrp(bech32_str, B::BECH32_HRP)
+}
+
+pub fn try_from_bech32_with_hrp(bech32_str: &str, expected_hrp: &'static str) -> Result<Vec<u8>> {
     let (hrp, bech32_data) = bech32::decode(bech32_str)?;
-    if hrp != B::BECH32_HRP {
+    if hrp != expected_hrp {
         return Err(Error::HrpInvalid {
-            expected: B::BECH32_HRP,
+            expected: expected_hrp,
             actual: hrp,
         });
     }
     Vec::<u8>::from_base32(&bech32_data).map_err(Into::into)
 }
 
+/// The human readable prefixes of the bech32 encodings of Cardano, as registered in CIP-5
+pub mod cip5 {
+    // keys
+    pub const ROOT_SK: &str = "root_sk";
+    pub const ROOT_VK: &str = "root_vk";
+    pub const ROOT_XSK: &str = "root_xsk";
+    pub const ROOT_XVK: &str = "root_xvk";
+    pub const ROOT_SHARED_SK: &str = "root_shared_sk";
+    pub const ROOT_SHARED_VK: &str = "root_shared_vk";
+    pub const ROOT_SHARED_XSK: &str = "root_shared_xsk";
+    pub const ROOT_SHARED_XVK: &str = "root_shared_xvk";
+    pub const ACCT_SK: &str = "acct_sk";
+    pub const ACCT_VK: &str = "acct_vk";
+    pub const ACCT_XSK: &str = "acct_xsk";
+    pub const ACCT_XVK: &str = "acct_xvk";
+    pub const ACCT_SHARED_SK: &str = "acct_shared_sk";
+    pub const ACCT_SHARED_VK: &str = "acct_shared_vk";
+    pub const ACCT_SHARED_XSK: &str = "acct_shared_xsk";
+    pub const ACCT_SHARED_XVK: &str = "acct_shared_xvk";
+    pub const ADDR_SK: &str = "addr_sk";
+    pub const ADDR_VK: &str = "addr_vk";
+    pub const ADDR_XSK: &str = "addr_xsk";
+    pub const ADDR_XVK: &str = "addr_xvk";
+    pub const ADDR_SHARED_SK: &str = "addr_shared_sk";
+    pub const ADDR_SHARED_VK: &str = "addr_shared_vk";
+    pub const ADDR_SHARED_XSK: &str = "addr_shared_xsk";
+    pub const ADDR_SHARED_XVK: &str = "addr_shared_xvk";
+    pub const STAKE_SK: &str = "stake_sk";
+    pub const STAKE_VK: &str = "stake_vk";
+    pub const STAKE_XSK: &str = "stake_xsk";
+    pub const STAKE_XVK: &str = "stake_xvk";
+    pub const STAKE_SHARED_SK: &str = "stake_shared_sk";
+    pub const STAKE_SHARED_VK: &str = "stake_shared_vk";
+    pub const STAKE_SHARED_XSK: &str = "stake_shared_xsk";
+    pub const STAKE_SHARED_XVK: &str = "stake_shared_xvk";
+    pub const POLICY_SK: &str = "policy_sk";
+    pub const POLICY_VK: &str = "policy_vk";
+    pub const POLICY_XSK: &str = "policy_xsk";
+    pub const POLICY_XVK: &str = "policy_xvk";
+    pub const POOL_SK: &str = "pool_sk";
+    pub const POOL_VK: &str = "pool_vk";
+    pub const POOL_XSK: &str = "pool_xsk";
+    pub const POOL_XVK: &str = "pool_xvk";
+    pub const DREP_SK: &str = "drep_sk";
+    pub const DREP_VK: &str = "drep_vk";
+    pub const DREP_XSK: &str = "drep_xsk";
+    pub const DREP_XVK: &str = "drep_xvk";
+    pub const CC_COLD_SK: &str = "cc_cold_sk";
+    pub const CC_COLD_VK: &str = "cc_cold_vk";
+    pub const CC_COLD_XSK: &str = "cc_cold_xsk";
+    pub const CC_COLD_XVK: &str = "cc_cold_xvk";
+    pub const CC_HOT_SK: &str = "cc_hot_sk";
+    pub const CC_HOT_VK: &str = "cc_hot_vk";
+    pub const CC_HOT_XSK: &str = "cc_hot_xsk";
+    pub const CC_HOT_XVK: &str = "cc_hot_xvk";
+    pub const CVOTE_SK: &str = "cvote_sk";
+    pub const CVOTE_VK: &str = "cvote_vk";
+    pub const KES_SK: &str = "kes_sk";
+    pub const KES_VK: &str = "kes_vk";
+    pub const VRF_SK: &str = "vrf_sk";
+    pub const VRF_VK: &str = "vrf_vk";
+
+    // hashes
+    pub const ASSET: &str = "asset";
+    pub const POOL: &str = "pool";
+    pub const SCRIPT: &str = "script";
+    pub const ADDR_VKH: &str = "addr_vkh";
+    pub const ADDR_SHARED_VKH: &str = "addr_shared_vkh";
+    pub const STAKE_VKH: &str = "stake_vkh";
+    pub const STAKE_SHARED_VKH: &str = "stake_shared_vkh";
+    pub const POLICY_VKH: &str = "policy_vkh";
+    pub const REQ_SIGNER_VKH: &str = "req_signer_vkh";
+    pub const VRF_VKH: &str = "vrf_vkh";
+    pub const DATUM: &str = "datum";
+    pub const SCRIPT_DATA: &str = "script_data";
+    pub const DREP_VKH: &str = "drep_vkh";
+    pub const DREP_SCRIPT: &str = "drep_script";
+    pub const CC_COLD_VKH: &str = "cc_cold_vkh";
+    pub const CC_COLD_SCRIPT: &str = "cc_cold_script";
+    pub const CC_HOT_VKH: &str = "cc_hot_vkh";
+    pub const CC_HOT_SCRIPT: &str = "cc_hot_script";
+
+    // governance identifiers of CIP-129
+    pub const DREP: &str = "drep";
+    pub const CC_COLD: &str = "cc_cold";
+    pub const CC_HOT: &str = "cc_hot";
+    pub const GOV_ACTION: &str = "gov_action";
+
+    // addresses
+    pub const ADDR: &str = "addr";
+    pub const ADDR_TEST: &str = "addr_test";
+    pub const STAKE: &str = "stake";
+    pub const STAKE_TEST: &str = "stake_test";
+}
+
 #[derive(Debug)]
 pub enum Error {
     Bech32Malformed(Bech32Error),
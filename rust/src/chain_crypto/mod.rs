@@ -6,6 +6,7 @@ cfg_if! {
     }
 }
 
+pub mod aes;
 pub mod algorithms;
 pub mod bech32;
 pub mod derive;
@@ -13,6 +14,8 @@ pub mod digest;
 pub mod hash;
 mod key;
 mod sign;
+pub mod sum_kes;
+pub mod vrf;
 
 pub use algorithms::*;
 pub use hash::{Blake2b256, Sha3_256};
@@ -0,0 +1,147 @@
+//! Sum6KES, the key evolving signature of the block headers
+//!
+//! It's the sum composition (MMM) of ed25519 with depth 6, hashing the pairs of keys with blake2b256.
+//! A signature is the ed25519 signature followed by the pairs of keys from the bottom of the tree,
+//! the verification key of the top level being the hash of the last pair.
+//!
+//! A signing key is the ed25519 seed of the current period followed, for each level from the bottom,
+//! by the seed of the right subtree (zeroed once used) and the pair of keys of the level.
+//!
+
+use crate::crypto::blake2b256;
+use cryptoxide::ed25519;
+use std::convert::TryInto;
+
+pub const DEPTH: usize = 6;
+pub const PERIODS: u32 = 1 << DEPTH;
+pub const PUBLIC_KEY_SIZE: usize = 32;
+pub const SIGNATURE_SIZE: usize = ed25519::SIGNATURE_LENGTH + DEPTH * 2 * PUBLIC_KEY_SIZE;
+pub const SEED_SIZE: usize = 32;
+pub const SECRET_KEY_SIZE: usize = secret_key_size(DEPTH);
+
+const fn secret_key_size(depth: usize) -> usize {
+    SEED_SIZE + depth * (SEED_SIZE + 2 * PUBLIC_KEY_SIZE)
+}
+
+/// The signing key of period 0 of the seed
+pub fn generate(seed: &[u8; SEED_SIZE]) -> Vec<u8> {
+    generate_depth(seed, DEPTH).0
+}
+
+pub fn public_key(secret_key: &[u8]) -> [u8; PUBLIC_KEY_SIZE] {
+    // the hash of the keys of the top level
+    blake2b256(&secret_key[SECRET_KEY_SIZE - 2 * PUBLIC_KEY_SIZE..])
+}
+
+/// Signature of the message by the signing key of its current period
+pub fn sign(secret_key: &[u8], message: &[u8]) -> Vec<u8> {
+    let seed: &[u8; SEED_SIZE] = secret_key[..SEED_SIZE].try_into().unwrap();
+    let (keypair, _) = ed25519::keypair(seed);
+    let mut signature = ed25519::signature(message, &keypair).to_vec();
+    for depth in 0..DEPTH {
+        let start = secret_key_size(depth) + SEED_SIZE;
+        signature.extend_from_slice(&secret_key[start..start + 2 * PUBLIC_KEY_SIZE]);
+    }
+    signature
+}
+
+/// Evolves the signing key of `period` to the next period, the last period can't evolve
+pub fn update(secret_key: &mut [u8], period: u32) -> bool {
+    if period + 1 >= PERIODS {
+        return false;
+    }
+    update_depth(&mut secret_key[..SECRET_KEY_SIZE], DEPTH, period);
+    true
+}
+
+// the seeds of the left and right subtrees
+fn expand_seed(seed: &[u8; SEED_SIZE]) -> ([u8; SEED_SIZE], [u8; SEED_SIZE]) {
+    let mut left = vec![1];
+    left.extend_from_slice(seed);
+    let mut right = vec![2];
+    right.extend_from_slice(seed);
+    (blake2b256(&left), blake2b256(&right))
+}
+
+fn generate_depth(seed: &[u8; SEED_SIZE], depth: usize) -> (Vec<u8>, [u8; PUBLIC_KEY_SIZE]) {
+    if depth == 0 {
+        let (_, public_key) = ed25519::keypair(seed);
+        return (seed.to_vec(), public_key);
+    }
+    let (left_seed, right_seed) = expand_seed(seed);
+    let (mut secret_key, left) = generate_depth(&left_seed, depth - 1);
+    let (_, right) = generate_depth(&right_seed, depth - 1);
+    secret_key.extend_from_slice(&right_seed);
+    secret_key.extend_from_slice(&left);
+    secret_key.extend_from_slice(&right);
+    let public_key = blake2b256(&secret_key[secret_key.len() - 2 * PUBLIC_KEY_SIZE..]);
+    (secret_key, public_key)
+}
+
+fn update_depth(secret_key: &mut [u8], depth: usize, period: u32) {
+    let half = 1 << (depth - 1);
+    let (subtree, level) = secret_key.split_at_mut(secret_key_size(depth - 1));
+    if period + 1 == half {
+        // the left subtree is exhausted, the right one starts from its seed
+        let right_seed: [u8; SEED_SIZE] = level[..SEED_SIZE].try_into().unwrap();
+        subtree.copy_from_slice(&generate_depth(&right_seed, depth - 1).0);
+        level[..SEED_SIZE].copy_from_slice(&[0; SEED_SIZE]);
+    } else if period < half {
+        update_depth(subtree, depth - 1, period);
+    } else {
+        update_depth(subtree, depth - 1, period - half);
+    }
+}
+
+pub fn verify(
+    public_key: &[u8; PUBLIC_KEY_SIZE],
+    period: u32,
+    message: &[u8],
+    signature: &[u8],
+) -> bool {
+    if signature.len() != SIGNATURE_SIZE || period >= PERIODS {
+        return false;
+    }
+    let mut key = *public_key;
+    let mut period = period;
+    for depth in (0..DEPTH).rev() {
+        let start = ed25519::SIGNATURE_LENGTH + depth * 2 * PUBLIC_KEY_SIZE;
+        let keys = &signature[start..start + 2 * PUBLIC_KEY_SIZE];
+        if blake2b256(keys) != key {
+            return false;
+        }
+        let half = 1 << depth;
+        if period < half {
+            key = keys[..PUBLIC_KEY_SIZE].try_into().unwrap();
+        } else {
+            key = keys[PUBLIC_KEY_SIZE..].try_into().unwrap();
+            period -= half;
+        }
+    }
+    ed25519::verify(
+        message,
+        &key,
+        signature[..ed25519::SIGNATURE_LENGTH].try_into().unwrap(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sign_and_evolve() {
+        let mut secret_key = generate(&[9; SEED_SIZE]);
+        assert_eq!(secret_key.len(), SECRET_KEY_SIZE);
+        let public_key = public_key(&secret_key);
+        for period in 0..PERIODS {
+            let signature = sign(&secret_key, b"header");
+            assert_eq!(signature.len(), SIGNATURE_SIZE);
+            assert!(verify(&public_key, period, b"header", &signature));
+            assert!(!verify(&public_key, period, b"other", &signature));
+            assert!(!verify(&public_key, (period + 1) % PERIODS, b"header", &signature));
+            assert_eq!(update(&mut secret_key, period), period + 1 < PERIODS);
+            assert_eq!(super::public_key(&secret_key), public_key);
+        }
+    }
+}
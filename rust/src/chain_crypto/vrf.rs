@@ -0,0 +1,269 @@
+//! ECVRF-ED25519-SHA512-Elligator2 of draft-irtf-cfrg-vrf-03,
+//! the VRF of the TPraos and Praos block headers
+//!
+//! A proof is `Gamma (32 bytes) || c (16 bytes) || s (32 bytes)`
+//! and the VRF output is the 64 bytes hash of the proof.
+//! Proving isn't constant time, it's meant for tooling and tests rather than block production.
+//!
+
+use cryptoxide::curve25519::{Fe, Ge, GePartial, Scalar};
+use cryptoxide::hashing::sha2::Sha512;
+use num_bigint::BigUint;
+use std::convert::TryInto;
+
+pub const SEED_SIZE: usize = 32;
+pub const PUBLIC_KEY_SIZE: usize = 32;
+pub const PROOF_SIZE: usize = 80;
+pub const OUTPUT_SIZE: usize = 64;
+
+const SUITE: u8 = 0x04;
+const CHALLENGE_SIZE: usize = 16;
+// Montgomery form of curve25519 is v^2 = u^3 + A u^2 + u
+const CURVE25519_A: [u8; 32] = [
+    0x06, 0x6d, 0x07, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0,
+];
+// order of the prime subgroup, little endian
+const L: [u8; 32] = [
+    0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x10,
+];
+
+pub fn public_key(seed: &[u8; SEED_SIZE]) -> [u8; PUBLIC_KEY_SIZE] {
+    let (x, _) = expand_seed(seed);
+    Ge::scalarmult_base(&Scalar::from_bytes(&x)).to_bytes()
+}
+
+pub fn prove(seed: &[u8; SEED_SIZE], alpha: &[u8]) -> [u8; PROOF_SIZE] {
+    let (x, nonce_prefix) = expand_seed(seed);
+    let public_key = Ge::scalarmult_base(&Scalar::from_bytes(&x)).to_bytes();
+    let h = hash_to_curve(&public_key, alpha).expect("the key of a seed is a valid point");
+    let h_bytes = h.to_bytes();
+    let gamma = mul(&h, &x);
+
+    let nonce = Sha512::new()
+        .update(&nonce_prefix)
+        .update(&h_bytes)
+        .finalize();
+    let k = Scalar::reduce_from_wide_bytes(&nonce).to_bytes();
+    let c = hash_points(
+        &h_bytes,
+        &gamma.to_bytes(),
+        &Ge::scalarmult_base(&Scalar::from_bytes(&k)).to_bytes(),
+        &mul(&h, &k).to_bytes(),
+    );
+
+    // s = k + c * x mod L
+    let s = (BigUint::from_bytes_le(&k) + BigUint::from_bytes_le(&c) * BigUint::from_bytes_le(&x))
+        % BigUint::from_bytes_le(&L);
+    let mut s_bytes = s.to_bytes_le();
+    s_bytes.resize(32, 0);
+
+    let mut proof = [0; PROOF_SIZE];
+    proof[..32].copy_from_slice(&gamma.to_bytes());
+    proof[32..48].copy_from_slice(&c);
+    proof[48..].copy_from_slice(&s_bytes);
+    proof
+}
+
+/// The VRF output of `alpha`, the hash of the proof of `prove` without computing the rest of the proof
+pub fn output(seed: &[u8; SEED_SIZE], alpha: &[u8]) -> [u8; OUTPUT_SIZE] {
+    let (x, _) = expand_seed(seed);
+    let public_key = Ge::scalarmult_base(&Scalar::from_bytes(&x)).to_bytes();
+    let h = hash_to_curve(&public_key, alpha).expect("the key of a seed is a valid point");
+    gamma_to_hash(&mul(&h, &x))
+}
+
+/// The VRF output of a valid proof of `alpha` by the key
+pub fn verify(
+    public_key: &[u8; PUBLIC_KEY_SIZE],
+    proof: &[u8; PROOF_SIZE],
+    alpha: &[u8],
+) -> Option<[u8; OUTPUT_SIZE]> {
+    let y = decode_point(public_key)?;
+    if is_small_order(&y) {
+        return None;
+    }
+    let gamma = decode_point(proof[..32].try_into().unwrap())?;
+    let mut c = [0; 32];
+    c[..CHALLENGE_SIZE].copy_from_slice(&proof[32..48]);
+    let s: [u8; 32] = proof[48..].try_into().unwrap();
+    let s_scalar = Scalar::from_bytes_canonical(&s)?;
+
+    let h = hash_to_curve(public_key, alpha)?;
+    // U = s*B - c*Y, cryptoxide decodes points negated
+    let u = GePartial::double_scalarmult_vartime(
+        &Scalar::from_bytes(&c),
+        Ge::from_bytes(public_key)?,
+        &s_scalar,
+    );
+    // V = s*H - c*Gamma
+    let v = (&mul(&h, &s) - &mul(&gamma, &c).to_cached()).to_full();
+
+    let challenge = hash_points(
+        &h.to_bytes(),
+        &proof[..32],
+        &u.to_bytes(),
+        &v.to_bytes(),
+    );
+    if challenge[..] == c[..CHALLENGE_SIZE] {
+        Some(gamma_to_hash(&gamma))
+    } else {
+        None
+    }
+}
+
+/// The VRF output of a proof, without checking the proof
+pub fn proof_to_hash(proof: &[u8; PROOF_SIZE]) -> Option<[u8; OUTPUT_SIZE]> {
+    decode_point(proof[..32].try_into().unwrap()).map(|gamma| gamma_to_hash(&gamma))
+}
+
+// the secret scalar and the nonce prefix, as for ed25519 signing
+fn expand_seed(seed: &[u8; SEED_SIZE]) -> ([u8; 32], [u8; 32]) {
+    let hash = Sha512::new().update(seed).finalize();
+    let mut x: [u8; 32] = hash[..32].try_into().unwrap();
+    x[0] &= 248;
+    x[31] &= 127;
+    x[31] |= 64;
+    (x, hash[32..].try_into().unwrap())
+}
+
+fn gamma_to_hash(gamma: &Ge) -> [u8; OUTPUT_SIZE] {
+    Sha512::new()
+        .update(&[SUITE, 0x03])
+        .update(&mul_by_cofactor(gamma).to_bytes())
+        .finalize()
+}
+
+fn hash_points(h: &[u8], gamma: &[u8], u: &[u8], v: &[u8]) -> [u8; CHALLENGE_SIZE] {
+    let hash = Sha512::new()
+        .update(&[SUITE, 0x02])
+        .update(h)
+        .update(gamma)
+        .update(u)
+        .update(v)
+        .finalize();
+    hash[..CHALLENGE_SIZE].try_into().unwrap()
+}
+
+// Elligator2 on the hash of the key and alpha, with a positive x
+fn hash_to_curve(public_key: &[u8; PUBLIC_KEY_SIZE], alpha: &[u8]) -> Option<Ge> {
+    let hash = Sha512::new()
+        .update(&[SUITE, 0x01])
+        .update(public_key)
+        .update(alpha)
+        .finalize();
+    let mut r: [u8; 32] = hash[..32].try_into().unwrap();
+    r[31] &= 0x7f;
+    let r = Fe::from_bytes(&r);
+    let a = Fe::from_bytes(&CURVE25519_A);
+
+    // u = -A / (1 + 2 r^2)
+    let u = -&(&a * &(&r.square_and_double() + &Fe::ONE).invert());
+    let u2 = u.square();
+    let e = &(&(&u2 * &u) + &u) + &(&u2 * &a);
+    let u = if is_minus_one(&legendre(&e)) {
+        &(-&u) - &a
+    } else {
+        u
+    };
+
+    // birational map to the twisted edwards y = (u - 1) / (u + 1)
+    let y = &(&u - &Fe::ONE) * &(&u + &Fe::ONE).invert();
+    decode_point(&y.to_bytes()).map(|point| mul_by_cofactor(&point))
+}
+
+// z^((p-1)/2), with (p-1)/2 = 4 * (2^252 - 3) + 2
+fn legendre(z: &Fe) -> Fe {
+    &z.pow25523().square().square() * &z.square()
+}
+
+fn is_minus_one(z: &Fe) -> bool {
+    (z + &Fe::ONE).to_bytes() == [0; 32]
+}
+
+// cryptoxide decodes the negation of the point, as needed by ed25519 verification
+fn decode_point(bytes: &[u8; 32]) -> Option<Ge> {
+    let mut y = bytes.to_owned();
+    y[31] &= 0x7f;
+    if Fe::from_bytes(&y).to_bytes() != y {
+        return None;
+    }
+    Ge::from_bytes(bytes).map(|point| (&Ge::ZERO - &point.to_cached()).to_full())
+}
+
+fn mul_by_cofactor(point: &Ge) -> Ge {
+    point.double().double().double()
+}
+
+fn is_small_order(point: &Ge) -> bool {
+    mul_by_cofactor(point).to_bytes() == Ge::ZERO.to_bytes()
+}
+
+// variable time sliding window multiplication, with a little endian scalar below 2^255.
+// cryptoxide only returns projective points from it, so the result is decoded back
+fn mul(point: &Ge, scalar: &[u8; 32]) -> Ge {
+    let product =
+        GePartial::double_scalarmult_vartime(&Scalar::from_bytes(scalar), point.clone(), &Scalar::ZERO);
+    decode_point(&product.to_bytes()).expect("the product is a valid point")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex32(s: &str) -> [u8; 32] {
+        hex::decode(s).unwrap().try_into().unwrap()
+    }
+
+    #[test]
+    fn points_decode_to_themselves() {
+        let base = Ge::scalarmult_base(&Scalar::ONE).to_bytes();
+        assert_eq!(decode_point(&base).unwrap().to_bytes(), base);
+        let point = mul(&decode_point(&base).unwrap(), &[3; 32]);
+        let expected = Ge::scalarmult_base(&Scalar::from_bytes(&[3; 32])).to_bytes();
+        assert_eq!(point.to_bytes(), expected);
+    }
+
+    // draft-irtf-cfrg-vrf-03 A.4
+    #[test]
+    fn draft_03_vectors() {
+        let vectors = [
+            (
+                "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+                "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+                "",
+                "b6b4699f87d56126c9117a7da55bd0085246f4c56dbc95d20172612e9d38e8d7ca65e573a126ed88d4e30a46f80a666854d675cf3ba81de0de043c3774f061560f55edc256a787afe701677c0f602900",
+                "5b49b554d05c0cd5a5325376b3387de59d924fd1e13ded44648ab33c21349a603f25b84ec5ed887995b33da5e3bfcb87cd2f64521c4c62cf825cffabbe5d31cc",
+            ),
+            (
+                "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+                "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+                "72",
+                "ae5b66bdf04b4c010bfe32b2fc126ead2107b697634f6f7337b9bff8785ee111200095ece87dde4dbe87343f6df3b107d91798c8a7eb1245d3bb9c5aafb093358c13e6ae1111a55717e895fd15f99f07",
+                "94f4487e1b2fec954309ef1289ecb2e15043a2461ecc7b2ae7d4470607ef82eb1cfa97d84991fe4a7bfdfd715606bc27e2967a6c557cfb5875879b671740b7d8",
+            ),
+            (
+                "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+                "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+                "af82",
+                "dfa2cba34b611cc8c833a6ea83b8eb1bb5e2ef2dd1b0c481bc42ff36ae7847f6ab52b976cfd5def172fa412defde270c8b8bdfbaae1c7ece17d9833b1bcf31064fff78ef493f820055b561ece45e1009",
+                "2031837f582cd17a9af9e0c7ef5a6540e3453ed894b62c293686ca3c1e319dde9d0aa489a4b59a9594fc2328bc3deff3c8a0929a369a72b1180a596e016b5ded",
+            ),
+        ];
+        for (seed, public, alpha, proof, output) in vectors.iter() {
+            let seed = hex32(seed);
+            let public = hex32(public);
+            let alpha = hex::decode(alpha).unwrap();
+            let proof: [u8; PROOF_SIZE] = hex::decode(proof).unwrap().try_into().unwrap();
+            let output = hex::decode(output).unwrap();
+
+            assert_eq!(public_key(&seed), public);
+            assert_eq!(prove(&seed, &alpha), proof);
+            assert_eq!(verify(&public, &proof, &alpha).unwrap().to_vec(), output);
+            assert_eq!(proof_to_hash(&proof).unwrap().to_vec(), output);
+            assert_eq!(super::output(&seed, &alpha).to_vec(), output);
+            assert!(verify(&public, &proof, b"other").is_none());
+        }
+    }
+}
@@ -1,5 +1,11 @@
 use cryptoxide::blake2b::Blake2b;
 
+pub(crate) fn blake2b160(data: &[u8]) -> [u8; 20] {
+    let mut out = [0; 20];
+    Blake2b::blake2b(&mut out, data, &[]);
+    out
+}
+
 pub(crate) fn blake2b224(data: &[u8]) -> [u8; 28] {
     let mut out = [0; 28];
     Blake2b::blake2b(&mut out, data, &[]);
@@ -0,0 +1,276 @@
+use super::*;
+use std::convert::TryFrom;
+
+// era boundaries of the public networks, times in POSIX milliseconds
+const MAINNET_SYSTEM_START: u64 = 1_506_203_091_000;
+const MAINNET_SHELLEY_EPOCH: Epoch = 208;
+const PREPROD_SYSTEM_START: u64 = 1_654_041_600_000;
+const PREPROD_SHELLEY_EPOCH: Epoch = 4;
+const PREVIEW_SYSTEM_START: u64 = 1_666_656_000_000;
+const BYRON_SLOT_LENGTH: u64 = 20_000;
+const BYRON_EPOCH_LENGTH: u32 = 21_600;
+const SHELLEY_SLOT_LENGTH: u64 = 1_000;
+const SHELLEY_EPOCH_LENGTH: u32 = 432_000;
+const PREVIEW_EPOCH_LENGTH: u32 = 86_400;
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct EraSummary {
+    start_slot: u64,
+    start_epoch: u64,
+    start_time: u64,
+    slot_length: u64,
+    epoch_length: u64,
+}
+
+impl EraSummary {
+    fn slot_to_time(&self, slot: u64) -> Option<u64> {
+        let millis = (slot - self.start_slot) as u128 * self.slot_length as u128;
+        u64::try_from(self.start_time as u128 + millis).ok()
+    }
+
+    fn slot_to_epoch(&self, slot: u64) -> u64 {
+        self.start_epoch + (slot - self.start_slot) / self.epoch_length
+    }
+
+    fn epoch_to_slot(&self, epoch: u64) -> Option<u64> {
+        let slots = (epoch - self.start_epoch) as u128 * self.epoch_length as u128;
+        u64::try_from(self.start_slot as u128 + slots).ok()
+    }
+}
+
+/// Slot lengths and epoch lengths of the eras of a network,
+/// used to convert between slots, epochs and POSIX time in milliseconds.
+/// Each era starts at an epoch boundary and the last one never ends.
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct EraHistory {
+    eras: Vec<EraSummary>,
+}
+
+#[wasm_bindgen]
+impl EraHistory {
+    /// A network with a single era starting at slot 0
+    pub fn new(
+        system_start_millis: &BigNum,
+        slot_length_millis: &BigNum,
+        epoch_length: u32,
+    ) -> Result<EraHistory, JsError> {
+        check_era_lengths(slot_length_millis, epoch_length)?;
+        Ok(Self {
+            eras: vec![EraSummary {
+                start_slot: 0,
+                start_epoch: 0,
+                start_time: system_start_millis.0,
+                slot_length: slot_length_millis.0,
+                epoch_length: epoch_length as u64,
+            }],
+        })
+    }
+
+    /// Adds an era starting at the first slot of `start_epoch`, after the current last era
+    pub fn add_era(
+        &mut self,
+        start_epoch: Epoch,
+        slot_length_millis: &BigNum,
+        epoch_length: u32,
+    ) -> Result<(), JsError> {
+        check_era_lengths(slot_length_millis, epoch_length)?;
+        let last = self.last_era();
+        if start_epoch as u64 <= last.start_epoch {
+            return Err(JsError::from_str(&format!(
+                "Era must start after epoch {}",
+                last.start_epoch
+            )));
+        }
+        let start_slot = last
+            .epoch_to_slot(start_epoch as u64)
+            .ok_or_else(|| JsError::from_str("Era start slot overflows"))?;
+        let start_time = last
+            .slot_to_time(start_slot)
+            .ok_or_else(|| JsError::from_str("Era start time overflows"))?;
+        self.eras.push(EraSummary {
+            start_slot,
+            start_epoch: start_epoch as u64,
+            start_time,
+            slot_length: slot_length_millis.0,
+            epoch_length: epoch_length as u64,
+        });
+        Ok(())
+    }
+
+    /// Byron until epoch 208, then 1 second slots and 5 day epochs
+    pub fn mainnet() -> EraHistory {
+        Self::with_byron_era(MAINNET_SYSTEM_START, MAINNET_SHELLEY_EPOCH, SHELLEY_EPOCH_LENGTH)
+    }
+
+    /// Byron until epoch 4, then 1 second slots and 5 day epochs
+    pub fn testnet_preprod() -> EraHistory {
+        Self::with_byron_era(PREPROD_SYSTEM_START, PREPROD_SHELLEY_EPOCH, SHELLEY_EPOCH_LENGTH)
+    }
+
+    /// 1 second slots and 1 day epochs from the start
+    pub fn testnet_preview() -> EraHistory {
+        Self {
+            eras: vec![EraSummary {
+                start_slot: 0,
+                start_epoch: 0,
+                start_time: PREVIEW_SYSTEM_START,
+                slot_length: SHELLEY_SLOT_LENGTH,
+                epoch_length: PREVIEW_EPOCH_LENGTH as u64,
+            }],
+        }
+    }
+
+    /// The preset of the network with the protocol magic of `network_info`, if it's a known network
+    pub fn from_network_info(network_info: &NetworkInfo) -> Option<EraHistory> {
+        match network_info.protocol_magic() {
+            magic if magic == NetworkInfo::mainnet().protocol_magic() => Some(Self::mainnet()),
+            magic if magic == NetworkInfo::testnet_preprod().protocol_magic() => {
+                Some(Self::testnet_preprod())
+            }
+            magic if magic == NetworkInfo::testnet_preview().protocol_magic() => {
+                Some(Self::testnet_preview())
+            }
+            _ => None,
+        }
+    }
+
+    /// A network starting with a Byron era when `byron` is passed and `shelley_start_epoch` isn't 0,
+    /// otherwise with the Shelley parameters from the Shelley system start.
+    /// The later eras don't change slot and epoch lengths.
+    pub fn from_genesis(
+        byron: Option<ByronGenesis>,
+        shelley: &ShelleyGenesis,
+        shelley_start_epoch: Epoch,
+    ) -> Result<EraHistory, JsError> {
+        let shelley_slot_length = BigNum(shelley.slot_length_millis);
+        match byron {
+            Some(byron) if shelley_start_epoch > 0 => {
+                let start = (byron.start_time as u128) * 1000;
+                let start = u64::try_from(start)
+                    .map_err(|_| JsError::from_str("Byron start time overflows"))?;
+                let mut history = Self::new(
+                    &BigNum(start),
+                    &BigNum(byron.slot_duration),
+                    byron.epoch_length(),
+                )?;
+                history.add_era(shelley_start_epoch, &shelley_slot_length, shelley.epoch_length)?;
+                Ok(history)
+            }
+            _ => Self::new(
+                &BigNum(shelley.system_start_millis),
+                &shelley_slot_length,
+                shelley.epoch_length,
+            ),
+        }
+    }
+
+    /// POSIX time in milliseconds of the start of the slot
+    pub fn slot_to_posix_millis(&self, slot: &SlotBigNum) -> Result<BigNum, JsError> {
+        self.era_of_slot(slot.0)
+            .slot_to_time(slot.0)
+            .map(BigNum)
+            .ok_or_else(|| JsError::from_str("Slot time overflows"))
+    }
+
+    /// The slot containing the POSIX time in milliseconds
+    pub fn posix_millis_to_slot(&self, posix_millis: &BigNum) -> Result<SlotBigNum, JsError> {
+        let era = self
+            .eras
+            .iter()
+            .rev()
+            .find(|era| era.start_time <= posix_millis.0)
+            .ok_or_else(|| {
+                JsError::from_str(&format!(
+                    "Time {} is before the system start {}",
+                    posix_millis.0, self.eras[0].start_time
+                ))
+            })?;
+        Ok(BigNum(
+            era.start_slot + (posix_millis.0 - era.start_time) / era.slot_length,
+        ))
+    }
+
+    pub fn slot_to_epoch(&self, slot: &SlotBigNum) -> Result<Epoch, JsError> {
+        let epoch = self.era_of_slot(slot.0).slot_to_epoch(slot.0);
+        Epoch::try_from(epoch).map_err(|_| JsError::from_str("Epoch overflows"))
+    }
+
+    pub fn epoch_first_slot(&self, epoch: Epoch) -> Result<SlotBigNum, JsError> {
+        self.era_of_epoch(epoch as u64)
+            .epoch_to_slot(epoch as u64)
+            .map(BigNum)
+            .ok_or_else(|| JsError::from_str("Epoch start slot overflows"))
+    }
+
+    pub fn slot_length_millis(&self, slot: &SlotBigNum) -> BigNum {
+        BigNum(self.era_of_slot(slot.0).slot_length)
+    }
+
+    pub fn epoch_length(&self, epoch: Epoch) -> u32 {
+        self.era_of_epoch(epoch as u64).epoch_length as u32
+    }
+}
+
+impl EraHistory {
+    fn with_byron_era(system_start: u64, shelley_epoch: Epoch, epoch_length: u32) -> Self {
+        let byron = EraSummary {
+            start_slot: 0,
+            start_epoch: 0,
+            start_time: system_start,
+            slot_length: BYRON_SLOT_LENGTH,
+            epoch_length: BYRON_EPOCH_LENGTH as u64,
+        };
+        let start_slot = shelley_epoch as u64 * BYRON_EPOCH_LENGTH as u64;
+        let shelley = EraSummary {
+            start_slot,
+            start_epoch: shelley_epoch as u64,
+            start_time: system_start + start_slot * BYRON_SLOT_LENGTH,
+            slot_length: SHELLEY_SLOT_LENGTH,
+            epoch_length: epoch_length as u64,
+        };
+        Self {
+            eras: vec![byron, shelley],
+        }
+    }
+
+    fn last_era(&self) -> &EraSummary {
+        // there is always at least one era
+        &self.eras[self.eras.len() - 1]
+    }
+
+    fn era_of_slot(&self, slot: u64) -> &EraSummary {
+        self.eras
+            .iter()
+            .rev()
+            .find(|era| era.start_slot <= slot)
+            .unwrap_or(&self.eras[0])
+    }
+
+    fn era_of_epoch(&self, epoch: u64) -> &EraSummary {
+        self.eras
+            .iter()
+            .rev()
+            .find(|era| era.start_epoch <= epoch)
+            .unwrap_or(&self.eras[0])
+    }
+
+    /// The first slot starting at or after the POSIX time
+    pub(crate) fn posix_millis_to_slot_ceil(&self, posix_millis: &BigNum) -> Result<SlotBigNum, JsError> {
+        let slot = self.posix_millis_to_slot(posix_millis)?;
+        if self.slot_to_posix_millis(&slot)? < *posix_millis {
+            Ok(BigNum(slot.0 + 1))
+        } else {
+            Ok(slot)
+        }
+    }
+}
+
+fn check_era_lengths(slot_length_millis: &BigNum, epoch_length: u32) -> Result<(), JsError> {
+    if slot_length_millis.0 == 0 || epoch_length == 0 {
+        return Err(JsError::from_str(
+            "Slot length and epoch length must be positive",
+        ));
+    }
+    Ok(())
+}
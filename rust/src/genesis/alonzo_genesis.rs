@@ -0,0 +1,162 @@
+use super::json::*;
+use crate::*;
+use serde_json::Value as JsonValue;
+
+// lovelacePerUTxOWord was replaced by coinsPerUTxOByte, a word being 8 bytes
+const UTXO_WORD_SIZE: u64 = 8;
+
+/// Alonzo genesis file, the `alonzo-genesis.json` of a node configuration
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct AlonzoGenesis {
+    pub(crate) lovelace_per_utxo_word: Coin,
+    pub(crate) execution_prices: ExUnitPrices,
+    pub(crate) max_tx_ex_units: ExUnits,
+    pub(crate) max_block_ex_units: ExUnits,
+    pub(crate) max_value_size: u32,
+    pub(crate) collateral_percentage: u32,
+    pub(crate) max_collateral_inputs: u32,
+    pub(crate) cost_models: Costmdls,
+}
+
+#[wasm_bindgen]
+impl AlonzoGenesis {
+    pub fn from_json(json: &str) -> Result<AlonzoGenesis, JsError> {
+        let genesis = parse_object(json, "Alonzo")?;
+        let mut cost_models = Costmdls::new();
+        if let Some(models) = optional(&genesis, &["costModels"]) {
+            for (language, model) in as_object(models, "costModels")? {
+                let language = match language.as_str() {
+                    "PlutusV1" | "PlutusScriptV1" => Language::new_plutus_v1(),
+                    "PlutusV2" | "PlutusScriptV2" => Language::new_plutus_v2(),
+                    "PlutusV3" | "PlutusScriptV3" => Language::new_plutus_v3(),
+                    other => {
+                        return Err(JsError::from_str(&format!(
+                            "Unknown genesis cost model language \"{}\"",
+                            other
+                        )))
+                    }
+                };
+                cost_models.insert(&language, &parse_cost_model(model, "costModels")?);
+            }
+        }
+
+        Ok(Self {
+            lovelace_per_utxo_word: as_coin(
+                required(&genesis, &["lovelacePerUTxOWord", "coinsPerUTxOWord"])?,
+                "lovelacePerUTxOWord",
+            )?,
+            execution_prices: parse_execution_prices(as_object(
+                required(&genesis, &["executionPrices"])?,
+                "executionPrices",
+            )?)?,
+            max_tx_ex_units: parse_ex_units(required(&genesis, &["maxTxExUnits"])?, "maxTxExUnits")?,
+            max_block_ex_units: parse_ex_units(
+                required(&genesis, &["maxBlockExUnits"])?,
+                "maxBlockExUnits",
+            )?,
+            max_value_size: as_u32(required(&genesis, &["maxValueSize"])?, "maxValueSize")?,
+            collateral_percentage: as_u32(
+                required(&genesis, &["collateralPercentage"])?,
+                "collateralPercentage",
+            )?,
+            max_collateral_inputs: as_u32(
+                required(&genesis, &["maxCollateralInputs"])?,
+                "maxCollateralInputs",
+            )?,
+            cost_models,
+        })
+    }
+
+    pub fn lovelace_per_utxo_word(&self) -> Coin {
+        self.lovelace_per_utxo_word
+    }
+
+    /// The Babbage `coinsPerUTxOByte` equivalent of `lovelacePerUTxOWord`
+    pub fn coins_per_utxo_byte(&self) -> Coin {
+        BigNum(self.lovelace_per_utxo_word.0 / UTXO_WORD_SIZE)
+    }
+
+    pub fn execution_prices(&self) -> ExUnitPrices {
+        self.execution_prices.clone()
+    }
+
+    pub fn max_tx_ex_units(&self) -> ExUnits {
+        self.max_tx_ex_units.clone()
+    }
+
+    pub fn max_block_ex_units(&self) -> ExUnits {
+        self.max_block_ex_units.clone()
+    }
+
+    pub fn max_value_size(&self) -> u32 {
+        self.max_value_size
+    }
+
+    pub fn collateral_percentage(&self) -> u32 {
+        self.collateral_percentage
+    }
+
+    pub fn max_collateral_inputs(&self) -> u32 {
+        self.max_collateral_inputs
+    }
+
+    pub fn cost_models(&self) -> Costmdls {
+        self.cost_models.clone()
+    }
+
+    /// The protocol parameters introduced by Alonzo
+    pub fn protocol_params(&self) -> ProtocolParamUpdate {
+        let mut update = ProtocolParamUpdate::new();
+        update.set_ada_per_utxo_byte(&self.coins_per_utxo_byte());
+        update.set_execution_costs(&self.execution_prices);
+        update.set_max_tx_ex_units(&self.max_tx_ex_units);
+        update.set_max_block_ex_units(&self.max_block_ex_units);
+        update.set_max_value_size(self.max_value_size);
+        update.set_collateral_percentage(self.collateral_percentage);
+        update.set_max_collateral_inputs(self.max_collateral_inputs);
+        if self.cost_models.len() > 0 {
+            update.set_cost_models(&self.cost_models);
+        }
+        update
+    }
+}
+
+/// A cost model is either an array of costs or a map of named costs.
+/// The named costs are ordered by name, the order the ledger uses for Plutus V1.
+pub(super) fn parse_cost_model(value: &JsonValue, key: &str) -> Result<CostModel, JsError> {
+    let costs: Vec<&JsonValue> = match value {
+        JsonValue::Array(costs) => costs.iter().collect(),
+        JsonValue::Object(costs) => {
+            let mut named: Vec<_> = costs.iter().collect();
+            named.sort_by(|a, b| a.0.cmp(b.0));
+            named.into_iter().map(|(_, cost)| cost).collect()
+        }
+        _ => return Err(JsError::from_str(&format!(
+            "Genesis field \"{}\" must be an array or an object of costs",
+            key
+        ))),
+    };
+    let costs = costs
+        .into_iter()
+        .map(|cost| as_i128(cost, key))
+        .collect::<Result<Vec<i128>, JsError>>()?;
+    Ok(CostModel::from(costs))
+}
+
+// {"prSteps": {"numerator", "denominator"}, "prMem": ...} or {"priceSteps": 7.21e-5, "priceMemory": 0.0577}
+fn parse_execution_prices(prices: &JsonObject) -> Result<ExUnitPrices, JsError> {
+    Ok(ExUnitPrices::new(
+        &as_unit_interval(required(prices, &["prMem", "priceMemory"])?, "prMem")?,
+        &as_unit_interval(required(prices, &["prSteps", "priceSteps"])?, "prSteps")?,
+    ))
+}
+
+// {"exUnitsMem": 10000000, "exUnitsSteps": 10000000000} or {"memory", "steps"}
+fn parse_ex_units(value: &JsonValue, key: &str) -> Result<ExUnits, JsError> {
+    let units = as_object(value, key)?;
+    Ok(ExUnits::new(
+        &as_coin(required(units, &["exUnitsMem", "memory"])?, key)?,
+        &as_coin(required(units, &["exUnitsSteps", "steps"])?, key)?,
+    ))
+}
@@ -0,0 +1,170 @@
+use super::json::*;
+use crate::legacy_address::ExtendedAddr;
+use crate::*;
+use std::convert::TryFrom;
+
+// Byron epochs are 10k slots long
+const EPOCH_LENGTH_PER_SECURITY_PARAM: u32 = 10;
+
+/// Initial balance of a Byron genesis file
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct ByronGenesisBalance {
+    pub(crate) address: ByronAddress,
+    pub(crate) amount: Coin,
+}
+
+#[wasm_bindgen]
+impl ByronGenesisBalance {
+    pub fn address(&self) -> ByronAddress {
+        self.address.clone()
+    }
+
+    pub fn amount(&self) -> Coin {
+        self.amount
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct ByronGenesisBalances(pub(crate) Vec<ByronGenesisBalance>);
+
+#[wasm_bindgen]
+impl ByronGenesisBalances {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn get(&self, index: usize) -> ByronGenesisBalance {
+        self.0[index].clone()
+    }
+}
+
+/// Byron genesis file, the `byron-genesis.json` of a node configuration
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct ByronGenesis {
+    pub(crate) protocol_magic: u32,
+    pub(crate) start_time: u64,
+    pub(crate) security_param: u32,
+    pub(crate) slot_duration: u64,
+    pub(crate) avvm_balances: Vec<ByronGenesisBalance>,
+    pub(crate) non_avvm_balances: Vec<ByronGenesisBalance>,
+}
+
+#[wasm_bindgen]
+impl ByronGenesis {
+    pub fn from_json(json: &str) -> Result<ByronGenesis, JsError> {
+        let genesis = parse_object(json, "Byron")?;
+        let protocol_consts = as_object(required(&genesis, &["protocolConsts"])?, "protocolConsts")?;
+        let protocol_magic = as_u32(required(protocol_consts, &["protocolMagic"])?, "protocolMagic")?;
+        let block_version_data =
+            as_object(required(&genesis, &["blockVersionData"])?, "blockVersionData")?;
+
+        // mainnet addresses don't carry the protocol magic
+        let address_magic = if protocol_magic == NetworkInfo::mainnet().protocol_magic() {
+            None
+        } else {
+            Some(protocol_magic)
+        };
+        let mut avvm_balances = Vec::new();
+        if let Some(distribution) = optional(&genesis, &["avvmDistr"]) {
+            for (key, amount) in as_object(distribution, "avvmDistr")? {
+                avvm_balances.push(ByronGenesisBalance {
+                    address: redeem_address(key, address_magic)?,
+                    amount: as_coin(amount, "avvmDistr")?,
+                });
+            }
+        }
+        let mut non_avvm_balances = Vec::new();
+        if let Some(balances) = optional(&genesis, &["nonAvvmBalances"]) {
+            for (address, amount) in as_object(balances, "nonAvvmBalances")? {
+                non_avvm_balances.push(ByronGenesisBalance {
+                    address: ByronAddress::from_base58(address)?,
+                    amount: as_coin(amount, "nonAvvmBalances")?,
+                });
+            }
+        }
+
+        Ok(Self {
+            protocol_magic,
+            start_time: as_u64(required(&genesis, &["startTime"])?, "startTime")?,
+            security_param: as_u32(required(protocol_consts, &["k"])?, "k")?,
+            slot_duration: as_u64(
+                required(block_version_data, &["slotDuration"])?,
+                "slotDuration",
+            )?,
+            avvm_balances,
+            non_avvm_balances,
+        })
+    }
+
+    pub fn protocol_magic(&self) -> u32 {
+        self.protocol_magic
+    }
+
+    /// POSIX time in seconds of the first slot
+    pub fn start_time(&self) -> BigNum {
+        BigNum(self.start_time)
+    }
+
+    /// The k parameter of the protocol
+    pub fn security_param(&self) -> u32 {
+        self.security_param
+    }
+
+    pub fn slot_duration_millis(&self) -> BigNum {
+        BigNum(self.slot_duration)
+    }
+
+    /// Slots per epoch, 10 * k
+    pub fn epoch_length(&self) -> u32 {
+        self.security_param
+            .saturating_mul(EPOCH_LENGTH_PER_SECURITY_PARAM)
+    }
+
+    /// Redeem addresses of the AVVM vending certificates
+    pub fn avvm_balances(&self) -> ByronGenesisBalances {
+        ByronGenesisBalances(self.avvm_balances.clone())
+    }
+
+    pub fn non_avvm_balances(&self) -> ByronGenesisBalances {
+        ByronGenesisBalances(self.non_avvm_balances.clone())
+    }
+
+    /// The genesis UTxO of both kinds of balances.
+    /// Each output is spent by the hash of its address with index 0.
+    pub fn initial_utxos(&self) -> TransactionUnspentOutputs {
+        let mut utxos = TransactionUnspentOutputs::new();
+        for balance in self.avvm_balances.iter().chain(&self.non_avvm_balances) {
+            let address = balance.address.to_address();
+            let output = TransactionOutput::new(&address, &Value::new(&balance.amount));
+            utxos.add(&TransactionUnspentOutput::new(
+                &genesis_utxo_input(&address),
+                &output,
+            ));
+        }
+        utxos
+    }
+}
+
+/// The genesis UTxO of an address is identified by the hash of the address bytes
+pub(super) fn genesis_utxo_input(address: &Address) -> TransactionInput {
+    TransactionInput::new(
+        &TransactionHash::from(blake2b256(&address.to_bytes())),
+        0,
+    )
+}
+
+// AVVM keys are ed25519 public keys in base64url
+fn redeem_address(key: &str, protocol_magic: Option<u32>) -> Result<ByronAddress, JsError> {
+    let key = base64_decode(&key.replace('-', "+").replace('_', "/"))
+        .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+        .ok_or_else(|| {
+            JsError::from_str(&format!(
+                "AVVM key \"{}\" is not a base64url ed25519 public key",
+                key
+            ))
+        })?;
+    Ok(ByronAddress(ExtendedAddr::new_redeem(&key, protocol_magic)))
+}
@@ -0,0 +1,202 @@
+use super::alonzo_genesis::parse_cost_model;
+use super::json::*;
+use crate::*;
+
+/// Conway genesis file, the `conway-genesis.json` of a node configuration
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct ConwayGenesis {
+    pub(crate) pool_voting_thresholds: PoolVotingThresholds,
+    pub(crate) drep_voting_thresholds: DRepVotingThresholds,
+    pub(crate) committee_min_size: u32,
+    pub(crate) committee_max_term_length: Epoch,
+    pub(crate) gov_action_lifetime: Epoch,
+    pub(crate) gov_action_deposit: Coin,
+    pub(crate) drep_deposit: Coin,
+    pub(crate) drep_activity: Epoch,
+    pub(crate) min_fee_ref_script_cost_per_byte: UnitInterval,
+    pub(crate) plutus_v3_cost_model: Option<CostModel>,
+    pub(crate) constitution: Option<Constitution>,
+    pub(crate) committee: Option<Committee>,
+}
+
+#[wasm_bindgen]
+impl ConwayGenesis {
+    pub fn from_json(json: &str) -> Result<ConwayGenesis, JsError> {
+        let genesis = parse_object(json, "Conway")?;
+        let pool = as_object(
+            required(&genesis, &["poolVotingThresholds"])?,
+            "poolVotingThresholds",
+        )?;
+        let threshold = |thresholds: &JsonObject, key: &str| {
+            as_unit_interval(required(thresholds, &[key])?, key)
+        };
+        let pool_voting_thresholds = PoolVotingThresholds::new(
+            &threshold(pool, "motionNoConfidence")?,
+            &threshold(pool, "committeeNormal")?,
+            &threshold(pool, "committeeNoConfidence")?,
+            &threshold(pool, "hardForkInitiation")?,
+            &threshold(pool, "ppSecurityGroup")?,
+        );
+        let drep = as_object(
+            required(&genesis, &["dRepVotingThresholds"])?,
+            "dRepVotingThresholds",
+        )?;
+        let drep_voting_thresholds = DRepVotingThresholds::new(
+            &threshold(drep, "motionNoConfidence")?,
+            &threshold(drep, "committeeNormal")?,
+            &threshold(drep, "committeeNoConfidence")?,
+            &threshold(drep, "updateToConstitution")?,
+            &threshold(drep, "hardForkInitiation")?,
+            &threshold(drep, "ppNetworkGroup")?,
+            &threshold(drep, "ppEconomicGroup")?,
+            &threshold(drep, "ppTechnicalGroup")?,
+            &threshold(drep, "ppGovGroup")?,
+            &threshold(drep, "treasuryWithdrawal")?,
+        );
+
+        Ok(Self {
+            pool_voting_thresholds,
+            drep_voting_thresholds,
+            committee_min_size: as_u32(
+                required(&genesis, &["committeeMinSize"])?,
+                "committeeMinSize",
+            )?,
+            committee_max_term_length: as_u32(
+                required(&genesis, &["committeeMaxTermLength"])?,
+                "committeeMaxTermLength",
+            )?,
+            gov_action_lifetime: as_u32(
+                required(&genesis, &["govActionLifetime"])?,
+                "govActionLifetime",
+            )?,
+            gov_action_deposit: as_coin(
+                required(&genesis, &["govActionDeposit"])?,
+                "govActionDeposit",
+            )?,
+            drep_deposit: as_coin(required(&genesis, &["dRepDeposit"])?, "dRepDeposit")?,
+            drep_activity: as_u32(required(&genesis, &["dRepActivity"])?, "dRepActivity")?,
+            min_fee_ref_script_cost_per_byte: as_unit_interval(
+                required(&genesis, &["minFeeRefScriptCostPerByte"])?,
+                "minFeeRefScriptCostPerByte",
+            )?,
+            plutus_v3_cost_model: optional(&genesis, &["plutusV3CostModel"])
+                .map(|model| parse_cost_model(model, "plutusV3CostModel"))
+                .transpose()?,
+            constitution: optional(&genesis, &["constitution"])
+                .map(|constitution| parse_constitution(as_object(constitution, "constitution")?))
+                .transpose()?,
+            committee: optional(&genesis, &["committee"])
+                .map(|committee| parse_committee(as_object(committee, "committee")?))
+                .transpose()?,
+        })
+    }
+
+    pub fn pool_voting_thresholds(&self) -> PoolVotingThresholds {
+        self.pool_voting_thresholds.clone()
+    }
+
+    pub fn drep_voting_thresholds(&self) -> DRepVotingThresholds {
+        self.drep_voting_thresholds.clone()
+    }
+
+    pub fn committee_min_size(&self) -> u32 {
+        self.committee_min_size
+    }
+
+    pub fn committee_max_term_length(&self) -> Epoch {
+        self.committee_max_term_length
+    }
+
+    pub fn gov_action_lifetime(&self) -> Epoch {
+        self.gov_action_lifetime
+    }
+
+    pub fn gov_action_deposit(&self) -> Coin {
+        self.gov_action_deposit
+    }
+
+    pub fn drep_deposit(&self) -> Coin {
+        self.drep_deposit
+    }
+
+    pub fn drep_activity(&self) -> Epoch {
+        self.drep_activity
+    }
+
+    pub fn min_fee_ref_script_cost_per_byte(&self) -> UnitInterval {
+        self.min_fee_ref_script_cost_per_byte.clone()
+    }
+
+    pub fn plutus_v3_cost_model(&self) -> Option<CostModel> {
+        self.plutus_v3_cost_model.clone()
+    }
+
+    pub fn constitution(&self) -> Option<Constitution> {
+        self.constitution.clone()
+    }
+
+    pub fn committee(&self) -> Option<Committee> {
+        self.committee.clone()
+    }
+
+    /// The protocol parameters introduced by Conway
+    pub fn protocol_params(&self) -> ProtocolParamUpdate {
+        let mut update = ProtocolParamUpdate::new();
+        update.set_pool_voting_thresholds(&self.pool_voting_thresholds);
+        update.set_drep_voting_thresholds(&self.drep_voting_thresholds);
+        update.set_min_committee_size(self.committee_min_size);
+        update.set_committee_term_limit(self.committee_max_term_length);
+        update.set_governance_action_validity_period(self.gov_action_lifetime);
+        update.set_governance_action_deposit(&self.gov_action_deposit);
+        update.set_drep_deposit(&self.drep_deposit);
+        update.set_drep_inactivity_period(self.drep_activity);
+        update.set_ref_script_coins_per_byte(&self.min_fee_ref_script_cost_per_byte);
+        if let Some(model) = &self.plutus_v3_cost_model {
+            let mut cost_models = Costmdls::new();
+            cost_models.insert(&Language::new_plutus_v3(), model);
+            update.set_cost_models(&cost_models);
+        }
+        update
+    }
+}
+
+// {"anchor": {"url": "...", "dataHash": "<hex>"}, "script": "<hex>"}
+fn parse_constitution(constitution: &JsonObject) -> Result<Constitution, JsError> {
+    let anchor = as_object(required(constitution, &["anchor"])?, "anchor")?;
+    let anchor = Anchor::new(
+        &URL::new(as_str(required(anchor, &["url"])?, "url")?.to_string())?,
+        &AnchorDataHash::from_hex(as_str(required(anchor, &["dataHash"])?, "dataHash")?)?,
+    );
+    match optional(constitution, &["script"]) {
+        Some(script) => Ok(Constitution::new_with_script_hash(
+            &anchor,
+            &ScriptHash::from_hex(as_str(script, "script")?)?,
+        )),
+        None => Ok(Constitution::new(&anchor)),
+    }
+}
+
+// {"members": {"keyHash-<hex>": <epoch>, "scriptHash-<hex>": <epoch>}, "threshold": 0.67}
+fn parse_committee(committee: &JsonObject) -> Result<Committee, JsError> {
+    let mut result = Committee::new(&as_unit_interval(
+        required(committee, &["threshold"])?,
+        "threshold",
+    )?);
+    if let Some(members) = optional(committee, &["members"]) {
+        for (member, epoch) in as_object(members, "members")? {
+            let credential = if let Some(hash) = member.strip_prefix("keyHash-") {
+                Credential::from_keyhash(&Ed25519KeyHash::from_hex(hash)?)
+            } else if let Some(hash) = member.strip_prefix("scriptHash-") {
+                Credential::from_scripthash(&ScriptHash::from_hex(hash)?)
+            } else {
+                return Err(JsError::from_str(&format!(
+                    "Unknown committee member \"{}\"",
+                    member
+                )));
+            };
+            result.add_member(&credential, as_u32(epoch, "members")?);
+        }
+    }
+    Ok(result)
+}
@@ -0,0 +1,210 @@
+use crate::*;
+use num_integer::Integer;
+use serde_json::{Map, Value};
+use std::convert::TryFrom;
+
+pub(super) type JsonObject = Map<String, Value>;
+
+pub(super) fn parse_object(json: &str, era: &str) -> Result<JsonObject, JsError> {
+    match serde_json::from_str(json) {
+        Ok(Value::Object(object)) => Ok(object),
+        Ok(_) => Err(JsError::from_str(&format!(
+            "{} genesis must be a JSON object",
+            era
+        ))),
+        Err(e) => Err(JsError::from_str(&format!(
+            "Invalid {} genesis JSON: {}",
+            era, e
+        ))),
+    }
+}
+
+/// Value of the first key that is present and not null
+pub(super) fn optional<'a>(object: &'a JsonObject, keys: &[&str]) -> Option<&'a Value> {
+    keys.iter()
+        .filter_map(|key| object.get(*key))
+        .find(|value| !value.is_null())
+}
+
+pub(super) fn required<'a>(object: &'a JsonObject, keys: &[&str]) -> Result<&'a Value, JsError> {
+    optional(object, keys)
+        .ok_or_else(|| JsError::from_str(&format!("Missing genesis field \"{}\"", keys[0])))
+}
+
+fn invalid(key: &str, expected: &str) -> JsError {
+    JsError::from_str(&format!(
+        "Genesis field \"{}\" must be {}",
+        key, expected
+    ))
+}
+
+pub(super) fn as_object<'a>(value: &'a Value, key: &str) -> Result<&'a JsonObject, JsError> {
+    value.as_object().ok_or_else(|| invalid(key, "an object"))
+}
+
+pub(super) fn as_array<'a>(value: &'a Value, key: &str) -> Result<&'a Vec<Value>, JsError> {
+    value.as_array().ok_or_else(|| invalid(key, "an array"))
+}
+
+pub(super) fn as_str<'a>(value: &'a Value, key: &str) -> Result<&'a str, JsError> {
+    value.as_str().ok_or_else(|| invalid(key, "a string"))
+}
+
+/// Byron genesis files keep most numbers as strings, so both forms are accepted
+pub(super) fn as_u64(value: &Value, key: &str) -> Result<u64, JsError> {
+    let number = match value {
+        Value::Number(number) => number.as_u64(),
+        Value::String(text) => text.parse().ok(),
+        _ => None,
+    };
+    number.ok_or_else(|| invalid(key, "an unsigned integer"))
+}
+
+pub(super) fn as_u32(value: &Value, key: &str) -> Result<u32, JsError> {
+    u32::try_from(as_u64(value, key)?).map_err(|_| invalid(key, "a 32 bit unsigned integer"))
+}
+
+pub(super) fn as_i128(value: &Value, key: &str) -> Result<i128, JsError> {
+    let text = match value {
+        Value::Number(number) => number.to_string(),
+        Value::String(text) => text.clone(),
+        _ => return Err(invalid(key, "an integer")),
+    };
+    text.parse().map_err(|_| invalid(key, "an integer"))
+}
+
+pub(super) fn as_coin(value: &Value, key: &str) -> Result<Coin, JsError> {
+    as_u64(value, key).map(BigNum)
+}
+
+/// Accepts a JSON number like `0.05` or `7.21e-5` and a `{"numerator", "denominator"}` object
+pub(super) fn as_unit_interval(value: &Value, key: &str) -> Result<UnitInterval, JsError> {
+    let (numerator, denominator) = as_rational(value, key)?;
+    let gcd = numerator.gcd(&denominator);
+    match (
+        u64::try_from(numerator / gcd),
+        u64::try_from(denominator / gcd),
+    ) {
+        (Ok(numerator), Ok(denominator)) => {
+            Ok(UnitInterval::new(&BigNum(numerator), &BigNum(denominator)))
+        }
+        _ => Err(invalid(key, "a rational with 64 bit numerator and denominator")),
+    }
+}
+
+fn as_rational(value: &Value, key: &str) -> Result<(u128, u128), JsError> {
+    let rational = match value {
+        Value::Number(number) => decimal_to_rational(&number.to_string()),
+        Value::Object(object) => {
+            let numerator = as_u64(required(object, &["numerator"])?, key)?;
+            let denominator = as_u64(required(object, &["denominator"])?, key)?;
+            Some((numerator as u128, denominator as u128))
+        }
+        _ => None,
+    };
+    match rational {
+        Some((numerator, denominator)) if denominator != 0 => Ok((numerator, denominator)),
+        _ => Err(invalid(key, "a non-negative rational number")),
+    }
+}
+
+fn decimal_to_rational(text: &str) -> Option<(u128, u128)> {
+    let (mantissa, exponent) = match text.find(['e', 'E']) {
+        Some(index) => (&text[..index], text[index + 1..].parse::<i32>().ok()?),
+        None => (text, 0),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = format!("{}{}", integer, fraction);
+    if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let mut numerator: u128 = digits.parse().ok()?;
+    let mut denominator: u128 = 10u128.checked_pow(fraction.len() as u32)?;
+    if exponent >= 0 {
+        numerator = numerator.checked_mul(10u128.checked_pow(exponent as u32)?)?;
+    } else {
+        denominator = denominator.checked_mul(10u128.checked_pow(exponent.unsigned_abs())?)?;
+    }
+    Some((numerator, denominator))
+}
+
+/// Converts a duration in seconds (possibly fractional, like a Shelley `slotLength` of 0.2)
+pub(super) fn as_millis(value: &Value, key: &str) -> Result<u64, JsError> {
+    let (numerator, denominator) = as_rational(value, key)?;
+    let millis = numerator.checked_mul(1000);
+    match millis {
+        Some(millis) if millis % denominator == 0 => {
+            u64::try_from(millis / denominator).map_err(|_| invalid(key, "a smaller duration"))
+        }
+        _ => Err(invalid(key, "a whole number of milliseconds")),
+    }
+}
+
+/// Parses an RFC 3339 UTC time like `2017-09-23T21:44:51Z` into POSIX milliseconds
+pub(super) fn rfc3339_to_posix_millis(text: &str, key: &str) -> Result<u64, JsError> {
+    parse_rfc3339(text).ok_or_else(|| invalid(key, "an RFC 3339 time after 1970"))
+}
+
+fn parse_rfc3339(text: &str) -> Option<u64> {
+    let bytes = text.as_bytes();
+    if bytes.len() < 20 || bytes[4] != b'-' || bytes[7] != b'-' || bytes[13] != b':' || bytes[16] != b':' {
+        return None;
+    }
+    if !matches!(bytes[10], b'T' | b't' | b' ') {
+        return None;
+    }
+    let number = |range: std::ops::Range<usize>| -> Option<i64> {
+        let part = text.get(range)?;
+        if part.bytes().all(|c| c.is_ascii_digit()) {
+            part.parse().ok()
+        } else {
+            None
+        }
+    };
+    let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+    let (hour, minute, second) = (number(11..13)?, number(14..16)?, number(17..19)?);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    let mut rest = &text[19..];
+    let mut millis = 0;
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let len = fraction.bytes().take_while(|c| c.is_ascii_digit()).count();
+        if len == 0 {
+            return None;
+        }
+        let digits = format!("{:0<3}", &fraction[..len.min(3)]);
+        millis = digits.parse::<i64>().ok()?;
+        rest = &fraction[len..];
+    }
+    let offset_minutes = match rest {
+        "Z" | "z" => 0,
+        _ if rest.len() == 6 && rest.as_bytes()[3] == b':' => {
+            let sign = match rest.as_bytes()[0] {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return None,
+            };
+            let hours: i64 = rest.get(1..3)?.parse().ok()?;
+            let minutes: i64 = rest.get(4..6)?.parse().ok()?;
+            sign * (hours * 60 + minutes)
+        }
+        _ => return None,
+    };
+
+    let seconds = days_from_civil(year, month, day) * 86400 + hour * 3600 + minute * 60 + second
+        - offset_minutes * 60;
+    u64::try_from(seconds * 1000 + millis).ok()
+}
+
+// days since 1970-01-01 of a proleptic Gregorian date
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
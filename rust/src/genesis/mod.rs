@@ -0,0 +1,13 @@
+mod json;
+
+mod byron_genesis;
+pub use byron_genesis::*;
+
+mod shelley_genesis;
+pub use shelley_genesis::*;
+
+mod alonzo_genesis;
+pub use alonzo_genesis::*;
+
+mod conway_genesis;
+pub use conway_genesis::*;
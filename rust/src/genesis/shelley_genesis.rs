@@ -0,0 +1,405 @@
+use super::byron_genesis::genesis_utxo_input;
+use super::json::*;
+use crate::*;
+use serde_json::Value as JsonValue;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// Shelley genesis file, the `shelley-genesis.json` of a node configuration
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShelleyGenesis {
+    pub(crate) system_start: String,
+    pub(crate) system_start_millis: u64,
+    pub(crate) network_magic: u32,
+    pub(crate) network_id: NetworkId,
+    pub(crate) active_slots_coeff: UnitInterval,
+    pub(crate) security_param: u32,
+    pub(crate) epoch_length: u32,
+    pub(crate) slot_length_millis: u64,
+    pub(crate) slots_per_kes_period: u32,
+    pub(crate) max_kes_evolutions: u32,
+    pub(crate) update_quorum: u32,
+    pub(crate) max_lovelace_supply: Coin,
+    pub(crate) protocol_params: ProtocolParamUpdate,
+    pub(crate) gen_delegs: BTreeMap<GenesisHash, GenesisKeyDelegation>,
+    pub(crate) initial_funds: Vec<TransactionOutput>,
+    pub(crate) pools: BTreeMap<Ed25519KeyHash, PoolParams>,
+    pub(crate) stake: BTreeMap<Ed25519KeyHash, Ed25519KeyHash>,
+}
+
+#[wasm_bindgen]
+impl ShelleyGenesis {
+    pub fn from_json(json: &str) -> Result<ShelleyGenesis, JsError> {
+        let genesis = parse_object(json, "Shelley")?;
+        let system_start = as_str(required(&genesis, &["systemStart"])?, "systemStart")?;
+        let network_id = match as_str(required(&genesis, &["networkId"])?, "networkId")? {
+            "Mainnet" => NetworkId::mainnet(),
+            "Testnet" => NetworkId::testnet(),
+            other => {
+                return Err(JsError::from_str(&format!(
+                    "Unknown genesis network id \"{}\"",
+                    other
+                )))
+            }
+        };
+
+        let mut gen_delegs = BTreeMap::new();
+        if let Some(delegations) = optional(&genesis, &["genDelegs"]) {
+            for (hash, delegation) in as_object(delegations, "genDelegs")? {
+                let genesis_hash = GenesisHash::from_hex(hash)?;
+                let delegation = as_object(delegation, "genDelegs")?;
+                let delegate = as_str(required(delegation, &["delegate"])?, "delegate")?;
+                let vrf = as_str(required(delegation, &["vrf"])?, "vrf")?;
+                let delegation = GenesisKeyDelegation::new(
+                    &genesis_hash,
+                    &GenesisDelegateHash::from_hex(delegate)?,
+                    &VRFKeyHash::from_hex(vrf)?,
+                );
+                gen_delegs.insert(genesis_hash, delegation);
+            }
+        }
+
+        let mut initial_funds = Vec::new();
+        if let Some(funds) = optional(&genesis, &["initialFunds"]) {
+            for (address, amount) in as_object(funds, "initialFunds")? {
+                initial_funds.push(TransactionOutput::new(
+                    &Address::from_hex(address)?,
+                    &Value::new(&as_coin(amount, "initialFunds")?),
+                ));
+            }
+        }
+
+        let mut pools = BTreeMap::new();
+        let mut stake = BTreeMap::new();
+        if let Some(staking) = optional(&genesis, &["staking"]) {
+            let staking = as_object(staking, "staking")?;
+            if let Some(pool_params) = optional(staking, &["pools"]) {
+                for (pool_id, params) in as_object(pool_params, "pools")? {
+                    pools.insert(Ed25519KeyHash::from_hex(pool_id)?, parse_pool_params(params)?);
+                }
+            }
+            if let Some(delegations) = optional(staking, &["stake"]) {
+                for (key, pool_id) in as_object(delegations, "stake")? {
+                    stake.insert(
+                        Ed25519KeyHash::from_hex(key)?,
+                        Ed25519KeyHash::from_hex(as_str(pool_id, "stake")?)?,
+                    );
+                }
+            }
+        }
+
+        Ok(Self {
+            system_start: system_start.to_string(),
+            system_start_millis: rfc3339_to_posix_millis(system_start, "systemStart")?,
+            network_magic: as_u32(required(&genesis, &["networkMagic"])?, "networkMagic")?,
+            network_id,
+            active_slots_coeff: as_unit_interval(
+                required(&genesis, &["activeSlotsCoeff"])?,
+                "activeSlotsCoeff",
+            )?,
+            security_param: as_u32(required(&genesis, &["securityParam"])?, "securityParam")?,
+            epoch_length: as_u32(required(&genesis, &["epochLength"])?, "epochLength")?,
+            slot_length_millis: as_millis(required(&genesis, &["slotLength"])?, "slotLength")?,
+            slots_per_kes_period: as_u32(
+                required(&genesis, &["slotsPerKESPeriod"])?,
+                "slotsPerKESPeriod",
+            )?,
+            max_kes_evolutions: as_u32(
+                required(&genesis, &["maxKESEvolutions"])?,
+                "maxKESEvolutions",
+            )?,
+            update_quorum: as_u32(required(&genesis, &["updateQuorum"])?, "updateQuorum")?,
+            max_lovelace_supply: as_coin(
+                required(&genesis, &["maxLovelaceSupply"])?,
+                "maxLovelaceSupply",
+            )?,
+            protocol_params: parse_protocol_params(as_object(
+                required(&genesis, &["protocolParams"])?,
+                "protocolParams",
+            )?)?,
+            gen_delegs,
+            initial_funds,
+            pools,
+            stake,
+        })
+    }
+
+    /// The `systemStart` as written in the file
+    pub fn system_start(&self) -> String {
+        self.system_start.clone()
+    }
+
+    /// POSIX time in milliseconds of the `systemStart`
+    pub fn system_start_millis(&self) -> BigNum {
+        BigNum(self.system_start_millis)
+    }
+
+    pub fn network_magic(&self) -> u32 {
+        self.network_magic
+    }
+
+    pub fn network_id(&self) -> NetworkId {
+        self.network_id
+    }
+
+    pub fn active_slots_coeff(&self) -> UnitInterval {
+        self.active_slots_coeff.clone()
+    }
+
+    /// The k parameter of the protocol
+    pub fn security_param(&self) -> u32 {
+        self.security_param
+    }
+
+    /// Slots per epoch
+    pub fn epoch_length(&self) -> u32 {
+        self.epoch_length
+    }
+
+    pub fn slot_length_millis(&self) -> BigNum {
+        BigNum(self.slot_length_millis)
+    }
+
+    pub fn slots_per_kes_period(&self) -> u32 {
+        self.slots_per_kes_period
+    }
+
+    pub fn max_kes_evolutions(&self) -> u32 {
+        self.max_kes_evolutions
+    }
+
+    pub fn update_quorum(&self) -> u32 {
+        self.update_quorum
+    }
+
+    pub fn max_lovelace_supply(&self) -> Coin {
+        self.max_lovelace_supply
+    }
+
+    /// The initial protocol parameters. Only the parameters present in the file are set.
+    pub fn protocol_params(&self) -> ProtocolParamUpdate {
+        self.protocol_params.clone()
+    }
+
+    pub fn genesis_hashes(&self) -> GenesisHashes {
+        let mut hashes = GenesisHashes::new();
+        for hash in self.gen_delegs.keys() {
+            hashes.add(hash);
+        }
+        hashes
+    }
+
+    pub fn genesis_key_delegation(&self, genesis_hash: &GenesisHash) -> Option<GenesisKeyDelegation> {
+        self.gen_delegs.get(genesis_hash).cloned()
+    }
+
+    pub fn initial_funds(&self) -> TransactionOutputs {
+        TransactionOutputs(self.initial_funds.clone())
+    }
+
+    /// The genesis UTxO of the initial funds.
+    /// Each output is spent by the hash of its address bytes with index 0.
+    pub fn initial_utxos(&self) -> TransactionUnspentOutputs {
+        let mut utxos = TransactionUnspentOutputs::new();
+        for output in &self.initial_funds {
+            utxos.add(&TransactionUnspentOutput::new(
+                &genesis_utxo_input(&output.address()),
+                output,
+            ));
+        }
+        utxos
+    }
+
+    /// Pools registered by the `staking` section
+    pub fn pool_ids(&self) -> Ed25519KeyHashes {
+        Ed25519KeyHashes::from_vec(self.pools.keys().cloned().collect())
+    }
+
+    pub fn pool_params(&self, pool_id: &Ed25519KeyHash) -> Option<PoolParams> {
+        self.pools.get(pool_id).cloned()
+    }
+
+    /// Stake keys delegated by the `staking` section
+    pub fn staked_keys(&self) -> Ed25519KeyHashes {
+        Ed25519KeyHashes::from_vec(self.stake.keys().cloned().collect())
+    }
+
+    pub fn stake_delegation(&self, stake_key: &Ed25519KeyHash) -> Option<Ed25519KeyHash> {
+        self.stake.get(stake_key).cloned()
+    }
+}
+
+fn parse_protocol_params(params: &JsonObject) -> Result<ProtocolParamUpdate, JsError> {
+    let mut update = ProtocolParamUpdate::new();
+    let get = |key: &str| optional(params, &[key]);
+    if let Some(value) = get("minFeeA") {
+        update.set_minfee_a(&as_coin(value, "minFeeA")?);
+    }
+    if let Some(value) = get("minFeeB") {
+        update.set_minfee_b(&as_coin(value, "minFeeB")?);
+    }
+    if let Some(value) = get("maxBlockBodySize") {
+        update.set_max_block_body_size(as_u32(value, "maxBlockBodySize")?);
+    }
+    if let Some(value) = get("maxTxSize") {
+        update.set_max_tx_size(as_u32(value, "maxTxSize")?);
+    }
+    if let Some(value) = get("maxBlockHeaderSize") {
+        update.set_max_block_header_size(as_u32(value, "maxBlockHeaderSize")?);
+    }
+    if let Some(value) = get("keyDeposit") {
+        update.set_key_deposit(&as_coin(value, "keyDeposit")?);
+    }
+    if let Some(value) = get("poolDeposit") {
+        update.set_pool_deposit(&as_coin(value, "poolDeposit")?);
+    }
+    if let Some(value) = get("eMax") {
+        update.set_max_epoch(as_u32(value, "eMax")?);
+    }
+    if let Some(value) = get("nOpt") {
+        update.set_n_opt(as_u32(value, "nOpt")?);
+    }
+    if let Some(value) = get("a0") {
+        update.set_pool_pledge_influence(&as_unit_interval(value, "a0")?);
+    }
+    if let Some(value) = get("rho") {
+        update.set_expansion_rate(&as_unit_interval(value, "rho")?);
+    }
+    if let Some(value) = get("tau") {
+        update.set_treasury_growth_rate(&as_unit_interval(value, "tau")?);
+    }
+    if let Some(value) = get("decentralisationParam") {
+        update.d = Some(as_unit_interval(value, "decentralisationParam")?);
+    }
+    if let Some(value) = get("extraEntropy") {
+        update.extra_entropy = Some(parse_nonce(value)?);
+    }
+    if let Some(value) = get("protocolVersion") {
+        let version = as_object(value, "protocolVersion")?;
+        update.set_protocol_version(&ProtocolVersion::new(
+            as_u32(required(version, &["major"])?, "major")?,
+            as_u32(required(version, &["minor"])?, "minor")?,
+        ));
+    }
+    if let Some(value) = get("minPoolCost") {
+        update.set_min_pool_cost(&as_coin(value, "minPoolCost")?);
+    }
+    Ok(update)
+}
+
+// {"tag": "NeutralNonce"} or {"tag": "Nonce", "contents": "<hex>"}
+fn parse_nonce(value: &JsonValue) -> Result<Nonce, JsError> {
+    let nonce = as_object(value, "extraEntropy")?;
+    match as_str(required(nonce, &["tag"])?, "tag")? {
+        "NeutralNonce" => Ok(Nonce::new_identity()),
+        "Nonce" => {
+            let hash = as_str(required(nonce, &["contents"])?, "contents")?;
+            Nonce::new_from_hash(hex::decode(hash).map_err(|e| JsError::from_str(&e.to_string()))?)
+        }
+        other => Err(JsError::from_str(&format!("Unknown nonce tag \"{}\"", other))),
+    }
+}
+
+fn parse_pool_params(value: &JsonValue) -> Result<PoolParams, JsError> {
+    let params = as_object(value, "pools")?;
+    let mut owners = Ed25519KeyHashes::new();
+    for owner in as_array(required(params, &["owners"])?, "owners")? {
+        owners.add(&Ed25519KeyHash::from_hex(as_str(owner, "owners")?)?);
+    }
+    let mut relays = Relays::new();
+    if let Some(pool_relays) = optional(params, &["relays"]) {
+        for relay in as_array(pool_relays, "relays")? {
+            relays.add(&parse_relay(relay)?);
+        }
+    }
+    let metadata = match optional(params, &["metadata"]) {
+        Some(metadata) => {
+            let metadata = as_object(metadata, "metadata")?;
+            Some(PoolMetadata::new(
+                &URL::new(as_str(required(metadata, &["url"])?, "url")?.to_string())?,
+                &PoolMetadataHash::from_hex(as_str(required(metadata, &["hash"])?, "hash")?)?,
+            ))
+        }
+        None => None,
+    };
+    Ok(PoolParams::new(
+        &Ed25519KeyHash::from_hex(as_str(required(params, &["publicKey"])?, "publicKey")?)?,
+        &VRFKeyHash::from_hex(as_str(required(params, &["vrf"])?, "vrf")?)?,
+        &as_coin(required(params, &["pledge"])?, "pledge")?,
+        &as_coin(required(params, &["cost"])?, "cost")?,
+        &as_unit_interval(required(params, &["margin"])?, "margin")?,
+        &parse_reward_account(required(params, &["rewardAccount"])?)?,
+        &owners,
+        &relays,
+        metadata,
+    ))
+}
+
+// {"network": "Testnet", "credential": {"keyHash": "<hex>"}}
+fn parse_reward_account(value: &JsonValue) -> Result<RewardAddress, JsError> {
+    let account = as_object(value, "rewardAccount")?;
+    let network = match as_str(required(account, &["network"])?, "network")? {
+        "Mainnet" => NetworkInfo::mainnet().network_id(),
+        _ => NetworkInfo::testnet_preprod().network_id(),
+    };
+    let credential = as_object(required(account, &["credential"])?, "credential")?;
+    let credential = if let Some(hash) = optional(credential, &["keyHash", "key hash"]) {
+        Credential::from_keyhash(&Ed25519KeyHash::from_hex(as_str(hash, "keyHash")?)?)
+    } else {
+        let hash = required(credential, &["scriptHash", "script hash"])?;
+        Credential::from_scripthash(&ScriptHash::from_hex(as_str(hash, "scriptHash")?)?)
+    };
+    Ok(RewardAddress::new(network, &credential))
+}
+
+// {"single host address": {"IPv4": "1.2.3.4", "IPv6": null, "port": 3001}},
+// {"single host name": {"dnsName": "relay.io", "port": 3001}} or {"multi host name": {"dnsName": "relays.io"}}
+fn parse_relay(value: &JsonValue) -> Result<Relay, JsError> {
+    let relay = as_object(value, "relays")?;
+    let port = |host: &JsonObject| -> Result<Option<Port>, JsError> {
+        optional(host, &["port"])
+            .map(|port| {
+                u16::try_from(as_u64(port, "port")?)
+                    .map_err(|_| JsError::from_str("Relay port must be a 16 bit unsigned integer"))
+            })
+            .transpose()
+    };
+    let dns_name = |host: &JsonObject| -> Result<String, JsError> {
+        Ok(as_str(required(host, &["dnsName"])?, "dnsName")?.to_string())
+    };
+    if let Some(host) = optional(relay, &["single host address"]) {
+        let host = as_object(host, "single host address")?;
+        let ipv4 = optional(host, &["IPv4"])
+            .map(|ip| {
+                let ip: Ipv4Addr = as_str(ip, "IPv4")?
+                    .parse()
+                    .map_err(|_| JsError::from_str("Invalid relay IPv4 address"))?;
+                Ipv4::new(ip.octets().to_vec())
+            })
+            .transpose()?;
+        let ipv6 = optional(host, &["IPv6"])
+            .map(|ip| {
+                let ip: Ipv6Addr = as_str(ip, "IPv6")?
+                    .parse()
+                    .map_err(|_| JsError::from_str("Invalid relay IPv6 address"))?;
+                Ipv6::new(ip.octets().to_vec())
+            })
+            .transpose()?;
+        Ok(Relay::new_single_host_addr(&SingleHostAddr::new(port(host)?, ipv4, ipv6)))
+    } else if let Some(host) = optional(relay, &["single host name"]) {
+        let host = as_object(host, "single host name")?;
+        Ok(Relay::new_single_host_name(&SingleHostName::new(
+            port(host)?,
+            &DNSRecordAorAAAA::new(dns_name(host)?)?,
+        )))
+    } else if let Some(host) = optional(relay, &["multi host name"]) {
+        let host = as_object(host, "multi host name")?;
+        Ok(Relay::new_multi_host_name(&MultiHostName::new(
+            &DNSRecordSRV::new(dns_name(host)?)?,
+        )))
+    } else {
+        Err(JsError::from_str("Unknown genesis pool relay"))
+    }
+}
@@ -183,6 +183,18 @@ fn hash_spending_data(addr_type: ByronAddressType, xpub: &XPub, attrs: &Attribut
     sha3_then_blake2b224(&buf)
 }
 
+fn hash_redeem_spending_data(key: &[u8; 32], attrs: &Attributes) -> [u8; 28] {
+    let buf = cbor!(&(&ByronAddressType::ATRedeem, &RedeemSpendingData(key), attrs))
+        .expect("serialize the HashedSpendingData's digest data");
+    sha3_then_blake2b224(&buf)
+}
+
+fn hash_script_spending_data(script: &ByronScript, attrs: &Attributes) -> [u8; 28] {
+    let buf = cbor!(&(&ByronAddressType::ATScript, &ScriptSpendingData(script), attrs))
+        .expect("serialize the HashedSpendingData's digest data");
+    sha3_then_blake2b224(&buf)
+}
+
 /// A valid cardano Address that is displayed in base58
 #[derive(Debug, Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Addr(Vec<u8>);
@@ -299,6 +311,37 @@ impl ExtendedAddr {
         ExtendedAddr::new(xpub, Attributes::new_bootstrap_era(None, protocol_magic))
     }
 
+    // redeem address of an AVVM ed25519 public key
+    pub fn new_redeem(key: &[u8; 32], protocol_magic: Option<u32>) -> Self {
+        let attrs = Attributes::new_bootstrap_era(None, protocol_magic);
+        ExtendedAddr {
+            addr: hash_redeem_spending_data(key, &attrs),
+            attributes: attrs,
+            addr_type: ByronAddressType::ATRedeem,
+        }
+    }
+
+    // script address of a versioned Byron era validator
+    pub fn new_script(script: &ByronScript, attrs: Attributes) -> Self {
+        ExtendedAddr {
+            addr: hash_script_spending_data(script, &attrs),
+            attributes: attrs,
+            addr_type: ByronAddressType::ATScript,
+        }
+    }
+
+    /// Check if the address is the redeem address of the ed25519 public key
+    pub fn identical_with_redeem_key(&self, key: &[u8; 32]) -> bool {
+        self.addr_type == ByronAddressType::ATRedeem
+            && self.addr == hash_redeem_spending_data(key, &self.attributes)
+    }
+
+    /// Check if the address is the script address of the validator
+    pub fn identical_with_script(&self, script: &ByronScript) -> bool {
+        self.addr_type == ByronAddressType::ATScript
+            && self.addr == hash_script_spending_data(script, &self.attributes)
+    }
+
     pub fn to_address(&self) -> Addr {
         Addr(cbor!(self).unwrap()) // unwrap should never fail from strongly typed extended addr to addr
     }
@@ -404,6 +447,57 @@ impl<'a> cbor_event::se::Serialize for SpendingData<'a> {
     }
 }
 
+const SPENDING_DATA_TAG_REDEEM: u64 = 2;
+
+struct RedeemSpendingData<'a>(&'a [u8; 32]);
+
+impl<'a> cbor_event::se::Serialize for RedeemSpendingData<'a> {
+    fn serialize<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        serializer
+            .write_array(cbor_event::Len::Len(2))?
+            .write_unsigned_integer(SPENDING_DATA_TAG_REDEEM)?
+            .write_bytes(&self.0[..])
+    }
+}
+
+const SPENDING_DATA_TAG_SCRIPT: u64 = 1;
+
+/// Byron era validator, never used on mainnet but part of the address format
+#[derive(Debug, Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ByronScript {
+    pub version: u16,
+    pub script: Vec<u8>,
+}
+
+impl cbor_event::se::Serialize for ByronScript {
+    fn serialize<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        serializer
+            .write_array(cbor_event::Len::Len(2))?
+            .write_unsigned_integer(self.version as u64)?
+            .write_bytes(&self.script)
+    }
+}
+
+struct ScriptSpendingData<'a>(&'a ByronScript);
+
+impl<'a> cbor_event::se::Serialize for ScriptSpendingData<'a> {
+    fn serialize<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        serializer
+            .write_array(cbor_event::Len::Len(2))?
+            .write_unsigned_integer(SPENDING_DATA_TAG_SCRIPT)?;
+        self.0.serialize(serializer)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{Addr, AddressMatchXPub};
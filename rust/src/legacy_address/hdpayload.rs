@@ -0,0 +1,137 @@
+//! Encrypted derivation path of the legacy Daedalus (random derivation) addresses
+//!
+//! The `HDAddressPayload` attribute holds the derivation path from the wallet root key,
+//! encrypted with ChaCha20-Poly1305 under a key derived from the root public key.
+//! Only the owner of the root key can decrypt it, which is how a wallet recognizes its addresses.
+//!
+
+use cbor_event::{self, de::Deserializer, se::Serializer};
+use cryptoxide::chacha20poly1305::ChaCha20Poly1305;
+use cryptoxide::hmac::Hmac;
+use cryptoxide::pbkdf2::pbkdf2;
+use cryptoxide::sha2::Sha512;
+use ed25519_bip32::XPub;
+use std::io::Cursor;
+
+const NONCE: &[u8] = b"serokellfore";
+const SALT: &[u8] = b"address-hashing";
+const ITERATIONS: u32 = 500;
+const TAG_LEN: usize = 16;
+pub const HDKEY_SIZE: usize = 32;
+
+/// Key encrypting the derivation paths of the addresses of a root key
+#[derive(Clone)]
+pub struct HDKey([u8; HDKEY_SIZE]);
+
+impl HDKey {
+    pub fn new(root_pub: &XPub) -> Self {
+        let mut mac = Hmac::new(Sha512::new(), root_pub.as_ref());
+        let mut key = [0; HDKEY_SIZE];
+        pbkdf2(&mut mac, SALT, ITERATIONS, &mut key);
+        HDKey(key)
+    }
+
+    pub fn from_bytes(bytes: [u8; HDKEY_SIZE]) -> Self {
+        HDKey(bytes)
+    }
+
+    /// encrypted bytes followed by the 16 bytes tag
+    pub fn encrypt(&self, input: &[u8]) -> Vec<u8> {
+        let mut ctx = ChaCha20Poly1305::new(&self.0, NONCE, &[]);
+        let mut out = vec![0; input.len()];
+        let mut tag = [0; TAG_LEN];
+        ctx.encrypt(input, &mut out, &mut tag);
+        out.extend_from_slice(&tag);
+        out
+    }
+
+    /// None when the payload wasn't encrypted with this key
+    pub fn decrypt(&self, input: &[u8]) -> Option<Vec<u8>> {
+        if input.len() < TAG_LEN {
+            return None;
+        }
+        let (encrypted, tag) = input.split_at(input.len() - TAG_LEN);
+        let mut ctx = ChaCha20Poly1305::new(&self.0, NONCE, &[]);
+        let mut out = vec![0; encrypted.len()];
+        if ctx.decrypt(encrypted, &mut out, tag) {
+            Some(out)
+        } else {
+            None
+        }
+    }
+
+    pub fn encrypt_path(&self, path: &[u32]) -> Vec<u8> {
+        self.encrypt(&serialize_path(path))
+    }
+
+    pub fn decrypt_path(&self, payload: &[u8]) -> Option<Vec<u32>> {
+        deserialize_path(&self.decrypt(payload)?).ok()
+    }
+}
+
+// the path is an indefinite array of u32, like any list in cardano-sl
+fn serialize_path(path: &[u32]) -> Vec<u8> {
+    let mut serializer = Serializer::new_vec();
+    serializer
+        .write_array(cbor_event::Len::Indefinite)
+        .expect("write to a vec");
+    for index in path {
+        serializer
+            .write_unsigned_integer(*index as u64)
+            .expect("write to a vec");
+    }
+    serializer
+        .write_special(cbor_event::Special::Break)
+        .expect("write to a vec");
+    serializer.finalize()
+}
+
+fn deserialize_path(bytes: &[u8]) -> cbor_event::Result<Vec<u32>> {
+    let mut raw = Deserializer::from(Cursor::new(bytes));
+    let len = raw.array()?;
+    let mut path = Vec::new();
+    while match len {
+        cbor_event::Len::Len(n) => (path.len() as u64) < n,
+        cbor_event::Len::Indefinite => raw.cbor_type()? != cbor_event::Type::Special,
+    } {
+        path.push(raw.deserialize::<u32>()?);
+    }
+    if len == cbor_event::Len::Indefinite {
+        raw.special_break()?;
+    }
+    Ok(path)
+}
+
+/// The `derivation_path` attribute holds the payload as CBOR bytes
+pub fn wrap_payload(payload: &[u8]) -> Vec<u8> {
+    let mut serializer = Serializer::new_vec();
+    serializer.write_bytes(payload).expect("write to a vec");
+    serializer.finalize()
+}
+
+// the length is checked before reading, since the attribute comes from untrusted addresses
+pub fn unwrap_payload(attribute: &[u8]) -> Option<Vec<u8>> {
+    let (&head, rest) = attribute.split_first()?;
+    if head >> 5 != 2 {
+        return None;
+    }
+    let (len, data) = match head & 0x1f {
+        len @ 0..=23 => (len as u64, rest),
+        info @ 24..=27 => {
+            let size = 1 << (info - 24);
+            if rest.len() < size {
+                return None;
+            }
+            let len = rest[..size]
+                .iter()
+                .fold(0u64, |len, byte| (len << 8) | *byte as u64);
+            (len, &rest[size..])
+        }
+        _ => return None,
+    };
+    if data.len() as u64 == len {
+        Some(data.to_vec())
+    } else {
+        None
+    }
+}
@@ -2,5 +2,10 @@ mod address;
 mod base58;
 mod cbor;
 mod crc32;
+mod hdpayload;
 
-pub use address::{Addr, AddressMatchXPub, ByronAddressType, ExtendedAddr, ParseExtendedAddrError};
+pub use address::{
+    Addr, AddressMatchXPub, Attributes, ByronAddressType, ByronScript, ExtendedAddr,
+    ParseExtendedAddrError,
+};
+pub use hdpayload::{unwrap_payload, wrap_payload, HDKey};
@@ -52,10 +52,14 @@ mod crypto;
 pub(crate) use crypto::*;
 mod emip3;
 pub use emip3::*;
+mod era_history;
+pub use era_history::*;
 mod error;
 pub use error::*;
 mod fees;
 pub use fees::*;
+mod genesis;
+pub use genesis::*;
 pub mod impl_mockchain;
 pub mod legacy_address;
 pub mod traits;
@@ -67,6 +71,13 @@ mod utils;
 pub use utils::*;
 mod serialization;
 mod rational;
+cfg_if! {
+    if #[cfg(test)] {
+        mod arbitrary;
+    } else if #[cfg(feature = "property-test-api")] {
+        pub mod arbitrary;
+    }
+}
 
 pub use serialization::*;
 
@@ -1,7 +1,7 @@
-use crate::legacy_address::{ExtendedAddr, ByronAddressType};
+use crate::legacy_address::{wrap_payload, Attributes, ByronAddressType, ByronScript, ExtendedAddr};
 use crate::*;
 use bech32::ToBase32;
-use ed25519_bip32::XPub;
+use crate::chain_crypto::bech32::cip5;
 
 #[wasm_bindgen]
 #[derive(Debug, Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -199,21 +199,65 @@ impl ByronAddress {
 
     // icarus-style address (Ae2)
     pub fn icarus_from_key(key: &Bip32PublicKey, protocol_magic: u32) -> ByronAddress {
-        let mut out = [0u8; 64];
-        out.clone_from_slice(&key.as_bytes());
+        ByronAddress(ExtendedAddr::new_simple(
+            &key.0 .0,
+            Self::protocol_magic_attribute(protocol_magic),
+        ))
+    }
 
-        // need to ensure we use None for mainnet since Byron-era addresses omitted the network id
-        let filtered_protocol_magic = if protocol_magic == NetworkInfo::mainnet().protocol_magic() {
-            None
-        } else {
-            Some(protocol_magic)
+    /// daedalus-style address (DdzFF) of the key at `path` from a legacy Daedalus root key.
+    /// The key is derived with the V1 scheme of Daedalus and the path is encrypted for the root key.
+    pub fn daedalus_from_key(
+        root_key: &LegacyDaedalusPrivateKey,
+        path: &DaedalusDerivationPath,
+        protocol_magic: u32,
+    ) -> ByronAddress {
+        let passphrase = DaedalusHDPassphrase::from_root_key(root_key);
+        let key = root_key.derive_path(path).to_public();
+        let attributes = Attributes::new_bootstrap_era(
+            Some(wrap_payload(&passphrase.encrypt_derivation_path(path))),
+            Self::protocol_magic_attribute(protocol_magic),
+        );
+        ByronAddress(ExtendedAddr::new(&key.0 .0, attributes))
+    }
+
+    /// redeem address of an AVVM ed25519 public key
+    pub fn redeem_from_key(key: &PublicKey, protocol_magic: u32) -> ByronAddress {
+        ByronAddress(ExtendedAddr::new_redeem(
+            &key.0 .0 .0,
+            Self::protocol_magic_attribute(protocol_magic),
+        ))
+    }
+
+    /// script address of a Byron era validator
+    pub fn from_script(script_version: u16, script: &[u8], protocol_magic: u32) -> ByronAddress {
+        let script = ByronScript {
+            version: script_version,
+            script: script.to_vec(),
         };
-        ByronAddress(ExtendedAddr::new_simple(
-            &XPub::from_bytes(out),
-            filtered_protocol_magic,
+        ByronAddress(ExtendedAddr::new_script(
+            &script,
+            Attributes::new_bootstrap_era(None, Self::protocol_magic_attribute(protocol_magic)),
         ))
     }
 
+    /// true if this public key address is the one of `key` with its attributes
+    pub fn matches_key(&self, key: &Bip32PublicKey) -> bool {
+        self.0.addr_type == ByronAddressType::ATPubKey
+            && ExtendedAddr::new(&key.0 .0, self.0.attributes.clone()) == self.0
+    }
+
+    pub fn matches_redeem_key(&self, key: &PublicKey) -> bool {
+        self.0.identical_with_redeem_key(&key.0 .0 .0)
+    }
+
+    pub fn matches_script(&self, script_version: u16, script: &[u8]) -> bool {
+        self.0.identical_with_script(&ByronScript {
+            version: script_version,
+            script: script.to_vec(),
+        })
+    }
+
     pub fn is_valid(s: &str) -> bool {
         use std::str::FromStr;
         match ExtendedAddr::from_str(s) {
@@ -234,6 +278,17 @@ impl ByronAddress {
     }
 }
 
+impl ByronAddress {
+    // need to ensure we use None for mainnet since Byron-era addresses omitted the network id
+    fn protocol_magic_attribute(protocol_magic: u32) -> Option<u32> {
+        if protocol_magic == NetworkInfo::mainnet().protocol_magic() {
+            None
+        } else {
+            Some(protocol_magic)
+        }
+    }
+}
+
 #[wasm_bindgen]
 #[derive(Debug, Clone, Eq, Ord, PartialEq, PartialOrd, Hash)]
 pub struct Address(pub(crate) AddrType);
@@ -516,8 +571,8 @@ impl Address {
             None => {
                 // see CIP5 for bech32 prefix rules
                 let prefix_header = match &self.0 {
-                    AddrType::Reward(_) => "stake",
-                    _ => "addr",
+                    AddrType::Reward(_) => cip5::STAKE,
+                    _ => cip5::ADDR,
                 };
                 let prefix_tail = if self.is_malformed() {
                     "_malformed"
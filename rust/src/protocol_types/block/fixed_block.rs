@@ -5,12 +5,13 @@ use crate::*;
 /// Read only view of a block with more strict structs for hash sensitive structs.
 /// Warning: This is experimental and may be removed or changed in the future.
 pub struct FixedBlock {
-    pub(crate) header: Header,
+    pub(crate) header: FixedHeader,
     pub(crate) transaction_bodies: FixedTransactionBodies,
     pub(crate) transaction_witness_sets: TransactionWitnessSets,
     pub(crate) auxiliary_data_set: AuxiliaryDataSet,
     pub(crate) invalid_transactions: TransactionIndexes,
-    pub(crate) block_hash: BlockHash,
+    pub(crate) body_hash: BlockHash,
+    pub(crate) body_size: u64,
 }
 
 from_bytes!(FixedBlock);
@@ -19,6 +20,10 @@ from_hex!(FixedBlock);
 #[wasm_bindgen]
 impl FixedBlock {
     pub fn header(&self) -> Header {
+        self.header.header.clone()
+    }
+
+    pub fn fixed_header(&self) -> FixedHeader {
         self.header.clone()
     }
 
@@ -39,6 +44,30 @@ impl FixedBlock {
     }
 
     pub fn block_hash(&self) -> BlockHash {
-        self.block_hash.clone()
+        self.header.block_hash.clone()
+    }
+
+    /// Hash of the original bytes of the block body, which the header commits to
+    pub fn body_hash(&self) -> BlockHash {
+        self.body_hash.clone()
+    }
+
+    /// Checks the body hash and size recorded in the header against the block body
+    pub fn verify_body(&self) -> Result<(), JsError> {
+        let header_body = &self.header.header.header_body;
+        if header_body.block_body_hash != self.body_hash {
+            return Err(JsError::from_str(&format!(
+                "Block body hash {} doesn't match the hash {} of the header",
+                self.body_hash.to_hex(),
+                header_body.block_body_hash.to_hex()
+            )));
+        }
+        if header_body.block_body_size as u64 != self.body_size {
+            return Err(JsError::from_str(&format!(
+                "Block body size {} doesn't match the size {} of the header",
+                self.body_size, header_body.block_body_size
+            )));
+        }
+        Ok(())
     }
 }
\ No newline at end of file
@@ -0,0 +1,146 @@
+use crate::*;
+
+// TPraos seeds of the nonce and leader VRFs
+const SEED_ETA: u64 = 0;
+const SEED_L: u64 = 1;
+
+#[wasm_bindgen]
+#[derive(Clone, Eq, Debug, PartialEq)]
+/// Read only view of a block header with the original bytes of its body,
+/// which are the ones signed with the KES key.
+/// Warning: This is experimental and may be removed or changed in the future.
+pub struct FixedHeader {
+    pub(crate) header: Header,
+    pub(crate) header_body_bytes: Vec<u8>,
+    pub(crate) block_hash: BlockHash,
+}
+
+from_bytes!(FixedHeader);
+from_hex!(FixedHeader);
+
+#[wasm_bindgen]
+impl FixedHeader {
+    pub fn header(&self) -> Header {
+        self.header.clone()
+    }
+
+    pub fn header_body_bytes(&self) -> Vec<u8> {
+        self.header_body_bytes.clone()
+    }
+
+    pub fn block_hash(&self) -> BlockHash {
+        self.block_hash.clone()
+    }
+
+    /// True if the operational certificate is signed by the issuer cold key
+    pub fn verify_operational_cert(&self) -> bool {
+        let body = &self.header.header_body;
+        body.operational_cert.verify(&body.issuer_vkey)
+    }
+
+    /// Checks the KES signature of the header body at the KES period of the header slot,
+    /// which must be in the validity range of the operational certificate
+    pub fn verify_body_signature(
+        &self,
+        slots_per_kes_period: &BigNum,
+        max_kes_evolutions: u32,
+    ) -> Result<(), JsError> {
+        if slots_per_kes_period.0 == 0 {
+            return Err(JsError::from_str("Slots per KES period must be positive"));
+        }
+        let body = &self.header.header_body;
+        let cert = &body.operational_cert;
+        let kes_period = body.slot.0 / slots_per_kes_period.0;
+        let start = cert.kes_period as u64;
+        if kes_period < start {
+            return Err(JsError::from_str(&format!(
+                "KES period {} of the header is before the start period {} of the operational certificate",
+                kes_period, start
+            )));
+        }
+        if kes_period >= start + max_kes_evolutions as u64 {
+            return Err(JsError::from_str(&format!(
+                "KES period {} of the header is after the last period {} of the operational certificate",
+                kes_period,
+                start + max_kes_evolutions as u64 - 1
+            )));
+        }
+        // below max_kes_evolutions
+        let period = (kes_period - start) as u32;
+        if !self
+            .header
+            .body_signature
+            .verify(&cert.hot_vkey, period, &self.header_body_bytes)
+        {
+            return Err(JsError::from_str(&format!(
+                "Invalid KES signature of the header body at KES period {}",
+                kes_period
+            )));
+        }
+        Ok(())
+    }
+
+    /// Checks the VRF proofs of the header for the nonce of its epoch:
+    /// the nonce and leader proofs until Alonzo, the single proof of Praos since Babbage
+    pub fn verify_vrf(&self, epoch_nonce: &Nonce) -> Result<(), JsError> {
+        let body = &self.header.header_body;
+        let input = vrf_input(&body.slot, epoch_nonce);
+        match &body.leader_cert {
+            HeaderLeaderCertEnum::NonceAndLeader(nonce_vrf, leader_vrf) => {
+                if !nonce_vrf.verify(&body.vrf_vkey, &tpraos_seed(&input, SEED_ETA)) {
+                    return Err(JsError::from_str("Invalid nonce VRF proof"));
+                }
+                if !leader_vrf.verify(&body.vrf_vkey, &tpraos_seed(&input, SEED_L)) {
+                    return Err(JsError::from_str("Invalid leader VRF proof"));
+                }
+            }
+            HeaderLeaderCertEnum::VrfResult(vrf_result) => {
+                if !vrf_result.verify(&body.vrf_vkey, &input) {
+                    return Err(JsError::from_str("Invalid VRF proof"));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the VRF leader value of the header wins its slot for a pool
+    /// with `relative_stake` of the active stake, the VRF proofs being checked by `verify_vrf`
+    pub fn verify_leader_value(
+        &self,
+        relative_stake: &UnitInterval,
+        active_slots_coeff: &UnitInterval,
+    ) -> Result<(), JsError> {
+        let check = LeaderCheck::new(relative_stake, active_slots_coeff)?;
+        let (value, max) = match &self.header.header_body.leader_cert {
+            HeaderLeaderCertEnum::NonceAndLeader(_, leader_vrf) => {
+                tpraos_leader_value(&leader_vrf.output)
+            }
+            HeaderLeaderCertEnum::VrfResult(vrf_result) => praos_leader_value(&vrf_result.output),
+        };
+        if !check.is_leader(&value, &max) {
+            return Err(JsError::from_str(&format!(
+                "The VRF leader value doesn't win slot {} for the stake of the pool",
+                self.header.header_body.slot.0
+            )));
+        }
+        Ok(())
+    }
+}
+
+// blake2b256 of the slot as 8 bytes big endian followed by the epoch nonce
+pub(crate) fn vrf_input(slot: &SlotBigNum, epoch_nonce: &Nonce) -> [u8; 32] {
+    let mut bytes = slot.0.to_be_bytes().to_vec();
+    if let Some(hash) = &epoch_nonce.hash {
+        bytes.extend_from_slice(hash);
+    }
+    blake2b256(&bytes)
+}
+
+// TPraos xors the input with the hash of a number specific to each VRF
+fn tpraos_seed(input: &[u8; 32], number: u64) -> [u8; 32] {
+    let mut seed = blake2b256(&number.to_be_bytes());
+    for (byte, input_byte) in seed.iter_mut().zip(input.iter()) {
+        *byte ^= input_byte;
+    }
+    seed
+}
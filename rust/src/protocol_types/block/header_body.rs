@@ -99,6 +99,20 @@ impl HeaderBody {
         }
     }
 
+    /// The contribution of the block to the evolving nonce: the hash of the nonce VRF output
+    /// until Alonzo, the hash of the hash of the "N" tagged VRF output since Babbage
+    pub fn vrf_nonce(&self) -> Nonce {
+        let hash = match &self.leader_cert {
+            HeaderLeaderCertEnum::NonceAndLeader(nonce_vrf, _) => blake2b256(&nonce_vrf.output),
+            HeaderLeaderCertEnum::VrfResult(vrf_result) => {
+                let mut bytes = b"N".to_vec();
+                bytes.extend_from_slice(&vrf_result.output);
+                blake2b256(&blake2b256(&bytes))
+            }
+        };
+        Nonce { hash: Some(hash) }
+    }
+
     pub fn block_body_size(&self) -> u32 {
         self.block_body_size.clone()
     }
@@ -0,0 +1,206 @@
+use crate::*;
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct ChainTip {
+    hash: BlockHash,
+    block_number: u32,
+    slot: SlotBigNum,
+}
+
+/// Validator of a sequence of Praos block headers, as followed by a light client.
+/// Each header must extend the previous one, have a protocol version known to the validator,
+/// an operational certificate signed by the pool cold key with a counter not going back and
+/// moving at most one step ahead, and a valid KES signature.
+/// When the epoch nonce is set, the VRF proofs are checked as well, and with the stake
+/// distribution of the epoch also the leader eligibility of the pool.
+/// The state is only updated by the headers that pass all the checks.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct HeaderValidator {
+    slots_per_kes_period: BigNum,
+    max_kes_evolutions: u32,
+    active_slots_coeff: UnitInterval,
+    max_major_protocol_version: u32,
+    tip: Option<ChainTip>,
+    opcert_counters: BTreeMap<Ed25519KeyHash, u32>,
+    epoch_nonce: Option<Nonce>,
+    pool_stakes: Option<BTreeMap<Ed25519KeyHash, UnitInterval>>,
+}
+
+#[wasm_bindgen]
+impl HeaderValidator {
+    pub fn new(
+        slots_per_kes_period: &BigNum,
+        max_kes_evolutions: u32,
+        active_slots_coeff: &UnitInterval,
+        max_major_protocol_version: u32,
+    ) -> Self {
+        Self {
+            slots_per_kes_period: *slots_per_kes_period,
+            max_kes_evolutions,
+            active_slots_coeff: active_slots_coeff.clone(),
+            max_major_protocol_version,
+            tip: None,
+            opcert_counters: BTreeMap::new(),
+            epoch_nonce: None,
+            pool_stakes: None,
+        }
+    }
+
+    pub fn from_shelley_genesis(
+        genesis: &ShelleyGenesis,
+        max_major_protocol_version: u32,
+    ) -> Self {
+        Self::new(
+            &BigNum(genesis.slots_per_kes_period as u64),
+            genesis.max_kes_evolutions,
+            &genesis.active_slots_coeff,
+            max_major_protocol_version,
+        )
+    }
+
+    /// The block the next header must extend, by default the first header is accepted as is
+    pub fn set_tip(&mut self, hash: &BlockHash, block_number: u32, slot: &SlotBigNum) {
+        self.tip = Some(ChainTip {
+            hash: hash.clone(),
+            block_number,
+            slot: *slot,
+        });
+    }
+
+    pub fn tip_hash(&self) -> Option<BlockHash> {
+        self.tip.as_ref().map(|tip| tip.hash.clone())
+    }
+
+    pub fn tip_block_number(&self) -> Option<u32> {
+        self.tip.as_ref().map(|tip| tip.block_number)
+    }
+
+    pub fn tip_slot(&self) -> Option<SlotBigNum> {
+        self.tip.as_ref().map(|tip| tip.slot)
+    }
+
+    /// The last operational certificate counter seen for the pool
+    pub fn set_opcert_counter(&mut self, pool_id: &Ed25519KeyHash, counter: u32) {
+        self.opcert_counters.insert(pool_id.clone(), counter);
+    }
+
+    pub fn opcert_counter(&self, pool_id: &Ed25519KeyHash) -> Option<u32> {
+        self.opcert_counters.get(pool_id).cloned()
+    }
+
+    /// The nonce of the epoch of the next headers, enabling the checks of the VRF proofs
+    pub fn set_epoch_nonce(&mut self, epoch_nonce: &Nonce) {
+        self.epoch_nonce = Some(epoch_nonce.clone());
+    }
+
+    /// Relative stake of a pool in the stake distribution of the epoch of the next headers,
+    /// enabling the checks of the leader eligibility along with the epoch nonce
+    pub fn set_pool_stake(&mut self, pool_id: &Ed25519KeyHash, relative_stake: &UnitInterval) {
+        self.pool_stakes
+            .get_or_insert_with(BTreeMap::new)
+            .insert(pool_id.clone(), relative_stake.clone());
+    }
+
+    /// Forgets the stake distribution, to set the one of a new epoch
+    pub fn clear_pool_stakes(&mut self) {
+        self.pool_stakes = None;
+    }
+
+    pub fn validate_header(&mut self, header: &FixedHeader) -> Result<(), JsError> {
+        let body = &header.header.header_body;
+        if body.protocol_version.major > self.max_major_protocol_version {
+            return Err(JsError::from_str(&format!(
+                "Protocol version {} of the header is above the supported version {}",
+                body.protocol_version.major, self.max_major_protocol_version
+            )));
+        }
+        if let Some(tip) = &self.tip {
+            if body.prev_hash.as_ref() != Some(&tip.hash) {
+                return Err(JsError::from_str(&format!(
+                    "Header of block {} doesn't extend the block {}",
+                    header.block_hash.to_hex(),
+                    tip.hash.to_hex()
+                )));
+            }
+            if body.block_number as u64 != tip.block_number as u64 + 1 {
+                return Err(JsError::from_str(&format!(
+                    "Block number {} doesn't follow the block number {}",
+                    body.block_number, tip.block_number
+                )));
+            }
+            if body.slot <= tip.slot {
+                return Err(JsError::from_str(&format!(
+                    "Slot {} isn't after the slot {} of the previous block",
+                    body.slot, tip.slot
+                )));
+            }
+        }
+        let pool_id = body.issuer_vkey.0.hash();
+        let counter = self.check_opcert_counter(header, &pool_id)?;
+        header.verify_body_signature(&self.slots_per_kes_period, self.max_kes_evolutions)?;
+        if let Some(epoch_nonce) = &self.epoch_nonce {
+            header.verify_vrf(epoch_nonce)?;
+            if let Some(pool_stakes) = &self.pool_stakes {
+                let relative_stake = pool_stakes.get(&pool_id).ok_or_else(|| {
+                    JsError::from_str(&format!(
+                        "Pool {} isn't in the stake distribution",
+                        pool_id.to_hex()
+                    ))
+                })?;
+                header.verify_leader_value(relative_stake, &self.active_slots_coeff)?;
+            }
+        }
+        self.opcert_counters.insert(pool_id, counter);
+        self.tip = Some(ChainTip {
+            hash: header.block_hash.clone(),
+            block_number: body.block_number,
+            slot: body.slot,
+        });
+        Ok(())
+    }
+
+    /// Validates the header of the block and the body hash and size it records
+    pub fn validate_block(&mut self, block: &FixedBlock) -> Result<(), JsError> {
+        block.verify_body()?;
+        self.validate_header(&block.header)
+    }
+}
+
+impl HeaderValidator {
+    // the counter of a pool moves at most by one, the pools of the stake distribution
+    // starting at 0 when they haven't been seen yet
+    fn check_opcert_counter(
+        &self,
+        header: &FixedHeader,
+        pool_id: &Ed25519KeyHash,
+    ) -> Result<u32, JsError> {
+        if !header.verify_operational_cert() {
+            return Err(JsError::from_str(
+                "Operational certificate isn't signed by the issuer of the header",
+            ));
+        }
+        let counter = header.header.header_body.operational_cert.sequence_number;
+        let last = match (self.opcert_counters.get(pool_id), &self.pool_stakes) {
+            (Some(last), _) => *last,
+            (None, Some(pool_stakes)) if pool_stakes.contains_key(pool_id) => 0,
+            (None, Some(_)) => {
+                return Err(JsError::from_str(&format!(
+                    "No operational certificate counter for the pool {}",
+                    pool_id.to_hex()
+                )))
+            }
+            (None, None) => return Ok(counter),
+        };
+        if counter < last || counter as u64 > last as u64 + 1 {
+            return Err(JsError::from_str(&format!(
+                "Operational certificate counter {} isn't {} or {}",
+                counter,
+                last,
+                last as u64 + 1
+            )));
+        }
+        Ok(counter)
+    }
+}
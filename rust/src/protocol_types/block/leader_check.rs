@@ -0,0 +1,186 @@
+use crate::*;
+use num_bigint::{BigInt, BigUint};
+use num_integer::Integer;
+use num_traits::{One, Signed, Zero};
+
+// the ledger compares in fixed point with 34 decimal digits
+const DIGITS: u32 = 34;
+const TAYLOR_BOUND: u32 = 3;
+// the series and continued fractions of the ledger stop after 1000 steps
+// or when a step changes the result by less than 10^-24
+const MAX_STEPS: usize = 1000;
+const EPSILON_DIGITS: u32 = 24;
+
+/// The check of the leader value of a slot for a pool:
+/// the slot is won when `p < 1 - (1 - f)^σ`, with `p` the leader value scaled to [0, 1),
+/// `f` the active slots coefficient and `σ` the relative stake of the pool.
+/// It's evaluated as the ledger does, comparing `1 / (1 - p)` with `exp(-σ ln(1 - f))`.
+#[derive(Clone, Debug)]
+pub(crate) struct LeaderCheck {
+    // the bounds of exp(-σ ln(1 - f)) at each step of its expansion,
+    // None when every slot is active
+    bounds: Option<Vec<(BigInt, BigInt)>>,
+}
+
+impl LeaderCheck {
+    pub(crate) fn new(
+        relative_stake: &UnitInterval,
+        active_slots_coeff: &UnitInterval,
+    ) -> Result<Self, JsError> {
+        let (f_num, f_den) = unit_interval_parts(active_slots_coeff, "active slots coefficient")?;
+        let (s_num, s_den) = unit_interval_parts(relative_stake, "relative stake")?;
+        if f_num == f_den {
+            return Ok(Self { bounds: None });
+        }
+        let ln_q = ln(&(scale(DIGITS) - from_rational(&f_num, &f_den)));
+        let sigma = from_rational(&s_num, &s_den);
+        Ok(Self {
+            bounds: Some(taylor_exp_bounds(&-mul(&sigma, &ln_q))),
+        })
+    }
+
+    /// `cert_nat` is the leader value, below `cert_nat_max`
+    pub(crate) fn is_leader(&self, cert_nat: &BigUint, cert_nat_max: &BigUint) -> bool {
+        let bounds = match &self.bounds {
+            Some(bounds) => bounds,
+            None => return true,
+        };
+        if cert_nat >= cert_nat_max {
+            return false;
+        }
+        let max = BigInt::from(cert_nat_max.clone());
+        let recip_q = from_rational(&max, &(&max - BigInt::from(cert_nat.clone())));
+        // whether recip_q < exp(x), expanding exp(x) until the error bound decides
+        for (upper, lower) in bounds {
+            if &recip_q >= upper {
+                return false;
+            }
+            if &recip_q < lower {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// The Praos leader value of the VRF output of a header and its bound
+pub(crate) fn praos_leader_value(vrf_output: &[u8]) -> (BigUint, BigUint) {
+    let mut bytes = b"L".to_vec();
+    bytes.extend_from_slice(vrf_output);
+    (
+        BigUint::from_bytes_be(&blake2b256(&bytes)),
+        BigUint::one() << 256,
+    )
+}
+
+/// The TPraos leader value is the output of the leader VRF
+pub(crate) fn tpraos_leader_value(vrf_output: &[u8]) -> (BigUint, BigUint) {
+    (BigUint::from_bytes_be(vrf_output), BigUint::one() << 512)
+}
+
+fn unit_interval_parts(
+    interval: &UnitInterval,
+    name: &str,
+) -> Result<(BigInt, BigInt), JsError> {
+    let numerator = BigInt::from(interval.numerator().0);
+    let denominator = BigInt::from(interval.denominator().0);
+    if denominator.is_zero() || numerator > denominator {
+        return Err(JsError::from_str(&format!(
+            "The {} must be between 0 and 1",
+            name
+        )));
+    }
+    Ok((numerator, denominator))
+}
+
+fn scale(digits: u32) -> BigInt {
+    BigInt::from(10u32).pow(digits)
+}
+
+fn from_rational(numerator: &BigInt, denominator: &BigInt) -> BigInt {
+    (numerator * scale(DIGITS)).div_floor(denominator)
+}
+
+fn mul(a: &BigInt, b: &BigInt) -> BigInt {
+    (a * b).div_floor(&scale(DIGITS))
+}
+
+fn div(a: &BigInt, b: &BigInt) -> BigInt {
+    (a * scale(DIGITS)).div_floor(b)
+}
+
+fn epsilon() -> BigInt {
+    scale(DIGITS - EPSILON_DIGITS)
+}
+
+// ln(x) for x in (0, 1] as the ledger's `ln'`: x = e^n x' with x' in [1, e),
+// and ln(x') from the continued fraction of ln(1 + y)
+fn ln(x: &BigInt) -> BigInt {
+    let one = scale(DIGITS);
+    let e = taylor_exp(&one);
+    let mut n = 0;
+    let mut e_n = one.clone();
+    while x < &e_n {
+        n -= 1;
+        e_n = div(&e_n, &e);
+    }
+    BigInt::from(n) * &one + ln_1p(&(div(x, &e_n) - &one))
+}
+
+// ln(1 + y) = y / (1 + 1²y / (2 + 1²y / (3 + 2²y / (4 + 2²y / (5 + ...)))))
+fn ln_1p(y: &BigInt) -> BigInt {
+    // the numerators and denominators of the last two convergents
+    let (mut a_prev, mut b_prev) = (scale(DIGITS), BigInt::zero());
+    let (mut a, mut b) = (BigInt::zero(), scale(DIGITS));
+    let mut convergent = BigInt::zero();
+    for step in 1..=MAX_STEPS {
+        let k = BigInt::from(step / 2);
+        let a_n = if step == 1 { y.clone() } else { y * &k * &k };
+        let b_n = BigInt::from(step) * scale(DIGITS);
+        let next_a = mul(&b_n, &a) + mul(&a_n, &a_prev);
+        let next_b = mul(&b_n, &b) + mul(&a_n, &b_prev);
+        a_prev = std::mem::replace(&mut a, next_a);
+        b_prev = std::mem::replace(&mut b, next_b);
+        let previous = std::mem::replace(&mut convergent, div(&a, &b));
+        if (&convergent - previous).abs() < epsilon() {
+            break;
+        }
+    }
+    convergent
+}
+
+// exp(x) from its series, stopped when a term is below the epsilon
+fn taylor_exp(x: &BigInt) -> BigInt {
+    let mut term = scale(DIGITS);
+    let mut acc = term.clone();
+    for divisor in 1..=MAX_STEPS {
+        term = div(&mul(&term, x), &(BigInt::from(divisor) * scale(DIGITS)));
+        if term.abs() < epsilon() {
+            break;
+        }
+        acc += &term;
+    }
+    acc
+}
+
+// the partial sums of exp(x) plus and minus the bound of the remainder, as the ledger
+// computes them, until the remainder vanishes
+fn taylor_exp_bounds(x: &BigInt) -> Vec<(BigInt, BigInt)> {
+    let bound = BigInt::from(TAYLOR_BOUND) * scale(DIGITS);
+    let mut err = x.clone();
+    let mut acc = scale(DIGITS);
+    let mut divisor = 1u32;
+    let mut bounds = Vec::new();
+    while bounds.len() < MAX_STEPS {
+        divisor += 1;
+        let next = err.clone();
+        err = mul(&err, x).div_floor(&BigInt::from(divisor));
+        acc += next;
+        let error_term = mul(&err, &bound).abs();
+        bounds.push((&acc + &error_term, &acc - &error_term));
+        if error_term.is_zero() {
+            break;
+        }
+    }
+    bounds
+}
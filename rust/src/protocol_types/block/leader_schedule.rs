@@ -0,0 +1,51 @@
+use crate::*;
+
+/// The slots of an epoch a stake pool is elected to lead with Praos
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeaderSchedule {
+    pub(crate) epoch: Epoch,
+    pub(crate) slots: Vec<SlotBigNum>,
+}
+
+#[wasm_bindgen]
+impl LeaderSchedule {
+    /// Checks every slot of the epoch with the VRF key of the pool, for the relative stake
+    /// of the pool in the stake distribution of the epoch
+    pub fn new(
+        vrf_signing_key: &VRFSigningKey,
+        relative_stake: &UnitInterval,
+        active_slots_coeff: &UnitInterval,
+        epoch_nonce: &Nonce,
+        era_history: &EraHistory,
+        epoch: Epoch,
+    ) -> Result<LeaderSchedule, JsError> {
+        let check = LeaderCheck::new(relative_stake, active_slots_coeff)?;
+        let start = era_history.epoch_first_slot(epoch)?;
+        let next_epoch = epoch
+            .checked_add(1)
+            .ok_or_else(|| JsError::from_str("the epoch is the last one"))?;
+        let end = era_history.epoch_first_slot(next_epoch)?;
+        let slots = (start.0..end.0)
+            .map(BigNum)
+            .filter(|slot| {
+                let output = vrf_signing_key.output(&vrf_input(slot, epoch_nonce));
+                let (value, max) = praos_leader_value(&output);
+                check.is_leader(&value, &max)
+            })
+            .collect();
+        Ok(Self { epoch, slots })
+    }
+
+    pub fn epoch(&self) -> Epoch {
+        self.epoch
+    }
+
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn get(&self, index: usize) -> SlotBigNum {
+        self.slots[index]
+    }
+}
@@ -4,6 +4,21 @@ pub use block::*;
 mod fixed_block;
 pub use fixed_block::*;
 
+mod fixed_header;
+pub use fixed_header::*;
+
+mod leader_check;
+pub(crate) use leader_check::*;
+
+mod leader_schedule;
+pub use leader_schedule::*;
+
+mod header_validator;
+pub use header_validator::*;
+
+mod nonce_evolution;
+pub use nonce_evolution::*;
+
 mod fixed_tx_body;
 pub use fixed_tx_body::*;
 
@@ -19,6 +34,9 @@ pub use header_body::*;
 mod operational_cert;
 pub use operational_cert::*;
 
+mod operational_cert_issue_counter;
+pub use operational_cert_issue_counter::*;
+
 mod fixed_versioned_block;
 pub use fixed_versioned_block::*;
 
@@ -0,0 +1,173 @@
+use crate::*;
+use std::convert::TryFrom;
+
+/// The Praos nonces of the chain, updated by each block header and at each epoch transition.
+/// Each block mixes its VRF nonce into the evolving nonce, which is copied to the candidate
+/// nonce until the stability window before the end of the epoch.
+/// The nonce of the next epoch combines the candidate nonce, the hash of the block before
+/// the last block of the previous epoch and the extra entropy of TPraos.
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NonceEvolution {
+    era_history: EraHistory,
+    stability_window: BigNum,
+    epoch: Epoch,
+    epoch_nonce: Nonce,
+    evolving_nonce: Nonce,
+    candidate_nonce: Nonce,
+    lab_nonce: Nonce,
+    last_epoch_block_nonce: Nonce,
+    extra_entropy: Nonce,
+}
+
+#[wasm_bindgen]
+impl NonceEvolution {
+    /// The state at the start of the first Shelley epoch, whose nonces are all `initial_nonce`,
+    /// the hash of the Shelley genesis file
+    pub fn new(
+        era_history: &EraHistory,
+        stability_window: &BigNum,
+        epoch: Epoch,
+        initial_nonce: &Nonce,
+    ) -> Self {
+        Self {
+            era_history: era_history.clone(),
+            stability_window: *stability_window,
+            epoch,
+            epoch_nonce: initial_nonce.clone(),
+            evolving_nonce: initial_nonce.clone(),
+            candidate_nonce: initial_nonce.clone(),
+            lab_nonce: Nonce::new_identity(),
+            last_epoch_block_nonce: Nonce::new_identity(),
+            extra_entropy: Nonce::new_identity(),
+        }
+    }
+
+    /// The nonces in the middle of the epoch of the state, as known from a node
+    pub fn set_nonces(
+        &mut self,
+        epoch_nonce: &Nonce,
+        evolving_nonce: &Nonce,
+        candidate_nonce: &Nonce,
+        lab_nonce: &Nonce,
+        last_epoch_block_nonce: &Nonce,
+    ) {
+        self.epoch_nonce = epoch_nonce.clone();
+        self.evolving_nonce = evolving_nonce.clone();
+        self.candidate_nonce = candidate_nonce.clone();
+        self.lab_nonce = lab_nonce.clone();
+        self.last_epoch_block_nonce = last_epoch_block_nonce.clone();
+    }
+
+    /// The window of `3k/f` slots of TPraos and Babbage
+    pub fn stability_window(
+        security_param: u32,
+        active_slots_coeff: &UnitInterval,
+    ) -> Result<BigNum, JsError> {
+        slots_window(3, security_param, active_slots_coeff)
+    }
+
+    /// The window of `4k/f` slots of the candidate nonce since Conway
+    pub fn randomness_stabilisation_window(
+        security_param: u32,
+        active_slots_coeff: &UnitInterval,
+    ) -> Result<BigNum, JsError> {
+        slots_window(4, security_param, active_slots_coeff)
+    }
+
+    /// Changes the window, when crossing the era which changes it
+    pub fn set_stability_window(&mut self, stability_window: &BigNum) {
+        self.stability_window = *stability_window;
+    }
+
+    /// The `extraEntropy` protocol parameter of TPraos, combined into the epoch nonces
+    pub fn set_extra_entropy(&mut self, extra_entropy: &Nonce) {
+        self.extra_entropy = extra_entropy.clone();
+    }
+
+    pub fn epoch(&self) -> Epoch {
+        self.epoch
+    }
+
+    pub fn epoch_nonce(&self) -> Nonce {
+        self.epoch_nonce.clone()
+    }
+
+    pub fn evolving_nonce(&self) -> Nonce {
+        self.evolving_nonce.clone()
+    }
+
+    pub fn candidate_nonce(&self) -> Nonce {
+        self.candidate_nonce.clone()
+    }
+
+    /// The nonce of the hash of the block before the last applied block
+    pub fn lab_nonce(&self) -> Nonce {
+        self.lab_nonce.clone()
+    }
+
+    pub fn last_epoch_block_nonce(&self) -> Nonce {
+        self.last_epoch_block_nonce.clone()
+    }
+
+    /// The nonce of the next epoch, which is final once the candidate nonce is frozen
+    pub fn next_epoch_nonce(&self) -> Nonce {
+        self.candidate_nonce
+            .combine(&self.last_epoch_block_nonce)
+            .combine(&self.extra_entropy)
+    }
+
+    /// Moves to the epoch of the header if needed and applies its VRF nonce
+    pub fn apply_header(&mut self, header_body: &HeaderBody) -> Result<(), JsError> {
+        let epoch = self.era_history.slot_to_epoch(&header_body.slot)?;
+        if epoch < self.epoch {
+            return Err(JsError::from_str(&format!(
+                "Header of epoch {} is before the epoch {}",
+                epoch, self.epoch
+            )));
+        }
+        let next_epoch_start = self.era_history.epoch_first_slot(epoch + 1)?;
+        if epoch > self.epoch {
+            self.tick_epoch(epoch);
+        }
+        self.evolving_nonce = self.evolving_nonce.combine(&header_body.vrf_nonce());
+        if header_body.slot.0.saturating_add(self.stability_window.0) < next_epoch_start.0 {
+            self.candidate_nonce = self.evolving_nonce.clone();
+        }
+        self.lab_nonce = match &header_body.prev_hash {
+            Some(prev_hash) => Nonce {
+                hash: Some(prev_hash.0),
+            },
+            None => Nonce::new_identity(),
+        };
+        Ok(())
+    }
+
+    /// Moves to an epoch without blocks applied before its first header
+    pub fn tick_epoch(&mut self, epoch: Epoch) {
+        if epoch > self.epoch {
+            self.epoch_nonce = self.next_epoch_nonce();
+            self.last_epoch_block_nonce = self.lab_nonce.clone();
+            self.epoch = epoch;
+        }
+    }
+}
+
+// ceil(multiplier * k / f)
+fn slots_window(
+    multiplier: u64,
+    security_param: u32,
+    active_slots_coeff: &UnitInterval,
+) -> Result<BigNum, JsError> {
+    let numerator = active_slots_coeff.numerator().0 as u128;
+    let denominator = active_slots_coeff.denominator().0 as u128;
+    if numerator == 0 {
+        return Err(JsError::from_str(
+            "The active slots coefficient must be positive",
+        ));
+    }
+    let slots = (multiplier as u128 * security_param as u128 * denominator).div_ceil(numerator);
+    u64::try_from(slots)
+        .map(BigNum)
+        .map_err(|_| JsError::from_str("Stability window overflow"))
+}
@@ -29,6 +29,11 @@ impl OperationalCert {
         self.sigma.clone()
     }
 
+    /// True if `sigma` is the signature of the certificate by the pool cold key
+    pub fn verify(&self, cold_vkey: &Vkey) -> bool {
+        cold_vkey.0.verify(&self.signable_bytes(), &self.sigma)
+    }
+
     pub fn new(
         hot_vkey: &KESVKey,
         sequence_number: u32,
@@ -42,4 +47,58 @@ impl OperationalCert {
             sigma: sigma.clone(),
         }
     }
-}
\ No newline at end of file
+
+    /// Certificate of the hot key starting at `kes_period`, signed by the pool cold key
+    pub fn new_signed(
+        hot_vkey: &KESVKey,
+        sequence_number: u32,
+        kes_period: u32,
+        cold_key: &PrivateKey,
+    ) -> Self {
+        let signable = signable_bytes(hot_vkey, sequence_number, kes_period);
+        Self::new(hot_vkey, sequence_number, kes_period, &cold_key.sign(&signable))
+    }
+
+    /// The node certificate file, which also holds the cold verification key
+    pub fn to_text_envelope(&self, cold_vkey: &Vkey) -> TextEnvelope {
+        let mut serializer = Serializer::new_vec();
+        serializer
+            .write_array(cbor_event::Len::Len(2))
+            .and_then(|serializer| self.serialize(serializer))
+            .and_then(|serializer| serializer.write_bytes(cold_vkey.0.as_bytes()))
+            .expect("write to a vec");
+        TextEnvelope::new(TextEnvelope::OPERATIONAL_CERT, "", &serializer.finalize())
+    }
+
+    /// Fails when the certificate isn't signed by the cold key of the file
+    pub fn from_text_envelope(envelope: &TextEnvelope) -> Result<OperationalCert, JsError> {
+        let cbor = envelope.cbor_of_type(TextEnvelope::OPERATIONAL_CERT)?;
+        let mut raw = Deserializer::from(std::io::Cursor::new(cbor));
+        let (cert, cold_vkey) = (|| -> Result<_, DeserializeError> {
+            raw.array()?;
+            let cert = OperationalCert::deserialize(&mut raw)?;
+            Ok((cert, raw.bytes()?))
+        })()
+        .map_err(|e| e.annotate("NodeOperationalCertificate"))?;
+        if !cert.verify(&Vkey::new(&PublicKey::from_bytes(&cold_vkey)?)) {
+            return Err(JsError::from_str(
+                "Operational certificate isn't signed by its cold key",
+            ));
+        }
+        Ok(cert)
+    }
+}
+
+impl OperationalCert {
+    pub(crate) fn signable_bytes(&self) -> Vec<u8> {
+        signable_bytes(&self.hot_vkey, self.sequence_number, self.kes_period)
+    }
+}
+
+// the hot key followed by the counter and the start period as 8 bytes big endian
+fn signable_bytes(hot_vkey: &KESVKey, sequence_number: u32, kes_period: u32) -> Vec<u8> {
+    let mut bytes = hot_vkey.to_bytes();
+    bytes.extend_from_slice(&(sequence_number as u64).to_be_bytes());
+    bytes.extend_from_slice(&(kes_period as u64).to_be_bytes());
+    bytes
+}
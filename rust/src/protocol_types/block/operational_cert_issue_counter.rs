@@ -0,0 +1,83 @@
+use crate::*;
+
+/// The counter of the operational certificates issued by a pool cold key,
+/// the node rejecting a certificate whose counter isn't above the last one seen
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OperationalCertIssueCounter {
+    pub(crate) counter: u32,
+    pub(crate) cold_vkey: Vkey,
+}
+
+#[wasm_bindgen]
+impl OperationalCertIssueCounter {
+    pub fn new(counter: u32, cold_vkey: &Vkey) -> Self {
+        Self {
+            counter,
+            cold_vkey: cold_vkey.clone(),
+        }
+    }
+
+    /// The sequence number of the next certificate
+    pub fn counter(&self) -> u32 {
+        self.counter
+    }
+
+    pub fn cold_vkey(&self) -> Vkey {
+        self.cold_vkey.clone()
+    }
+
+    /// The id of the pool of the cold key
+    pub fn pool_id(&self) -> Ed25519KeyHash {
+        self.cold_vkey.0.hash()
+    }
+
+    /// Certificate of the hot key with the next sequence number, incrementing the counter
+    pub fn issue(
+        &mut self,
+        hot_vkey: &KESVKey,
+        kes_period: u32,
+        cold_key: &PrivateKey,
+    ) -> Result<OperationalCert, JsError> {
+        if cold_key.to_public() != self.cold_vkey.0 {
+            return Err(JsError::from_str(
+                "Cold key doesn't match the key of the issue counter",
+            ));
+        }
+        let next = self
+            .counter
+            .checked_add(1)
+            .ok_or_else(|| JsError::from_str("Operational certificate counter overflow"))?;
+        let cert = OperationalCert::new_signed(hot_vkey, self.counter, kes_period, cold_key);
+        self.counter = next;
+        Ok(cert)
+    }
+
+    pub fn to_text_envelope(&self) -> TextEnvelope {
+        let mut serializer = Serializer::new_vec();
+        serializer
+            .write_array(cbor_event::Len::Len(2))
+            .and_then(|serializer| serializer.write_unsigned_integer(self.counter as u64))
+            .and_then(|serializer| serializer.write_bytes(self.cold_vkey.0.as_bytes()))
+            .expect("write to a vec");
+        TextEnvelope::new(
+            TextEnvelope::OPERATIONAL_CERT_ISSUE_COUNTER,
+            &format!("Next certificate issue number: {}", self.counter),
+            &serializer.finalize(),
+        )
+    }
+
+    pub fn from_text_envelope(
+        envelope: &TextEnvelope,
+    ) -> Result<OperationalCertIssueCounter, JsError> {
+        let cbor = envelope.cbor_of_type(TextEnvelope::OPERATIONAL_CERT_ISSUE_COUNTER)?;
+        let mut raw = Deserializer::from(std::io::Cursor::new(cbor));
+        let (counter, cold_vkey) = (|| -> Result<_, DeserializeError> {
+            raw.array()?;
+            let counter = u32::deserialize(&mut raw)?;
+            Ok((counter, raw.bytes()?))
+        })()
+        .map_err(|e| e.annotate("NodeOperationalCertificateIssueCounter"))?;
+        Ok(Self::new(counter, &Vkey::new(&PublicKey::from_bytes(&cold_vkey)?)))
+    }
+}
@@ -0,0 +1,109 @@
+use crate::*;
+
+const CIP67_LABEL_LENGTH: usize = 4;
+const MAX_ASSET_NAME_BODY_LENGTH: usize = 32 - CIP67_LABEL_LENGTH;
+
+/// Asset name label from CIP-67.
+/// The label is encoded as 4 bytes prefix of the asset name:
+/// 4 zero bits, 16 bits of the label, 8 bits of CRC-8 checksum of the label and 4 zero bits.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Cip67Label(u16);
+
+#[wasm_bindgen]
+impl Cip67Label {
+    pub fn new(label: u16) -> Self {
+        Self(label)
+    }
+
+    /// (100) Reference NFT that holds the CIP-68 datum
+    pub fn reference_nft() -> Self {
+        Self(100)
+    }
+
+    /// (222) NFT held by the user
+    pub fn nft() -> Self {
+        Self(222)
+    }
+
+    /// (333) Fungible token held by the user
+    pub fn fungible_token() -> Self {
+        Self(333)
+    }
+
+    /// (444) Rich fungible token held by the user
+    pub fn rich_fungible_token() -> Self {
+        Self(444)
+    }
+
+    pub fn label(&self) -> u16 {
+        self.0
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let label = self.0.to_be_bytes();
+        let checksum = crc8(&label);
+        let prefix: u32 = ((self.0 as u32) << 12) | ((checksum as u32) << 4);
+        prefix.to_be_bytes().to_vec()
+    }
+
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Cip67Label, JsError> {
+        Self::from_prefix(&bytes).ok_or_else(|| JsError::from_str("Invalid CIP-67 label"))
+    }
+
+    /// Creates an asset name from the label prefix followed by the body (at most 28 bytes)
+    pub fn asset_name(&self, body: Vec<u8>) -> Result<AssetName, JsError> {
+        if body.len() > MAX_ASSET_NAME_BODY_LENGTH {
+            return Err(JsError::from_str(&format!(
+                "Asset name body is too long: {} bytes, max {}",
+                body.len(),
+                MAX_ASSET_NAME_BODY_LENGTH
+            )));
+        }
+        let mut name = self.to_bytes();
+        name.extend(body);
+        AssetName::new(name)
+    }
+
+    /// Returns the label of the asset name if it starts with a valid CIP-67 prefix
+    pub fn from_asset_name(asset_name: &AssetName) -> Option<Cip67Label> {
+        Self::from_prefix(&asset_name.0)
+    }
+
+    /// Returns the asset name without the CIP-67 label prefix
+    pub fn asset_name_body(asset_name: &AssetName) -> Option<Vec<u8>> {
+        Self::from_prefix(&asset_name.0).map(|_| asset_name.0[CIP67_LABEL_LENGTH..].to_vec())
+    }
+
+    fn from_prefix(bytes: &[u8]) -> Option<Cip67Label> {
+        if bytes.len() < CIP67_LABEL_LENGTH {
+            return None;
+        }
+        let prefix = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        if prefix & 0xF000_000F != 0 {
+            return None;
+        }
+        let label = (prefix >> 12) as u16;
+        let checksum = ((prefix >> 4) & 0xFF) as u8;
+        if crc8(&label.to_be_bytes()) != checksum {
+            return None;
+        }
+        Some(Self(label))
+    }
+}
+
+// CRC-8 with polynomial x^8 + x^2 + x + 1 (0x07) and zero init, as specified by CIP-67
+fn crc8(data: &[u8]) -> u8 {
+    let mut crc: u8 = 0;
+    for byte in data {
+        crc ^= byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
@@ -0,0 +1,125 @@
+use crate::*;
+
+/// Datum of a CIP-68 reference token: `Constr 0 [metadata, version, extra]`
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Cip68Datum {
+    metadata: PlutusMap,
+    version: BigInt,
+    extra: PlutusData,
+}
+
+#[wasm_bindgen]
+impl Cip68Datum {
+    /// Creates a datum with the unit value (`Constr 0 []`) as extra
+    pub fn new(metadata: &PlutusMap, version: u64) -> Self {
+        Self::new_with_extra(
+            metadata,
+            version,
+            &PlutusData::new_empty_constr_plutus_data(&BigNum::zero()),
+        )
+    }
+
+    pub fn new_with_extra(metadata: &PlutusMap, version: u64, extra: &PlutusData) -> Self {
+        Self {
+            metadata: metadata.clone(),
+            version: BigInt::from(version),
+            extra: extra.clone(),
+        }
+    }
+
+    /// Creates a datum from a JSON object with the token metadata, e.g.
+    /// {"name": "SpaceBud #1", "image": "ipfs://...", "files": [{"src": "ipfs://...", "mediaType": "image/png"}]}
+    /// Object keys and strings are encoded as their utf8 bytes, strings starting with 0x as hex bytes.
+    pub fn from_json_metadata(
+        json: &str,
+        version: u64,
+        extra: Option<PlutusData>,
+    ) -> Result<Cip68Datum, JsError> {
+        let value: serde_json::Value =
+            serde_json::from_str(json).map_err(|e| JsError::from_str(&e.to_string()))?;
+        let obj = match value {
+            serde_json::Value::Object(obj) => obj,
+            _ => return Err(JsError::from_str("CIP-68 metadata must be a JSON object")),
+        };
+        let mut metadata = PlutusMap::new();
+        for (key, value) in obj {
+            let value = encode_json_value_to_plutus_datum(value, PlutusDatumSchema::BasicConversions)
+                .map_err(|e| JsError::from_str(&format!("Invalid value of \"{}\": {:?}", key, e)))?;
+            metadata.add_value_move(PlutusData::new_bytes(key.into_bytes()), value);
+        }
+        Ok(match extra {
+            Some(extra) => Self::new_with_extra(&metadata, version, &extra),
+            None => Self::new(&metadata, version),
+        })
+    }
+
+    pub fn metadata(&self) -> PlutusMap {
+        self.metadata.clone()
+    }
+
+    /// Returns the metadata as a JSON object, the reverse of `from_json_metadata`.
+    /// Bytes that aren't printable utf8 are encoded as hex strings starting with 0x.
+    pub fn metadata_json(&self) -> Result<String, JsError> {
+        decode_plutus_datum_to_json_str(
+            &PlutusData::new_map(&self.metadata),
+            PlutusDatumSchema::BasicConversions,
+        )
+    }
+
+    /// Returns a metadata value by its utf8 key, e.g. "name"
+    pub fn get(&self, key: &str) -> Option<PlutusData> {
+        self.metadata
+            .get(&PlutusData::new_bytes(key.as_bytes().to_vec()))
+            .and_then(|values| values.get(0))
+    }
+
+    pub fn version(&self) -> BigInt {
+        self.version.clone()
+    }
+
+    pub fn extra(&self) -> PlutusData {
+        self.extra.clone()
+    }
+
+    pub fn to_plutus_data(&self) -> PlutusData {
+        let mut fields = PlutusList::new();
+        fields.add(&PlutusData::new_map(&self.metadata));
+        fields.add(&PlutusData::new_integer(&self.version));
+        fields.add(&self.extra);
+        PlutusData::new_constr_plutus_data(&ConstrPlutusData::new(&BigNum::zero(), &fields))
+    }
+
+    pub fn from_plutus_data(data: &PlutusData) -> Result<Cip68Datum, JsError> {
+        let constr = data
+            .as_constr_plutus_data()
+            .ok_or_else(|| JsError::from_str("CIP-68 datum must be a constructor"))?;
+        if constr.alternative() != BigNum::zero() {
+            return Err(JsError::from_str("CIP-68 datum must use constructor 0"));
+        }
+        let fields = constr.data();
+        if fields.len() < 2 || fields.len() > 3 {
+            return Err(JsError::from_str(
+                "CIP-68 datum must contain metadata, version and extra fields",
+            ));
+        }
+        let metadata = fields
+            .get(0)
+            .as_map()
+            .ok_or_else(|| JsError::from_str("CIP-68 metadata must be a map"))?;
+        let version = fields
+            .get(1)
+            .as_integer()
+            .ok_or_else(|| JsError::from_str("CIP-68 version must be an integer"))?;
+        let extra = if fields.len() == 3 {
+            fields.get(2)
+        } else {
+            PlutusData::new_empty_constr_plutus_data(&BigNum::zero())
+        };
+        Ok(Self {
+            metadata,
+            version,
+            extra,
+        })
+    }
+}
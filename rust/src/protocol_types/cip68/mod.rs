@@ -0,0 +1,5 @@
+mod cip67_label;
+pub use cip67_label::*;
+
+mod cip68_datum;
+pub use cip68_datum::*;
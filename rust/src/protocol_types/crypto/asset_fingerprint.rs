@@ -0,0 +1,11 @@
+use crate::*;
+
+#[wasm_bindgen]
+impl AssetFingerprint {
+    /// The CIP-14 fingerprint of the asset, hash of the policy id followed by the asset name
+    pub fn new(policy_id: &PolicyID, asset_name: &AssetName) -> Self {
+        let mut bytes = policy_id.to_bytes();
+        bytes.extend_from_slice(&asset_name.0);
+        AssetFingerprint(blake2b160(&bytes))
+    }
+}
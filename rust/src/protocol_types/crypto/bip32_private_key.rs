@@ -96,6 +96,15 @@ impl Bip32PrivateKey {
         self.0.to_bech32_str()
     }
 
+    /// Bech32 with the CIP-5 prefix of the extended signing keys of `role`, like `acct_xsk`
+    pub fn to_cip5_bech32(&self, role: KeyRole) -> Result<String, JsError> {
+        role.encode(KeyForm::ExtendedSigningKey, &self.as_bytes())
+    }
+
+    pub fn from_cip5_bech32(bech32_str: &str, role: KeyRole) -> Result<Bip32PrivateKey, JsError> {
+        Self::from_bytes(&role.decode(KeyForm::ExtendedSigningKey, bech32_str)?)
+    }
+
     pub fn from_bip39_entropy(entropy: &[u8], password: &[u8]) -> Bip32PrivateKey {
         Bip32PrivateKey(crate::chain_crypto::derive::from_bip39_entropy(&entropy, &password))
     }
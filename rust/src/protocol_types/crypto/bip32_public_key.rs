@@ -1,4 +1,4 @@
-use crate::{JsError, PublicKey, wasm_bindgen};
+use crate::{JsError, KeyForm, KeyRole, PublicKey, wasm_bindgen};
 use crate::chain_crypto::bech32::Bech32;
 
 #[wasm_bindgen]
@@ -60,6 +60,15 @@ impl Bip32PublicKey {
         self.0.to_bech32_str()
     }
 
+    /// Bech32 with the CIP-5 prefix of the extended verification keys of `role`, like `acct_xvk`
+    pub fn to_cip5_bech32(&self, role: KeyRole) -> Result<String, JsError> {
+        role.encode(KeyForm::ExtendedVerificationKey, &self.as_bytes())
+    }
+
+    pub fn from_cip5_bech32(bech32_str: &str, role: KeyRole) -> Result<Bip32PublicKey, JsError> {
+        Self::from_bytes(&role.decode(KeyForm::ExtendedVerificationKey, bech32_str)?)
+    }
+
     pub fn chaincode(&self) -> Vec<u8> {
         const ED25519_PUBLIC_KEY_LENGTH: usize = 32;
         const XPUB_SIZE: usize = 64;
@@ -0,0 +1,12 @@
+use crate::*;
+use rand_os::rand_core::RngCore;
+use rand_os::OsRng;
+
+/// Random seed of the VRF and KES keys
+pub(crate) fn random_seed() -> Result<[u8; 32], JsError> {
+    let mut seed = [0; 32];
+    OsRng::new()
+        .map_err(|e| JsError::from_str(&format!("{}", e)))?
+        .fill_bytes(&mut seed);
+    Ok(seed)
+}
@@ -0,0 +1,73 @@
+use crate::*;
+use crate::legacy_address::{unwrap_payload, HDKey};
+
+/// Derivation path from the root key of a legacy Daedalus wallet, hardened indexes included
+#[wasm_bindgen]
+#[derive(Clone, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct DaedalusDerivationPath(pub(crate) Vec<u32>);
+
+#[wasm_bindgen]
+impl DaedalusDerivationPath {
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn get(&self, index: usize) -> u32 {
+        self.0[index]
+    }
+
+    pub fn add(&mut self, index: u32) {
+        self.0.push(index);
+    }
+}
+
+/// Key decrypting the derivation paths stored in the addresses of a legacy Daedalus wallet.
+/// The path of an address gives, through `LegacyDaedalusPrivateKey::derive_path`,
+/// the key passed to `make_daedalus_bootstrap_witness` when spending from it.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct DaedalusHDPassphrase(pub(crate) HDKey);
+
+#[wasm_bindgen]
+impl DaedalusHDPassphrase {
+    pub fn from_root_key(root_key: &LegacyDaedalusPrivateKey) -> DaedalusHDPassphrase {
+        Self(HDKey::new(&root_key.0.to_public().0))
+    }
+
+    pub fn from_root_public_key(root_key: &Bip32PublicKey) -> DaedalusHDPassphrase {
+        Self(HDKey::new(&root_key.0 .0))
+    }
+
+    /// Encrypted payload, to be stored in the `derivation_path` attribute of an address
+    pub fn encrypt_derivation_path(&self, path: &DaedalusDerivationPath) -> Vec<u8> {
+        self.0.encrypt_path(&path.0)
+    }
+
+    pub fn decrypt_derivation_path(
+        &self,
+        payload: &[u8],
+    ) -> Result<DaedalusDerivationPath, JsError> {
+        self.0
+            .decrypt_path(payload)
+            .map(DaedalusDerivationPath)
+            .ok_or_else(|| {
+                JsError::from_str("Payload isn't a derivation path encrypted with this key")
+            })
+    }
+
+    /// The derivation path of the address, if it belongs to the wallet of this key
+    pub fn derivation_path(&self, address: &ByronAddress) -> Option<DaedalusDerivationPath> {
+        let attribute = address.0.attributes.derivation_path.as_ref()?;
+        self.0
+            .decrypt_path(&unwrap_payload(attribute)?)
+            .map(DaedalusDerivationPath)
+    }
+
+    pub fn is_own_address(&self, address: &ByronAddress) -> bool {
+        self.derivation_path(address).is_some()
+    }
+}
@@ -1,5 +1,23 @@
 #[macro_export]
 macro_rules! impl_hash_type {
+    ($name:ident, $byte_count:expr, $bech32_prefix:expr) => {
+        $crate::impl_hash_type!($name, $byte_count);
+
+        #[wasm_bindgen]
+        impl $name {
+            /// Bech32 with the CIP-5 prefix of the type
+            pub fn to_cip5_bech32(&self) -> String {
+                $crate::chain_crypto::bech32::to_bech32_with_hrp($bech32_prefix, &self.0)
+            }
+
+            pub fn from_cip5_bech32(bech_str: &str) -> Result<$name, JsError> {
+                let bytes =
+                    $crate::chain_crypto::bech32::try_from_bech32_with_hrp(bech_str, $bech32_prefix)
+                        .map_err(|e| JsError::from_str(&e.to_string()))?;
+                Self::from_bytes(bytes).map_err(|e| JsError::from_str(&format!("{:?}", e)))
+            }
+        }
+    };
     ($name:ident, $byte_count:expr) => {
         #[wasm_bindgen]
         #[derive(Debug, Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -1,4 +1,5 @@
 use crate::*;
+use crate::chain_crypto::sum_kes;
 
 #[wasm_bindgen]
 #[derive(Debug, Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -9,6 +10,12 @@ impl KESSignature {
     pub fn to_bytes(&self) -> Vec<u8> {
         self.0.clone()
     }
+
+    /// True if this is a signature of `message` by `hot_vkey` at `period`,
+    /// counted from the start period of the operational certificate
+    pub fn verify(&self, hot_vkey: &KESVKey, period: u32, message: &[u8]) -> bool {
+        sum_kes::verify(&hot_vkey.0, period, message, &self.0)
+    }
 }
 
 // associated consts are not supported in wasm_bindgen
@@ -0,0 +1,139 @@
+use crate::*;
+use crate::chain_crypto::bech32::{self, cip5};
+use crate::chain_crypto::sum_kes;
+use std::convert::TryFrom;
+
+/// Sum6KES signing key of a stake pool, signing the block headers during the
+/// 64 periods following the start period of its operational certificate
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct KESSigningKey {
+    pub(crate) secret: Vec<u8>,
+    pub(crate) period: u32,
+}
+
+#[wasm_bindgen]
+impl KESSigningKey {
+    pub fn generate() -> Result<KESSigningKey, JsError> {
+        let seed = random_seed()?;
+        Ok(Self {
+            secret: sum_kes::generate(&seed),
+            period: 0,
+        })
+    }
+
+    pub fn from_seed(seed: &[u8]) -> Result<KESSigningKey, JsError> {
+        let seed = <[u8; sum_kes::SEED_SIZE]>::try_from(seed).map_err(|_| {
+            JsError::from_str(&format!("KES seed must be {} bytes", sum_kes::SEED_SIZE))
+        })?;
+        Ok(Self {
+            secret: sum_kes::generate(&seed),
+            period: 0,
+        })
+    }
+
+    /// The key file format doesn't record the period, so it's given by the caller
+    pub fn from_bytes(bytes: &[u8], period: u32) -> Result<KESSigningKey, JsError> {
+        if bytes.len() != sum_kes::SECRET_KEY_SIZE {
+            return Err(JsError::from_str(&format!(
+                "KES signing key must be {} bytes",
+                sum_kes::SECRET_KEY_SIZE
+            )));
+        }
+        if period >= sum_kes::PERIODS {
+            return Err(JsError::from_str(&format!(
+                "KES period must be lower than {}",
+                sum_kes::PERIODS
+            )));
+        }
+        Ok(Self {
+            secret: bytes.to_vec(),
+            period,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.secret.clone()
+    }
+
+    /// The period of the key, counted from the start period of the operational certificate
+    pub fn period(&self) -> u32 {
+        self.period
+    }
+
+    pub fn to_public(&self) -> KESVKey {
+        KESVKey(sum_kes::public_key(&self.secret))
+    }
+
+    pub fn sign(&self, message: &[u8]) -> KESSignature {
+        KESSignature(sum_kes::sign(&self.secret, message))
+    }
+
+    /// Evolves the key to the next period, forgetting the keys of the past periods
+    pub fn evolve(&mut self) -> Result<(), JsError> {
+        if !sum_kes::update(&mut self.secret, self.period) {
+            return Err(JsError::from_str("KES key is at its last period"));
+        }
+        self.period += 1;
+        Ok(())
+    }
+
+    /// Evolves the key up to `period`, a key can't go back to a past period
+    pub fn evolve_to(&mut self, period: u32) -> Result<(), JsError> {
+        if period < self.period || period >= sum_kes::PERIODS {
+            return Err(JsError::from_str(&format!(
+                "KES key of period {} can't evolve to period {}",
+                self.period, period
+            )));
+        }
+        while self.period < period {
+            self.evolve()?;
+        }
+        Ok(())
+    }
+
+    pub fn to_text_envelope(&self) -> TextEnvelope {
+        TextEnvelope::new_bytes(
+            TextEnvelope::KES_SIGNING_KEY,
+            "KES Signing Key",
+            &self.secret,
+        )
+    }
+
+    pub fn from_text_envelope(
+        envelope: &TextEnvelope,
+        period: u32,
+    ) -> Result<KESSigningKey, JsError> {
+        Self::from_bytes(
+            &envelope.bytes_of_type(TextEnvelope::KES_SIGNING_KEY)?,
+            period,
+        )
+    }
+
+    /// Bech32 `kes_sk` of the bytes of the key files, which don't record the period either
+    pub fn to_cip5_bech32(&self) -> String {
+        bech32::to_bech32_with_hrp(cip5::KES_SK, &self.secret)
+    }
+
+    pub fn from_cip5_bech32(bech32_str: &str, period: u32) -> Result<KESSigningKey, JsError> {
+        let bytes = bech32::try_from_bech32_with_hrp(bech32_str, cip5::KES_SK)
+            .map_err(|e| JsError::from_str(&e.to_string()))?;
+        Self::from_bytes(&bytes, period)
+    }
+}
+
+#[wasm_bindgen]
+impl KESVKey {
+    pub fn to_text_envelope(&self) -> TextEnvelope {
+        TextEnvelope::new_bytes(
+            TextEnvelope::KES_VERIFICATION_KEY,
+            "KES Verification Key",
+            &self.0,
+        )
+    }
+
+    pub fn from_text_envelope(envelope: &TextEnvelope) -> Result<KESVKey, JsError> {
+        let bytes = envelope.bytes_of_type(TextEnvelope::KES_VERIFICATION_KEY)?;
+        KESVKey::from_bytes(bytes).map_err(|e| JsError::from_str(&format!("{:?}", e)))
+    }
+}
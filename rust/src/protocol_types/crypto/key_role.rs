@@ -0,0 +1,166 @@
+use crate::chain_crypto::bech32::{self, cip5};
+use crate::*;
+
+/// The role of an ed25519 key in the wallets and on chain,
+/// which selects the CIP-5 prefixes of the key and of its hash
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum KeyRole {
+    Root,
+    RootShared,
+    Acct,
+    AcctShared,
+    Addr,
+    AddrShared,
+    Stake,
+    StakeShared,
+    Policy,
+    Pool,
+    DRep,
+    CCCold,
+    CCHot,
+    CVote,
+    ReqSigner,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum KeyForm {
+    SigningKey,
+    VerificationKey,
+    ExtendedSigningKey,
+    ExtendedVerificationKey,
+    KeyHash,
+}
+
+impl KeyRole {
+    // signing key, verification key, their extended forms and key hash
+    fn prefixes(&self) -> [Option<&'static str>; 5] {
+        use cip5::*;
+        match self {
+            KeyRole::Root => [
+                Some(ROOT_SK),
+                Some(ROOT_VK),
+                Some(ROOT_XSK),
+                Some(ROOT_XVK),
+                None,
+            ],
+            KeyRole::RootShared => [
+                Some(ROOT_SHARED_SK),
+                Some(ROOT_SHARED_VK),
+                Some(ROOT_SHARED_XSK),
+                Some(ROOT_SHARED_XVK),
+                None,
+            ],
+            KeyRole::Acct => [
+                Some(ACCT_SK),
+                Some(ACCT_VK),
+                Some(ACCT_XSK),
+                Some(ACCT_XVK),
+                None,
+            ],
+            KeyRole::AcctShared => [
+                Some(ACCT_SHARED_SK),
+                Some(ACCT_SHARED_VK),
+                Some(ACCT_SHARED_XSK),
+                Some(ACCT_SHARED_XVK),
+                None,
+            ],
+            KeyRole::Addr => [
+                Some(ADDR_SK),
+                Some(ADDR_VK),
+                Some(ADDR_XSK),
+                Some(ADDR_XVK),
+                Some(ADDR_VKH),
+            ],
+            KeyRole::AddrShared => [
+                Some(ADDR_SHARED_SK),
+                Some(ADDR_SHARED_VK),
+                Some(ADDR_SHARED_XSK),
+                Some(ADDR_SHARED_XVK),
+                Some(ADDR_SHARED_VKH),
+            ],
+            KeyRole::Stake => [
+                Some(STAKE_SK),
+                Some(STAKE_VK),
+                Some(STAKE_XSK),
+                Some(STAKE_XVK),
+                Some(STAKE_VKH),
+            ],
+            KeyRole::StakeShared => [
+                Some(STAKE_SHARED_SK),
+                Some(STAKE_SHARED_VK),
+                Some(STAKE_SHARED_XSK),
+                Some(STAKE_SHARED_XVK),
+                Some(STAKE_SHARED_VKH),
+            ],
+            KeyRole::Policy => [
+                Some(POLICY_SK),
+                Some(POLICY_VK),
+                Some(POLICY_XSK),
+                Some(POLICY_XVK),
+                Some(POLICY_VKH),
+            ],
+            KeyRole::Pool => [
+                Some(POOL_SK),
+                Some(POOL_VK),
+                Some(POOL_XSK),
+                Some(POOL_XVK),
+                Some(POOL),
+            ],
+            KeyRole::DRep => [
+                Some(DREP_SK),
+                Some(DREP_VK),
+                Some(DREP_XSK),
+                Some(DREP_XVK),
+                Some(DREP_VKH),
+            ],
+            KeyRole::CCCold => [
+                Some(CC_COLD_SK),
+                Some(CC_COLD_VK),
+                Some(CC_COLD_XSK),
+                Some(CC_COLD_XVK),
+                Some(CC_COLD_VKH),
+            ],
+            KeyRole::CCHot => [
+                Some(CC_HOT_SK),
+                Some(CC_HOT_VK),
+                Some(CC_HOT_XSK),
+                Some(CC_HOT_XVK),
+                Some(CC_HOT_VKH),
+            ],
+            KeyRole::CVote => [Some(CVOTE_SK), Some(CVOTE_VK), None, None, None],
+            KeyRole::ReqSigner => [None, None, None, None, Some(REQ_SIGNER_VKH)],
+        }
+    }
+
+    pub(crate) fn prefix(&self, form: KeyForm) -> Result<&'static str, JsError> {
+        self.prefixes()[form as usize].ok_or_else(|| {
+            JsError::from_str(&format!(
+                "No CIP-5 prefix for the {:?} of the {:?} role",
+                form, self
+            ))
+        })
+    }
+
+    pub(crate) fn encode(&self, form: KeyForm, bytes: &[u8]) -> Result<String, JsError> {
+        Ok(bech32::to_bech32_with_hrp(self.prefix(form)?, bytes))
+    }
+
+    pub(crate) fn decode(&self, form: KeyForm, bech32_str: &str) -> Result<Vec<u8>, JsError> {
+        bech32::try_from_bech32_with_hrp(bech32_str, self.prefix(form)?)
+            .map_err(|e| JsError::from_str(&e.to_string()))
+    }
+}
+
+#[wasm_bindgen]
+impl Ed25519KeyHash {
+    /// Bech32 with the CIP-5 prefix of the key hashes of `role`, `pool` for the pool ids
+    pub fn to_cip5_bech32(&self, role: KeyRole) -> Result<String, JsError> {
+        role.encode(KeyForm::KeyHash, &self.0)
+    }
+
+    pub fn from_cip5_bech32(bech_str: &str, role: KeyRole) -> Result<Ed25519KeyHash, JsError> {
+        let bytes = role.decode(KeyForm::KeyHash, bech_str)?;
+        Self::from_bytes(bytes).map_err(|e| JsError::from_str(&format!("{:?}", e)))
+    }
+}
@@ -2,6 +2,7 @@ use crate::*;
 use crate::chain_crypto as crypto;
 
 #[wasm_bindgen]
+#[derive(Clone)]
 pub struct LegacyDaedalusPrivateKey(pub(crate) crypto::SecretKey<crypto::LegacyDaedalus>);
 
 #[wasm_bindgen]
@@ -16,6 +17,22 @@ impl LegacyDaedalusPrivateKey {
         self.0.as_ref().to_vec()
     }
 
+    /// Child key with the V1 derivation of the legacy Daedalus wallets,
+    /// indexes from 0x80000000 are hardened
+    pub fn derive(&self, index: u32) -> LegacyDaedalusPrivateKey {
+        LegacyDaedalusPrivateKey(crypto::SecretKey(self.0 .0.derive_v1(index)))
+    }
+
+    /// Key of the address with this derivation path from the root key,
+    /// as recovered by `DaedalusHDPassphrase::derivation_path`
+    pub fn derive_path(&self, path: &DaedalusDerivationPath) -> LegacyDaedalusPrivateKey {
+        path.0.iter().fold(self.clone(), |key, index| key.derive(*index))
+    }
+
+    pub fn to_public(&self) -> Bip32PublicKey {
+        Bip32PublicKey(crypto::PublicKey(self.0.to_public().0))
+    }
+
     pub fn chaincode(&self) -> Vec<u8> {
         const ED25519_PRIVATE_KEY_LENGTH: usize = 64;
         const XPRV_SIZE: usize = 96;
@@ -1,20 +1,23 @@
 use crate::*;
+use crate::chain_crypto::bech32::cip5;
 
 impl_hash_type!(Ed25519KeyHash, 28);
-impl_hash_type!(ScriptHash, 28);
+impl_hash_type!(ScriptHash, 28, cip5::SCRIPT);
 impl_hash_type!(AnchorDataHash, 32);
 impl_hash_type!(TransactionHash, 32);
 impl_hash_type!(GenesisDelegateHash, 28);
 impl_hash_type!(GenesisHash, 28);
 impl_hash_type!(AuxiliaryDataHash, 32);
 impl_hash_type!(PoolMetadataHash, 32);
-impl_hash_type!(VRFKeyHash, 32);
+impl_hash_type!(VRFKeyHash, 32, cip5::VRF_VKH);
 impl_hash_type!(BlockHash, 32);
-impl_hash_type!(DataHash, 32);
-impl_hash_type!(ScriptDataHash, 32);
+impl_hash_type!(DataHash, 32, cip5::DATUM);
+impl_hash_type!(ScriptDataHash, 32, cip5::SCRIPT_DATA);
+// CIP-14 fingerprint of a native asset
+impl_hash_type!(AssetFingerprint, 20, cip5::ASSET);
 // We might want to make these two vkeys normal classes later but for now it's just arbitrary bytes for us (used in block parsing)
-impl_hash_type!(VRFVKey, 32);
-impl_hash_type!(KESVKey, 32);
+impl_hash_type!(VRFVKey, 32, cip5::VRF_VK);
+impl_hash_type!(KESVKey, 32, cip5::KES_VK);
 // same for this signature
 //impl_hash_type!(KESSignature, 448);
 // TODO: when >32 size trait implementations are out of nightly and into stable
@@ -1,6 +1,9 @@
 mod impl_signature_macro;
 mod impl_hash_type_macro;
 
+mod crypto_utils;
+pub(crate) use crypto_utils::*;
+
 mod bip32_private_key;
 pub use bip32_private_key::*;
 
@@ -19,6 +22,11 @@ pub use macro_implemented_signature_types::*;
 mod macro_implemented_hash_types;
 pub use macro_implemented_hash_types::*;
 
+mod key_role;
+pub use key_role::*;
+
+mod asset_fingerprint;
+
 mod vkey;
 pub use vkey::*;
 mod vkeys;
@@ -30,6 +38,9 @@ pub use public_keys::*;
 mod legacy_daedalus_private_key;
 pub use legacy_daedalus_private_key::*;
 
+mod daedalus_hd_passphrase;
+pub use daedalus_hd_passphrase::*;
+
 mod kes_signature;
 pub use kes_signature::*;
 
@@ -37,4 +48,13 @@ mod nonce;
 pub use nonce::*;
 
 mod vrf_cert;
-pub use vrf_cert::*;
\ No newline at end of file
+pub use vrf_cert::*;
+
+mod text_envelope;
+pub use text_envelope::*;
+
+mod vrf_signing_key;
+pub use vrf_signing_key::*;
+
+mod kes_signing_key;
+pub use kes_signing_key::*;
\ No newline at end of file
@@ -44,4 +44,19 @@ impl Nonce {
     pub fn get_hash(&self) -> Option<Vec<u8>> {
         Some(self.hash?.to_vec())
     }
+
+    /// The nonce of both nonces, the hash of their concatenation, the identity being neutral
+    pub fn combine(&self, other: &Nonce) -> Nonce {
+        match (&self.hash, &other.hash) {
+            (Some(left), Some(right)) => {
+                let mut bytes = left.to_vec();
+                bytes.extend_from_slice(right);
+                Self {
+                    hash: Some(blake2b256(&bytes)),
+                }
+            }
+            (Some(_), None) => self.clone(),
+            (None, _) => other.clone(),
+        }
+    }
 }
\ No newline at end of file
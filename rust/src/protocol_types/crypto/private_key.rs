@@ -1,4 +1,4 @@
-use crate::{Ed25519Signature, JsError, PublicKey, wasm_bindgen};
+use crate::{Ed25519Signature, JsError, KeyForm, KeyRole, PublicKey, TextEnvelope, wasm_bindgen};
 use crate::impl_mockchain::key;
 use rand_os::OsRng;
 use crate::chain_crypto::bech32::Bech32;
@@ -60,6 +60,20 @@ impl PrivateKey {
         }
     }
 
+    /// Bech32 with the CIP-5 prefix of the signing keys of `role`, like `addr_sk`,
+    /// for both the normal and the extended keys
+    pub fn to_cip5_bech32(&self, role: KeyRole) -> Result<String, JsError> {
+        role.encode(KeyForm::SigningKey, &self.as_bytes())
+    }
+
+    pub fn from_cip5_bech32(bech32_str: &str, role: KeyRole) -> Result<PrivateKey, JsError> {
+        let bytes = role.decode(KeyForm::SigningKey, bech32_str)?;
+        match bytes.len() {
+            32 => Self::from_normal_bytes(&bytes),
+            _ => Self::from_extended_bytes(&bytes),
+        }
+    }
+
     pub fn as_bytes(&self) -> Vec<u8> {
         match self.0 {
             key::EitherEd25519SecretKey::Normal(ref secret) => secret.as_ref().to_vec(),
@@ -104,4 +118,22 @@ impl PrivateKey {
             .map(PrivateKey)
             .map_err(|_| JsError::from_str("Invalid secret key"))
     }
+
+    /// The cold key file of a stake pool, which is a normal ed25519 key
+    pub fn to_stake_pool_text_envelope(&self) -> Result<TextEnvelope, JsError> {
+        match self.0 {
+            key::EitherEd25519SecretKey::Normal(ref secret) => Ok(TextEnvelope::new_bytes(
+                TextEnvelope::STAKE_POOL_SIGNING_KEY,
+                "Stake Pool Operator Signing Key",
+                secret.as_ref(),
+            )),
+            key::EitherEd25519SecretKey::Extended(_) => Err(JsError::from_str(
+                "Stake pool cold keys are normal ed25519 keys",
+            )),
+        }
+    }
+
+    pub fn from_stake_pool_text_envelope(envelope: &TextEnvelope) -> Result<PrivateKey, JsError> {
+        Self::from_normal_bytes(&envelope.bytes_of_type(TextEnvelope::STAKE_POOL_SIGNING_KEY)?)
+    }
 }
\ No newline at end of file
@@ -1,5 +1,5 @@
 use schemars::JsonSchema;
-use crate::{Ed25519KeyHash, Ed25519Signature, JsError, wasm_bindgen};
+use crate::{Ed25519KeyHash, Ed25519Signature, JsError, KeyForm, KeyRole, TextEnvelope, wasm_bindgen};
 use crate::chain_crypto::bech32::Bech32;
 use crate::crypto::blake2b224;
 
@@ -31,6 +31,15 @@ impl PublicKey {
         self.0.to_bech32_str()
     }
 
+    /// Bech32 with the CIP-5 prefix of the verification keys of `role`, like `pool_vk`
+    pub fn to_cip5_bech32(&self, role: KeyRole) -> Result<String, JsError> {
+        role.encode(KeyForm::VerificationKey, &self.as_bytes())
+    }
+
+    pub fn from_cip5_bech32(bech32_str: &str, role: KeyRole) -> Result<PublicKey, JsError> {
+        Self::from_bytes(&role.decode(KeyForm::VerificationKey, bech32_str)?)
+    }
+
     pub fn as_bytes(&self) -> Vec<u8> {
         self.0.as_ref().to_vec()
     }
@@ -59,6 +68,19 @@ impl PublicKey {
             Err(e) => Err(JsError::from_str(&e.to_string())),
         }
     }
+
+    /// The cold verification key file of a stake pool
+    pub fn to_stake_pool_text_envelope(&self) -> TextEnvelope {
+        TextEnvelope::new_bytes(
+            TextEnvelope::STAKE_POOL_VERIFICATION_KEY,
+            "Stake Pool Operator Verification Key",
+            &self.as_bytes(),
+        )
+    }
+
+    pub fn from_stake_pool_text_envelope(envelope: &TextEnvelope) -> Result<PublicKey, JsError> {
+        Self::from_bytes(&envelope.bytes_of_type(TextEnvelope::STAKE_POOL_VERIFICATION_KEY)?)
+    }
 }
 
 impl serde::Serialize for PublicKey {
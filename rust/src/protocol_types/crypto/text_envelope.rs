@@ -0,0 +1,81 @@
+use crate::*;
+
+/// The JSON format of the key and certificate files of cardano-cli and cardano-node:
+/// a type, a description and the CBOR of the content in hex.
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd, serde::Serialize, serde::Deserialize)]
+pub struct TextEnvelope {
+    #[serde(rename = "type")]
+    pub(crate) envelope_type: String,
+    pub(crate) description: String,
+    #[serde(rename = "cborHex")]
+    pub(crate) cbor_hex: String,
+}
+
+#[wasm_bindgen]
+impl TextEnvelope {
+    pub fn new(envelope_type: &str, description: &str, cbor: &[u8]) -> Self {
+        Self {
+            envelope_type: envelope_type.to_string(),
+            description: description.to_string(),
+            cbor_hex: hex::encode(cbor),
+        }
+    }
+
+    pub fn envelope_type(&self) -> String {
+        self.envelope_type.clone()
+    }
+
+    pub fn description(&self) -> String {
+        self.description.clone()
+    }
+
+    pub fn cbor(&self) -> Result<Vec<u8>, JsError> {
+        hex::decode(&self.cbor_hex).map_err(|e| JsError::from_str(&e.to_string()))
+    }
+
+    pub fn to_json(&self) -> Result<String, JsError> {
+        serde_json::to_string_pretty(self).map_err(|e| JsError::from_str(&e.to_string()))
+    }
+
+    pub fn from_json(json: &str) -> Result<TextEnvelope, JsError> {
+        serde_json::from_str(json).map_err(|e| JsError::from_str(&e.to_string()))
+    }
+}
+
+// text envelope types of the pool operator files
+impl TextEnvelope {
+    pub(crate) const VRF_SIGNING_KEY: &'static str = "VrfSigningKey_PraosVRF";
+    pub(crate) const VRF_VERIFICATION_KEY: &'static str = "VrfVerificationKey_PraosVRF";
+    pub(crate) const KES_SIGNING_KEY: &'static str = "KesSigningKey_ed25519_kes_2^6";
+    pub(crate) const KES_VERIFICATION_KEY: &'static str = "KesVerificationKey_ed25519_kes_2^6";
+    pub(crate) const STAKE_POOL_SIGNING_KEY: &'static str = "StakePoolSigningKey_ed25519";
+    pub(crate) const STAKE_POOL_VERIFICATION_KEY: &'static str = "StakePoolVerificationKey_ed25519";
+    pub(crate) const OPERATIONAL_CERT: &'static str = "NodeOperationalCertificate";
+    pub(crate) const OPERATIONAL_CERT_ISSUE_COUNTER: &'static str =
+        "NodeOperationalCertificateIssueCounter";
+
+    /// Envelope of raw bytes, which are CBOR encoded as a byte string
+    pub(crate) fn new_bytes(envelope_type: &str, description: &str, bytes: &[u8]) -> Self {
+        let mut serializer = Serializer::new_vec();
+        serializer.write_bytes(bytes).expect("write to a vec");
+        Self::new(envelope_type, description, &serializer.finalize())
+    }
+
+    pub(crate) fn cbor_of_type(&self, envelope_type: &str) -> Result<Vec<u8>, JsError> {
+        if self.envelope_type != envelope_type {
+            return Err(JsError::from_str(&format!(
+                "Expected a text envelope of type {}, found {}",
+                envelope_type, self.envelope_type
+            )));
+        }
+        self.cbor()
+    }
+
+    pub(crate) fn bytes_of_type(&self, envelope_type: &str) -> Result<Vec<u8>, JsError> {
+        let cbor = self.cbor_of_type(envelope_type)?;
+        let mut raw = Deserializer::from(std::io::Cursor::new(cbor));
+        raw.bytes()
+            .map_err(|_| JsError::from_str("Text envelope content isn't a CBOR byte string"))
+    }
+}
@@ -1,4 +1,6 @@
 use crate::*;
+use crate::chain_crypto::vrf;
+use std::convert::TryFrom;
 
 #[wasm_bindgen]
 #[derive(
@@ -38,4 +40,16 @@ impl VRFCert {
             proof: proof,
         })
     }
-}
\ No newline at end of file
+
+    /// True if the proof is a proof of `input` by `vrf_vkey` and the output is the one of the proof
+    pub fn verify(&self, vrf_vkey: &VRFVKey, input: &[u8]) -> bool {
+        let proof = match <&[u8; vrf::PROOF_SIZE]>::try_from(self.proof.as_slice()) {
+            Ok(proof) => proof,
+            Err(_) => return false,
+        };
+        match vrf::verify(&vrf_vkey.0, proof, input) {
+            Some(output) => output[..] == self.output[..],
+            None => false,
+        }
+    }
+}
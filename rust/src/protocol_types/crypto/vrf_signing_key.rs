@@ -0,0 +1,113 @@
+use crate::*;
+use crate::chain_crypto::bech32::{self, cip5};
+use crate::chain_crypto::vrf;
+use std::convert::TryFrom;
+
+/// VRF signing key of a stake pool, proving the leadership of its blocks
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct VRFSigningKey(pub(crate) [u8; vrf::SEED_SIZE]);
+
+#[wasm_bindgen]
+impl VRFSigningKey {
+    pub fn generate() -> Result<VRFSigningKey, JsError> {
+        random_seed().map(VRFSigningKey)
+    }
+
+    pub fn from_seed(seed: &[u8]) -> Result<VRFSigningKey, JsError> {
+        <[u8; vrf::SEED_SIZE]>::try_from(seed)
+            .map(VRFSigningKey)
+            .map_err(|_| {
+                JsError::from_str(&format!("VRF seed must be {} bytes", vrf::SEED_SIZE))
+            })
+    }
+
+    /// The seed followed by the verification key, as in the key files
+    pub fn from_bytes(bytes: &[u8]) -> Result<VRFSigningKey, JsError> {
+        if bytes.len() != vrf::SEED_SIZE + vrf::PUBLIC_KEY_SIZE {
+            return Err(JsError::from_str(&format!(
+                "VRF signing key must be {} bytes",
+                vrf::SEED_SIZE + vrf::PUBLIC_KEY_SIZE
+            )));
+        }
+        let key = Self::from_seed(&bytes[..vrf::SEED_SIZE])?;
+        if key.to_public().0[..] != bytes[vrf::SEED_SIZE..] {
+            return Err(JsError::from_str(
+                "VRF verification key doesn't match the seed",
+            ));
+        }
+        Ok(key)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.0.to_vec();
+        bytes.extend_from_slice(&vrf::public_key(&self.0));
+        bytes
+    }
+
+    pub fn to_public(&self) -> VRFVKey {
+        VRFVKey(vrf::public_key(&self.0))
+    }
+
+    /// Proof and output of the VRF for `input`
+    pub fn prove(&self, input: &[u8]) -> VRFCert {
+        let proof = vrf::prove(&self.0, input);
+        VRFCert {
+            output: vrf::proof_to_hash(&proof)
+                .expect("proofs have a valid point")
+                .to_vec(),
+            proof: proof.to_vec(),
+        }
+    }
+
+    pub fn to_text_envelope(&self) -> TextEnvelope {
+        TextEnvelope::new_bytes(
+            TextEnvelope::VRF_SIGNING_KEY,
+            "VRF Signing Key",
+            &self.to_bytes(),
+        )
+    }
+
+    pub fn from_text_envelope(envelope: &TextEnvelope) -> Result<VRFSigningKey, JsError> {
+        Self::from_bytes(&envelope.bytes_of_type(TextEnvelope::VRF_SIGNING_KEY)?)
+    }
+
+    /// Bech32 `vrf_sk` of the bytes of the key files
+    pub fn to_cip5_bech32(&self) -> String {
+        bech32::to_bech32_with_hrp(cip5::VRF_SK, &self.to_bytes())
+    }
+
+    pub fn from_cip5_bech32(bech32_str: &str) -> Result<VRFSigningKey, JsError> {
+        let bytes = bech32::try_from_bech32_with_hrp(bech32_str, cip5::VRF_SK)
+            .map_err(|e| JsError::from_str(&e.to_string()))?;
+        Self::from_bytes(&bytes)
+    }
+}
+
+impl VRFSigningKey {
+    /// The output of `prove` without the proof, for checking many slots
+    pub(crate) fn output(&self, input: &[u8]) -> [u8; vrf::OUTPUT_SIZE] {
+        vrf::output(&self.0, input)
+    }
+}
+
+#[wasm_bindgen]
+impl VRFVKey {
+    /// The hash registered in the pool parameters
+    pub fn hash(&self) -> VRFKeyHash {
+        VRFKeyHash::from(blake2b256(&self.0))
+    }
+
+    pub fn to_text_envelope(&self) -> TextEnvelope {
+        TextEnvelope::new_bytes(
+            TextEnvelope::VRF_VERIFICATION_KEY,
+            "VRF Verification Key",
+            &self.0,
+        )
+    }
+
+    pub fn from_text_envelope(envelope: &TextEnvelope) -> Result<VRFVKey, JsError> {
+        let bytes = envelope.bytes_of_type(TextEnvelope::VRF_VERIFICATION_KEY)?;
+        VRFVKey::from_bytes(bytes).map_err(|e| JsError::from_str(&format!("{:?}", e)))
+    }
+}
@@ -1,14 +1,25 @@
 use crate::{CredType, Credential, Ed25519KeyHash, GovernanceActionId, JsError, ScriptHash, TransactionHash};
+use crate::chain_crypto::bech32::cip5;
 use bech32::{ToBase32, FromBase32};
 use std::convert::TryFrom;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub(crate) enum GovIdType {
     CCHot = 0x0,
     CCCold = 0x1,
     DRep = 0x2,
 }
 
+impl GovIdType {
+    pub(crate) fn prefix(&self) -> &'static str {
+        match self {
+            GovIdType::CCHot => cip5::CC_HOT,
+            GovIdType::CCCold => cip5::CC_COLD,
+            GovIdType::DRep => cip5::DREP,
+        }
+    }
+}
+
 impl TryFrom<u8> for GovIdType {
     type Error = &'static str;
 
@@ -40,8 +51,8 @@ impl TryFrom<u8> for CredentialType {
     }
 }
 
-#[derive(Debug, Clone)]
-pub(crate) enum GovernanceIdentifier {
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub(crate) enum GovernanceIdentifierEnum {
     GovCredential {
         gov_id_type: GovIdType,
         credential: Credential
@@ -49,10 +60,10 @@ pub(crate) enum GovernanceIdentifier {
     GovAction(GovernanceActionId),
 }
 
-impl GovernanceIdentifier {
-    pub(crate) fn encode(&self) -> Vec<u8> {
+impl GovernanceIdentifierEnum {
+    pub(crate) fn encode(&self) -> Result<Vec<u8>, JsError> {
         match self {
-            GovernanceIdentifier::GovCredential {
+            GovernanceIdentifierEnum::GovCredential {
                 gov_id_type,
                 credential
             } => {
@@ -63,26 +74,38 @@ impl GovernanceIdentifier {
                 let header = ((*gov_id_type as u8) << 4) | (cred_type as u8);
                 let mut bytes = vec![header];
                 bytes.extend_from_slice(&cred_bytes);
-                bytes
+                Ok(bytes)
             }
-            GovernanceIdentifier::GovAction(gov_action_id) => {
+            GovernanceIdentifierEnum::GovAction(gov_action_id) => {
                 let mut bytes = gov_action_id.transaction_id.to_bytes();
-                let index = gov_action_id.index().to_be_bytes();
-                bytes.extend_from_slice(&index);
-                bytes
+                // the index is encoded with the minimal number of bytes, ledger limits it to u16
+                let index = u16::try_from(gov_action_id.index())
+                    .map_err(|_| JsError::from_str("Governance action index doesn't fit into u16"))?;
+                if index <= u8::MAX as u16 {
+                    bytes.push(index as u8);
+                } else {
+                    bytes.extend_from_slice(&index.to_be_bytes());
+                }
+                Ok(bytes)
             }
         }
     }
 
     pub(crate) fn decode(prefix: &str, bytes: &[u8]) -> Result<Self, JsError> {
         match prefix {
-            "drep" | "cc_hot" | "cc_cold" => {
+            cip5::DREP | cip5::CC_HOT | cip5::CC_COLD => {
                 if bytes.len() < 1 {
                     return Err(JsError::from_str("Invalid data length"));
                 }
                 let header = bytes[0];
                 let gov_id_type = GovIdType::try_from(header >> 4).
                     map_err(|_| JsError::from_str("Invalid GovIdType"))?;
+                if gov_id_type.prefix() != prefix {
+                    return Err(JsError::from_str(&format!(
+                        "Header of {} identifier doesn't match the prefix",
+                        prefix
+                    )));
+                }
                 let credential_type = CredentialType::try_from(header & 0x0F)
                     .map_err(|_| JsError::from_str("Invalid CredentialType"))?;
                 let credential_bytes = bytes[1..].to_vec();
@@ -96,12 +119,12 @@ impl GovernanceIdentifier {
                             .map_err(|_| JsError::from_str("Invalid script hash"))?)
                     }
                 };
-                Ok(GovernanceIdentifier::GovCredential {
+                Ok(GovernanceIdentifierEnum::GovCredential {
                     gov_id_type,
                     credential,
                 })
             }
-            "gov_action" => {
+            cip5::GOV_ACTION => {
                 if bytes.len() < 33 {
                     return Err(JsError::from_str("Invalid data length"));
                 }
@@ -115,31 +138,71 @@ impl GovernanceIdentifier {
                 let tx_hash = TransactionHash::from_bytes(tx_id)
                     .map_err(|_| JsError::from_str("Invalid transaction hash"))?;
                 let governance_action_id = GovernanceActionId::new(&tx_hash, index.into());
-                Ok(GovernanceIdentifier::GovAction(governance_action_id))
+                Ok(GovernanceIdentifierEnum::GovAction(governance_action_id))
             }
             _ => Err(JsError::from_str("Unknown prefix")),
         }
     }
 
+    // CIP-105 identifiers carry a bare 28 byte hash, the role and the credential type
+    // are defined by the prefix. "drep" with a hash is the legacy form of the CIP-129 "drep".
+    pub(crate) fn decode_legacy(prefix: &str, bytes: &[u8]) -> Result<Self, JsError> {
+        let (gov_id_type, is_script) = match prefix {
+            cip5::DREP | cip5::DREP_VKH => (GovIdType::DRep, false),
+            cip5::DREP_SCRIPT => (GovIdType::DRep, true),
+            cip5::CC_HOT_VKH => (GovIdType::CCHot, false),
+            cip5::CC_HOT_SCRIPT => (GovIdType::CCHot, true),
+            cip5::CC_COLD_VKH => (GovIdType::CCCold, false),
+            cip5::CC_COLD_SCRIPT => (GovIdType::CCCold, true),
+            _ => return Err(JsError::from_str("Unknown prefix")),
+        };
+        let credential = if is_script {
+            Credential::from_scripthash(&ScriptHash::from_bytes(bytes.to_vec())
+                .map_err(|_| JsError::from_str("Invalid script hash"))?)
+        } else {
+            Credential::from_keyhash(&Ed25519KeyHash::from_bytes(bytes.to_vec())
+                .map_err(|_| JsError::from_str("Invalid key hash"))?)
+        };
+        Ok(GovernanceIdentifierEnum::GovCredential {
+            gov_id_type,
+            credential,
+        })
+    }
+
     pub(crate) fn to_bech32(&self) -> Result<String, JsError> {
-        let (prefix, data) = match self {
-            GovernanceIdentifier::GovCredential { gov_id_type, .. } => {
-                let prefix = match gov_id_type {
-                    GovIdType::CCHot => "cc_hot",
-                    GovIdType::CCCold => "cc_cold",
-                    GovIdType::DRep => "drep",
-                };
-                (prefix, self.encode())
-            }
-            GovernanceIdentifier::GovAction { .. } => ("gov_action", self.encode()),
+        let prefix = match self {
+            GovernanceIdentifierEnum::GovCredential { gov_id_type, .. } => gov_id_type.prefix(),
+            GovernanceIdentifierEnum::GovAction { .. } => cip5::GOV_ACTION,
         };
-        let bech32_data = data.to_base32();
+        let bech32_data = self.encode()?.to_base32();
         bech32::encode(prefix, bech32_data).map_err(|e| JsError::from_str(&e.to_string()))
     }
 
+    pub(crate) fn to_legacy_bech32(&self) -> Result<String, JsError> {
+        match self {
+            GovernanceIdentifierEnum::GovCredential { gov_id_type, credential } => {
+                let (suffix, data) = match &credential.0 {
+                    CredType::Key(key_hash) => ("vkh", key_hash.to_bytes()),
+                    CredType::Script(script_hash) => ("script", script_hash.to_bytes()),
+                };
+                let prefix = format!("{}_{}", gov_id_type.prefix(), suffix);
+                bech32::encode(&prefix, data.to_base32()).map_err(|e| JsError::from_str(&e.to_string()))
+            }
+            GovernanceIdentifierEnum::GovAction(_) => {
+                Err(JsError::from_str("Governance action id has no CIP-105 bech32 form"))
+            }
+        }
+    }
+
     pub(crate) fn from_bech32(s: &str) -> Result<Self, JsError> {
         let (prefix, data) = bech32::decode(s).map_err(|e| JsError::from_str(&e.to_string()))?;
         let bytes = Vec::<u8>::from_base32(&data).map_err(|e| JsError::from_str(&e.to_string()))?;
-        GovernanceIdentifier::decode(&prefix, &bytes)
+        match (prefix.as_str(), bytes.len()) {
+            (cip5::DREP, 28) => GovernanceIdentifierEnum::decode_legacy(&prefix, &bytes),
+            (cip5::DREP, _) | (cip5::CC_HOT, _) | (cip5::CC_COLD, _) | (cip5::GOV_ACTION, _) => {
+                GovernanceIdentifierEnum::decode(&prefix, &bytes)
+            }
+            _ => GovernanceIdentifierEnum::decode_legacy(&prefix, &bytes),
+        }
     }
-}
\ No newline at end of file
+}
@@ -1,7 +1,8 @@
 use std::convert::TryFrom;
 use bech32::ToBase32;
 use crate::*;
-use crate::protocol_types::governance::cip129_decoder::{GovIdType, GovernanceIdentifier};
+use crate::chain_crypto::bech32::cip5;
+use crate::protocol_types::governance::cip129_decoder::{GovIdType, GovernanceIdentifierEnum};
 
 #[derive(
     Clone,
@@ -99,12 +100,12 @@ impl DRep {
 
     pub fn to_bech32(&self, cip_129_format: bool) -> Result<String, JsError> {
         if cip_129_format {
-            let gov_identifier: GovernanceIdentifier = self.try_into()?;
+            let gov_identifier: GovernanceIdentifierEnum = self.try_into()?;
             gov_identifier.to_bech32()
         } else {
             let (hrp, data) = match &self.0 {
-                DRepEnum::KeyHash(keyhash) => Ok(("drep_vkh", keyhash.to_bytes())),
-                DRepEnum::ScriptHash(scripthash) => Ok(("drep_script", scripthash.to_bytes())),
+                DRepEnum::KeyHash(keyhash) => Ok((cip5::DREP_VKH, keyhash.to_bytes())),
+                DRepEnum::ScriptHash(scripthash) => Ok((cip5::DREP_SCRIPT, scripthash.to_bytes())),
                 DRepEnum::AlwaysAbstain => {
                     Err(JsError::from_str("Cannot convert AlwaysAbstain to bech32"))
                 }
@@ -123,9 +124,9 @@ impl DRep {
             .map_err(|e: bech32::Error| JsError::from_str(&format!("Malformed DRep base32: {}", &e.to_string())))?;
         let prefix = hrp.as_str();
         match prefix {
-            "drep" => match data.len() {
+            cip5::DREP => match data.len() {
                 28 => Self::from_bech32_internal(prefix, data),
-                29 => GovernanceIdentifier::from_bech32(bech32_str)?.try_into(),
+                29 => GovernanceIdentifierEnum::from_bech32(bech32_str)?.try_into(),
                 _ => Err(JsError::from_str("Malformed DRep (drep1 byte len)"))
             },
             _ => Self::from_bech32_internal(prefix, data),
@@ -134,9 +135,9 @@ impl DRep {
 
     fn from_bech32_internal(prefix: &str, data: Vec<u8>) -> Result<DRep, JsError> {
         let kind = match prefix {
-            "drep" => DRepKind::KeyHash,
-            "drep_vkh" => DRepKind::KeyHash,
-            "drep_script" => DRepKind::ScriptHash,
+            cip5::DREP => DRepKind::KeyHash,
+            cip5::DREP_VKH => DRepKind::KeyHash,
+            cip5::DREP_SCRIPT => DRepKind::ScriptHash,
             _ => return Err(JsError::from_str("Malformed DRep")),
         };
         let drep = match kind {
@@ -167,28 +168,28 @@ impl TryFrom<&DRep> for Credential {
     }
 }
 
-impl TryFrom<&DRep> for GovernanceIdentifier {
+impl TryFrom<&DRep> for GovernanceIdentifierEnum {
     type Error = JsError;
 
     fn try_from(drep: &DRep) -> Result<Self, Self::Error> {
         let credential = drep.try_into()?;
-        Ok(GovernanceIdentifier::GovCredential {
+        Ok(GovernanceIdentifierEnum::GovCredential {
             gov_id_type: GovIdType::DRep,
             credential,
         })
     }
 }
 
-impl TryFrom<GovernanceIdentifier> for DRep {
+impl TryFrom<GovernanceIdentifierEnum> for DRep {
     type Error = JsError;
 
-    fn try_from(gov_id: GovernanceIdentifier) -> Result<Self, Self::Error> {
+    fn try_from(gov_id: GovernanceIdentifierEnum) -> Result<Self, Self::Error> {
         match gov_id {
-            GovernanceIdentifier::GovCredential {
+            GovernanceIdentifierEnum::GovCredential {
                 gov_id_type: GovIdType::DRep,
                 credential,
             } => Ok(DRep::new_from_credential(&credential)),
-            _ => Err(JsError::from_str("Cannot convert GovernanceActionId to DRep")),
+            _ => Err(JsError::from_str("Governance identifier is not a DRep")),
         }
     }
 }
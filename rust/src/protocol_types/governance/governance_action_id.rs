@@ -36,4 +36,15 @@ impl GovernanceActionId {
             index: index,
         }
     }
+
+    /// CIP-129 "gov_action" bech32 encoding
+    pub fn to_bech32(&self) -> Result<String, JsError> {
+        GovernanceIdentifier::new_governance_action_id(self).to_bech32(true)
+    }
+
+    pub fn from_bech32(bech32_str: &str) -> Result<GovernanceActionId, JsError> {
+        GovernanceIdentifier::from_bech32(bech32_str)?
+            .as_governance_action_id()
+            .ok_or_else(|| JsError::from_str("Governance identifier is not a governance action id"))
+    }
 }
@@ -0,0 +1,127 @@
+use crate::protocol_types::governance::cip129_decoder::{GovIdType, GovernanceIdentifierEnum};
+use crate::*;
+use std::convert::TryFrom;
+
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum GovernanceIdentifierKind {
+    DRep,
+    CommitteeHotCredential,
+    CommitteeColdCredential,
+    GovernanceActionId,
+}
+
+/// Governance identifier from CIP-129 (drep, cc_hot, cc_cold, gov_action).
+/// Parsing also accepts the CIP-105 prefixes (drep_vkh, drep_script, cc_hot_vkh, cc_hot_script,
+/// cc_cold_vkh, cc_cold_script) and the legacy "drep" form with a bare key hash.
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct GovernanceIdentifier(pub(crate) GovernanceIdentifierEnum);
+
+#[wasm_bindgen]
+impl GovernanceIdentifier {
+    pub fn new_drep(drep: &DRep) -> Result<GovernanceIdentifier, JsError> {
+        let credential = Credential::try_from(drep)?;
+        Ok(Self::new_credential(GovIdType::DRep, &credential))
+    }
+
+    pub fn new_committee_hot_credential(credential: &Credential) -> Self {
+        Self::new_credential(GovIdType::CCHot, credential)
+    }
+
+    pub fn new_committee_cold_credential(credential: &Credential) -> Self {
+        Self::new_credential(GovIdType::CCCold, credential)
+    }
+
+    pub fn new_governance_action_id(governance_action_id: &GovernanceActionId) -> Self {
+        Self(GovernanceIdentifierEnum::GovAction(
+            governance_action_id.clone(),
+        ))
+    }
+
+    pub fn kind(&self) -> GovernanceIdentifierKind {
+        match &self.0 {
+            GovernanceIdentifierEnum::GovCredential { gov_id_type, .. } => match gov_id_type {
+                GovIdType::DRep => GovernanceIdentifierKind::DRep,
+                GovIdType::CCHot => GovernanceIdentifierKind::CommitteeHotCredential,
+                GovIdType::CCCold => GovernanceIdentifierKind::CommitteeColdCredential,
+            },
+            GovernanceIdentifierEnum::GovAction(_) => GovernanceIdentifierKind::GovernanceActionId,
+        }
+    }
+
+    pub fn as_drep(&self) -> Option<DRep> {
+        match &self.0 {
+            GovernanceIdentifierEnum::GovCredential {
+                gov_id_type: GovIdType::DRep,
+                credential,
+            } => Some(DRep::new_from_credential(credential)),
+            _ => None,
+        }
+    }
+
+    /// Returns the credential of a DRep or a committee member, the role is available via `kind`
+    pub fn as_credential(&self) -> Option<Credential> {
+        match &self.0 {
+            GovernanceIdentifierEnum::GovCredential { credential, .. } => Some(credential.clone()),
+            GovernanceIdentifierEnum::GovAction(_) => None,
+        }
+    }
+
+    pub fn as_governance_action_id(&self) -> Option<GovernanceActionId> {
+        match &self.0 {
+            GovernanceIdentifierEnum::GovAction(governance_action_id) => {
+                Some(governance_action_id.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// Encodes the identifier as CIP-129 bech32, or as CIP-105 bech32 if `cip_129_format` is false.
+    /// Governance action ids exist only in the CIP-129 format.
+    pub fn to_bech32(&self, cip_129_format: bool) -> Result<String, JsError> {
+        if cip_129_format {
+            self.0.to_bech32()
+        } else {
+            self.0.to_legacy_bech32()
+        }
+    }
+
+    pub fn from_bech32(bech32_str: &str) -> Result<GovernanceIdentifier, JsError> {
+        GovernanceIdentifierEnum::from_bech32(bech32_str).map(Self)
+    }
+
+    /// CIP-129 binary form: a header byte followed by the credential hash,
+    /// or a transaction hash followed by the action index
+    pub fn to_bytes(&self) -> Result<Vec<u8>, JsError> {
+        self.0.encode()
+    }
+
+    pub fn to_hex(&self) -> Result<String, JsError> {
+        self.to_bytes().map(hex::encode)
+    }
+
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<GovernanceIdentifier, JsError> {
+        let prefix = match bytes.len() {
+            29 => match GovIdType::try_from(bytes[0] >> 4) {
+                Ok(gov_id_type) => gov_id_type.prefix(),
+                Err(_) => return Err(JsError::from_str("Invalid GovIdType")),
+            },
+            33 | 34 => "gov_action",
+            _ => return Err(JsError::from_str("Invalid data length")),
+        };
+        GovernanceIdentifierEnum::decode(prefix, &bytes).map(Self)
+    }
+
+    pub fn from_hex(hex_str: &str) -> Result<GovernanceIdentifier, JsError> {
+        let bytes = hex::decode(hex_str).map_err(|e| JsError::from_str(&e.to_string()))?;
+        Self::from_bytes(bytes)
+    }
+
+    fn new_credential(gov_id_type: GovIdType, credential: &Credential) -> Self {
+        Self(GovernanceIdentifierEnum::GovCredential {
+            gov_id_type,
+            credential: credential.clone(),
+        })
+    }
+}
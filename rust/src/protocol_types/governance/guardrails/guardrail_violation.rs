@@ -0,0 +1,80 @@
+use crate::*;
+
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd, Hash)]
+pub enum GuardrailViolationKind {
+    /// The parameter value does not satisfy one of the ruleset predicates
+    PredicateFailed,
+    /// The ruleset has no definition for the parameter, so it can't be changed
+    ParameterNotAllowed,
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd, Hash)]
+pub struct GuardrailViolation {
+    pub(crate) kind: GuardrailViolationKind,
+    pub(crate) param_key: u32,
+    pub(crate) param_name: String,
+    pub(crate) rule_id: Option<String>,
+    pub(crate) description: String,
+}
+
+#[wasm_bindgen]
+impl GuardrailViolation {
+    pub fn kind(&self) -> GuardrailViolationKind {
+        self.kind
+    }
+
+    /// Key of the parameter inside the protocol param update map (e.g. 0 for minfee_a)
+    pub fn param_key(&self) -> u32 {
+        self.param_key
+    }
+
+    /// Name of the violated field, list elements are addressed as "execution_costs.mem_price"
+    pub fn param_name(&self) -> String {
+        self.param_name.clone()
+    }
+
+    /// Rule code taken from the ruleset comment (e.g. "TFPB-01") if it has one
+    pub fn rule_id(&self) -> Option<String> {
+        self.rule_id.clone()
+    }
+
+    pub fn description(&self) -> String {
+        self.description.clone()
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd, Hash)]
+pub struct GuardrailViolations(pub(crate) Vec<GuardrailViolation>);
+
+#[wasm_bindgen]
+impl GuardrailViolations {
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn get(&self, index: usize) -> GuardrailViolation {
+        self.0[index].clone()
+    }
+
+    pub fn add(&mut self, violation: &GuardrailViolation) {
+        self.0.push(violation.clone());
+    }
+
+    /// Returns violations related to the parameter with the specified key
+    pub fn for_param(&self, param_key: u32) -> GuardrailViolations {
+        self.0
+            .iter()
+            .filter(|v| v.param_key == param_key)
+            .cloned()
+            .collect()
+    }
+}
+
+impl_vec_wrapper!(GuardrailViolations, GuardrailViolation);
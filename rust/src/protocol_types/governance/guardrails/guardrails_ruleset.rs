@@ -0,0 +1,522 @@
+use crate::rational::Rational;
+use crate::*;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+// Guardrails of the Conway constitution in the format used by the guardrails script config
+// (https://github.com/IntersectMBO/plutus/tree/master/cardano-constitution).
+// Every key is a protocol param update key, predicates are objects with "minValue", "maxValue"
+// and "notEqual" fields, the rule code is taken from the parentheses at the end of "$comment".
+// Cost models are checked per language id against the allowed numbers of parameters.
+const DEFAULT_GUARDRAILS_JSON: &str = include_str!("default_guardrails.json");
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum GuardrailValueType {
+    Integer,
+    Unit,
+    List(&'static [GuardrailValueType]),
+    CostModels,
+}
+
+const UNIT_PAIR: &[GuardrailValueType] = &[GuardrailValueType::Unit, GuardrailValueType::Unit];
+const INTEGER_PAIR: &[GuardrailValueType] =
+    &[GuardrailValueType::Integer, GuardrailValueType::Integer];
+const POOL_THRESHOLDS: &[GuardrailValueType] = &[GuardrailValueType::Unit; 5];
+const DREP_THRESHOLDS: &[GuardrailValueType] = &[GuardrailValueType::Unit; 10];
+
+#[derive(Clone, Debug)]
+struct GuardrailPredicate {
+    rule_id: Option<String>,
+    description: Option<String>,
+    min_value: Option<Rational>,
+    max_value: Option<Rational>,
+    not_equal: Option<Rational>,
+}
+
+#[derive(Clone, Debug)]
+struct CostModelRule {
+    rule_id: Option<String>,
+    description: Option<String>,
+    lengths: Vec<usize>,
+}
+
+#[derive(Clone, Debug)]
+enum GuardrailSpec {
+    Numeric(Vec<GuardrailPredicate>),
+    List(Vec<GuardrailSpec>),
+    CostModels(BTreeMap<u64, CostModelRule>),
+    Any,
+}
+
+enum ParamValue {
+    Numeric(Rational),
+    List(Vec<(&'static str, ParamValue)>),
+    // language id, its name and the number of parameters of its cost model
+    CostModels(Vec<(u64, &'static str, usize)>),
+    Opaque,
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct GuardrailsRuleset {
+    params: BTreeMap<u32, Vec<GuardrailSpec>>,
+}
+
+#[wasm_bindgen]
+impl GuardrailsRuleset {
+    /// Parses a ruleset from the guardrails script config JSON.
+    /// Each top-level key is a protocol param update key mapped to one or several value definitions.
+    pub fn from_json(json: &str) -> Result<GuardrailsRuleset, JsError> {
+        let value: serde_json::Value =
+            serde_json::from_str(json).map_err(|e| JsError::from_str(&e.to_string()))?;
+        let obj = value
+            .as_object()
+            .ok_or_else(|| JsError::from_str("Guardrails ruleset must be a JSON object"))?;
+        let mut params = BTreeMap::new();
+        for (key, spec) in obj {
+            if key.starts_with('$') {
+                continue;
+            }
+            let param_key = key
+                .parse::<u32>()
+                .map_err(|_| JsError::from_str(&format!("Invalid parameter key: {}", key)))?;
+            let expected_type = expected_value_type(param_key).ok_or_else(|| {
+                JsError::from_str(&format!("Unknown protocol parameter key: {}", param_key))
+            })?;
+            let specs = match spec {
+                serde_json::Value::Array(items) => items
+                    .iter()
+                    .map(|item| parse_spec(item, &expected_type, key))
+                    .collect::<Result<Vec<_>, _>>()?,
+                _ => vec![parse_spec(spec, &expected_type, key)?],
+            };
+            params.insert(param_key, specs);
+        }
+        Ok(Self { params })
+    }
+
+    /// Guardrails from the Conway constitution
+    pub fn conway_default() -> GuardrailsRuleset {
+        GuardrailsRuleset::from_json(DEFAULT_GUARDRAILS_JSON)
+            .expect("Default guardrails ruleset must be valid")
+    }
+
+    /// Returns true if the ruleset has a definition for the protocol param update key
+    pub fn has_param(&self, param_key: u32) -> bool {
+        self.params.contains_key(&param_key)
+    }
+
+    /// Evaluates all set fields of the update against the ruleset.
+    /// A field without a definition in the ruleset is reported as `ParameterNotAllowed`.
+    pub fn check_protocol_param_update(
+        &self,
+        update: &ProtocolParamUpdate,
+    ) -> GuardrailViolations {
+        let mut violations = Vec::new();
+        for (param_key, param_name, value) in param_values(update) {
+            match self.params.get(&param_key) {
+                Some(specs) => {
+                    for spec in specs {
+                        check_value(spec, &value, param_key, param_name, &mut violations);
+                    }
+                }
+                None => violations.push(GuardrailViolation {
+                    kind: GuardrailViolationKind::ParameterNotAllowed,
+                    param_key,
+                    param_name: param_name.to_string(),
+                    rule_id: None,
+                    description: format!("{} is not allowed to be changed", param_name),
+                }),
+            }
+        }
+        GuardrailViolations(violations)
+    }
+
+    pub fn check_parameter_change_action(
+        &self,
+        action: &ParameterChangeAction,
+    ) -> GuardrailViolations {
+        self.check_protocol_param_update(&action.protocol_param_updates)
+    }
+}
+
+fn expected_value_type(param_key: u32) -> Option<GuardrailValueType> {
+    match param_key {
+        0..=8 | 16 | 17 | 22..=24 | 27..=32 => Some(GuardrailValueType::Integer),
+        9..=11 | 33 => Some(GuardrailValueType::Unit),
+        18 => Some(GuardrailValueType::CostModels),
+        19 => Some(GuardrailValueType::List(UNIT_PAIR)),
+        20 | 21 => Some(GuardrailValueType::List(INTEGER_PAIR)),
+        25 => Some(GuardrailValueType::List(POOL_THRESHOLDS)),
+        26 => Some(GuardrailValueType::List(DREP_THRESHOLDS)),
+        _ => None,
+    }
+}
+
+fn parse_spec(
+    value: &serde_json::Value,
+    expected_type: &GuardrailValueType,
+    path: &str,
+) -> Result<GuardrailSpec, JsError> {
+    let type_name = value
+        .get("type")
+        .and_then(|t| t.as_str())
+        .ok_or_else(|| JsError::from_str(&format!("Missing type for {}", path)))?;
+    match (type_name, expected_type) {
+        ("any", _) => Ok(GuardrailSpec::Any),
+        ("integer", GuardrailValueType::Integer) | ("unit", GuardrailValueType::Unit) => {
+            let predicates = match value.get("predicates") {
+                Some(serde_json::Value::Array(predicates)) => predicates
+                    .iter()
+                    .enumerate()
+                    .map(|(i, p)| parse_predicate(p, &format!("{}.predicates[{}]", path, i)))
+                    .collect::<Result<Vec<_>, _>>()?,
+                None => Vec::new(),
+                _ => {
+                    return Err(JsError::from_str(&format!(
+                        "Predicates of {} must be an array",
+                        path
+                    )))
+                }
+            };
+            Ok(GuardrailSpec::Numeric(predicates))
+        }
+        ("list", GuardrailValueType::List(element_types)) => {
+            let elements = value
+                .get("elements")
+                .and_then(|e| e.as_array())
+                .ok_or_else(|| JsError::from_str(&format!("Missing elements for {}", path)))?;
+            if elements.len() != element_types.len() {
+                return Err(JsError::from_str(&format!(
+                    "Expected {} elements for {}, found {}",
+                    element_types.len(),
+                    path,
+                    elements.len()
+                )));
+            }
+            let specs = elements
+                .iter()
+                .zip(element_types.iter())
+                .enumerate()
+                .map(|(i, (e, t))| parse_spec(e, t, &format!("{}.elements[{}]", path, i)))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(GuardrailSpec::List(specs))
+        }
+        ("costModels", GuardrailValueType::CostModels) => {
+            let languages = value
+                .get("languages")
+                .and_then(|l| l.as_object())
+                .ok_or_else(|| JsError::from_str(&format!("Missing languages for {}", path)))?;
+            let rules = languages
+                .iter()
+                .map(|(language, rule)| {
+                    let rule_path = format!("{}.languages.{}", path, language);
+                    let language = language.parse::<u64>().map_err(|_| {
+                        JsError::from_str(&format!("Invalid language id in {}", rule_path))
+                    })?;
+                    Ok((language, parse_cost_model_rule(rule, &rule_path)?))
+                })
+                .collect::<Result<BTreeMap<_, _>, JsError>>()?;
+            Ok(GuardrailSpec::CostModels(rules))
+        }
+        _ => Err(JsError::from_str(&format!(
+            "Unexpected type \"{}\" for {}",
+            type_name, path
+        ))),
+    }
+}
+
+fn parse_predicate(value: &serde_json::Value, path: &str) -> Result<GuardrailPredicate, JsError> {
+    let obj = value
+        .as_object()
+        .ok_or_else(|| JsError::from_str(&format!("{} must be an object", path)))?;
+    let number = |name: &str| -> Result<Option<Rational>, JsError> {
+        obj.get(name)
+            .map(|v| parse_number(v, &format!("{}.{}", path, name)))
+            .transpose()
+    };
+    let description = obj
+        .get("$comment")
+        .and_then(|c| c.as_str())
+        .map(|c| c.to_string());
+    Ok(GuardrailPredicate {
+        rule_id: description.as_deref().and_then(extract_rule_id),
+        description,
+        min_value: number("minValue")?,
+        max_value: number("maxValue")?,
+        not_equal: number("notEqual")?,
+    })
+}
+
+fn parse_cost_model_rule(value: &serde_json::Value, path: &str) -> Result<CostModelRule, JsError> {
+    let lengths = value
+        .get("lengths")
+        .and_then(|l| l.as_array())
+        .ok_or_else(|| JsError::from_str(&format!("Missing lengths for {}", path)))?
+        .iter()
+        .map(|length| {
+            length
+                .as_u64()
+                .map(|length| length as usize)
+                .ok_or_else(|| JsError::from_str(&format!("Lengths of {} must be integers", path)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let description = value
+        .get("$comment")
+        .and_then(|c| c.as_str())
+        .map(|c| c.to_string());
+    Ok(CostModelRule {
+        rule_id: description.as_deref().and_then(extract_rule_id),
+        description,
+        lengths,
+    })
+}
+
+fn parse_number(value: &serde_json::Value, path: &str) -> Result<Rational, JsError> {
+    let parse_int = |v: &serde_json::Value| -> Result<BigInt, JsError> {
+        match v {
+            serde_json::Value::Number(n) => BigInt::from_str(&n.to_string()),
+            _ => Err(JsError::from_str("not an integer")),
+        }
+        .map_err(|_| JsError::from_str(&format!("{} must be an integer", path)))
+    };
+    match value {
+        serde_json::Value::Object(obj) => {
+            let numerator = obj
+                .get("numerator")
+                .ok_or_else(|| JsError::from_str(&format!("Missing numerator in {}", path)))?;
+            let denominator = obj
+                .get("denominator")
+                .ok_or_else(|| JsError::from_str(&format!("Missing denominator in {}", path)))?;
+            let denominator = parse_int(denominator)?;
+            if denominator.is_zero() || denominator.is_negative() {
+                return Err(JsError::from_str(&format!(
+                    "Denominator in {} must be positive",
+                    path
+                )));
+            }
+            Ok(Rational::new(parse_int(numerator)?, denominator))
+        }
+        _ => Ok(Rational::new(parse_int(value)?, BigInt::one())),
+    }
+}
+
+// "txFeePerByte must not be lower than 30 (TFPB-01)" -> "TFPB-01"
+fn extract_rule_id(comment: &str) -> Option<String> {
+    let comment = comment.trim_end();
+    if !comment.ends_with(')') {
+        return None;
+    }
+    let start = comment.rfind('(')?;
+    let id = &comment[start + 1..comment.len() - 1];
+    if !id.is_empty() && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        Some(id.to_string())
+    } else {
+        None
+    }
+}
+
+fn check_value(
+    spec: &GuardrailSpec,
+    value: &ParamValue,
+    param_key: u32,
+    param_name: &str,
+    violations: &mut Vec<GuardrailViolation>,
+) {
+    match (spec, value) {
+        (GuardrailSpec::Any, _) => {}
+        (GuardrailSpec::Numeric(predicates), ParamValue::Numeric(value)) => {
+            for predicate in predicates {
+                if !predicate_holds(predicate, value) {
+                    violations.push(GuardrailViolation {
+                        kind: GuardrailViolationKind::PredicateFailed,
+                        param_key,
+                        param_name: param_name.to_string(),
+                        rule_id: predicate.rule_id.clone(),
+                        description: predicate
+                            .description
+                            .clone()
+                            .unwrap_or_else(|| format!("{} is out of the allowed range", param_name)),
+                    });
+                }
+            }
+        }
+        (GuardrailSpec::List(specs), ParamValue::List(values)) => {
+            for (spec, (name, value)) in specs.iter().zip(values) {
+                let element_name = format!("{}.{}", param_name, name);
+                check_value(spec, value, param_key, &element_name, violations);
+            }
+        }
+        (GuardrailSpec::CostModels(rules), ParamValue::CostModels(cost_models)) => {
+            for (language, language_name, length) in cost_models {
+                let element_name = format!("{}.{}", param_name, language_name);
+                let (rule_id, description) = match rules.get(language) {
+                    Some(rule) if rule.lengths.contains(length) => continue,
+                    Some(rule) => (
+                        rule.rule_id.clone(),
+                        rule.description.clone().unwrap_or_else(|| {
+                            format!("{} has an unexpected number of parameters", element_name)
+                        }),
+                    ),
+                    None => (
+                        None,
+                        format!("{} is not an allowed cost model language", element_name),
+                    ),
+                };
+                violations.push(GuardrailViolation {
+                    kind: GuardrailViolationKind::PredicateFailed,
+                    param_key,
+                    param_name: element_name,
+                    rule_id,
+                    description,
+                });
+            }
+        }
+        // shapes are checked against expected_value_type while parsing the ruleset
+        _ => {}
+    }
+}
+
+// denominators are positive for both parsed predicates and unit intervals
+fn compare(a: &Rational, b: &Rational) -> Ordering {
+    let a_scaled = a.numerator().mul(b.denominator());
+    let b_scaled = b.numerator().mul(a.denominator());
+    a_scaled.cmp(&b_scaled)
+}
+
+fn predicate_holds(predicate: &GuardrailPredicate, value: &Rational) -> bool {
+    let min_ok = match &predicate.min_value {
+        Some(min) => compare(value, min) != Ordering::Less,
+        None => true,
+    };
+    let max_ok = match &predicate.max_value {
+        Some(max) => compare(value, max) != Ordering::Greater,
+        None => true,
+    };
+    let not_equal_ok = match &predicate.not_equal {
+        Some(not_equal) => compare(value, not_equal) != Ordering::Equal,
+        None => true,
+    };
+    min_ok && max_ok && not_equal_ok
+}
+
+fn param_values(update: &ProtocolParamUpdate) -> Vec<(u32, &'static str, ParamValue)> {
+    fn int<T: Into<BigInt>>(x: T) -> ParamValue {
+        ParamValue::Numeric(Rational::new(x.into(), BigInt::one()))
+    }
+    fn coin(x: &Coin) -> ParamValue {
+        int(BigInt::from(x))
+    }
+    fn unit(x: &UnitInterval) -> ParamValue {
+        ParamValue::Numeric(Rational::from(x))
+    }
+
+    let mut values = Vec::new();
+    let mut push = |key: u32, name: &'static str, value: Option<ParamValue>| {
+        if let Some(value) = value {
+            values.push((key, name, value));
+        }
+    };
+    push(0, "minfee_a", update.minfee_a.as_ref().map(coin));
+    push(1, "minfee_b", update.minfee_b.as_ref().map(coin));
+    push(2, "max_block_body_size", update.max_block_body_size.map(int));
+    push(3, "max_tx_size", update.max_tx_size.map(int));
+    push(4, "max_block_header_size", update.max_block_header_size.map(int));
+    push(5, "key_deposit", update.key_deposit.as_ref().map(coin));
+    push(6, "pool_deposit", update.pool_deposit.as_ref().map(coin));
+    push(7, "max_epoch", update.max_epoch.map(int));
+    push(8, "n_opt", update.n_opt.map(int));
+    push(9, "pool_pledge_influence", update.pool_pledge_influence.as_ref().map(unit));
+    push(10, "expansion_rate", update.expansion_rate.as_ref().map(unit));
+    push(11, "treasury_growth_rate", update.treasury_growth_rate.as_ref().map(unit));
+    push(12, "d", update.d.as_ref().map(unit));
+    push(13, "extra_entropy", update.extra_entropy.as_ref().map(|_| ParamValue::Opaque));
+    push(14, "protocol_version", update.protocol_version.as_ref().map(|_| ParamValue::Opaque));
+    push(16, "min_pool_cost", update.min_pool_cost.as_ref().map(coin));
+    push(17, "ada_per_utxo_byte", update.ada_per_utxo_byte.as_ref().map(coin));
+    push(
+        18,
+        "cost_models",
+        update.cost_models.as_ref().map(|cost_models| {
+            ParamValue::CostModels(
+                cost_models
+                    .0
+                    .iter()
+                    .map(|(language, cost_model)| {
+                        let name = match language.kind() {
+                            LanguageKind::PlutusV1 => "plutus_v1",
+                            LanguageKind::PlutusV2 => "plutus_v2",
+                            LanguageKind::PlutusV3 => "plutus_v3",
+                        };
+                        (language.kind() as u64, name, cost_model.len())
+                    })
+                    .collect(),
+            )
+        }),
+    );
+    push(
+        19,
+        "execution_costs",
+        update.execution_costs.as_ref().map(|prices| {
+            ParamValue::List(vec![
+                ("mem_price", unit(&prices.mem_price)),
+                ("step_price", unit(&prices.step_price)),
+            ])
+        }),
+    );
+    let ex_units = |units: &ExUnits| {
+        ParamValue::List(vec![("mem", coin(&units.mem)), ("steps", coin(&units.steps))])
+    };
+    push(20, "max_tx_ex_units", update.max_tx_ex_units.as_ref().map(ex_units));
+    push(21, "max_block_ex_units", update.max_block_ex_units.as_ref().map(ex_units));
+    push(22, "max_value_size", update.max_value_size.map(int));
+    push(23, "collateral_percentage", update.collateral_percentage.map(int));
+    push(24, "max_collateral_inputs", update.max_collateral_inputs.map(int));
+    push(
+        25,
+        "pool_voting_thresholds",
+        update.pool_voting_thresholds.as_ref().map(|t| {
+            ParamValue::List(vec![
+                ("motion_no_confidence", unit(&t.motion_no_confidence)),
+                ("committee_normal", unit(&t.committee_normal)),
+                ("committee_no_confidence", unit(&t.committee_no_confidence)),
+                ("hard_fork_initiation", unit(&t.hard_fork_initiation)),
+                ("security_relevant_threshold", unit(&t.security_relevant_threshold)),
+            ])
+        }),
+    );
+    push(
+        26,
+        "drep_voting_thresholds",
+        update.drep_voting_thresholds.as_ref().map(|t| {
+            ParamValue::List(vec![
+                ("motion_no_confidence", unit(&t.motion_no_confidence)),
+                ("committee_normal", unit(&t.committee_normal)),
+                ("committee_no_confidence", unit(&t.committee_no_confidence)),
+                ("update_constitution", unit(&t.update_constitution)),
+                ("hard_fork_initiation", unit(&t.hard_fork_initiation)),
+                ("pp_network_group", unit(&t.pp_network_group)),
+                ("pp_economic_group", unit(&t.pp_economic_group)),
+                ("pp_technical_group", unit(&t.pp_technical_group)),
+                ("pp_governance_group", unit(&t.pp_governance_group)),
+                ("treasury_withdrawal", unit(&t.treasury_withdrawal)),
+            ])
+        }),
+    );
+    push(27, "min_committee_size", update.min_committee_size.map(int));
+    push(28, "committee_term_limit", update.committee_term_limit.map(int));
+    push(
+        29,
+        "governance_action_validity_period",
+        update.governance_action_validity_period.map(int),
+    );
+    push(30, "governance_action_deposit", update.governance_action_deposit.as_ref().map(coin));
+    push(31, "drep_deposit", update.drep_deposit.as_ref().map(coin));
+    push(32, "drep_inactivity_period", update.drep_inactivity_period.map(int));
+    push(
+        33,
+        "ref_script_coins_per_byte",
+        update.ref_script_coins_per_byte.as_ref().map(unit),
+    );
+    values
+}
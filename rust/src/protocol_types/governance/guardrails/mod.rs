@@ -0,0 +1,5 @@
+mod guardrail_violation;
+pub use guardrail_violation::*;
+
+mod guardrails_ruleset;
+pub use guardrails_ruleset::*;
@@ -25,4 +25,10 @@ pub use governance_action_ids::*;
 mod proposals;
 mod cip129_decoder;
 
+mod governance_identifier;
+pub use governance_identifier::*;
+
 pub use proposals::*;
+
+mod guardrails;
+pub use guardrails::*;
@@ -422,6 +422,20 @@ impl AuxiliaryData {
     }
 }
 
+impl AuxiliaryData {
+    pub(crate) fn label_metadatum(&self, label: u64) -> Option<TransactionMetadatum> {
+        self.metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get(&BigNum::from(label)))
+    }
+
+    // replaces any previous metadatum of the label
+    pub(crate) fn set_label_metadatum(&mut self, label: u64, metadatum: &TransactionMetadatum) {
+        let metadata = self.metadata.get_or_insert_with(Default::default);
+        metadata.insert(&BigNum::from(label), metadatum);
+    }
+}
+
 // encodes arbitrary bytes into chunks of 64 bytes (the limit for bytes) as a list to be valid Metadata
 #[wasm_bindgen]
 pub fn encode_arbitrary_bytes_as_metadatum(bytes: &[u8]) -> TransactionMetadatum {
@@ -445,6 +459,53 @@ pub fn decode_arbitrary_bytes_from_metadatum(
     Ok(bytes)
 }
 
+// splits text into chunks of at most 64 bytes (the limit for strings) without breaking utf8 characters
+pub(crate) fn split_metadata_text(text: &str) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut chunk = String::new();
+    for c in text.chars() {
+        if chunk.len() + c.len_utf8() > MD_MAX_LEN {
+            chunks.push(std::mem::take(&mut chunk));
+        }
+        chunk.push(c);
+    }
+    if !chunk.is_empty() || chunks.is_empty() {
+        chunks.push(chunk);
+    }
+    chunks
+}
+
+// encodes text as is when it fits into the limit, otherwise as a list of chunks
+pub(crate) fn encode_chunked_text_metadatum(text: &str) -> TransactionMetadatum {
+    let mut chunks = split_metadata_text(text);
+    if chunks.len() == 1 {
+        // this should never fail as we are already chunking it
+        TransactionMetadatum::new_text(chunks.remove(0)).unwrap()
+    } else {
+        let mut list = MetadataList::new();
+        for chunk in chunks {
+            list.add(&TransactionMetadatum::new_text(chunk).unwrap());
+        }
+        TransactionMetadatum::new_list(&list)
+    }
+}
+
+// decodes text or a list of text chunks
+pub(crate) fn decode_chunked_text_metadatum(
+    metadata: &TransactionMetadatum,
+) -> Result<String, JsError> {
+    match &metadata.0 {
+        TransactionMetadatumEnum::Text(text) => Ok(text.clone()),
+        TransactionMetadatumEnum::MetadataList(list) => list
+            .0
+            .iter()
+            .map(|chunk| chunk.as_text())
+            .collect::<Result<Vec<_>, _>>()
+            .map(|chunks| chunks.concat()),
+        _ => Err(JsError::from_str("not text or a list of text chunks")),
+    }
+}
+
 #[wasm_bindgen]
 #[derive(Copy, Clone, Eq, PartialEq)]
 // Different schema methods for mapping between JSON and the metadata CBOR.
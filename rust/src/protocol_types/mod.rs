@@ -14,6 +14,15 @@ pub use plutus::*;
 mod metadata;
 pub use metadata::*;
 
+mod cip68;
+pub use cip68::*;
+
+mod nft_metadata;
+pub use nft_metadata::*;
+
+mod tx_messages;
+pub use tx_messages::*;
+
 mod transaction_body;
 pub use transaction_body::*;
 
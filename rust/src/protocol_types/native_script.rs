@@ -265,6 +265,16 @@ impl TimelockStart {
     pub fn new_timelockstart(slot: &SlotBigNum) -> Self {
         Self { slot: slot.clone() }
     }
+
+    /// Timelock of the first slot starting at or after the POSIX time in milliseconds
+    pub fn new_from_time(
+        posix_millis: &BigNum,
+        era_history: &EraHistory,
+    ) -> Result<TimelockStart, JsError> {
+        Ok(Self {
+            slot: era_history.posix_millis_to_slot_ceil(posix_millis)?,
+        })
+    }
 }
 
 #[wasm_bindgen]
@@ -303,4 +313,14 @@ impl TimelockExpiry {
     pub fn new_timelockexpiry(slot: &SlotBigNum) -> Self {
         Self { slot: slot.clone() }
     }
+
+    /// Timelock of the slot containing the POSIX time in milliseconds
+    pub fn new_from_time(
+        posix_millis: &BigNum,
+        era_history: &EraHistory,
+    ) -> Result<TimelockExpiry, JsError> {
+        Ok(Self {
+            slot: era_history.posix_millis_to_slot(posix_millis)?,
+        })
+    }
 }
@@ -0,0 +1,289 @@
+use crate::*;
+
+const NAME_KEY: &str = "name";
+const IMAGE_KEY: &str = "image";
+const MEDIA_TYPE_KEY: &str = "mediaType";
+const DESCRIPTION_KEY: &str = "description";
+const FILES_KEY: &str = "files";
+const SRC_KEY: &str = "src";
+
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Cip25File {
+    name: Option<String>,
+    media_type: String,
+    src: String,
+    properties: MetadataMap,
+}
+
+#[wasm_bindgen]
+impl Cip25File {
+    pub fn new(media_type: &str, src: &str) -> Self {
+        Self {
+            name: None,
+            media_type: media_type.to_string(),
+            src: src.to_string(),
+            properties: MetadataMap::new(),
+        }
+    }
+
+    pub fn name(&self) -> Option<String> {
+        self.name.clone()
+    }
+
+    pub fn set_name(&mut self, name: &str) {
+        self.name = Some(name.to_string());
+    }
+
+    pub fn media_type(&self) -> String {
+        self.media_type.clone()
+    }
+
+    pub fn src(&self) -> String {
+        self.src.clone()
+    }
+
+    /// Additional properties of the file besides name, mediaType and src
+    pub fn properties(&self) -> MetadataMap {
+        self.properties.clone()
+    }
+
+    pub fn set_property(&mut self, key: &str, value: &TransactionMetadatum) -> Result<(), JsError> {
+        if [NAME_KEY, MEDIA_TYPE_KEY, SRC_KEY].contains(&key) {
+            return Err(JsError::from_str(&format!(
+                "\"{}\" must be set with its own setter",
+                key
+            )));
+        }
+        self.properties.insert_str(key, value)?;
+        Ok(())
+    }
+
+    pub fn to_metadatum(&self) -> Result<TransactionMetadatum, JsError> {
+        let mut map = MetadataMap::new();
+        if let Some(name) = &self.name {
+            map.insert_str(NAME_KEY, &TransactionMetadatum::new_text(name.clone())?)?;
+        }
+        map.insert_str(
+            MEDIA_TYPE_KEY,
+            &TransactionMetadatum::new_text(self.media_type.clone())?,
+        )?;
+        map.insert_str(SRC_KEY, &encode_chunked_text_metadatum(&self.src))?;
+        for (key, value) in &self.properties.0 {
+            map.insert(key, value);
+        }
+        Ok(TransactionMetadatum::new_map(&map))
+    }
+
+    pub fn from_metadatum(metadatum: &TransactionMetadatum) -> Result<Cip25File, JsError> {
+        let map = metadatum
+            .as_map()
+            .map_err(|_| JsError::from_str("CIP-25 file must be a map"))?;
+        let mut file = Self::new(
+            &required_text(&map, MEDIA_TYPE_KEY)?,
+            &required_chunked_text(&map, SRC_KEY)?,
+        );
+        file.name = optional_text(&map, NAME_KEY)?;
+        file.properties = properties_except(&map, &[NAME_KEY, MEDIA_TYPE_KEY, SRC_KEY]);
+        Ok(file)
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Cip25Files(pub(crate) Vec<Cip25File>);
+
+#[wasm_bindgen]
+impl Cip25Files {
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn get(&self, index: usize) -> Cip25File {
+        self.0[index].clone()
+    }
+
+    pub fn add(&mut self, elem: &Cip25File) {
+        self.0.push(elem.clone());
+    }
+}
+
+/// Metadata of a single asset from CIP-25 (label 721)
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Cip25AssetMetadata {
+    name: String,
+    image: String,
+    media_type: Option<String>,
+    description: Option<String>,
+    files: Cip25Files,
+    properties: MetadataMap,
+}
+
+#[wasm_bindgen]
+impl Cip25AssetMetadata {
+    pub fn new(name: &str, image: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            image: image.to_string(),
+            media_type: None,
+            description: None,
+            files: Cip25Files::new(),
+            properties: MetadataMap::new(),
+        }
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Image URI, it is split into 64 byte chunks when encoded
+    pub fn image(&self) -> String {
+        self.image.clone()
+    }
+
+    pub fn media_type(&self) -> Option<String> {
+        self.media_type.clone()
+    }
+
+    pub fn set_media_type(&mut self, media_type: &str) {
+        self.media_type = Some(media_type.to_string());
+    }
+
+    pub fn description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    pub fn set_description(&mut self, description: &str) {
+        self.description = Some(description.to_string());
+    }
+
+    pub fn files(&self) -> Cip25Files {
+        self.files.clone()
+    }
+
+    pub fn add_file(&mut self, file: &Cip25File) {
+        self.files.add(file);
+    }
+
+    /// Additional properties of the asset (e.g. "attributes") besides the standard fields
+    pub fn properties(&self) -> MetadataMap {
+        self.properties.clone()
+    }
+
+    pub fn set_property(&mut self, key: &str, value: &TransactionMetadatum) -> Result<(), JsError> {
+        if [NAME_KEY, IMAGE_KEY, MEDIA_TYPE_KEY, DESCRIPTION_KEY, FILES_KEY].contains(&key) {
+            return Err(JsError::from_str(&format!(
+                "\"{}\" must be set with its own setter",
+                key
+            )));
+        }
+        self.properties.insert_str(key, value)?;
+        Ok(())
+    }
+
+    pub fn to_metadatum(&self) -> Result<TransactionMetadatum, JsError> {
+        let mut map = MetadataMap::new();
+        map.insert_str(NAME_KEY, &TransactionMetadatum::new_text(self.name.clone())?)?;
+        map.insert_str(IMAGE_KEY, &encode_chunked_text_metadatum(&self.image))?;
+        if let Some(media_type) = &self.media_type {
+            map.insert_str(
+                MEDIA_TYPE_KEY,
+                &TransactionMetadatum::new_text(media_type.clone())?,
+            )?;
+        }
+        if let Some(description) = &self.description {
+            map.insert_str(DESCRIPTION_KEY, &encode_chunked_text_metadatum(description))?;
+        }
+        if self.files.len() > 0 {
+            let mut files = MetadataList::new();
+            for file in &self.files.0 {
+                files.add(&file.to_metadatum()?);
+            }
+            map.insert_str(FILES_KEY, &TransactionMetadatum::new_list(&files))?;
+        }
+        for (key, value) in &self.properties.0 {
+            map.insert(key, value);
+        }
+        Ok(TransactionMetadatum::new_map(&map))
+    }
+
+    pub fn from_metadatum(metadatum: &TransactionMetadatum) -> Result<Cip25AssetMetadata, JsError> {
+        let map = metadatum
+            .as_map()
+            .map_err(|_| JsError::from_str("CIP-25 asset metadata must be a map"))?;
+        let mut asset = Self::new(
+            &required_text(&map, NAME_KEY)?,
+            &required_chunked_text(&map, IMAGE_KEY)?,
+        );
+        asset.media_type = optional_text(&map, MEDIA_TYPE_KEY)?;
+        asset.description = match map.get_str(DESCRIPTION_KEY) {
+            Ok(description) => Some(decode_chunked_text_metadatum(&description).map_err(|e| {
+                JsError::from_str(&format!("Invalid \"{}\": {:?}", DESCRIPTION_KEY, e))
+            })?),
+            Err(_) => None,
+        };
+        if let Ok(files) = map.get_str(FILES_KEY) {
+            let files = files
+                .as_list()
+                .map_err(|_| JsError::from_str("CIP-25 files must be a list"))?;
+            for (i, file) in files.0.iter().enumerate() {
+                let file = Cip25File::from_metadatum(file)
+                    .map_err(|e| JsError::from_str(&format!("Invalid file {}: {:?}", i, e)))?;
+                asset.add_file(&file);
+            }
+        }
+        asset.properties = properties_except(
+            &map,
+            &[NAME_KEY, IMAGE_KEY, MEDIA_TYPE_KEY, DESCRIPTION_KEY, FILES_KEY],
+        );
+        Ok(asset)
+    }
+}
+
+fn required_text(map: &MetadataMap, key: &str) -> Result<String, JsError> {
+    map.get_str(key)
+        .map_err(|_| JsError::from_str(&format!("Missing \"{}\"", key)))?
+        .as_text()
+        .map_err(|_| JsError::from_str(&format!("\"{}\" must be a string", key)))
+}
+
+fn optional_text(map: &MetadataMap, key: &str) -> Result<Option<String>, JsError> {
+    match map.get_str(key) {
+        Ok(value) => value
+            .as_text()
+            .map(Some)
+            .map_err(|_| JsError::from_str(&format!("\"{}\" must be a string", key))),
+        Err(_) => Ok(None),
+    }
+}
+
+fn required_chunked_text(map: &MetadataMap, key: &str) -> Result<String, JsError> {
+    let value = map
+        .get_str(key)
+        .map_err(|_| JsError::from_str(&format!("Missing \"{}\"", key)))?;
+    decode_chunked_text_metadatum(&value).map_err(|_| {
+        JsError::from_str(&format!(
+            "\"{}\" must be a string or a list of strings",
+            key
+        ))
+    })
+}
+
+fn properties_except(map: &MetadataMap, reserved: &[&str]) -> MetadataMap {
+    let mut properties = MetadataMap::new();
+    for (key, value) in &map.0 {
+        let is_reserved = match &key.0 {
+            TransactionMetadatumEnum::Text(text) => reserved.contains(&text.as_str()),
+            _ => false,
+        };
+        if !is_reserved {
+            properties.insert(key, value);
+        }
+    }
+    properties
+}
@@ -0,0 +1,207 @@
+use crate::*;
+use std::collections::BTreeMap;
+
+pub(crate) const CIP25_METADATA_LABEL: u64 = 721;
+const VERSION_KEY: &str = "version";
+
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Cip25Version {
+    /// Policy ids are hex strings and asset names are utf8 strings
+    V1,
+    /// Policy ids and asset names are raw bytes
+    V2,
+}
+
+/// NFT metadata from CIP-25, stored under the label 721 of the transaction metadata
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Cip25Metadata {
+    version: Cip25Version,
+    assets: BTreeMap<PolicyID, BTreeMap<AssetName, Cip25AssetMetadata>>,
+}
+
+#[wasm_bindgen]
+impl Cip25Metadata {
+    pub fn new(version: Cip25Version) -> Self {
+        Self {
+            version,
+            assets: BTreeMap::new(),
+        }
+    }
+
+    pub fn version(&self) -> Cip25Version {
+        self.version
+    }
+
+    pub fn insert(
+        &mut self,
+        policy_id: &PolicyID,
+        asset_name: &AssetName,
+        metadata: &Cip25AssetMetadata,
+    ) -> Option<Cip25AssetMetadata> {
+        self.assets
+            .entry(policy_id.clone())
+            .or_default()
+            .insert(asset_name.clone(), metadata.clone())
+    }
+
+    pub fn get(&self, policy_id: &PolicyID, asset_name: &AssetName) -> Option<Cip25AssetMetadata> {
+        self.assets
+            .get(policy_id)
+            .and_then(|assets| assets.get(asset_name))
+            .cloned()
+    }
+
+    pub fn policy_ids(&self) -> PolicyIDs {
+        let mut policy_ids = PolicyIDs::new();
+        for policy_id in self.assets.keys() {
+            policy_ids.add(policy_id);
+        }
+        policy_ids
+    }
+
+    pub fn asset_names(&self, policy_id: &PolicyID) -> Option<AssetNames> {
+        self.assets.get(policy_id).map(|assets| {
+            let mut names = AssetNames::new();
+            for name in assets.keys() {
+                names.add(name);
+            }
+            names
+        })
+    }
+
+    pub fn to_metadatum(&self) -> Result<TransactionMetadatum, JsError> {
+        let mut root = MetadataMap::new();
+        for (policy_id, assets) in &self.assets {
+            let mut policy_map = MetadataMap::new();
+            for (asset_name, metadata) in assets {
+                policy_map.insert(&self.encode_asset_name(asset_name)?, &metadata.to_metadatum()?);
+            }
+            let policy_key = match self.version {
+                Cip25Version::V1 => TransactionMetadatum::new_text(policy_id.to_hex())?,
+                Cip25Version::V2 => TransactionMetadatum::new_bytes(policy_id.to_bytes())?,
+            };
+            root.insert(&policy_key, &TransactionMetadatum::new_map(&policy_map));
+        }
+        if self.version == Cip25Version::V2 {
+            root.insert_str(VERSION_KEY, &TransactionMetadatum::new_int(&Int::new_i32(2)))?;
+        }
+        Ok(TransactionMetadatum::new_map(&root))
+    }
+
+    /// Parses and validates the content of the label 721.
+    /// The keys must have the shape of the declared version: strings for v1 and bytes for v2.
+    pub fn from_metadatum(metadatum: &TransactionMetadatum) -> Result<Cip25Metadata, JsError> {
+        let root = metadatum
+            .as_map()
+            .map_err(|_| JsError::from_str("CIP-25 metadata must be a map"))?;
+        let version = match root.get_str(VERSION_KEY) {
+            Ok(version) => parse_version(&version)?,
+            Err(_) => Cip25Version::V1,
+        };
+        let mut result = Self::new(version);
+        for (policy_key, assets) in &root.0 {
+            if policy_key.as_text().ok().as_deref() == Some(VERSION_KEY) {
+                continue;
+            }
+            let policy_id = parse_policy_id(policy_key, version)?;
+            let assets = assets.as_map().map_err(|_| {
+                JsError::from_str(&format!("Assets of policy {} must be a map", policy_id.to_hex()))
+            })?;
+            for (asset_key, metadata) in &assets.0 {
+                let asset_name = parse_asset_name(asset_key, version).map_err(|e| {
+                    JsError::from_str(&format!("Policy {}: {:?}", policy_id.to_hex(), e))
+                })?;
+                let metadata = Cip25AssetMetadata::from_metadatum(metadata).map_err(|e| {
+                    JsError::from_str(&format!(
+                        "Asset {}.{}: {:?}",
+                        policy_id.to_hex(),
+                        asset_name,
+                        e
+                    ))
+                })?;
+                result.insert(&policy_id, &asset_name, &metadata);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Sets the label 721 of the auxiliary data, replacing any previous CIP-25 metadata
+    pub fn add_to_auxiliary_data(&self, auxiliary_data: &mut AuxiliaryData) -> Result<(), JsError> {
+        auxiliary_data.set_label_metadatum(CIP25_METADATA_LABEL, &self.to_metadatum()?);
+        Ok(())
+    }
+
+    /// Returns None when the auxiliary data has no label 721
+    pub fn from_auxiliary_data(
+        auxiliary_data: &AuxiliaryData,
+    ) -> Result<Option<Cip25Metadata>, JsError> {
+        auxiliary_data
+            .label_metadatum(CIP25_METADATA_LABEL)
+            .map(|metadatum| Self::from_metadatum(&metadatum))
+            .transpose()
+    }
+}
+
+impl Cip25Metadata {
+    fn encode_asset_name(&self, asset_name: &AssetName) -> Result<TransactionMetadatum, JsError> {
+        match self.version {
+            // v1 can't express names that aren't utf8, they need v2
+            Cip25Version::V1 => match String::from_utf8(asset_name.name()) {
+                Ok(name) => TransactionMetadatum::new_text(name),
+                Err(_) => Err(JsError::from_str(&format!(
+                    "Asset name {} isn't utf8, it requires CIP-25 version 2",
+                    asset_name
+                ))),
+            },
+            Cip25Version::V2 => TransactionMetadatum::new_bytes(asset_name.name()),
+        }
+    }
+}
+
+fn parse_version(version: &TransactionMetadatum) -> Result<Cip25Version, JsError> {
+    let version = match &version.0 {
+        TransactionMetadatumEnum::Int(int) => int.as_i32_or_nothing().map(|v| v.to_string()),
+        TransactionMetadatumEnum::Text(text) => Some(text.clone()),
+        _ => None,
+    };
+    match version.as_deref() {
+        Some("1") | Some("1.0") => Ok(Cip25Version::V1),
+        Some("2") | Some("2.0") => Ok(Cip25Version::V2),
+        _ => Err(JsError::from_str("Unsupported CIP-25 version")),
+    }
+}
+
+fn parse_policy_id(
+    key: &TransactionMetadatum,
+    version: Cip25Version,
+) -> Result<PolicyID, JsError> {
+    let bytes = match (&key.0, version) {
+        (TransactionMetadatumEnum::Text(text), Cip25Version::V1) => hex::decode(text).ok(),
+        (TransactionMetadatumEnum::Bytes(bytes), Cip25Version::V2) => Some(bytes.clone()),
+        (_, Cip25Version::V1) => {
+            return Err(JsError::from_str("Policy id must be a hex string in CIP-25 v1"))
+        }
+        (_, Cip25Version::V2) => {
+            return Err(JsError::from_str("Policy id must be bytes in CIP-25 v2"))
+        }
+    };
+    bytes
+        .and_then(|bytes| PolicyID::from_bytes(bytes).ok())
+        .ok_or_else(|| JsError::from_str("Invalid policy id"))
+}
+
+fn parse_asset_name(
+    key: &TransactionMetadatum,
+    version: Cip25Version,
+) -> Result<AssetName, JsError> {
+    match (&key.0, version) {
+        (TransactionMetadatumEnum::Text(text), Cip25Version::V1) => {
+            AssetName::new(text.as_bytes().to_vec())
+        }
+        (TransactionMetadatumEnum::Bytes(bytes), Cip25Version::V2) => AssetName::new(bytes.clone()),
+        (_, Cip25Version::V1) => Err(JsError::from_str("Asset name must be a string in CIP-25 v1")),
+        (_, Cip25Version::V2) => Err(JsError::from_str("Asset name must be bytes in CIP-25 v2")),
+    }
+}
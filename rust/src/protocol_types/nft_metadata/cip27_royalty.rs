@@ -0,0 +1,106 @@
+use crate::*;
+
+pub(crate) const CIP27_METADATA_LABEL: u64 = 777;
+const RATE_KEY: &str = "rate";
+// the first revision of CIP-27 used "pct" for the rate
+const LEGACY_RATE_KEY: &str = "pct";
+const ADDRESS_KEY: &str = "addr";
+
+/// Collection royalty from CIP-27, stored under the label 777 of the transaction metadata
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Cip27Royalty {
+    rate: String,
+    address: Address,
+}
+
+#[wasm_bindgen]
+impl Cip27Royalty {
+    /// The rate is a decimal string between 0 and 1, e.g. "0.2" for 20%
+    pub fn new(rate: &str, address: &Address) -> Result<Cip27Royalty, JsError> {
+        validate_rate(rate)?;
+        Ok(Self {
+            rate: rate.to_string(),
+            address: address.clone(),
+        })
+    }
+
+    pub fn rate(&self) -> String {
+        self.rate.clone()
+    }
+
+    pub fn address(&self) -> Address {
+        self.address.clone()
+    }
+
+    pub fn to_metadatum(&self) -> Result<TransactionMetadatum, JsError> {
+        let mut map = MetadataMap::new();
+        map.insert_str(RATE_KEY, &TransactionMetadatum::new_text(self.rate.clone())?)?;
+        map.insert_str(
+            ADDRESS_KEY,
+            &encode_chunked_text_metadatum(&self.address.to_bech32(None)?),
+        )?;
+        Ok(TransactionMetadatum::new_map(&map))
+    }
+
+    pub fn from_metadatum(metadatum: &TransactionMetadatum) -> Result<Cip27Royalty, JsError> {
+        let map = metadatum
+            .as_map()
+            .map_err(|_| JsError::from_str("CIP-27 royalty must be a map"))?;
+        let rate = map
+            .get_str(RATE_KEY)
+            .or_else(|_| map.get_str(LEGACY_RATE_KEY))
+            .map_err(|_| JsError::from_str("Missing \"rate\""))?
+            .as_text()
+            .map_err(|_| JsError::from_str("\"rate\" must be a string"))?;
+        let address = map
+            .get_str(ADDRESS_KEY)
+            .map_err(|_| JsError::from_str("Missing \"addr\""))?;
+        let address = decode_chunked_text_metadatum(&address)
+            .map_err(|_| JsError::from_str("\"addr\" must be a string or a list of strings"))?;
+        let address = Address::from_bech32(&address)
+            .map_err(|e| JsError::from_str(&format!("Invalid \"addr\": {:?}", e)))?;
+        Self::new(&rate, &address)
+    }
+
+    /// Sets the label 777 of the auxiliary data, replacing any previous royalty
+    pub fn add_to_auxiliary_data(&self, auxiliary_data: &mut AuxiliaryData) -> Result<(), JsError> {
+        auxiliary_data.set_label_metadatum(CIP27_METADATA_LABEL, &self.to_metadatum()?);
+        Ok(())
+    }
+
+    /// Returns None when the auxiliary data has no label 777
+    pub fn from_auxiliary_data(
+        auxiliary_data: &AuxiliaryData,
+    ) -> Result<Option<Cip27Royalty>, JsError> {
+        auxiliary_data
+            .label_metadatum(CIP27_METADATA_LABEL)
+            .map(|metadatum| Self::from_metadatum(&metadatum))
+            .transpose()
+    }
+}
+
+fn validate_rate(rate: &str) -> Result<(), JsError> {
+    let invalid = || JsError::from_str(&format!("Invalid royalty rate \"{}\"", rate));
+    let (int_part, frac_part) = match rate.split_once('.') {
+        Some((int_part, frac_part)) => (int_part, frac_part),
+        None => (rate, ""),
+    };
+    let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    if int_part.is_empty() || !is_digits(int_part) || !is_digits(frac_part) {
+        return Err(invalid());
+    }
+    if rate.contains('.') && frac_part.is_empty() {
+        return Err(invalid());
+    }
+    let int_part = int_part.trim_start_matches('0');
+    let frac_is_zero = frac_part.chars().all(|c| c == '0');
+    match int_part {
+        "" => Ok(()),
+        "1" if frac_is_zero => Ok(()),
+        _ => Err(JsError::from_str(&format!(
+            "Royalty rate \"{}\" must be between 0 and 1",
+            rate
+        ))),
+    }
+}
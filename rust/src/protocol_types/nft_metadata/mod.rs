@@ -0,0 +1,8 @@
+mod cip25_asset_metadata;
+pub use cip25_asset_metadata::*;
+
+mod cip25_metadata;
+pub use cip25_metadata::*;
+
+mod cip27_royalty;
+pub use cip27_royalty::*;
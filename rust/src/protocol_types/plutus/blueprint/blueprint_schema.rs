@@ -0,0 +1,423 @@
+use crate::*;
+use serde_json::{Map, Value};
+
+// refs can point to other refs, this only guards against cycles of bare refs
+const MAX_REF_DEPTH: usize = 64;
+
+/// Encoder and decoder of PlutusData driven by the CIP-57 schemas of a blueprint.
+///
+/// Friendly JSON used by it:
+/// * integer: a JSON number or a string with a decimal number
+/// * bytes: a hex string
+/// * list: a JSON array, tuples (`items` is an array of schemas) must have the exact length
+/// * map: an array of `[key, value]` pairs, or an object when the keys can be written as strings
+/// * constructor: an object with the field titles as keys, or an array when fields have no titles
+/// * anyOf: `"Title"` for constructors without fields, `{"Title": fields}` otherwise.
+///   Single constructor types are written as their fields and `Bool` as a JSON boolean
+/// * schemas without a data type (opaque `Data`): the cardano-cli detailed JSON schema
+pub(crate) struct BlueprintSchemaCodec<'a> {
+    definitions: &'a Map<String, Value>,
+}
+
+impl<'a> BlueprintSchemaCodec<'a> {
+    pub(crate) fn new(definitions: &'a Map<String, Value>) -> Self {
+        Self { definitions }
+    }
+
+    pub(crate) fn definition(&self, name: &str) -> Result<&'a Value, String> {
+        self.definitions
+            .get(name)
+            .ok_or_else(|| format!("Definition \"{}\" not found", name))
+    }
+
+    fn resolve(&self, schema: &'a Value) -> Result<&'a Value, String> {
+        let mut schema = schema;
+        for _ in 0..MAX_REF_DEPTH {
+            match schema.get("$ref").and_then(Value::as_str) {
+                Some(reference) => {
+                    let name = reference
+                        .strip_prefix("#/definitions/")
+                        .ok_or_else(|| format!("Unsupported reference \"{}\"", reference))?
+                        .replace("~1", "/")
+                        .replace("~0", "~");
+                    schema = self.definition(&name)?;
+                }
+                None => return Ok(schema),
+            }
+        }
+        Err("Too many nested references".to_string())
+    }
+
+    pub(crate) fn encode(&self, schema: &'a Value, json: &Value, path: &str) -> Result<PlutusData, String> {
+        let schema = self.resolve(schema)?;
+        if let Some(variants) = variants(schema) {
+            return self.encode_variants(variants, json, path);
+        }
+        match data_type(schema) {
+            Some("integer") => encode_integer(json)
+                .ok_or_else(|| format!("{}: expected an integer", path)),
+            Some("bytes") => json
+                .as_str()
+                .and_then(|s| hex::decode(s).ok())
+                .map(PlutusData::new_bytes)
+                .ok_or_else(|| format!("{}: expected a hex string", path)),
+            Some("list") => self.encode_list(schema, json, path),
+            Some("map") => self.encode_map(schema, json, path),
+            Some("constructor") => self.encode_constructor(schema, json, path),
+            Some(other) => Err(format!("{}: unsupported data type \"{}\"", path, other)),
+            None => encode_json_value_to_plutus_datum(json.clone(), PlutusDatumSchema::DetailedSchema)
+                .map_err(|e| format!("{}: {:?}", path, e)),
+        }
+    }
+
+    pub(crate) fn decode(&self, schema: &'a Value, data: &PlutusData, path: &str) -> Result<Value, String> {
+        let schema = self.resolve(schema)?;
+        if let Some(variants) = variants(schema) {
+            return self.decode_variants(variants, data, path);
+        }
+        match data_type(schema) {
+            Some("integer") => data
+                .as_integer()
+                .ok_or_else(|| format!("{}: expected an integer", path))
+                .and_then(|int| bigint_to_serde_value(&int).map_err(|e| format!("{}: {:?}", path, e))),
+            Some("bytes") => data
+                .as_bytes()
+                .map(|bytes| Value::from(hex::encode(bytes)))
+                .ok_or_else(|| format!("{}: expected bytes", path)),
+            Some("list") => self.decode_list(schema, data, path),
+            Some("map") => self.decode_map(schema, data, path),
+            Some("constructor") => {
+                let constr = data
+                    .as_constr_plutus_data()
+                    .ok_or_else(|| format!("{}: expected a constructor", path))?;
+                let index = constructor_index(schema, path)?;
+                if constr.alternative() != BigNum::from(index) {
+                    return Err(format!(
+                        "{}: expected constructor {}, found {}",
+                        path,
+                        index,
+                        constr.alternative()
+                    ));
+                }
+                self.decode_fields(schema, &constr.data(), path)
+            }
+            Some(other) => Err(format!("{}: unsupported data type \"{}\"", path, other)),
+            None => decode_plutus_datum_to_json_value(data, PlutusDatumSchema::DetailedSchema)
+                .map_err(|e| format!("{}: {:?}", path, e)),
+        }
+    }
+
+    fn encode_list(&self, schema: &'a Value, json: &Value, path: &str) -> Result<PlutusData, String> {
+        let elems = json
+            .as_array()
+            .ok_or_else(|| format!("{}: expected an array", path))?;
+        let mut list = PlutusList::new();
+        match schema.get("items") {
+            Some(Value::Array(items)) => {
+                if items.len() != elems.len() {
+                    return Err(format!(
+                        "{}: expected a tuple of {} elements, found {}",
+                        path,
+                        items.len(),
+                        elems.len()
+                    ));
+                }
+                for (i, (item, elem)) in items.iter().zip(elems).enumerate() {
+                    list.add(&self.encode(item, elem, &format!("{}[{}]", path, i))?);
+                }
+            }
+            Some(item) => {
+                for (i, elem) in elems.iter().enumerate() {
+                    list.add(&self.encode(item, elem, &format!("{}[{}]", path, i))?);
+                }
+            }
+            None => {
+                for (i, elem) in elems.iter().enumerate() {
+                    list.add(&self.encode(&Value::Null, elem, &format!("{}[{}]", path, i))?);
+                }
+            }
+        }
+        Ok(PlutusData::new_list(&list))
+    }
+
+    fn decode_list(&self, schema: &'a Value, data: &PlutusData, path: &str) -> Result<Value, String> {
+        let list = data
+            .as_list()
+            .ok_or_else(|| format!("{}: expected a list", path))?;
+        if let Some(Value::Array(items)) = schema.get("items") {
+            if items.len() != list.len() {
+                return Err(format!(
+                    "{}: expected a tuple of {} elements, found {}",
+                    path,
+                    items.len(),
+                    list.len()
+                ));
+            }
+        }
+        let mut elems = Vec::with_capacity(list.len());
+        for (i, elem) in list.elems.iter().enumerate() {
+            let item = match schema.get("items") {
+                Some(Value::Array(items)) => &items[i],
+                Some(item) => item,
+                None => &Value::Null,
+            };
+            elems.push(self.decode(item, elem, &format!("{}[{}]", path, i))?);
+        }
+        Ok(Value::from(elems))
+    }
+
+    fn encode_map(&self, schema: &'a Value, json: &Value, path: &str) -> Result<PlutusData, String> {
+        let keys = schema.get("keys").unwrap_or(&Value::Null);
+        let values = schema.get("values").unwrap_or(&Value::Null);
+        let mut map = PlutusMap::new();
+        match json {
+            Value::Array(pairs) => {
+                for (i, pair) in pairs.iter().enumerate() {
+                    let (key, value) = match pair.as_array().map(Vec::as_slice) {
+                        Some([key, value]) => (key, value),
+                        _ => return Err(format!("{}[{}]: expected a [key, value] pair", path, i)),
+                    };
+                    let key = self.encode(keys, key, &format!("{}[{}][0]", path, i))?;
+                    let value = self.encode(values, value, &format!("{}[{}][1]", path, i))?;
+                    map.add_value_move(key, value);
+                }
+            }
+            Value::Object(obj) => {
+                for (key, value) in obj {
+                    let key_path = format!("{}.{}", path, key);
+                    let key = self.encode(keys, &Value::from(key.clone()), &key_path)?;
+                    let value = self.encode(values, value, &key_path)?;
+                    map.add_value_move(key, value);
+                }
+            }
+            _ => return Err(format!("{}: expected an array of pairs or an object", path)),
+        }
+        Ok(PlutusData::new_map(&map))
+    }
+
+    fn decode_map(&self, schema: &'a Value, data: &PlutusData, path: &str) -> Result<Value, String> {
+        let keys = schema.get("keys").unwrap_or(&Value::Null);
+        let values = schema.get("values").unwrap_or(&Value::Null);
+        let map = data
+            .as_map()
+            .ok_or_else(|| format!("{}: expected a map", path))?;
+        let mut pairs = Vec::new();
+        for (key, key_values) in map.0.iter() {
+            for value in &key_values.elems {
+                let i = pairs.len();
+                pairs.push(Value::from(vec![
+                    self.decode(keys, key, &format!("{}[{}][0]", path, i))?,
+                    self.decode(values, value, &format!("{}[{}][1]", path, i))?,
+                ]));
+            }
+        }
+        Ok(Value::from(pairs))
+    }
+
+    fn encode_constructor(&self, schema: &'a Value, json: &Value, path: &str) -> Result<PlutusData, String> {
+        let index = constructor_index(schema, path)?;
+        let fields = fields(schema);
+        let mut list = PlutusList::new();
+        match json {
+            Value::Null if fields.is_empty() => {}
+            Value::Array(elems) => {
+                if elems.len() != fields.len() {
+                    return Err(format!(
+                        "{}: expected {} fields, found {}",
+                        path,
+                        fields.len(),
+                        elems.len()
+                    ));
+                }
+                for (i, (field, elem)) in fields.iter().zip(elems).enumerate() {
+                    list.add(&self.encode(field, elem, &format!("{}[{}]", path, i))?);
+                }
+            }
+            Value::Object(obj) => {
+                if let Some(unknown) = obj
+                    .keys()
+                    .find(|key| !fields.iter().any(|field| field_title(field) == Some(key.as_str())))
+                {
+                    return Err(format!("{}: unknown field \"{}\"", path, unknown));
+                }
+                for (i, field) in fields.iter().enumerate() {
+                    let title = field_title(field)
+                        .ok_or_else(|| format!("{}: field {} has no title, use an array", path, i))?;
+                    let field_path = format!("{}.{}", path, title);
+                    let value = obj
+                        .get(title)
+                        .ok_or_else(|| format!("{}: missing field", field_path))?;
+                    list.add(&self.encode(field, value, &field_path)?);
+                }
+            }
+            _ => return Err(format!("{}: expected an object or an array of fields", path)),
+        }
+        Ok(PlutusData::new_constr_plutus_data(&ConstrPlutusData::new(
+            &BigNum::from(index),
+            &list,
+        )))
+    }
+
+    fn decode_fields(&self, schema: &'a Value, data: &PlutusList, path: &str) -> Result<Value, String> {
+        let fields = fields(schema);
+        if fields.len() != data.len() {
+            return Err(format!(
+                "{}: expected {} fields, found {}",
+                path,
+                fields.len(),
+                data.len()
+            ));
+        }
+        if !fields.is_empty() && fields.iter().all(|field| field_title(field).is_some()) {
+            let mut obj = Map::new();
+            for (field, elem) in fields.iter().zip(data.elems.iter()) {
+                let title = field_title(field).unwrap_or_default();
+                let value = self.decode(field, elem, &format!("{}.{}", path, title))?;
+                obj.insert(title.to_string(), value);
+            }
+            Ok(Value::from(obj))
+        } else {
+            let mut elems = Vec::with_capacity(fields.len());
+            for (i, (field, elem)) in fields.iter().zip(data.elems.iter()).enumerate() {
+                elems.push(self.decode(field, elem, &format!("{}[{}]", path, i))?);
+            }
+            Ok(Value::from(elems))
+        }
+    }
+
+    fn resolve_variants(&self, variants: &'a [Value]) -> Result<Vec<&'a Value>, String> {
+        variants.iter().map(|variant| self.resolve(variant)).collect()
+    }
+
+    fn encode_variants(&self, variants: &'a [Value], json: &Value, path: &str) -> Result<PlutusData, String> {
+        let variants = self.resolve_variants(variants)?;
+        if variants.len() == 1 {
+            return self.encode_constructor(variants[0], json, path);
+        }
+        if let (Some(value), true) = (json.as_bool(), is_bool(&variants)) {
+            let index = if value { 1 } else { 0 };
+            return Ok(PlutusData::new_empty_constr_plutus_data(&BigNum::from(index as u64)));
+        }
+        let (title, fields) = match json {
+            Value::String(title) => (title.as_str(), &Value::Null),
+            Value::Object(obj) if obj.len() == 1 => {
+                let (title, fields) = obj.iter().next().unwrap();
+                (title.as_str(), fields)
+            }
+            _ => {
+                return Err(format!(
+                    "{}: expected one of {}",
+                    path,
+                    variant_titles(&variants)
+                ))
+            }
+        };
+        let variant = variants
+            .iter()
+            .find(|variant| field_title(variant) == Some(title))
+            .ok_or_else(|| {
+                format!(
+                    "{}: unknown constructor \"{}\", expected one of {}",
+                    path,
+                    title,
+                    variant_titles(&variants)
+                )
+            })?;
+        self.encode_constructor(variant, fields, &format!("{}.{}", path, title))
+    }
+
+    fn decode_variants(&self, variants: &'a [Value], data: &PlutusData, path: &str) -> Result<Value, String> {
+        let variants = self.resolve_variants(variants)?;
+        let constr = data
+            .as_constr_plutus_data()
+            .ok_or_else(|| format!("{}: expected a constructor", path))?;
+        let mut variant = None;
+        for candidate in &variants {
+            if BigNum::from(constructor_index(candidate, path)?) == constr.alternative() {
+                variant = Some(*candidate);
+            }
+        }
+        let variant = variant.ok_or_else(|| {
+            format!("{}: unexpected constructor {}", path, constr.alternative())
+        })?;
+        if variants.len() == 1 {
+            return self.decode_fields(variant, &constr.data(), path);
+        }
+        if is_bool(&variants) && constr.data().elems.is_empty() {
+            return Ok(Value::from(constr.alternative() == BigNum::one()));
+        }
+        let title = field_title(variant)
+            .map(str::to_string)
+            .unwrap_or_else(|| constr.alternative().to_str());
+        if fields(variant).is_empty() && constr.data().elems.is_empty() {
+            return Ok(Value::from(title));
+        }
+        let fields = self.decode_fields(variant, &constr.data(), &format!("{}.{}", path, title))?;
+        let mut obj = Map::new();
+        obj.insert(title, fields);
+        Ok(Value::from(obj))
+    }
+}
+
+fn data_type(schema: &Value) -> Option<&str> {
+    schema.get("dataType").and_then(Value::as_str)
+}
+
+fn variants(schema: &Value) -> Option<&[Value]> {
+    schema
+        .get("anyOf")
+        .or_else(|| schema.get("oneOf"))
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+}
+
+fn fields(schema: &Value) -> &[Value] {
+    schema
+        .get("fields")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or(&[])
+}
+
+fn field_title(schema: &Value) -> Option<&str> {
+    schema.get("title").and_then(Value::as_str)
+}
+
+fn constructor_index(schema: &Value, path: &str) -> Result<u64, String> {
+    schema
+        .get("index")
+        .and_then(Value::as_u64)
+        .ok_or_else(|| format!("{}: constructor schema without an index", path))
+}
+
+fn is_bool(variants: &[&Value]) -> bool {
+    variants.len() == 2
+        && field_title(variants[0]) == Some("False")
+        && field_title(variants[1]) == Some("True")
+        && variants
+            .iter()
+            .enumerate()
+            .all(|(i, variant)| {
+                variant.get("index").and_then(Value::as_u64) == Some(i as u64)
+                    && fields(variant).is_empty()
+            })
+}
+
+fn variant_titles(variants: &[&Value]) -> String {
+    variants
+        .iter()
+        .map(|variant| format!("\"{}\"", field_title(variant).unwrap_or("?")))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn encode_integer(json: &Value) -> Option<PlutusData> {
+    let text = match json {
+        Value::Number(number) => number.to_string(),
+        Value::String(text) => text.clone(),
+        _ => return None,
+    };
+    BigInt::from_str(&text)
+        .ok()
+        .map(|int| PlutusData::new_integer(&int))
+}
@@ -0,0 +1,155 @@
+use crate::*;
+use serde_json::{Map, Value};
+use std::rc::Rc;
+
+/// Validator of a CIP-57 blueprint with its compiled code and the schemas of its datum,
+/// redeemer and parameters. See `PlutusBlueprint` for the JSON accepted by the encode methods.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct BlueprintValidator {
+    pub(crate) title: String,
+    pub(crate) compiled_code: Option<Vec<u8>>,
+    pub(crate) hash: Option<ScriptHash>,
+    pub(crate) language: Option<Language>,
+    pub(crate) datum: Option<Value>,
+    pub(crate) redeemer: Option<Value>,
+    pub(crate) parameters: Vec<(Option<String>, Value)>,
+    pub(crate) definitions: Rc<Map<String, Value>>,
+}
+
+#[wasm_bindgen]
+impl BlueprintValidator {
+    pub fn title(&self) -> String {
+        self.title.clone()
+    }
+
+    /// Plutus version from the preamble of the blueprint
+    pub fn language(&self) -> Option<Language> {
+        self.language
+    }
+
+    /// Hash declared in the blueprint
+    pub fn hash(&self) -> Option<ScriptHash> {
+        self.hash.clone()
+    }
+
+    /// Returns the compiled script, the declared hash is checked when it's present.
+    /// Parameterized validators have to be applied before they can be used on chain.
+    pub fn script(&self) -> Result<PlutusScript, JsError> {
+        let code = self.compiled_code.as_ref().ok_or_else(|| {
+            JsError::from_str(&format!("Validator \"{}\" has no compiled code", self.title))
+        })?;
+        let language = self.language.ok_or_else(|| {
+            JsError::from_str("Blueprint preamble doesn't specify the plutus version")
+        })?;
+        let script = PlutusScript::new_with_version(code.clone(), &language);
+        if let Some(hash) = &self.hash {
+            if &script.hash() != hash {
+                return Err(JsError::from_str(&format!(
+                    "Compiled code of validator \"{}\" doesn't match its hash",
+                    self.title
+                )));
+            }
+        }
+        Ok(script)
+    }
+
+    pub fn has_datum(&self) -> bool {
+        self.datum.is_some()
+    }
+
+    pub fn parameters_len(&self) -> usize {
+        self.parameters.len()
+    }
+
+    pub fn parameter_title(&self, index: usize) -> Option<String> {
+        self.parameters.get(index).and_then(|(title, _)| title.clone())
+    }
+
+    pub fn encode_datum(&self, json: &str) -> Result<PlutusData, JsError> {
+        self.encode(self.datum.as_ref(), "datum", json)
+    }
+
+    pub fn decode_datum(&self, data: &PlutusData) -> Result<String, JsError> {
+        self.decode(self.datum.as_ref(), "datum", data)
+    }
+
+    pub fn encode_redeemer(&self, json: &str) -> Result<PlutusData, JsError> {
+        self.encode(self.redeemer.as_ref(), "redeemer", json)
+    }
+
+    pub fn decode_redeemer(&self, data: &PlutusData) -> Result<String, JsError> {
+        self.decode(self.redeemer.as_ref(), "redeemer", data)
+    }
+
+    pub fn encode_parameter(&self, index: usize, json: &str) -> Result<PlutusData, JsError> {
+        let schema = self.parameters.get(index).map(|(_, schema)| schema);
+        self.encode(schema, &format!("parameter {}", index), json)
+    }
+
+    /// Encodes all parameters from a JSON array, in the order they have to be applied
+    pub fn encode_parameters(&self, json: &str) -> Result<PlutusList, JsError> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|e| JsError::from_str(&e.to_string()))?;
+        let values = value
+            .as_array()
+            .ok_or_else(|| JsError::from_str("Parameters must be a JSON array"))?;
+        if values.len() != self.parameters.len() {
+            return Err(JsError::from_str(&format!(
+                "Validator \"{}\" expects {} parameters, found {}",
+                self.title,
+                self.parameters.len(),
+                values.len()
+            )));
+        }
+        let codec = BlueprintSchemaCodec::new(&self.definitions);
+        let mut list = PlutusList::new();
+        for (i, ((title, schema), value)) in self.parameters.iter().zip(values).enumerate() {
+            let path = title.clone().unwrap_or_else(|| format!("${}", i));
+            list.add(
+                &codec
+                    .encode(schema, value, &path)
+                    .map_err(|e| JsError::from_str(&e))?,
+            );
+        }
+        Ok(list)
+    }
+}
+
+impl BlueprintValidator {
+    fn encode(&self, schema: Option<&Value>, name: &str, json: &str) -> Result<PlutusData, JsError> {
+        let schema = schema.ok_or_else(|| {
+            JsError::from_str(&format!("Validator \"{}\" has no {}", self.title, name))
+        })?;
+        let value: Value =
+            serde_json::from_str(json).map_err(|e| JsError::from_str(&e.to_string()))?;
+        BlueprintSchemaCodec::new(&self.definitions)
+            .encode(schema, &value, "$")
+            .map_err(|e| JsError::from_str(&e))
+    }
+
+    fn decode(&self, schema: Option<&Value>, name: &str, data: &PlutusData) -> Result<String, JsError> {
+        let schema = schema.ok_or_else(|| {
+            JsError::from_str(&format!("Validator \"{}\" has no {}", self.title, name))
+        })?;
+        let value = BlueprintSchemaCodec::new(&self.definitions)
+            .decode(schema, data, "$")
+            .map_err(|e| JsError::from_str(&e))?;
+        serde_json::to_string(&value).map_err(|e| JsError::from_str(&e.to_string()))
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct BlueprintValidators(pub(crate) Vec<BlueprintValidator>);
+
+#[wasm_bindgen]
+impl BlueprintValidators {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn get(&self, index: usize) -> BlueprintValidator {
+        self.0[index].clone()
+    }
+}
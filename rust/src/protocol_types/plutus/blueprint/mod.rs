@@ -0,0 +1,8 @@
+mod blueprint_schema;
+pub(crate) use blueprint_schema::*;
+
+mod blueprint_validator;
+pub use blueprint_validator::*;
+
+mod plutus_blueprint;
+pub use plutus_blueprint::*;
@@ -0,0 +1,164 @@
+use crate::*;
+use serde_json::{Map, Value};
+use std::rc::Rc;
+
+/// CIP-57 plutus blueprint (`plutus.json`) with its validators and type definitions.
+///
+/// The blueprint schemas are used to convert between PlutusData and friendly JSON:
+/// * integer: a JSON number or a string with a decimal number
+/// * bytes: a hex string
+/// * list: a JSON array, tuples must have the exact length
+/// * map: an array of `[key, value]` pairs, or an object when the keys can be written as strings
+/// * constructor: an object with the field titles as keys, or an array when fields have no titles
+/// * anyOf: `"Title"` for constructors without fields, `{"Title": fields}` otherwise.
+///   Single constructor types are written as their fields and `Bool` as a JSON boolean
+/// * opaque `Data`: the cardano-cli detailed JSON schema (see `PlutusDatumSchema::DetailedSchema`)
+///
+/// Errors point to the offending value, e.g. `$.owner.Some[0]: expected a hex string`.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct PlutusBlueprint {
+    title: Option<String>,
+    version: Option<String>,
+    language: Option<Language>,
+    validators: Vec<BlueprintValidator>,
+    definitions: Rc<Map<String, Value>>,
+}
+
+#[wasm_bindgen]
+impl PlutusBlueprint {
+    pub fn from_json(json: &str) -> Result<PlutusBlueprint, JsError> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|e| JsError::from_str(&e.to_string()))?;
+        let preamble = value
+            .get("preamble")
+            .ok_or_else(|| JsError::from_str("Blueprint has no preamble"))?;
+        let language = match preamble.get("plutusVersion").and_then(Value::as_str) {
+            Some("v1") => Some(Language::new_plutus_v1()),
+            Some("v2") => Some(Language::new_plutus_v2()),
+            Some("v3") => Some(Language::new_plutus_v3()),
+            Some(other) => {
+                return Err(JsError::from_str(&format!(
+                    "Unknown plutus version \"{}\"",
+                    other
+                )))
+            }
+            None => None,
+        };
+        let definitions = Rc::new(match value.get("definitions") {
+            Some(Value::Object(definitions)) => definitions.clone(),
+            Some(_) => return Err(JsError::from_str("Blueprint definitions must be an object")),
+            None => Map::new(),
+        });
+        let mut validators = Vec::new();
+        let validator_values = match value.get("validators") {
+            Some(Value::Array(validators)) => validators.as_slice(),
+            Some(_) => return Err(JsError::from_str("Blueprint validators must be an array")),
+            None => &[],
+        };
+        for (i, validator) in validator_values.iter().enumerate() {
+            validators.push(
+                parse_validator(validator, language, &definitions)
+                    .map_err(|e| JsError::from_str(&format!("Validator {}: {}", i, e)))?,
+            );
+        }
+        Ok(Self {
+            title: text_field(preamble, "title"),
+            version: text_field(preamble, "version"),
+            language,
+            validators,
+            definitions,
+        })
+    }
+
+    pub fn title(&self) -> Option<String> {
+        self.title.clone()
+    }
+
+    pub fn version(&self) -> Option<String> {
+        self.version.clone()
+    }
+
+    pub fn plutus_version(&self) -> Option<Language> {
+        self.language
+    }
+
+    pub fn validators(&self) -> BlueprintValidators {
+        BlueprintValidators(self.validators.clone())
+    }
+
+    /// Finds a validator by its title, e.g. "marketplace.spend"
+    pub fn validator(&self, title: &str) -> Option<BlueprintValidator> {
+        self.validators
+            .iter()
+            .find(|validator| validator.title == title)
+            .cloned()
+    }
+
+    pub fn definition_names(&self) -> Strings {
+        Strings(self.definitions.keys().cloned().collect())
+    }
+
+    /// Encodes friendly JSON into PlutusData using a type definition, e.g. "types/Datum"
+    pub fn encode_json(&self, definition: &str, json: &str) -> Result<PlutusData, JsError> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|e| JsError::from_str(&e.to_string()))?;
+        let codec = BlueprintSchemaCodec::new(&self.definitions);
+        let schema = codec.definition(definition).map_err(|e| JsError::from_str(&e))?;
+        codec
+            .encode(schema, &value, "$")
+            .map_err(|e| JsError::from_str(&e))
+    }
+
+    /// Decodes PlutusData into friendly JSON using a type definition
+    pub fn decode_to_json(&self, definition: &str, data: &PlutusData) -> Result<String, JsError> {
+        let codec = BlueprintSchemaCodec::new(&self.definitions);
+        let schema = codec.definition(definition).map_err(|e| JsError::from_str(&e))?;
+        let value = codec
+            .decode(schema, data, "$")
+            .map_err(|e| JsError::from_str(&e))?;
+        serde_json::to_string(&value).map_err(|e| JsError::from_str(&e.to_string()))
+    }
+}
+
+fn text_field(value: &Value, key: &str) -> Option<String> {
+    value.get(key).and_then(Value::as_str).map(str::to_string)
+}
+
+fn parse_validator(
+    value: &Value,
+    language: Option<Language>,
+    definitions: &Rc<Map<String, Value>>,
+) -> Result<BlueprintValidator, String> {
+    let title = text_field(value, "title").ok_or("missing title")?;
+    let compiled_code = match text_field(value, "compiledCode") {
+        Some(code) => Some(hex::decode(code).map_err(|_| "compiledCode must be hex")?),
+        None => None,
+    };
+    let hash = match text_field(value, "hash") {
+        Some(hash) => Some(ScriptHash::from_hex(&hash).map_err(|_| "invalid hash")?),
+        None => None,
+    };
+    let schema = |key: &str| value.get(key).and_then(|arg| arg.get("schema")).cloned();
+    let mut parameters = Vec::new();
+    if let Some(params) = value.get("parameters") {
+        let params = params.as_array().ok_or("parameters must be an array")?;
+        for param in params {
+            let schema = param
+                .get("schema")
+                .cloned()
+                .ok_or("parameter without a schema")?;
+            parameters.push((text_field(param, "title"), schema));
+        }
+    }
+    Ok(BlueprintValidator {
+        title,
+        compiled_code,
+        hash,
+        language,
+        datum: schema("datum"),
+        redeemer: schema("redeemer"),
+        parameters,
+        definitions: definitions.clone(),
+    })
+}
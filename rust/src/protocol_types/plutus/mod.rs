@@ -35,3 +35,11 @@ pub use strings::*;
 
 mod plutus_data;
 pub use plutus_data::*;
+
+mod plutus_data_lossless_json;
+
+mod blueprint;
+pub use blueprint::*;
+
+mod uplc;
+pub use uplc::*;
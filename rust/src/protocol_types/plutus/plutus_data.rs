@@ -1,4 +1,7 @@
 use crate::*;
+use super::plutus_data_lossless_json::{
+    decode_plutus_datum_to_lossless_json, encode_lossless_json_to_plutus_datum,
+};
 use core::hash::Hasher;
 use hashlink::LinkedHashMap;
 use std::hash::Hash;
@@ -929,6 +932,7 @@ impl From<Vec<PlutusData>> for PlutusList {
 ///
 /// All methods here have the following restrictions due to limitations on dependencies:
 /// * JSON numbers above u64::MAX (positive) or below i64::MIN (negative) will throw errors
+///   (except for LosslessSchema which accepts integers of any size)
 /// * Hex strings for bytes don't accept odd-length (half-byte) strings.
 ///      cardano-cli seems to support these however but it seems to be different than just 0-padding
 ///      on either side when tested so proceed with caution
@@ -972,6 +976,22 @@ pub enum PlutusDatumSchema {
     /// * all Plutus datums should be fully supported outside of the integer range limitations outlined above.
     ////
     DetailedSchema,
+    /// Extension of DetailedSchema that keeps everything needed to reproduce the exact CBOR of a datum.
+    ///
+    /// The datum hash of a JSON created from on-chain bytes is kept when converting it back.
+    /// Differences to DetailedSchema:
+    /// * integers of any size are supported. They are JSON numbers when they are within the JS safe integer range
+    ///   (+/-(2^53 - 1)) and strings otherwise e.g. {"int": "340282366920938463463374607431768211456"}
+    /// * "constructor" can be any unsigned 64 bit integer, alternatives above 127 use the general form of the CBOR encoding
+    /// * map entries are kept in the encoded order, including duplicate keys
+    /// * lists, maps and constructors have an optional "definite" bool when their array or map length is not encoded
+    ///   the default way (indefinite for non-empty lists and fields, definite for empty ones and for maps)
+    ///   e.g. {"list": [{"int": 1}], "definite": true}
+    /// * constructors with alternatives 0-127 have an optional "general_form": true when written with tag 102
+    /// * any datum can have a "cbor" hex string with its exact bytes when they can't be described by the above
+    ///   (e.g. non-minimal lengths or integers). The bytes must decode to the same datum.
+    ////
+    LosslessSchema,
 }
 
 #[wasm_bindgen]
@@ -1142,6 +1162,7 @@ pub fn encode_json_value_to_plutus_datum(
                 value
             ))),
         },
+        PlutusDatumSchema::LosslessSchema => encode_lossless_json_to_plutus_datum(&value),
     }
 }
 
@@ -1161,6 +1182,9 @@ pub fn decode_plutus_datum_to_json_value(
     schema: PlutusDatumSchema,
 ) -> Result<serde_json::Value, JsError> {
     use serde_json::Value;
+    if schema == PlutusDatumSchema::LosslessSchema {
+        return decode_plutus_datum_to_lossless_json(datum);
+    }
     let (type_tag, json_value) = match &datum.datum {
         PlutusDataEnum::ConstrPlutusData(constr) => {
             let mut obj = serde_json::map::Map::with_capacity(2);
@@ -1196,7 +1220,8 @@ pub fn decode_plutus_datum_to_json_value(
                     Err(JsError::from_str("plutus maps are not allowed to have empty values in this schema. Use DetailedSchema."))
                 }
             }).collect::<Result<serde_json::map::Map<String, Value>, JsError>>()?)),
-            PlutusDatumSchema::DetailedSchema => {
+            // lossless JSON is created from the datum bytes before this
+            PlutusDatumSchema::DetailedSchema | PlutusDatumSchema::LosslessSchema => {
                 let mut entries = Vec::new();
                 for (key, values) in map.0.iter() {
                     for value in &values.elems {
@@ -1231,7 +1256,7 @@ pub fn decode_plutus_datum_to_json_value(
                 // otherwise we hex-encode the bytes with a 0x prefix
                     .unwrap_or_else(|| format!("0x{}", hex::encode(bytes)))
             },
-            PlutusDatumSchema::DetailedSchema => hex::encode(bytes),
+            PlutusDatumSchema::DetailedSchema | PlutusDatumSchema::LosslessSchema => hex::encode(bytes),
         })),
     };
     if type_tag.is_none() || schema != PlutusDatumSchema::DetailedSchema {
@@ -1244,7 +1269,7 @@ pub fn decode_plutus_datum_to_json_value(
 }
 
 #[cfg(not(feature = "arbitrary-precision-json"))]
-fn bigint_to_serde_value(bigint: &BigInt) -> Result<serde_json::Value, JsError> {
+pub(crate) fn bigint_to_serde_value(bigint: &BigInt) -> Result<serde_json::Value, JsError> {
     bigint
         .as_int()
         .as_ref()
@@ -1253,7 +1278,7 @@ fn bigint_to_serde_value(bigint: &BigInt) -> Result<serde_json::Value, JsError>
 }
 
 #[cfg(feature = "arbitrary-precision-json")]
-fn bigint_to_serde_value(bigint: &BigInt) -> Result<serde_json::Value, JsError> {
+pub(crate) fn bigint_to_serde_value(bigint: &BigInt) -> Result<serde_json::Value, JsError> {
     use serde_json::Number;
     Ok(serde_json::Value::Number(Number::from_string_unchecked(bigint.to_str())))
 }
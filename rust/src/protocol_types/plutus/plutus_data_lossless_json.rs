@@ -0,0 +1,387 @@
+use crate::*;
+use cbor_event::{de::Deserializer, se::Serializer, Len};
+use serde_json::{Map, Value};
+use std::io::Cursor;
+
+// Lossless JSON for plutus datums, see `PlutusDatumSchema::LosslessSchema` for the format.
+// Datums are converted from and to their CBOR bytes directly instead of the `PlutusDataEnum`
+// tree, because the tree groups duplicate map keys and doesn't keep all encoding details.
+
+// integers outside of the JS safe integer range are written as strings to not lose precision
+const MAX_SAFE_JSON_INTEGER: i64 = (1 << 53) - 1;
+
+// datums are decoded recursively, deeper ones are rejected to keep the recursion from overflowing
+// the stack. JSON nested deeper than 128 levels is already rejected by serde_json
+const MAX_DEPTH: usize = 128;
+
+const CBOR_KEY: &str = "cbor";
+const DEFINITE_KEY: &str = "definite";
+const GENERAL_FORM_KEY: &str = "general_form";
+
+type Raw<'a> = Deserializer<Cursor<&'a [u8]>>;
+
+pub(crate) fn decode_plutus_datum_to_lossless_json(datum: &PlutusData) -> Result<Value, JsError> {
+    decode_lossless_json(&datum.to_bytes())
+        .map_err(|e| JsError::from_str(&format!("Invalid plutus datum: {}", e)))
+}
+
+pub(crate) fn encode_lossless_json_to_plutus_datum(value: &Value) -> Result<PlutusData, JsError> {
+    let mut serializer = Serializer::new_vec();
+    encode_node(value, &mut serializer)?;
+    PlutusData::from_bytes(serializer.finalize())
+        .map_err(|e| JsError::from_str(&format!("Invalid plutus datum: {}", e)))
+}
+
+fn decode_lossless_json(bytes: &[u8]) -> Result<Value, DeserializeError> {
+    let mut raw = Deserializer::from(Cursor::new(bytes));
+    let value = decode_node(&mut raw, bytes, 0)?;
+    if position(&mut raw) != bytes.len() {
+        return Err(DeserializeFailure::CBOR(cbor_event::Error::TrailingData).into());
+    }
+    Ok(value)
+}
+
+fn position(raw: &mut Raw) -> usize {
+    raw.as_mut_ref().position() as usize
+}
+
+// lists are written as indefinite arrays unless they are empty, see `PlutusList` serialization
+fn is_default_encoding(definite: bool, len: usize) -> bool {
+    definite == (len == 0)
+}
+
+// `depth` counts the nodes around this one
+fn decode_node(raw: &mut Raw, bytes: &[u8], depth: usize) -> Result<Value, DeserializeError> {
+    if depth == MAX_DEPTH {
+        return Err(DeserializeFailure::CustomError(format!(
+            "plutus datum is nested deeper than {} levels",
+            MAX_DEPTH
+        ))
+        .into());
+    }
+    let start = position(raw);
+    let mut node = Map::new();
+    let exact = match raw.cbor_type()? {
+        CBORType::UnsignedInteger | CBORType::NegativeInteger => decode_int(raw, bytes, &mut node)?,
+        CBORType::Bytes => {
+            let data = read_bounded_bytes(raw)?;
+            let mut expected = Serializer::new_vec();
+            write_bounded_bytes(&mut expected, &data)?;
+            node.insert("bytes".to_string(), Value::from(hex::encode(data)));
+            expected.finalize() == bytes[start..position(raw)]
+        }
+        CBORType::Array => {
+            let (items, definite, exact) = decode_list(raw, bytes, depth)?;
+            insert_definite(&mut node, definite, items.len());
+            node.insert("list".to_string(), Value::from(items));
+            exact
+        }
+        CBORType::Map => decode_map(raw, bytes, &mut node, depth)?,
+        CBORType::Tag => {
+            let tag = raw.tag()?;
+            let mut exact = is_canonical(bytes, start, position(raw), |s| s.write_tag(tag));
+            match tag {
+                2 | 3 => {
+                    raw.as_mut_ref().set_position(start as u64);
+                    exact = decode_int(raw, bytes, &mut node)?;
+                }
+                ConstrPlutusData::GENERAL_FORM_TAG => {
+                    let header_start = position(raw);
+                    let len = raw.array()?;
+                    exact &= len == Len::Len(2);
+                    if len != Len::Len(2) && len != Len::Indefinite {
+                        return Err(DeserializeFailure::CBOR(cbor_event::Error::WrongLen(
+                            2,
+                            len,
+                            "general form of ConstrPlutusData",
+                        ))
+                        .into());
+                    }
+                    exact &= is_canonical(bytes, header_start, position(raw), |s| s.write_array(len));
+                    let alternative_start = position(raw);
+                    let alternative = raw.unsigned_integer()?;
+                    exact &= is_canonical(bytes, alternative_start, position(raw), |s| {
+                        s.write_unsigned_integer(alternative)
+                    });
+                    let (fields, definite, fields_exact) = decode_list(raw, bytes, depth)?;
+                    exact &= fields_exact;
+                    if len == Len::Indefinite && !is_break(raw)? {
+                        return Err(DeserializeFailure::EndingBreakMissing.into());
+                    }
+                    node.insert("constructor".to_string(), Value::from(alternative));
+                    if ConstrPlutusData::alternative_to_compact_cbor_tag(alternative).is_some() {
+                        node.insert(GENERAL_FORM_KEY.to_string(), Value::from(true));
+                    }
+                    insert_definite(&mut node, definite, fields.len());
+                    node.insert("fields".to_string(), Value::from(fields));
+                }
+                tag => {
+                    let alternative = ConstrPlutusData::compact_cbor_tag_to_alternative(tag)
+                        .ok_or(DeserializeFailure::TagMismatch {
+                            found: tag,
+                            expected: ConstrPlutusData::GENERAL_FORM_TAG,
+                        })?;
+                    let (fields, definite, fields_exact) = decode_list(raw, bytes, depth)?;
+                    exact &= fields_exact;
+                    node.insert("constructor".to_string(), Value::from(alternative));
+                    insert_definite(&mut node, definite, fields.len());
+                    node.insert("fields".to_string(), Value::from(fields));
+                }
+            }
+            exact
+        }
+        _ => return Err(DeserializeFailure::NoVariantMatched.into()),
+    };
+    if !exact {
+        node.insert(CBOR_KEY.to_string(), Value::from(hex::encode(&bytes[start..position(raw)])));
+    }
+    Ok(Value::from(node))
+}
+
+fn decode_int(raw: &mut Raw, bytes: &[u8], node: &mut Map<String, Value>) -> Result<bool, DeserializeError> {
+    let start = position(raw);
+    let int = BigInt::deserialize(raw)?;
+    let json = match int.as_int() {
+        Some(small) if small.0.abs() <= MAX_SAFE_JSON_INTEGER as i128 => Value::from(small.0 as i64),
+        _ => Value::from(int.to_str()),
+    };
+    node.insert("int".to_string(), json);
+    Ok(int.to_bytes() == bytes[start..position(raw)])
+}
+
+fn decode_list(raw: &mut Raw, bytes: &[u8], depth: usize) -> Result<(Vec<Value>, bool, bool), DeserializeError> {
+    let start = position(raw);
+    let len = raw.array()?;
+    let exact = is_canonical(bytes, start, position(raw), |s| s.write_array(len));
+    let mut items = Vec::new();
+    while match len {
+        Len::Len(n) => items.len() < n as usize,
+        Len::Indefinite => !is_break(raw)?,
+    } {
+        items.push(decode_node(raw, bytes, depth + 1)?);
+    }
+    Ok((items, len != Len::Indefinite, exact))
+}
+
+fn decode_map(
+    raw: &mut Raw,
+    bytes: &[u8],
+    node: &mut Map<String, Value>,
+    depth: usize,
+) -> Result<bool, DeserializeError> {
+    let start = position(raw);
+    let len = raw.map()?;
+    let exact = is_canonical(bytes, start, position(raw), |s| s.write_map(len));
+    let mut entries = Vec::new();
+    while match len {
+        Len::Len(n) => entries.len() < n as usize,
+        Len::Indefinite => !is_break(raw)?,
+    } {
+        let mut entry = Map::with_capacity(2);
+        entry.insert("k".to_string(), decode_node(raw, bytes, depth + 1)?);
+        entry.insert("v".to_string(), decode_node(raw, bytes, depth + 1)?);
+        entries.push(Value::from(entry));
+    }
+    // maps are always written with a definite length
+    if len == Len::Indefinite {
+        node.insert(DEFINITE_KEY.to_string(), Value::from(false));
+    }
+    node.insert("map".to_string(), Value::from(entries));
+    Ok(exact)
+}
+
+fn insert_definite(node: &mut Map<String, Value>, definite: bool, len: usize) {
+    if !is_default_encoding(definite, len) {
+        node.insert(DEFINITE_KEY.to_string(), Value::from(definite));
+    }
+}
+
+fn is_break(raw: &mut Raw) -> Result<bool, DeserializeError> {
+    if raw.cbor_type()? == CBORType::Special {
+        if raw.special()? == CBORSpecial::Break {
+            return Ok(true);
+        }
+        return Err(DeserializeFailure::EndingBreakMissing.into());
+    }
+    Ok(false)
+}
+
+// checks that a header was written with the shortest encoding of its argument
+fn is_canonical(
+    bytes: &[u8],
+    start: usize,
+    end: usize,
+    write: impl FnOnce(&mut Serializer<Vec<u8>>) -> cbor_event::Result<&mut Serializer<Vec<u8>>>,
+) -> bool {
+    let mut expected = Serializer::new_vec();
+    write(&mut expected).is_ok() && expected.finalize() == bytes[start..end]
+}
+
+fn encode_node(value: &Value, serializer: &mut Serializer<Vec<u8>>) -> Result<(), JsError> {
+    let node = value
+        .as_object()
+        .ok_or_else(|| JsError::from_str(&format!("Expected a tagged object but got {}", value)))?;
+    if let Some(cbor) = node.get(CBOR_KEY) {
+        return encode_raw_node(node, cbor, serializer);
+    }
+    let cbor_error = |e: cbor_event::Error| JsError::from_str(&e.to_string());
+    if let Some(int) = node.get("int") {
+        check_keys(node, &["int"])?;
+        let int = match int {
+            Value::Number(number) => BigInt::from_str(&number.to_string()),
+            Value::String(string) => BigInt::from_str(string),
+            _ => Err(JsError::from_str("\"int\" must be a number or a string")),
+        }?;
+        int.serialize(serializer).map_err(cbor_error)?;
+    } else if let Some(bytes) = node.get("bytes") {
+        check_keys(node, &["bytes"])?;
+        let bytes = bytes
+            .as_str()
+            .ok_or_else(|| JsError::from_str("\"bytes\" must be a hex string"))
+            .and_then(|hex_str| hex::decode(hex_str).map_err(|e| JsError::from_str(&e.to_string())))?;
+        write_bounded_bytes(serializer, &bytes).map_err(cbor_error)?;
+    } else if let Some(list) = node.get("list") {
+        check_keys(node, &["list", DEFINITE_KEY])?;
+        encode_list(node, list, "list", serializer)?;
+    } else if let Some(map) = node.get("map") {
+        check_keys(node, &["map", DEFINITE_KEY])?;
+        let entries = map
+            .as_array()
+            .ok_or_else(|| JsError::from_str("\"map\" must be an array of {\"k\", \"v\"} entries"))?;
+        let definite = get_definite(node)?.unwrap_or(true);
+        serializer
+            .write_map(if definite { Len::Len(entries.len() as u64) } else { Len::Indefinite })
+            .map_err(cbor_error)?;
+        for entry in entries {
+            let (key, value) = entry
+                .as_object()
+                .filter(|entry| entry.len() == 2)
+                .and_then(|entry| Some((entry.get("k")?, entry.get("v")?)))
+                .ok_or_else(|| JsError::from_str("map entries must be objects with \"k\" and \"v\" keys"))?;
+            encode_node(key, serializer)?;
+            encode_node(value, serializer)?;
+        }
+        if !definite {
+            serializer.write_special(CBORSpecial::Break).map_err(cbor_error)?;
+        }
+    } else if let Some(constructor) = node.get("constructor") {
+        check_keys(node, &["constructor", "fields", DEFINITE_KEY, GENERAL_FORM_KEY])?;
+        let alternative = match constructor {
+            Value::Number(number) => number.as_u64(),
+            Value::String(string) => string.parse::<u64>().ok(),
+            _ => None,
+        }
+        .ok_or_else(|| JsError::from_str("\"constructor\" must be an unsigned integer"))?;
+        let general_form = match node.get(GENERAL_FORM_KEY) {
+            Some(flag) => flag
+                .as_bool()
+                .ok_or_else(|| JsError::from_str("\"general_form\" must be a bool"))?,
+            None => false,
+        };
+        let fields = node
+            .get("fields")
+            .ok_or_else(|| JsError::from_str("constructors must contain a list called \"fields\""))?;
+        match ConstrPlutusData::alternative_to_compact_cbor_tag(alternative) {
+            Some(tag) if !general_form => {
+                serializer.write_tag(tag).map_err(cbor_error)?;
+            }
+            _ => {
+                serializer
+                    .write_tag(ConstrPlutusData::GENERAL_FORM_TAG)
+                    .map_err(cbor_error)?;
+                serializer.write_array(Len::Len(2)).map_err(cbor_error)?;
+                serializer.write_unsigned_integer(alternative).map_err(cbor_error)?;
+            }
+        }
+        encode_list(node, fields, "fields", serializer)?;
+    } else {
+        return Err(JsError::from_str(&format!(
+            "Expected one of the keys \"int\", \"bytes\", \"list\", \"map\" or \"constructor\" but got {}",
+            value
+        )));
+    }
+    Ok(())
+}
+
+fn encode_list(
+    node: &Map<String, Value>,
+    list: &Value,
+    name: &str,
+    serializer: &mut Serializer<Vec<u8>>,
+) -> Result<(), JsError> {
+    let cbor_error = |e: cbor_event::Error| JsError::from_str(&e.to_string());
+    let items = list
+        .as_array()
+        .ok_or_else(|| JsError::from_str(&format!("\"{}\" must be an array", name)))?;
+    let definite = get_definite(node)?.unwrap_or(items.is_empty());
+    serializer
+        .write_array(if definite { Len::Len(items.len() as u64) } else { Len::Indefinite })
+        .map_err(cbor_error)?;
+    for item in items {
+        encode_node(item, serializer)?;
+    }
+    if !definite {
+        serializer.write_special(CBORSpecial::Break).map_err(cbor_error)?;
+    }
+    Ok(())
+}
+
+// the exact bytes of a node take precedence, but they must still match the described value
+fn encode_raw_node(
+    node: &Map<String, Value>,
+    cbor: &Value,
+    serializer: &mut Serializer<Vec<u8>>,
+) -> Result<(), JsError> {
+    let bytes = cbor
+        .as_str()
+        .ok_or_else(|| JsError::from_str("\"cbor\" must be a hex string"))
+        .and_then(|hex_str| hex::decode(hex_str).map_err(|e| JsError::from_str(&e.to_string())))?;
+    let decoded = decode_lossless_json(&bytes)
+        .map_err(|e| JsError::from_str(&format!("Invalid \"cbor\" of a plutus datum: {}", e)))?;
+    let mut described = node.clone();
+    described.remove(CBOR_KEY);
+    if without_cbor(&decoded) != without_cbor(&Value::from(described)) {
+        return Err(JsError::from_str(&format!(
+            "\"cbor\" {} doesn't match the datum it is attached to",
+            cbor
+        )));
+    }
+    serializer
+        .write_raw_bytes(&bytes)
+        .map_err(|e| JsError::from_str(&e.to_string()))?;
+    Ok(())
+}
+
+fn without_cbor(value: &Value) -> Value {
+    match value {
+        Value::Object(node) => Value::from(
+            node.iter()
+                .filter(|(key, _)| key.as_str() != CBOR_KEY)
+                .map(|(key, value)| (key.clone(), without_cbor(value)))
+                .collect::<Map<String, Value>>(),
+        ),
+        Value::Array(items) => Value::from(items.iter().map(without_cbor).collect::<Vec<_>>()),
+        // integers may be given as numbers or strings
+        Value::Number(number) => Value::from(number.to_string()),
+        other => other.clone(),
+    }
+}
+
+fn get_definite(node: &Map<String, Value>) -> Result<Option<bool>, JsError> {
+    node.get(DEFINITE_KEY)
+        .map(|flag| {
+            flag.as_bool()
+                .ok_or_else(|| JsError::from_str("\"definite\" must be a bool"))
+        })
+        .transpose()
+}
+
+fn check_keys(node: &Map<String, Value>, allowed: &[&str]) -> Result<(), JsError> {
+    match node.keys().find(|key| !allowed.contains(&key.as_str())) {
+        Some(key) => Err(JsError::from_str(&format!(
+            "Unexpected key \"{}\", expected only {:?}",
+            key, allowed
+        ))),
+        None => Ok(()),
+    }
+}
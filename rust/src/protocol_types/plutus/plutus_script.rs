@@ -102,6 +102,17 @@ impl PlutusScript {
         Language(self.language.clone())
     }
 
+    /// Applies the parameters to a parameterized script in the given order, like `aiken blueprint apply`.
+    /// The returned script has the same language and its bytes wrapped into CBOR once,
+    /// use `.hash()` on it to get the hash of the applied script.
+    pub fn apply_params(&self, params: &PlutusList) -> Result<PlutusScript, JsError> {
+        let mut program = UplcProgram::from_plutus_script(self)?;
+        for param in &params.elems {
+            program = program.apply_data(param);
+        }
+        Ok(program.to_plutus_script(&self.language_version()))
+    }
+
     pub(crate) fn script_namespace(&self) -> ScriptHashNamespace {
         match self.language {
             LanguageKind::PlutusV1 => ScriptHashNamespace::PlutusScript,
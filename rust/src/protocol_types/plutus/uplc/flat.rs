@@ -0,0 +1,222 @@
+use crate::error::{DeserializeError, DeserializeFailure};
+use num_bigint::Sign;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+
+// Bit level reader and writer for the flat format used by serialized UPLC programs.
+// Bits are read and written starting from the most significant bit of each byte.
+
+pub(crate) fn flat_error(message: impl Into<String>) -> DeserializeError {
+    DeserializeError::new(
+        "UplcProgram",
+        DeserializeFailure::CustomError(message.into()),
+    )
+}
+
+pub(crate) struct FlatDecoder<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    bit: u8,
+}
+
+impl<'a> FlatDecoder<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            pos: 0,
+            bit: 0,
+        }
+    }
+
+    /// Current position in bits, used for error messages and size statistics
+    pub(crate) fn position(&self) -> usize {
+        self.pos * 8 + self.bit as usize
+    }
+
+    pub(crate) fn bit(&mut self) -> Result<bool, DeserializeError> {
+        let byte = *self
+            .bytes
+            .get(self.pos)
+            .ok_or_else(|| flat_error("unexpected end of flat data"))?;
+        let value = byte & (0x80 >> self.bit) != 0;
+        self.bit += 1;
+        if self.bit == 8 {
+            self.bit = 0;
+            self.pos += 1;
+        }
+        Ok(value)
+    }
+
+    pub(crate) fn bits(&mut self, count: u8) -> Result<u8, DeserializeError> {
+        let mut value = 0u8;
+        for _ in 0..count {
+            value = (value << 1) | self.bit()? as u8;
+        }
+        Ok(value)
+    }
+
+    pub(crate) fn natural(&mut self) -> Result<num_bigint::BigInt, DeserializeError> {
+        let mut value = num_bigint::BigInt::zero();
+        let mut shift = 0usize;
+        loop {
+            let more = self.bit()?;
+            let chunk = self.bits(7)?;
+            value |= num_bigint::BigInt::from(chunk) << shift;
+            shift += 7;
+            if !more {
+                return Ok(value);
+            }
+        }
+    }
+
+    pub(crate) fn word(&mut self) -> Result<u64, DeserializeError> {
+        self.natural()?
+            .to_u64()
+            .ok_or_else(|| flat_error("natural number doesn't fit into 64 bits"))
+    }
+
+    pub(crate) fn integer(&mut self) -> Result<num_bigint::BigInt, DeserializeError> {
+        let zigzag = self.natural()?;
+        let is_negative = !(&zigzag & num_bigint::BigInt::one()).is_zero();
+        let half: num_bigint::BigInt = zigzag >> 1;
+        if is_negative {
+            Ok(-half - 1)
+        } else {
+            Ok(half)
+        }
+    }
+
+    /// Reads the padding that aligns the data to the next byte: zeros followed by a one
+    pub(crate) fn filler(&mut self) -> Result<(), DeserializeError> {
+        while !self.bit()? {}
+        if self.bit != 0 {
+            return Err(flat_error("filler doesn't end at a byte boundary"));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn bytes(&mut self) -> Result<Vec<u8>, DeserializeError> {
+        self.filler()?;
+        let mut result = Vec::new();
+        loop {
+            let len = *self
+                .bytes
+                .get(self.pos)
+                .ok_or_else(|| flat_error("unexpected end of flat data"))? as usize;
+            self.pos += 1;
+            if len == 0 {
+                return Ok(result);
+            }
+            let chunk = self
+                .bytes
+                .get(self.pos..self.pos + len)
+                .ok_or_else(|| flat_error("unexpected end of flat data"))?;
+            result.extend_from_slice(chunk);
+            self.pos += len;
+        }
+    }
+
+    /// Reads the flat list encoding, every element is preceded by a 1 bit and the list ends with a 0 bit
+    pub(crate) fn list<T>(
+        &mut self,
+        mut decode: impl FnMut(&mut Self) -> Result<T, DeserializeError>,
+    ) -> Result<Vec<T>, DeserializeError> {
+        let mut items = Vec::new();
+        while self.bit()? {
+            items.push(decode(self)?);
+        }
+        Ok(items)
+    }
+
+    pub(crate) fn is_finished(&self) -> bool {
+        self.pos == self.bytes.len() && self.bit == 0
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct FlatEncoder {
+    bytes: Vec<u8>,
+    current: u8,
+    bit: u8,
+}
+
+impl FlatEncoder {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn bit(&mut self, value: bool) {
+        if value {
+            self.current |= 0x80 >> self.bit;
+        }
+        self.bit += 1;
+        if self.bit == 8 {
+            self.bytes.push(self.current);
+            self.current = 0;
+            self.bit = 0;
+        }
+    }
+
+    pub(crate) fn bits(&mut self, count: u8, value: u8) {
+        for i in (0..count).rev() {
+            self.bit(value & (1 << i) != 0);
+        }
+    }
+
+    pub(crate) fn natural(&mut self, value: &num_bigint::BigInt) {
+        let mut value = value.clone();
+        let mask = num_bigint::BigInt::from(0x7f);
+        loop {
+            let chunk = (&value & &mask).to_u8().unwrap_or(0);
+            value >>= 7;
+            let more = !value.is_zero();
+            self.bit(more);
+            self.bits(7, chunk);
+            if !more {
+                return;
+            }
+        }
+    }
+
+    pub(crate) fn word(&mut self, value: u64) {
+        self.natural(&num_bigint::BigInt::from(value));
+    }
+
+    pub(crate) fn integer(&mut self, value: &num_bigint::BigInt) {
+        let zigzag = if value.sign() == Sign::Minus {
+            (value.abs() << 1) - 1
+        } else {
+            value << 1
+        };
+        self.natural(&zigzag);
+    }
+
+    pub(crate) fn filler(&mut self) {
+        while self.bit != 7 {
+            self.bit(false);
+        }
+        self.bit(true);
+    }
+
+    pub(crate) fn bytes(&mut self, bytes: &[u8]) {
+        self.filler();
+        for chunk in bytes.chunks(255) {
+            self.bytes.push(chunk.len() as u8);
+            self.bytes.extend_from_slice(chunk);
+        }
+        self.bytes.push(0);
+    }
+
+    pub(crate) fn list<T>(&mut self, items: &[T], mut encode: impl FnMut(&mut Self, &T)) {
+        for item in items {
+            self.bit(true);
+            encode(self, item);
+        }
+        self.bit(false);
+    }
+
+    /// Pads the data to a byte boundary and returns the encoded bytes
+    pub(crate) fn finish(mut self) -> Vec<u8> {
+        self.filler();
+        self.bytes
+    }
+}
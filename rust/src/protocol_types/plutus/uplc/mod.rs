@@ -0,0 +1,11 @@
+mod flat;
+
+mod pretty;
+
+mod term;
+
+mod uplc_program;
+pub use uplc_program::*;
+
+mod uplc_program_stats;
+pub use uplc_program_stats::*;
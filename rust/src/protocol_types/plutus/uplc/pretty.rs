@@ -0,0 +1,235 @@
+use super::term::{check_depth, UplcConstant, UplcTerm, UplcType, BUILTIN_NAMES};
+use crate::*;
+
+const INDENT: &str = "  ";
+
+// Prints terms in the textual UPLC syntax. Variables are named after the depth of their binder
+// (`i_0` is bound by the outermost lambda), atoms and terms with only atomic subterms stay on one line.
+// Terms nested deeper than the decoder accepts are rejected.
+pub(crate) fn print_program(version: &(u64, u64, u64), term: &UplcTerm) -> Result<String, String> {
+    let mut out = format!("(program {}.{}.{}\n{}", version.0, version.1, version.2, INDENT);
+    let mut steps = vec![PrintStep::Term(term, 0, 1, 0)];
+    while let Some(step) = steps.pop() {
+        match step {
+            PrintStep::Term(term, depth, indent, level) => {
+                print_term(term, depth, indent, level, &mut out, &mut steps)?
+            }
+            PrintStep::Atomic(term, depth, level) => print_atomic(term, depth, level, &mut out)?,
+            PrintStep::Space => {
+                if !out.ends_with('[') {
+                    out.push(' ');
+                }
+            }
+            PrintStep::Newline(indent) => {
+                out.push('\n');
+                out.push_str(&INDENT.repeat(indent));
+            }
+            PrintStep::Close(close) => out.push_str(close),
+        }
+    }
+    out.push_str("\n)");
+    Ok(out)
+}
+
+// Step of the printing, terms are printed with an explicit stack of steps so that their depth
+// only costs heap memory. `depth` counts the binders in scope and `level` the terms around a term.
+enum PrintStep<'a> {
+    Term(&'a UplcTerm, u64, usize, usize),
+    Atomic(&'a UplcTerm, u64, usize),
+    // space before a subterm on the same line, unless it follows an opening bracket
+    Space,
+    Newline(usize),
+    Close(&'static str),
+}
+
+fn is_atomic(mut term: &UplcTerm) -> bool {
+    while let UplcTerm::Force(inner) | UplcTerm::Delay(inner) = term {
+        term = inner;
+    }
+    matches!(
+        term,
+        UplcTerm::Var(_) | UplcTerm::Constant(_) | UplcTerm::Builtin(_) | UplcTerm::Error
+    )
+}
+
+// prints the opening of a term and pushes the steps printing the rest of it
+fn print_term<'a>(
+    term: &'a UplcTerm,
+    depth: u64,
+    indent: usize,
+    level: usize,
+    out: &mut String,
+    steps: &mut Vec<PrintStep<'a>>,
+) -> Result<(), String> {
+    check_depth("term", level)?;
+    let (open, close, children, depth, nesting) = match compound_parts(term, depth) {
+        Some(parts) => parts,
+        None => {
+            print_atom(term, depth, out);
+            return Ok(());
+        }
+    };
+    out.push_str(&open);
+    let level = level + nesting + 1;
+    // the steps are pushed in reverse order
+    if children.iter().all(|child| is_atomic(child)) {
+        steps.push(PrintStep::Close(close));
+        for child in children.into_iter().rev() {
+            steps.push(PrintStep::Atomic(child, depth, level));
+            steps.push(PrintStep::Space);
+        }
+        return Ok(());
+    }
+    steps.push(PrintStep::Close(close));
+    steps.push(PrintStep::Newline(indent));
+    for child in children.into_iter().rev() {
+        steps.push(PrintStep::Term(child, depth, indent + 1, level));
+        steps.push(PrintStep::Newline(indent + 1));
+    }
+    Ok(())
+}
+
+// prints an atomic term on one line
+fn print_atomic(
+    mut term: &UplcTerm,
+    depth: u64,
+    mut level: usize,
+    out: &mut String,
+) -> Result<(), String> {
+    let mut closes = 0;
+    loop {
+        check_depth("term", level)?;
+        term = match term {
+            UplcTerm::Force(inner) => {
+                out.push_str("(force ");
+                inner
+            }
+            UplcTerm::Delay(inner) => {
+                out.push_str("(delay ");
+                inner
+            }
+            _ => break,
+        };
+        closes += 1;
+        level += 1;
+    }
+    print_atom(term, depth, out);
+    out.push_str(&")".repeat(closes));
+    Ok(())
+}
+
+fn print_atom(term: &UplcTerm, depth: u64, out: &mut String) {
+    match term {
+        UplcTerm::Var(index) => {
+            if *index == 0 || *index > depth {
+                out.push_str(&format!("free_{}", index));
+            } else {
+                out.push_str(&format!("i_{}", depth - index));
+            }
+        }
+        UplcTerm::Constant(constant) => {
+            out.push_str(&format!("(con {} {})", print_type(&constant.uplc_type()), print_constant(constant)));
+        }
+        UplcTerm::Builtin(builtin) => {
+            out.push_str(&format!("(builtin {})", BUILTIN_NAMES[*builtin as usize]));
+        }
+        _ => out.push_str("(error)"),
+    }
+}
+
+// Opening and closing of a term with subterms, its subterms, the binder depth of the subterms
+// and how many terms are between it and its subterms
+fn compound_parts(
+    term: &UplcTerm,
+    depth: u64,
+) -> Option<(String, &'static str, Vec<&UplcTerm>, u64, usize)> {
+    Some(match term {
+        UplcTerm::Lambda(body) => (format!("(lam i_{}", depth), ")", vec![body], depth + 1, 0),
+        UplcTerm::Delay(inner) => ("(delay".to_string(), ")", vec![inner], depth, 0),
+        UplcTerm::Force(inner) => ("(force".to_string(), ")", vec![inner], depth, 0),
+        UplcTerm::Apply(_, _) => {
+            // [f a b] is the same as [[f a] b]
+            let mut args = Vec::new();
+            let mut head = term;
+            while let UplcTerm::Apply(function, argument) = head {
+                args.push(argument.as_ref());
+                head = function;
+            }
+            args.push(head);
+            args.reverse();
+            // the head is nested into all the applications
+            let nesting = args.len() - 2;
+            ("[".to_string(), "]", args, depth, nesting)
+        }
+        UplcTerm::Constr(tag, fields) => {
+            (format!("(constr {}", tag), ")", fields.iter().collect(), depth, 0)
+        }
+        UplcTerm::Case(scrutinee, branches) => {
+            let mut terms = vec![scrutinee.as_ref()];
+            terms.extend(branches.iter());
+            ("(case".to_string(), ")", terms, depth, 0)
+        }
+        UplcTerm::Var(_) | UplcTerm::Constant(_) | UplcTerm::Builtin(_) | UplcTerm::Error => {
+            return None
+        }
+    })
+}
+
+fn print_type(uplc_type: &UplcType) -> String {
+    match uplc_type {
+        UplcType::Integer => "integer".to_string(),
+        UplcType::ByteString => "bytestring".to_string(),
+        UplcType::String => "string".to_string(),
+        UplcType::Unit => "unit".to_string(),
+        UplcType::Bool => "bool".to_string(),
+        UplcType::Data => "data".to_string(),
+        UplcType::List(elem) => format!("(list {})", print_type(elem)),
+        UplcType::Pair(first, second) => {
+            format!("(pair {} {})", print_type(first), print_type(second))
+        }
+    }
+}
+
+fn print_constant(constant: &UplcConstant) -> String {
+    match constant {
+        UplcConstant::Integer(int) => int.to_str(),
+        UplcConstant::ByteString(bytes) => format!("#{}", hex::encode(bytes)),
+        UplcConstant::String(text) => format!("{:?}", text),
+        UplcConstant::Unit => "()".to_string(),
+        UplcConstant::Bool(true) => "True".to_string(),
+        UplcConstant::Bool(false) => "False".to_string(),
+        UplcConstant::List(_, items) => format!(
+            "[{}]",
+            items.iter().map(print_constant).collect::<Vec<_>>().join(", ")
+        ),
+        UplcConstant::Pair(first, second) => {
+            format!("({}, {})", print_constant(first), print_constant(second))
+        }
+        UplcConstant::Data(data) => format!("({})", print_data(data)),
+    }
+}
+
+fn print_data(data: &PlutusData) -> String {
+    let join = |items: &mut dyn Iterator<Item = String>| items.collect::<Vec<_>>().join(", ");
+    match &data.datum {
+        PlutusDataEnum::ConstrPlutusData(constr) => format!(
+            "Constr {} [{}]",
+            constr.alternative,
+            join(&mut constr.data.elems.iter().map(print_data))
+        ),
+        PlutusDataEnum::Map(map) => format!(
+            "Map [{}]",
+            join(&mut map.0.iter().flat_map(|(key, values)| {
+                values
+                    .elems
+                    .iter()
+                    .map(move |value| format!("({}, {})", print_data(key), print_data(value)))
+            }))
+        ),
+        PlutusDataEnum::List(list) => {
+            format!("List [{}]", join(&mut list.elems.iter().map(print_data)))
+        }
+        PlutusDataEnum::Integer(int) => format!("I {}", int.to_str()),
+        PlutusDataEnum::Bytes(bytes) => format!("B #{}", hex::encode(bytes)),
+    }
+}
@@ -0,0 +1,620 @@
+use super::flat::{flat_error, FlatDecoder, FlatEncoder};
+use crate::*;
+
+// names of the builtin functions indexed by their flat tag
+pub(crate) const BUILTIN_NAMES: &[&str] = &[
+    "addInteger",
+    "subtractInteger",
+    "multiplyInteger",
+    "divideInteger",
+    "quotientInteger",
+    "remainderInteger",
+    "modInteger",
+    "equalsInteger",
+    "lessThanInteger",
+    "lessThanEqualsInteger",
+    "appendByteString",
+    "consByteString",
+    "sliceByteString",
+    "lengthOfByteString",
+    "indexByteString",
+    "equalsByteString",
+    "lessThanByteString",
+    "lessThanEqualsByteString",
+    "sha2_256",
+    "sha3_256",
+    "blake2b_256",
+    "verifyEd25519Signature",
+    "appendString",
+    "equalsString",
+    "encodeUtf8",
+    "decodeUtf8",
+    "ifThenElse",
+    "chooseUnit",
+    "trace",
+    "fstPair",
+    "sndPair",
+    "chooseList",
+    "mkCons",
+    "headList",
+    "tailList",
+    "nullList",
+    "chooseData",
+    "constrData",
+    "mapData",
+    "listData",
+    "iData",
+    "bData",
+    "unConstrData",
+    "unMapData",
+    "unListData",
+    "unIData",
+    "unBData",
+    "equalsData",
+    "mkPairData",
+    "mkNilData",
+    "mkNilPairData",
+    "serialiseData",
+    "verifyEcdsaSecp256k1Signature",
+    "verifySchnorrSecp256k1Signature",
+    "bls12_381_G1_add",
+    "bls12_381_G1_neg",
+    "bls12_381_G1_scalarMul",
+    "bls12_381_G1_equal",
+    "bls12_381_G1_compress",
+    "bls12_381_G1_uncompress",
+    "bls12_381_G1_hashToGroup",
+    "bls12_381_G2_add",
+    "bls12_381_G2_neg",
+    "bls12_381_G2_scalarMul",
+    "bls12_381_G2_equal",
+    "bls12_381_G2_compress",
+    "bls12_381_G2_uncompress",
+    "bls12_381_G2_hashToGroup",
+    "bls12_381_millerLoop",
+    "bls12_381_mulMlResult",
+    "bls12_381_finalVerify",
+    "keccak_256",
+    "blake2b_224",
+    "integerToByteString",
+    "byteStringToInteger",
+    "andByteString",
+    "orByteString",
+    "xorByteString",
+    "complementByteString",
+    "readBit",
+    "writeBits",
+    "replicateByte",
+    "shiftByteString",
+    "rotateByteString",
+    "countSetBits",
+    "findFirstSetBit",
+    "ripemd_160",
+];
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum UplcType {
+    Integer,
+    ByteString,
+    String,
+    Unit,
+    Bool,
+    List(Box<UplcType>),
+    Pair(Box<UplcType>, Box<UplcType>),
+    Data,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum UplcConstant {
+    Integer(BigInt),
+    ByteString(Vec<u8>),
+    String(String),
+    Unit,
+    Bool(bool),
+    List(UplcType, Vec<UplcConstant>),
+    Pair(Box<UplcConstant>, Box<UplcConstant>),
+    Data(PlutusData),
+}
+
+/// UPLC term with de Bruijn indices
+#[derive(Debug)]
+pub(crate) enum UplcTerm {
+    Var(u64),
+    Delay(Box<UplcTerm>),
+    Lambda(Box<UplcTerm>),
+    Apply(Box<UplcTerm>, Box<UplcTerm>),
+    Constant(UplcConstant),
+    Force(Box<UplcTerm>),
+    Error,
+    Builtin(u8),
+    Constr(u64, Vec<UplcTerm>),
+    Case(Box<UplcTerm>, Vec<UplcTerm>),
+}
+
+// Terms are decoded, encoded, printed, measured, cloned, compared and dropped with explicit stacks,
+// so their depth only costs heap memory. The limit bounds that memory without rejecting large scripts.
+pub(crate) const MAX_DEPTH: usize = 100_000;
+
+// constant types are decoded recursively, deeper ones are rejected to keep the recursion from
+// overflowing the stack
+const MAX_TYPE_DEPTH: usize = 1_000;
+
+pub(crate) fn check_depth(what: &str, depth: usize) -> Result<(), String> {
+    if depth > MAX_DEPTH {
+        return Err(format!(
+            "{} is nested deeper than {} levels",
+            what, MAX_DEPTH
+        ));
+    }
+    Ok(())
+}
+
+const TYPE_INTEGER: u8 = 0;
+const TYPE_BYTESTRING: u8 = 1;
+const TYPE_STRING: u8 = 2;
+const TYPE_UNIT: u8 = 3;
+const TYPE_BOOL: u8 = 4;
+const TYPE_LIST: u8 = 5;
+const TYPE_PAIR: u8 = 6;
+const TYPE_APPLICATION: u8 = 7;
+const TYPE_DATA: u8 = 8;
+
+impl UplcType {
+    fn decode(tags: &mut std::slice::Iter<u8>, depth: usize) -> Result<UplcType, DeserializeError> {
+        if depth > MAX_TYPE_DEPTH {
+            return Err(flat_error(format!(
+                "constant type is nested deeper than {} levels",
+                MAX_TYPE_DEPTH
+            )));
+        }
+        let tag = tags
+            .next()
+            .ok_or_else(|| flat_error("incomplete constant type"))?;
+        match *tag {
+            TYPE_INTEGER => Ok(UplcType::Integer),
+            TYPE_BYTESTRING => Ok(UplcType::ByteString),
+            TYPE_STRING => Ok(UplcType::String),
+            TYPE_UNIT => Ok(UplcType::Unit),
+            TYPE_BOOL => Ok(UplcType::Bool),
+            TYPE_DATA => Ok(UplcType::Data),
+            TYPE_APPLICATION => match tags.next() {
+                Some(&TYPE_LIST) => Ok(UplcType::List(Box::new(Self::decode(tags, depth + 1)?))),
+                Some(&TYPE_APPLICATION) => match tags.next() {
+                    Some(&TYPE_PAIR) => Ok(UplcType::Pair(
+                        Box::new(Self::decode(tags, depth + 1)?),
+                        Box::new(Self::decode(tags, depth + 1)?),
+                    )),
+                    _ => Err(flat_error("unsupported constant type application")),
+                },
+                _ => Err(flat_error("unsupported constant type application")),
+            },
+            other => Err(flat_error(format!("unsupported constant type {}", other))),
+        }
+    }
+
+    fn encode(&self, tags: &mut Vec<u8>) {
+        match self {
+            UplcType::Integer => tags.push(TYPE_INTEGER),
+            UplcType::ByteString => tags.push(TYPE_BYTESTRING),
+            UplcType::String => tags.push(TYPE_STRING),
+            UplcType::Unit => tags.push(TYPE_UNIT),
+            UplcType::Bool => tags.push(TYPE_BOOL),
+            UplcType::Data => tags.push(TYPE_DATA),
+            UplcType::List(elem) => {
+                tags.extend_from_slice(&[TYPE_APPLICATION, TYPE_LIST]);
+                elem.encode(tags);
+            }
+            UplcType::Pair(first, second) => {
+                tags.extend_from_slice(&[TYPE_APPLICATION, TYPE_APPLICATION, TYPE_PAIR]);
+                first.encode(tags);
+                second.encode(tags);
+            }
+        }
+    }
+}
+
+impl UplcConstant {
+    pub(crate) fn uplc_type(&self) -> UplcType {
+        match self {
+            UplcConstant::Integer(_) => UplcType::Integer,
+            UplcConstant::ByteString(_) => UplcType::ByteString,
+            UplcConstant::String(_) => UplcType::String,
+            UplcConstant::Unit => UplcType::Unit,
+            UplcConstant::Bool(_) => UplcType::Bool,
+            UplcConstant::List(elem, _) => UplcType::List(Box::new(elem.clone())),
+            UplcConstant::Pair(first, second) => {
+                UplcType::Pair(Box::new(first.uplc_type()), Box::new(second.uplc_type()))
+            }
+            UplcConstant::Data(_) => UplcType::Data,
+        }
+    }
+
+    fn decode(
+        decoder: &mut FlatDecoder,
+        uplc_type: &UplcType,
+    ) -> Result<UplcConstant, DeserializeError> {
+        Ok(match uplc_type {
+            UplcType::Integer => UplcConstant::Integer(BigInt(decoder.integer()?)),
+            UplcType::ByteString => UplcConstant::ByteString(decoder.bytes()?),
+            UplcType::String => UplcConstant::String(
+                String::from_utf8(decoder.bytes()?)
+                    .map_err(|_| flat_error("string constant is not valid utf8"))?,
+            ),
+            UplcType::Unit => UplcConstant::Unit,
+            UplcType::Bool => UplcConstant::Bool(decoder.bit()?),
+            UplcType::List(elem) => UplcConstant::List(
+                (**elem).clone(),
+                decoder.list(|decoder| Self::decode(decoder, elem))?,
+            ),
+            UplcType::Pair(first, second) => UplcConstant::Pair(
+                Box::new(Self::decode(decoder, first)?),
+                Box::new(Self::decode(decoder, second)?),
+            ),
+            UplcType::Data => UplcConstant::Data(
+                PlutusData::from_bytes(decoder.bytes()?)
+                    .map_err(|e| flat_error(format!("invalid data constant: {}", e)))?,
+            ),
+        })
+    }
+
+    fn encode_value(&self, encoder: &mut FlatEncoder) {
+        match self {
+            UplcConstant::Integer(int) => encoder.integer(&int.0),
+            UplcConstant::ByteString(bytes) => encoder.bytes(bytes),
+            UplcConstant::String(text) => encoder.bytes(text.as_bytes()),
+            UplcConstant::Unit => {}
+            UplcConstant::Bool(value) => encoder.bit(*value),
+            UplcConstant::List(_, items) => {
+                encoder.list(items, |encoder, item| item.encode_value(encoder))
+            }
+            UplcConstant::Pair(first, second) => {
+                first.encode_value(encoder);
+                second.encode_value(encoder);
+            }
+            UplcConstant::Data(data) => encoder.bytes(&data.to_bytes()),
+        }
+    }
+}
+
+// term being decoded that still misses some of its subterms
+enum PartialTerm {
+    Delay,
+    Lambda,
+    Force,
+    Function,
+    Argument(UplcTerm),
+    Constr(u64, Vec<UplcTerm>),
+    Scrutinee,
+    Branches(UplcTerm, Vec<UplcTerm>),
+}
+
+const TERM_VAR: u8 = 0;
+const TERM_DELAY: u8 = 1;
+const TERM_LAMBDA: u8 = 2;
+const TERM_APPLY: u8 = 3;
+const TERM_CONSTANT: u8 = 4;
+const TERM_FORCE: u8 = 5;
+const TERM_ERROR: u8 = 6;
+const TERM_BUILTIN: u8 = 7;
+const TERM_CONSTR: u8 = 8;
+const TERM_CASE: u8 = 9;
+
+impl UplcTerm {
+    // Decodes with an explicit stack of the unfinished parent terms, so that the depth of the
+    // terms only costs heap memory
+    pub(crate) fn decode(decoder: &mut FlatDecoder) -> Result<UplcTerm, DeserializeError> {
+        let mut parents = Vec::new();
+        loop {
+            let mut term = loop {
+                check_depth("term", parents.len()).map_err(flat_error)?;
+                let position = decoder.position();
+                match decoder.bits(4)? {
+                    TERM_VAR => break UplcTerm::Var(decoder.word()?),
+                    TERM_DELAY => parents.push(PartialTerm::Delay),
+                    TERM_LAMBDA => parents.push(PartialTerm::Lambda),
+                    TERM_APPLY => parents.push(PartialTerm::Function),
+                    TERM_CONSTANT => break Self::decode_constant(decoder, position)?,
+                    TERM_FORCE => parents.push(PartialTerm::Force),
+                    TERM_ERROR => break UplcTerm::Error,
+                    TERM_BUILTIN => {
+                        let builtin = decoder.bits(7)?;
+                        if builtin as usize >= BUILTIN_NAMES.len() {
+                            return Err(flat_error(format!(
+                                "bit {}: unknown builtin {}",
+                                position, builtin
+                            )));
+                        }
+                        break UplcTerm::Builtin(builtin);
+                    }
+                    TERM_CONSTR => {
+                        let tag = decoder.word()?;
+                        if !decoder.bit()? {
+                            break UplcTerm::Constr(tag, Vec::new());
+                        }
+                        parents.push(PartialTerm::Constr(tag, Vec::new()));
+                    }
+                    TERM_CASE => parents.push(PartialTerm::Scrutinee),
+                    other => {
+                        return Err(flat_error(format!(
+                            "bit {}: unknown term tag {}",
+                            position, other
+                        )))
+                    }
+                }
+            };
+            // completes the parents until one of them needs another subterm
+            loop {
+                match parents.pop() {
+                    None => return Ok(term),
+                    Some(PartialTerm::Delay) => term = UplcTerm::Delay(Box::new(term)),
+                    Some(PartialTerm::Lambda) => term = UplcTerm::Lambda(Box::new(term)),
+                    Some(PartialTerm::Force) => term = UplcTerm::Force(Box::new(term)),
+                    Some(PartialTerm::Function) => {
+                        parents.push(PartialTerm::Argument(term));
+                        break;
+                    }
+                    Some(PartialTerm::Argument(function)) => {
+                        term = UplcTerm::Apply(Box::new(function), Box::new(term))
+                    }
+                    Some(PartialTerm::Constr(tag, mut fields)) => {
+                        fields.push(term);
+                        if decoder.bit()? {
+                            parents.push(PartialTerm::Constr(tag, fields));
+                            break;
+                        }
+                        term = UplcTerm::Constr(tag, fields);
+                    }
+                    Some(PartialTerm::Scrutinee) => {
+                        if decoder.bit()? {
+                            parents.push(PartialTerm::Branches(term, Vec::new()));
+                            break;
+                        }
+                        term = UplcTerm::Case(Box::new(term), Vec::new());
+                    }
+                    Some(PartialTerm::Branches(scrutinee, mut branches)) => {
+                        branches.push(term);
+                        if decoder.bit()? {
+                            parents.push(PartialTerm::Branches(scrutinee, branches));
+                            break;
+                        }
+                        term = UplcTerm::Case(Box::new(scrutinee), branches);
+                    }
+                }
+            }
+        }
+    }
+
+    fn decode_constant(
+        decoder: &mut FlatDecoder,
+        position: usize,
+    ) -> Result<UplcTerm, DeserializeError> {
+        let tags = decoder.list(|decoder| decoder.bits(4))?;
+        let mut iter = tags.iter();
+        let uplc_type = UplcType::decode(&mut iter, 0)?;
+        if iter.next().is_some() {
+            return Err(flat_error(format!(
+                "bit {}: invalid constant type",
+                position
+            )));
+        }
+        Ok(UplcTerm::Constant(UplcConstant::decode(
+            decoder, &uplc_type,
+        )?))
+    }
+
+    // Encodes with an explicit stack of the terms left to encode
+    pub(crate) fn encode(&self, encoder: &mut FlatEncoder) {
+        let mut steps = vec![EncodeStep::Term(self)];
+        while let Some(step) = steps.pop() {
+            let term = match step {
+                EncodeStep::Term(term) => term,
+                EncodeStep::ListItem(term) => {
+                    encoder.bit(true);
+                    term
+                }
+                EncodeStep::ListEnd => {
+                    encoder.bit(false);
+                    continue;
+                }
+            };
+            // the subterms are pushed in reverse order
+            match term {
+                UplcTerm::Var(index) => {
+                    encoder.bits(4, TERM_VAR);
+                    encoder.word(*index);
+                }
+                UplcTerm::Delay(term) => {
+                    encoder.bits(4, TERM_DELAY);
+                    steps.push(EncodeStep::Term(term));
+                }
+                UplcTerm::Lambda(body) => {
+                    encoder.bits(4, TERM_LAMBDA);
+                    steps.push(EncodeStep::Term(body));
+                }
+                UplcTerm::Apply(function, argument) => {
+                    encoder.bits(4, TERM_APPLY);
+                    steps.push(EncodeStep::Term(argument));
+                    steps.push(EncodeStep::Term(function));
+                }
+                UplcTerm::Constant(constant) => {
+                    encoder.bits(4, TERM_CONSTANT);
+                    let mut tags = Vec::new();
+                    constant.uplc_type().encode(&mut tags);
+                    encoder.list(&tags, |encoder, tag| encoder.bits(4, *tag));
+                    constant.encode_value(encoder);
+                }
+                UplcTerm::Force(term) => {
+                    encoder.bits(4, TERM_FORCE);
+                    steps.push(EncodeStep::Term(term));
+                }
+                UplcTerm::Error => encoder.bits(4, TERM_ERROR),
+                UplcTerm::Builtin(builtin) => {
+                    encoder.bits(4, TERM_BUILTIN);
+                    encoder.bits(7, *builtin);
+                }
+                UplcTerm::Constr(tag, fields) => {
+                    encoder.bits(4, TERM_CONSTR);
+                    encoder.word(*tag);
+                    EncodeStep::push_list(&mut steps, fields);
+                }
+                UplcTerm::Case(scrutinee, branches) => {
+                    encoder.bits(4, TERM_CASE);
+                    EncodeStep::push_list(&mut steps, branches);
+                    steps.push(EncodeStep::Term(scrutinee));
+                }
+            }
+        }
+    }
+
+    pub(crate) fn subterms(&self) -> Vec<&UplcTerm> {
+        match self {
+            UplcTerm::Delay(inner) | UplcTerm::Force(inner) | UplcTerm::Lambda(inner) => {
+                vec![inner]
+            }
+            UplcTerm::Apply(function, argument) => vec![function, argument],
+            UplcTerm::Constr(_, fields) => fields.iter().collect(),
+            UplcTerm::Case(scrutinee, branches) => std::iter::once(scrutinee.as_ref())
+                .chain(branches)
+                .collect(),
+            UplcTerm::Var(_) | UplcTerm::Constant(_) | UplcTerm::Builtin(_) | UplcTerm::Error => {
+                Vec::new()
+            }
+        }
+    }
+
+    // Visits the terms in post-order with an explicit stack, `combine` gets every term with the
+    // results of its subterms
+    pub(crate) fn fold<T>(&self, mut combine: impl FnMut(&UplcTerm, Vec<T>) -> T) -> T {
+        enum Visit<'a> {
+            Enter(&'a UplcTerm),
+            Exit(&'a UplcTerm, usize),
+        }
+        let mut visits = vec![Visit::Enter(self)];
+        let mut results = Vec::new();
+        while let Some(visit) = visits.pop() {
+            match visit {
+                Visit::Enter(term) => {
+                    let subterms = term.subterms();
+                    visits.push(Visit::Exit(term, subterms.len()));
+                    visits.extend(subterms.into_iter().rev().map(Visit::Enter));
+                }
+                Visit::Exit(term, count) => {
+                    let subterm_results = results.split_off(results.len() - count);
+                    results.push(combine(term, subterm_results));
+                }
+            }
+        }
+        results.pop().expect("the term itself is visited last")
+    }
+
+    // copy of the term with the subterms replaced
+    fn with_subterms(&self, subterms: Vec<UplcTerm>) -> UplcTerm {
+        let mut subterms = subterms.into_iter();
+        let mut next = || Box::new(subterms.next().expect("a copy of every subterm"));
+        match self {
+            UplcTerm::Var(index) => UplcTerm::Var(*index),
+            UplcTerm::Delay(_) => UplcTerm::Delay(next()),
+            UplcTerm::Lambda(_) => UplcTerm::Lambda(next()),
+            UplcTerm::Apply(_, _) => {
+                let function = next();
+                UplcTerm::Apply(function, next())
+            }
+            UplcTerm::Constant(constant) => UplcTerm::Constant(constant.clone()),
+            UplcTerm::Force(_) => UplcTerm::Force(next()),
+            UplcTerm::Error => UplcTerm::Error,
+            UplcTerm::Builtin(builtin) => UplcTerm::Builtin(*builtin),
+            UplcTerm::Constr(tag, _) => UplcTerm::Constr(*tag, subterms.collect()),
+            UplcTerm::Case(_, _) => {
+                let scrutinee = next();
+                UplcTerm::Case(scrutinee, subterms.collect())
+            }
+        }
+    }
+
+    // moves the subterms with subterms of their own out of the term
+    fn take_subterms(&mut self, into: &mut Vec<UplcTerm>) {
+        let mut take = |term: &mut UplcTerm| {
+            let leaf = match term {
+                UplcTerm::Constr(_, fields) => fields.is_empty(),
+                UplcTerm::Delay(_)
+                | UplcTerm::Lambda(_)
+                | UplcTerm::Apply(_, _)
+                | UplcTerm::Force(_)
+                | UplcTerm::Case(_, _) => false,
+                _ => true,
+            };
+            if !leaf {
+                into.push(std::mem::replace(term, UplcTerm::Error));
+            }
+        };
+        match self {
+            UplcTerm::Delay(inner) | UplcTerm::Force(inner) | UplcTerm::Lambda(inner) => {
+                take(inner)
+            }
+            UplcTerm::Apply(function, argument) => {
+                take(function);
+                take(argument);
+            }
+            UplcTerm::Constr(_, fields) => fields.iter_mut().for_each(take),
+            UplcTerm::Case(scrutinee, branches) => {
+                take(scrutinee);
+                branches.iter_mut().for_each(take);
+            }
+            UplcTerm::Var(_) | UplcTerm::Constant(_) | UplcTerm::Builtin(_) | UplcTerm::Error => {}
+        }
+    }
+}
+
+// step of the encoding, the items of the lists of terms are preceded by a 1 bit and the lists end
+// with a 0 bit
+enum EncodeStep<'a> {
+    Term(&'a UplcTerm),
+    ListItem(&'a UplcTerm),
+    ListEnd,
+}
+
+impl<'a> EncodeStep<'a> {
+    fn push_list(steps: &mut Vec<EncodeStep<'a>>, terms: &'a [UplcTerm]) {
+        steps.push(EncodeStep::ListEnd);
+        steps.extend(terms.iter().rev().map(EncodeStep::ListItem));
+    }
+}
+
+impl Clone for UplcTerm {
+    fn clone(&self) -> Self {
+        self.fold(|term, subterms| term.with_subterms(subterms))
+    }
+}
+
+impl PartialEq for UplcTerm {
+    fn eq(&self, other: &Self) -> bool {
+        let mut pairs = vec![(self, other)];
+        while let Some((left, right)) = pairs.pop() {
+            let same_term = match (left, right) {
+                (UplcTerm::Var(left), UplcTerm::Var(right)) => left == right,
+                (UplcTerm::Constant(left), UplcTerm::Constant(right)) => left == right,
+                (UplcTerm::Builtin(left), UplcTerm::Builtin(right)) => left == right,
+                (UplcTerm::Constr(left, _), UplcTerm::Constr(right, _)) => left == right,
+                _ => std::mem::discriminant(left) == std::mem::discriminant(right),
+            };
+            let (left_subterms, right_subterms) = (left.subterms(), right.subterms());
+            if !same_term || left_subterms.len() != right_subterms.len() {
+                return false;
+            }
+            pairs.extend(left_subterms.into_iter().zip(right_subterms));
+        }
+        true
+    }
+}
+
+impl Eq for UplcTerm {}
+
+impl Drop for UplcTerm {
+    fn drop(&mut self) {
+        let mut terms = Vec::new();
+        self.take_subterms(&mut terms);
+        while let Some(mut term) = terms.pop() {
+            term.take_subterms(&mut terms);
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use super::flat::{flat_error, FlatDecoder, FlatEncoder};
+use super::pretty::print_program;
+use super::term::{UplcConstant, UplcTerm};
+use crate::*;
+use std::io::Cursor;
+
+/// Untyped Plutus Core program decoded from its flat encoding
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UplcProgram {
+    pub(crate) version: (u64, u64, u64),
+    pub(crate) term: UplcTerm,
+}
+
+#[wasm_bindgen]
+impl UplcProgram {
+    /// Decodes a program from the flat bytes without any CBOR wrapping
+    pub fn from_flat_bytes(bytes: Vec<u8>) -> Result<UplcProgram, JsError> {
+        Self::decode_flat(&bytes).map_err(|e| JsError::from_str(&format!("Invalid UPLC program: {}", e)))
+    }
+
+    pub fn to_flat_bytes(&self) -> Vec<u8> {
+        let mut encoder = FlatEncoder::new();
+        encoder.word(self.version.0);
+        encoder.word(self.version.1);
+        encoder.word(self.version.2);
+        self.term.encode(&mut encoder);
+        encoder.finish()
+    }
+
+    /// Decodes the program of a script. Scripts with their bytes wrapped into CBOR more than once
+    /// (e.g. "cborHex" of cardano-cli passed to `PlutusScript::new`) are accepted too.
+    pub fn from_plutus_script(script: &PlutusScript) -> Result<UplcProgram, JsError> {
+        Self::from_flat_bytes(unwrap_cbor_bytes(&script.bytes))
+    }
+
+    /// Returns a script with the flat program wrapped into CBOR once, as it is stored on chain
+    pub fn to_plutus_script(&self, language: &Language) -> PlutusScript {
+        let mut serializer = Serializer::new_vec();
+        // writing into a vec can't fail
+        serializer.write_bytes(self.to_flat_bytes()).unwrap();
+        PlutusScript::new_with_version(serializer.finalize(), language)
+    }
+
+    /// Version of the program, e.g. "1.0.0" for Plutus V1/V2 and "1.1.0" for Plutus V3
+    pub fn version(&self) -> String {
+        format!("{}.{}.{}", self.version.0, self.version.1, self.version.2)
+    }
+
+    /// Prints the program in the textual UPLC syntax, variables are named `i_<depth of the binder>`
+    pub fn to_pretty_string(&self) -> Result<String, JsError> {
+        print_program(&self.version, &self.term).map_err(|e| JsError::from_str(&e))
+    }
+
+    pub fn stats(&self) -> Result<UplcProgramStats, JsError> {
+        UplcProgramStats::new(self).map_err(|e| JsError::from_str(&e))
+    }
+
+    /// Applies the program to a data argument
+    pub fn apply_data(&self, data: &PlutusData) -> UplcProgram {
+        Self {
+            version: self.version,
+            term: UplcTerm::Apply(
+                Box::new(self.term.clone()),
+                Box::new(UplcTerm::Constant(UplcConstant::Data(data.clone()))),
+            ),
+        }
+    }
+}
+
+impl UplcProgram {
+    fn decode_flat(bytes: &[u8]) -> Result<UplcProgram, DeserializeError> {
+        let mut decoder = FlatDecoder::new(bytes);
+        let version = (decoder.word()?, decoder.word()?, decoder.word()?);
+        let term = UplcTerm::decode(&mut decoder)?;
+        decoder.filler()?;
+        if !decoder.is_finished() {
+            return Err(flat_error("unexpected bytes after the program"));
+        }
+        Ok(Self { version, term })
+    }
+}
+
+// flat programs start with the major version 1, so a CBOR byte string header means another wrapping
+fn unwrap_cbor_bytes(bytes: &[u8]) -> Vec<u8> {
+    let mut bytes = bytes.to_vec();
+    while let Some(0x40..=0x5f) = bytes.first() {
+        let mut raw = Deserializer::from(Cursor::new(&bytes));
+        match raw.bytes() {
+            Ok(inner) if raw.as_mut_ref().position() as usize == bytes.len() => bytes = inner,
+            _ => break,
+        }
+    }
+    bytes
+}
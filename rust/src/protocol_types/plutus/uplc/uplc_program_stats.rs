@@ -0,0 +1,193 @@
+use super::term::{check_depth, UplcConstant, UplcTerm, BUILTIN_NAMES};
+use crate::*;
+use std::collections::BTreeMap;
+
+// builtins added with Plutus V2 (Vasil) and V3 (Chang and later), by flat tag
+const FIRST_V2_BUILTIN: u8 = 51;
+const FIRST_V3_BUILTIN: u8 = 54;
+
+/// Kinds of the UPLC terms
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum UplcTermKind {
+    Var,
+    Delay,
+    Lambda,
+    Apply,
+    Constant,
+    Force,
+    Error,
+    Builtin,
+    Constr,
+    Case,
+}
+
+/// Size statistics of a UPLC program.
+/// Sizes of terms are counted in terms (AST nodes), like the program size of the Plutus tools.
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UplcProgramStats {
+    flat_size: usize,
+    size: u64,
+    depth: u64,
+    term_counts: BTreeMap<UplcTermKind, u64>,
+    constants_size: u64,
+    builtin_sizes: BTreeMap<u8, u64>,
+    uses_sums_of_products: bool,
+    version: (u64, u64, u64),
+}
+
+#[wasm_bindgen]
+impl UplcProgramStats {
+    /// Size of the flat encoded program in bytes
+    pub fn flat_size(&self) -> usize {
+        self.flat_size
+    }
+
+    /// Number of terms of the program
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Nesting depth of the terms, 1 for a program made of a single term
+    pub fn depth(&self) -> u64 {
+        self.depth
+    }
+
+    pub fn term_count(&self, kind: UplcTermKind) -> u64 {
+        self.term_counts.get(&kind).cloned().unwrap_or(0)
+    }
+
+    /// Total size in bytes of the bytestring, string and data constants
+    pub fn constants_size(&self) -> u64 {
+        self.constants_size
+    }
+
+    /// Names of the builtins used by the program
+    pub fn builtin_names(&self) -> Strings {
+        Strings(
+            self.builtin_sizes
+                .keys()
+                .map(|tag| BUILTIN_NAMES[*tag as usize].to_string())
+                .collect(),
+        )
+    }
+
+    /// Size of the calls of a builtin, e.g. "verifyEd25519Signature": the builtin terms
+    /// with the forces and applications on them and their arguments.
+    /// Calls in the arguments of another builtin count for both builtins.
+    pub fn builtin_size(&self, name: &str) -> u64 {
+        BUILTIN_NAMES
+            .iter()
+            .position(|builtin| *builtin == name)
+            .and_then(|tag| self.builtin_sizes.get(&(tag as u8)))
+            .cloned()
+            .unwrap_or(0)
+    }
+
+    /// Whether the program uses `constr` or `case` terms from Plutus V3
+    pub fn uses_sums_of_products(&self) -> bool {
+        self.uses_sums_of_products
+    }
+
+    /// The oldest Plutus version supporting the program version, terms and builtins used.
+    /// Builtins are only available in the language versions that introduced them.
+    pub fn minimum_language(&self) -> Language {
+        let max_builtin = self.builtin_sizes.keys().next_back().cloned().unwrap_or(0);
+        // programs 1.1.0 and newer are only accepted by Plutus V3
+        if self.version >= (1, 1, 0)
+            || self.uses_sums_of_products
+            || max_builtin >= FIRST_V3_BUILTIN
+        {
+            Language::new_plutus_v3()
+        } else if max_builtin >= FIRST_V2_BUILTIN {
+            Language::new_plutus_v2()
+        } else {
+            Language::new_plutus_v1()
+        }
+    }
+}
+
+// size of a visited term and the builtin it calls, if any
+struct TermStats {
+    size: u64,
+    depth: u64,
+    builtin: Option<u8>,
+}
+
+impl UplcProgramStats {
+    pub(crate) fn new(program: &UplcProgram) -> Result<Self, String> {
+        let mut stats = Self {
+            flat_size: program.to_flat_bytes().len(),
+            size: 0,
+            depth: 0,
+            term_counts: BTreeMap::new(),
+            constants_size: 0,
+            builtin_sizes: BTreeMap::new(),
+            uses_sums_of_products: false,
+            version: program.version,
+        };
+        let term = program
+            .term
+            .fold(|term, subterms| stats.record(term, &subterms));
+        // terms deeper than the decoder accepts are rejected, like when printing
+        check_depth("term", term.depth as usize - 1)?;
+        stats.size = term.size;
+        stats.depth = term.depth;
+        Ok(stats)
+    }
+
+    fn record(&mut self, term: &UplcTerm, subterms: &[TermStats]) -> TermStats {
+        *self.term_counts.entry(term_kind(term)).or_insert(0) += 1;
+        match term {
+            UplcTerm::Constant(constant) => self.constants_size += constant_size(constant),
+            UplcTerm::Constr(_, _) | UplcTerm::Case(_, _) => self.uses_sums_of_products = true,
+            _ => {}
+        }
+        // the calls of a builtin grow with the forces and applications on the builtin
+        let (builtin, call_size) = match term {
+            UplcTerm::Builtin(builtin) => (Some(*builtin), 1),
+            UplcTerm::Force(_) => (subterms[0].builtin, 1),
+            UplcTerm::Apply(_, _) => (subterms[0].builtin, 1 + subterms[1].size),
+            _ => (None, 0),
+        };
+        if let Some(builtin) = builtin {
+            *self.builtin_sizes.entry(builtin).or_insert(0) += call_size;
+        }
+        TermStats {
+            size: 1 + subterms.iter().map(|subterm| subterm.size).sum::<u64>(),
+            depth: 1 + subterms
+                .iter()
+                .map(|subterm| subterm.depth)
+                .max()
+                .unwrap_or(0),
+            builtin,
+        }
+    }
+}
+
+fn term_kind(term: &UplcTerm) -> UplcTermKind {
+    match term {
+        UplcTerm::Var(_) => UplcTermKind::Var,
+        UplcTerm::Delay(_) => UplcTermKind::Delay,
+        UplcTerm::Lambda(_) => UplcTermKind::Lambda,
+        UplcTerm::Apply(_, _) => UplcTermKind::Apply,
+        UplcTerm::Constant(_) => UplcTermKind::Constant,
+        UplcTerm::Force(_) => UplcTermKind::Force,
+        UplcTerm::Error => UplcTermKind::Error,
+        UplcTerm::Builtin(_) => UplcTermKind::Builtin,
+        UplcTerm::Constr(_, _) => UplcTermKind::Constr,
+        UplcTerm::Case(_, _) => UplcTermKind::Case,
+    }
+}
+
+fn constant_size(constant: &UplcConstant) -> u64 {
+    match constant {
+        UplcConstant::ByteString(bytes) => bytes.len() as u64,
+        UplcConstant::String(text) => text.len() as u64,
+        UplcConstant::Data(data) => data.to_bytes().len() as u64,
+        UplcConstant::List(_, items) => items.iter().map(constant_size).sum(),
+        UplcConstant::Pair(first, second) => constant_size(first) + constant_size(second),
+        UplcConstant::Integer(_) | UplcConstant::Unit | UplcConstant::Bool(_) => 0,
+    }
+}
@@ -0,0 +1,100 @@
+use crate::*;
+
+pub(crate) const CIP20_METADATA_LABEL: u64 = 674;
+pub(crate) const MESSAGE_KEY: &str = "msg";
+pub(crate) const ENCRYPTION_KEY: &str = "enc";
+
+/// Transaction message from CIP-20, stored under the label 674 of the transaction metadata.
+/// Every line is a string of at most 64 bytes, longer lines are split when added.
+#[wasm_bindgen]
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Cip20Message(pub(crate) Vec<String>);
+
+#[wasm_bindgen]
+impl Cip20Message {
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Creates a message from a text, every text line becomes one or more message lines
+    pub fn from_text(text: &str) -> Self {
+        let mut message = Self::new();
+        for line in text.lines() {
+            message.add_line(line);
+        }
+        message
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn get(&self, index: usize) -> String {
+        self.0[index].clone()
+    }
+
+    /// Adds a line, splitting it into 64 byte chunks if it's too long
+    pub fn add_line(&mut self, line: &str) {
+        self.0.extend(split_metadata_text(line));
+    }
+
+    /// Returns the message lines joined with a line break
+    pub fn text(&self) -> String {
+        self.0.join("\n")
+    }
+
+    pub fn to_metadatum(&self) -> Result<TransactionMetadatum, JsError> {
+        let mut lines = MetadataList::new();
+        for line in &self.0 {
+            lines.add(&TransactionMetadatum::new_text(line.clone())?);
+        }
+        let mut map = MetadataMap::new();
+        map.insert_str(MESSAGE_KEY, &TransactionMetadatum::new_list(&lines))?;
+        Ok(TransactionMetadatum::new_map(&map))
+    }
+
+    pub fn from_metadatum(metadatum: &TransactionMetadatum) -> Result<Cip20Message, JsError> {
+        let map = metadatum
+            .as_map()
+            .map_err(|_| JsError::from_str("CIP-20 message must be a map"))?;
+        if map.get_str(ENCRYPTION_KEY).is_ok() {
+            return Err(JsError::from_str(
+                "CIP-20 message is encrypted, use Cip83EncryptedMessage to read it",
+            ));
+        }
+        Ok(Self(parse_message_lines(&map)?))
+    }
+
+    /// Sets the label 674 of the auxiliary data, replacing any previous message
+    pub fn add_to_auxiliary_data(&self, auxiliary_data: &mut AuxiliaryData) -> Result<(), JsError> {
+        auxiliary_data.set_label_metadatum(CIP20_METADATA_LABEL, &self.to_metadatum()?);
+        Ok(())
+    }
+
+    /// Returns None when the auxiliary data has no label 674
+    pub fn from_auxiliary_data(
+        auxiliary_data: &AuxiliaryData,
+    ) -> Result<Option<Cip20Message>, JsError> {
+        auxiliary_data
+            .label_metadatum(CIP20_METADATA_LABEL)
+            .map(|metadatum| Self::from_metadatum(&metadatum))
+            .transpose()
+    }
+}
+
+pub(crate) fn parse_message_lines(map: &MetadataMap) -> Result<Vec<String>, JsError> {
+    let lines = map
+        .get_str(MESSAGE_KEY)
+        .map_err(|_| JsError::from_str("Missing \"msg\""))?
+        .as_list()
+        .map_err(|_| JsError::from_str("\"msg\" must be a list of strings"))?;
+    lines
+        .0
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            line.as_text()
+                .map_err(|_| JsError::from_str(&format!("Line {} of \"msg\" must be a string", i)))
+        })
+        .collect()
+}
@@ -0,0 +1,146 @@
+use crate::chain_crypto::aes;
+use crate::*;
+use cryptoxide::hmac::Hmac;
+use cryptoxide::pbkdf2::pbkdf2;
+use cryptoxide::sha2::Sha256;
+use rand::Rng;
+
+const BASIC_ENCRYPTION: &str = "basic";
+// CIP-83 uses this passphrase when the sender doesn't set one
+const DEFAULT_PASSPHRASE: &str = "cardano";
+// the header of the salted format of `openssl enc`
+const SALTED_MAGIC: &[u8; 8] = b"Salted__";
+const SALT_SIZE: usize = 8;
+const PBKDF2_ITERATIONS: u32 = 10_000;
+
+/// Encrypted transaction message from CIP-83 with the "basic" encryption, stored under the label 674.
+/// The JSON array of the message lines is encrypted like
+/// `openssl enc -aes-256-cbc -pbkdf2 -iter 10000 -md sha256` does,
+/// base64 encoded and split into 64 byte chunks.
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Cip83EncryptedMessage {
+    encrypted: Vec<u8>,
+}
+
+#[wasm_bindgen]
+impl Cip83EncryptedMessage {
+    /// Encrypts the message with a random salt.
+    /// The default CIP-83 passphrase "cardano" is used when no passphrase is passed.
+    pub fn encrypt(
+        message: &Cip20Message,
+        passphrase: Option<String>,
+    ) -> Result<Cip83EncryptedMessage, JsError> {
+        let mut salt = [0u8; SALT_SIZE];
+        rand::thread_rng().fill(&mut salt);
+        Self::encrypt_with_salt(message, passphrase, &salt)
+    }
+
+    pub fn decrypt(&self, passphrase: Option<String>) -> Result<Cip20Message, JsError> {
+        if self.encrypted.len() < SALTED_MAGIC.len() + SALT_SIZE
+            || !self.encrypted.starts_with(SALTED_MAGIC)
+        {
+            return Err(JsError::from_str(
+                "Encrypted message doesn't start with the \"Salted__\" header",
+            ));
+        }
+        let (salt, ciphertext) = self.encrypted[SALTED_MAGIC.len()..].split_at(SALT_SIZE);
+        let (key, iv) = derive_key_and_iv(passphrase, salt);
+        let decrypted = aes::decrypt_cbc(&key, &iv, ciphertext)
+            .ok_or_else(|| JsError::from_str("Decryption failed, wrong passphrase?"))?;
+        let lines: Vec<String> = serde_json::from_slice(&decrypted)
+            .map_err(|_| JsError::from_str("Decrypted message is not a JSON array of strings"))?;
+        let mut message = Cip20Message::new();
+        for line in lines {
+            message.add_line(&line);
+        }
+        Ok(message)
+    }
+
+    /// Encrypted payload: the "Salted__" header, the salt and the AES-256-CBC ciphertext
+    pub fn encrypted_bytes(&self) -> Vec<u8> {
+        self.encrypted.clone()
+    }
+
+    pub fn to_metadatum(&self) -> Result<TransactionMetadatum, JsError> {
+        let mut lines = MetadataList::new();
+        for chunk in split_metadata_text(&base64_encode(&self.encrypted)) {
+            lines.add(&TransactionMetadatum::new_text(chunk)?);
+        }
+        let mut map = MetadataMap::new();
+        map.insert_str(
+            ENCRYPTION_KEY,
+            &TransactionMetadatum::new_text(BASIC_ENCRYPTION.to_string())?,
+        )?;
+        map.insert_str(MESSAGE_KEY, &TransactionMetadatum::new_list(&lines))?;
+        Ok(TransactionMetadatum::new_map(&map))
+    }
+
+    pub fn from_metadatum(metadatum: &TransactionMetadatum) -> Result<Cip83EncryptedMessage, JsError> {
+        let map = metadatum
+            .as_map()
+            .map_err(|_| JsError::from_str("CIP-83 message must be a map"))?;
+        let enc = map
+            .get_str(ENCRYPTION_KEY)
+            .map_err(|_| JsError::from_str("CIP-20 message is not encrypted"))?
+            .as_text()
+            .map_err(|_| JsError::from_str("\"enc\" must be a string"))?;
+        if enc != BASIC_ENCRYPTION {
+            return Err(JsError::from_str(&format!(
+                "Unsupported CIP-83 encryption \"{}\"",
+                enc
+            )));
+        }
+        let encrypted = base64_decode(&parse_message_lines(&map)?.concat())
+            .ok_or_else(|| JsError::from_str("\"msg\" is not valid base64"))?;
+        Ok(Self { encrypted })
+    }
+
+    /// Sets the label 674 of the auxiliary data, replacing any previous message
+    pub fn add_to_auxiliary_data(&self, auxiliary_data: &mut AuxiliaryData) -> Result<(), JsError> {
+        auxiliary_data.set_label_metadatum(CIP20_METADATA_LABEL, &self.to_metadatum()?);
+        Ok(())
+    }
+
+    /// Returns None when the auxiliary data has no label 674
+    pub fn from_auxiliary_data(
+        auxiliary_data: &AuxiliaryData,
+    ) -> Result<Option<Cip83EncryptedMessage>, JsError> {
+        auxiliary_data
+            .label_metadatum(CIP20_METADATA_LABEL)
+            .map(|metadatum| Self::from_metadatum(&metadatum))
+            .transpose()
+    }
+}
+
+impl Cip83EncryptedMessage {
+    pub(crate) fn encrypt_with_salt(
+        message: &Cip20Message,
+        passphrase: Option<String>,
+        salt: &[u8; SALT_SIZE],
+    ) -> Result<Cip83EncryptedMessage, JsError> {
+        let data =
+            serde_json::to_vec(&message.0).map_err(|e| JsError::from_str(&e.to_string()))?;
+        let (key, iv) = derive_key_and_iv(passphrase, salt);
+        let mut encrypted = SALTED_MAGIC.to_vec();
+        encrypted.extend_from_slice(salt);
+        encrypted.extend(aes::encrypt_cbc(&key, &iv, &data));
+        Ok(Self { encrypted })
+    }
+}
+
+// `openssl enc -pbkdf2` derives the key and the iv together
+fn derive_key_and_iv(
+    passphrase: Option<String>,
+    salt: &[u8],
+) -> ([u8; aes::KEY_SIZE], [u8; aes::IV_SIZE]) {
+    let passphrase = passphrase.unwrap_or_else(|| DEFAULT_PASSPHRASE.to_string());
+    let mut derived = [0u8; aes::KEY_SIZE + aes::IV_SIZE];
+    let mut mac = Hmac::new(Sha256::new(), passphrase.as_bytes());
+    pbkdf2(&mut mac, salt, PBKDF2_ITERATIONS, &mut derived);
+    let mut key = [0u8; aes::KEY_SIZE];
+    let mut iv = [0u8; aes::IV_SIZE];
+    key.copy_from_slice(&derived[..aes::KEY_SIZE]);
+    iv.copy_from_slice(&derived[aes::KEY_SIZE..]);
+    (key, iv)
+}
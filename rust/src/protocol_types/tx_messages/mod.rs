@@ -0,0 +1,5 @@
+mod cip20_message;
+pub use cip20_message::*;
+
+mod cip83_encrypted_message;
+pub use cip83_encrypted_message::*;
@@ -6,43 +6,64 @@ impl Deserialize for FixedBlock {
         let len = raw.array()?;
         let mut read_len = CBORReadLen::new(len);
         read_len.read_elems(4)?;
-        let (header, header_bytes) =
+        let header = (|| -> Result<_, DeserializeError> { Ok(FixedHeader::deserialize(raw)?) })()
+            .map_err(|e| e.annotate("header"))?;
+        let (transaction_bodies, transaction_bodies_bytes) =
             deserilized_with_orig_bytes(raw, |raw| -> Result<_, DeserializeError> {
-                Ok(Header::deserialize(raw)?)
+                Ok(FixedTransactionBodies::deserialize(raw)?)
             })
-            .map_err(|e| e.annotate("header"))?;
-        let transaction_bodies =
-            (|| -> Result<_, DeserializeError> { Ok(FixedTransactionBodies::deserialize(raw)?) })()
-                .map_err(|e| e.annotate("fixed_transaction_bodies"))?;
-        let transaction_witness_sets =
-            (|| -> Result<_, DeserializeError> { Ok(TransactionWitnessSets::deserialize(raw)?) })()
-                .map_err(|e| e.annotate("transaction_witness_sets"))?;
-        let auxiliary_data_set =
-            (|| -> Result<_, DeserializeError> { Ok(AuxiliaryDataSet::deserialize(raw)?) })()
-                .map_err(|e| e.annotate("auxiliary_data_set"))?;
+            .map_err(|e| e.annotate("fixed_transaction_bodies"))?;
+        let (transaction_witness_sets, transaction_witness_sets_bytes) =
+            deserilized_with_orig_bytes(raw, |raw| -> Result<_, DeserializeError> {
+                Ok(TransactionWitnessSets::deserialize(raw)?)
+            })
+            .map_err(|e| e.annotate("transaction_witness_sets"))?;
+        let (auxiliary_data_set, auxiliary_data_set_bytes) =
+            deserilized_with_orig_bytes(raw, |raw| -> Result<_, DeserializeError> {
+                Ok(AuxiliaryDataSet::deserialize(raw)?)
+            })
+            .map_err(|e| e.annotate("auxiliary_data_set"))?;
         let invalid_present = match len {
             Len::Indefinite => raw.cbor_type()? == CBORType::Array,
             Len::Len(4) => false,
             _ => true,
         };
-        let invalid_transactions = (|| -> Result<_, DeserializeError> {
-            let mut arr = Vec::new();
-            if invalid_present {
-                read_len.read_elems(1)?;
-                let len = raw.array()?;
-                while match len {
-                    Len::Len(n) => arr.len() < n as usize,
-                    Len::Indefinite => true,
-                } {
-                    if is_break_tag(raw, "invalid_transactions")? {
-                        break;
+        // the body hash is the hash of the hashes of the original bytes of each segment,
+        // the invalid transactions segment being there since Alonzo
+        let mut segment_hashes = Vec::new();
+        let mut body_size = 0;
+        for segment in [
+            &transaction_bodies_bytes,
+            &transaction_witness_sets_bytes,
+            &auxiliary_data_set_bytes,
+        ] {
+            segment_hashes.extend_from_slice(&blake2b256(segment));
+            body_size += segment.len() as u64;
+        }
+        let invalid_transactions = if invalid_present {
+            read_len.read_elems(1)?;
+            let (invalid_transactions, invalid_transactions_bytes) =
+                deserilized_with_orig_bytes(raw, |raw| -> Result<_, DeserializeError> {
+                    let mut arr = Vec::new();
+                    let len = raw.array()?;
+                    while match len {
+                        Len::Len(n) => arr.len() < n as usize,
+                        Len::Indefinite => true,
+                    } {
+                        if is_break_tag(raw, "invalid_transactions")? {
+                            break;
+                        }
+                        arr.push(TransactionIndex::deserialize(raw)?);
                     }
-                    arr.push(TransactionIndex::deserialize(raw)?);
-                }
-            }
-            Ok(arr)
-        })()
-        .map_err(|e| e.annotate("invalid_transactions"))?;
+                    Ok(arr)
+                })
+                .map_err(|e| e.annotate("invalid_transactions"))?;
+            segment_hashes.extend_from_slice(&blake2b256(&invalid_transactions_bytes));
+            body_size += invalid_transactions_bytes.len() as u64;
+            invalid_transactions
+        } else {
+            Vec::new()
+        };
         match len {
             Len::Len(_) => (),
             Len::Indefinite => match raw.special()? {
@@ -50,14 +71,14 @@ impl Deserialize for FixedBlock {
                 _ => return Err(DeserializeFailure::EndingBreakMissing.into()),
             },
         }
-        let block_hash = BlockHash(blake2b256(header_bytes.as_ref()));
         Ok(FixedBlock {
             header,
             transaction_bodies,
             transaction_witness_sets,
             auxiliary_data_set,
             invalid_transactions,
-            block_hash,
+            body_hash: BlockHash(blake2b256(&segment_hashes)),
+            body_size,
         })
     }
 }
@@ -0,0 +1,41 @@
+use crate::serialization::utils::deserilized_with_orig_bytes;
+use crate::*;
+
+impl Deserialize for FixedHeader {
+    fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        let (header, header_bytes) =
+            deserilized_with_orig_bytes(raw, |raw| -> Result<_, DeserializeError> {
+                let len = raw.array()?;
+                let mut read_len = CBORReadLen::new(len);
+                read_len.read_elems(2)?;
+                let (header_body, header_body_bytes) =
+                    deserilized_with_orig_bytes(raw, |raw| -> Result<_, DeserializeError> {
+                        HeaderBody::deserialize(raw)
+                    })
+                    .map_err(|e| e.annotate("header_body"))?;
+                let body_signature =
+                    KESSignature::deserialize(raw).map_err(|e| e.annotate("body_signature"))?;
+                match len {
+                    Len::Len(_) => (),
+                    Len::Indefinite => match raw.special()? {
+                        CBORSpecial::Break => (),
+                        _ => return Err(DeserializeFailure::EndingBreakMissing.into()),
+                    },
+                }
+                Ok((
+                    Header {
+                        header_body,
+                        body_signature,
+                    },
+                    header_body_bytes,
+                ))
+            })
+            .map_err(|e| e.annotate("FixedHeader"))?;
+        let (header, header_body_bytes) = header;
+        Ok(FixedHeader {
+            header,
+            header_body_bytes,
+            block_hash: BlockHash(blake2b256(&header_bytes)),
+        })
+    }
+}
@@ -4,6 +4,7 @@ mod operational_cert;
 mod header_body;
 mod transaction_bodies;
 mod fixed_block;
+mod fixed_header;
 mod fixed_transaction_body;
 mod fixed_transaction_bodies;
 mod versioned_block;
@@ -0,0 +1,291 @@
+use crate::*;
+use std::collections::HashSet;
+
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd, serde::Serialize)]
+pub enum CborMajorType {
+    UnsignedInteger = 0,
+    NegativeInteger = 1,
+    Bytes = 2,
+    Text = 3,
+    Array = 4,
+    Map = 5,
+    Tag = 6,
+    Simple = 7,
+}
+
+/// A CBOR item with its position in the explored bytes.
+/// Map values keep their key in `key()`, so the children of a map are its values.
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize)]
+pub struct CborNode {
+    pub(crate) offset: usize,
+    pub(crate) length: usize,
+    #[serde(skip)]
+    pub(crate) head_length: usize,
+    pub(crate) major_type: CborMajorType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) key: Option<Box<CborNode>>,
+    // the argument of the head: value of integers, length of strings/arrays/maps and tag number
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) argument: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) value: Option<String>,
+    pub(crate) indefinite: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) error: Option<String>,
+    // set on a root read completely, whose error is the bytes left after it
+    #[serde(skip)]
+    pub(crate) trailing: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) children: Vec<CborNode>,
+}
+
+#[wasm_bindgen]
+impl CborNode {
+    /// Offset of the first byte of the item
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Size of the whole item in bytes, including its children
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    pub fn major_type(&self) -> CborMajorType {
+        self.major_type
+    }
+
+    /// Field name in the ledger CDDL, e.g. "inputs" or "collateral_return"
+    pub fn name(&self) -> Option<String> {
+        self.name.clone()
+    }
+
+    pub fn key(&self) -> Option<CborNode> {
+        self.key.as_ref().map(|key| key.as_ref().clone())
+    }
+
+    /// Value of integers, length of definite strings, arrays and maps, or the tag number
+    pub fn argument(&self) -> Option<u64> {
+        self.argument
+    }
+
+    /// Diagnostic notation of integers, strings and simple values
+    pub fn value(&self) -> Option<String> {
+        self.value.clone()
+    }
+
+    pub fn is_indefinite(&self) -> bool {
+        self.indefinite
+    }
+
+    /// Why the bytes couldn't be explored further at this item
+    pub fn error(&self) -> Option<String> {
+        self.error.clone()
+    }
+
+    pub fn children(&self) -> CborNodes {
+        CborNodes(self.children.clone())
+    }
+
+    /// First node named `name` in depth-first order, including this node
+    pub fn find(&self, name: &str) -> Option<CborNode> {
+        if self.name.as_deref() == Some(name) {
+            return Some(self.clone());
+        }
+        self.children.iter().find_map(|child| child.find(name))
+    }
+
+    pub fn to_json(&self) -> Result<String, JsError> {
+        serde_json::to_string_pretty(&self)
+            .map_err(|e| JsError::from_str(&format!("to_json: {}", e)))
+    }
+
+    /// One line per item with its offset, a description and the field name, e.g.
+    /// `     1   map(4) # transaction_body`
+    pub fn to_annotated_string(&self) -> String {
+        let mut out = String::new();
+        self.annotate(0, &mut out);
+        out
+    }
+}
+
+impl CborNode {
+    /// Paths of the children: `.name` for map values and array records, `[index]` or `[key]` otherwise
+    pub(crate) fn child_paths(&self, path: &str) -> Vec<String> {
+        let names: Vec<Option<&str>> = self.children.iter().map(|child| child.name.as_deref()).collect();
+        let is_record = names.len() >= 2
+            && names.iter().all(|name| name.is_some())
+            && names.iter().collect::<HashSet<_>>().len() == names.len();
+        self.children
+            .iter()
+            .enumerate()
+            .map(|(index, child)| match (self.major_type, child.name.as_deref()) {
+                (CborMajorType::Tag, _) => path.to_string(),
+                (CborMajorType::Map, Some(name)) => format!("{}.{}", path, name),
+                (CborMajorType::Map, None) => format!(
+                    "{}[{}]",
+                    path,
+                    child.key.as_ref().and_then(|key| key.value.clone()).unwrap_or_default()
+                ),
+                (_, Some(name)) if is_record => format!("{}.{}", path, name),
+                _ => format!("{}[{}]", path, index),
+            })
+            .collect()
+    }
+
+    /// Description of the argument when the head isn't written in its shortest form
+    pub(crate) fn non_canonical_head(&self) -> Option<String> {
+        let argument = match (self.argument, self.major_type) {
+            (_, CborMajorType::Simple) => return None,
+            (Some(argument), _) if !self.indefinite => argument,
+            _ => return None,
+        };
+        let shortest = match argument {
+            0..=23 => 1,
+            24..=0xff => 2,
+            0x100..=0xffff => 3,
+            0x1_0000..=0xffff_ffff => 5,
+            _ => 9,
+        };
+        if self.head_length <= shortest {
+            return None;
+        }
+        let what = match self.major_type {
+            CborMajorType::UnsignedInteger => format!("integer {}", argument),
+            CborMajorType::NegativeInteger => format!("integer -{}", argument as u128 + 1),
+            CborMajorType::Tag => format!("tag {}", argument),
+            _ => format!("length {}", argument),
+        };
+        Some(format!("{} is encoded in {} bytes instead of {}", what, self.head_length, shortest))
+    }
+
+    /// The item in canonical CBOR: shortest heads, definite lengths, joined string chunks and sorted map keys.
+    /// `bytes` are the explored bytes, so equal items have equal canonical bytes however they are written.
+    pub(crate) fn canonical_bytes(&self, bytes: &[u8]) -> Vec<u8> {
+        let major = self.major_type as u8;
+        match self.major_type {
+            CborMajorType::UnsignedInteger | CborMajorType::NegativeInteger => {
+                write_head(major, self.argument.unwrap_or_default())
+            }
+            CborMajorType::Bytes | CborMajorType::Text => {
+                let content: Vec<u8> = match self.indefinite {
+                    true => self.children.iter().flat_map(|chunk| chunk.content(bytes)).copied().collect(),
+                    false => self.content(bytes).to_vec(),
+                };
+                let mut out = write_head(major, content.len() as u64);
+                out.extend(content);
+                out
+            }
+            CborMajorType::Array => {
+                let mut out = write_head(major, self.children.len() as u64);
+                for child in &self.children {
+                    out.extend(child.canonical_bytes(bytes));
+                }
+                out
+            }
+            CborMajorType::Map => {
+                let mut entries: Vec<(Vec<u8>, Vec<u8>)> = self
+                    .children
+                    .iter()
+                    .filter_map(|value| Some((value.key.as_ref()?.canonical_bytes(bytes), value.canonical_bytes(bytes))))
+                    .collect();
+                entries.sort_by(|(a, _), (b, _)| (a.len(), a).cmp(&(b.len(), b)));
+                let mut out = write_head(major, entries.len() as u64);
+                for (key, value) in entries {
+                    out.extend(key);
+                    out.extend(value);
+                }
+                out
+            }
+            CborMajorType::Tag => {
+                let mut out = write_head(major, self.argument.unwrap_or_default());
+                for child in &self.children {
+                    out.extend(child.canonical_bytes(bytes));
+                }
+                out
+            }
+            CborMajorType::Simple => bytes[self.offset..self.offset + self.length].to_vec(),
+        }
+    }
+
+    // the bytes after the head of a definite length string
+    fn content<'a>(&self, bytes: &'a [u8]) -> &'a [u8] {
+        &bytes[self.offset + self.head_length..self.offset + self.length]
+    }
+
+    fn description(&self) -> String {
+        let len = match (self.indefinite, self.argument) {
+            (true, _) => "_".to_string(),
+            (false, Some(len)) => len.to_string(),
+            (false, None) => String::new(),
+        };
+        match self.major_type {
+            CborMajorType::Array => format!("array({})", len),
+            CborMajorType::Map => format!("map({})", len),
+            CborMajorType::Tag => format!("tag({})", len),
+            CborMajorType::Bytes | CborMajorType::Text => format!(
+                "{}({}) {}",
+                if self.major_type == CborMajorType::Bytes { "bytes" } else { "text" },
+                len,
+                self.value.clone().unwrap_or_default()
+            ),
+            _ => self.value.clone().unwrap_or_default(),
+        }
+    }
+
+    fn annotate(&self, depth: usize, out: &mut String) {
+        out.push_str(&format!("{:>6}  {}", self.offset, "  ".repeat(depth)));
+        if let Some(key) = &self.key {
+            out.push_str(&key.description());
+            out.push_str(": ");
+        }
+        out.push_str(&self.description());
+        if let Some(name) = &self.name {
+            out.push_str(" # ");
+            out.push_str(name);
+        }
+        if let Some(error) = &self.error {
+            out.push_str(" !! ");
+            out.push_str(error);
+        }
+        out.push('\n');
+        for child in &self.children {
+            child.annotate(depth + 1, out);
+        }
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CborNodes(pub(crate) Vec<CborNode>);
+
+impl_vec_wrapper!(CborNodes, CborNode);
+
+#[wasm_bindgen]
+impl CborNodes {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn get(&self, index: usize) -> CborNode {
+        self.0[index].clone()
+    }
+}
+
+/// The shortest head of a CBOR item with the major type and argument
+pub(crate) fn write_head(major: u8, value: u64) -> Vec<u8> {
+    let (info, size) = match value {
+        0..=23 => (value as u8, 0),
+        0x18..=0xff => (24, 1),
+        0x100..=0xffff => (25, 2),
+        0x1_0000..=0xffff_ffff => (26, 4),
+        _ => (27, 8),
+    };
+    let mut head = vec![major << 5 | info];
+    head.extend_from_slice(&value.to_be_bytes()[8 - size..]);
+    head
+}
@@ -0,0 +1,291 @@
+use super::cbor_node::{CborMajorType, CborNode};
+use crate::serialization::map_names::{
+    CertificateIndexNames, TxBodyNames, VotingProposalIndexNames, WitnessSetNames,
+};
+use num_traits::FromPrimitive;
+
+// fields that are sets and may have the 258 tag
+pub(crate) const SET_FIELDS: &[&str] = &[
+    "inputs",
+    "collateral",
+    "reference_inputs",
+    "required_signers",
+    "certs",
+    "proposal_procedures",
+    "vkeywitnesses",
+    "native_scripts",
+    "bootstrap_witnesses",
+    "plutus_v1_scripts",
+    "plutus_v2_scripts",
+    "plutus_v3_scripts",
+    "plutus_data",
+];
+
+// maps with a fixed set of keys and their mandatory keys
+pub(crate) const STRUCT_MAPS: &[(&str, &[&str])] = &[
+    ("transaction_body", &["inputs", "outputs", "fee"]),
+    ("transaction_witness_set", &[]),
+    ("transaction_output", &["address", "amount"]),
+    ("collateral_return", &["address", "amount"]),
+    ("auxiliary_data_map", &[]),
+];
+
+// Expected structure of the explored bytes, only used to name the nodes.
+// Nodes that don't have the expected shape are left without names.
+#[derive(Clone, Copy)]
+pub(super) enum CborSchema {
+    Any,
+    // array with named elements
+    Fields(&'static [(&'static str, CborSchema)]),
+    // array, set or map values with the same name and structure
+    ListOf(&'static str, &'static CborSchema),
+    Transaction,
+    VersionedBlock,
+    HeaderBody,
+    TxBody,
+    Output,
+    Certificate,
+    GovAction,
+    WitnessSet,
+    Redeemers,
+    AuxiliaryData,
+}
+
+const INPUT: CborSchema = CborSchema::Fields(&[("transaction_id", CborSchema::Any), ("index", CborSchema::Any)]);
+const PROPOSAL_PROCEDURE: CborSchema = CborSchema::Fields(&[
+    ("deposit", CborSchema::Any),
+    ("reward_account", CborSchema::Any),
+    ("gov_action", CborSchema::GovAction),
+    ("anchor", ANCHOR),
+]);
+const ANCHOR: CborSchema = CborSchema::Fields(&[("anchor_url", CborSchema::Any), ("anchor_data_hash", CborSchema::Any)]);
+const VKEY_WITNESS: CborSchema = CborSchema::Fields(&[("vkey", CborSchema::Any), ("signature", CborSchema::Any)]);
+const BOOTSTRAP_WITNESS: CborSchema = CborSchema::Fields(&[
+    ("public_key", CborSchema::Any),
+    ("signature", CborSchema::Any),
+    ("chain_code", CborSchema::Any),
+    ("attributes", CborSchema::Any),
+]);
+const EX_UNITS: CborSchema = CborSchema::Fields(&[("mem", CborSchema::Any), ("steps", CborSchema::Any)]);
+const BLOCK: CborSchema = CborSchema::Fields(&[
+    ("header", CborSchema::Fields(&[("header_body", CborSchema::HeaderBody), ("body_signature", CborSchema::Any)])),
+    ("transaction_bodies", CborSchema::ListOf("transaction_body", &CborSchema::TxBody)),
+    ("transaction_witness_sets", CborSchema::ListOf("transaction_witness_set", &CborSchema::WitnessSet)),
+    ("auxiliary_data_set", CborSchema::ListOf("auxiliary_data", &CborSchema::AuxiliaryData)),
+    ("invalid_transactions", CborSchema::ListOf("transaction_index", &CborSchema::Any)),
+]);
+const PRAOS_HEADER_BODY: CborSchema = CborSchema::Fields(&[
+    ("block_number", CborSchema::Any),
+    ("slot", CborSchema::Any),
+    ("prev_hash", CborSchema::Any),
+    ("issuer_vkey", CborSchema::Any),
+    ("vrf_vkey", CborSchema::Any),
+    ("vrf_result", CborSchema::Fields(&[("output", CborSchema::Any), ("proof", CborSchema::Any)])),
+    ("block_body_size", CborSchema::Any),
+    ("block_body_hash", CborSchema::Any),
+    (
+        "operational_cert",
+        CborSchema::Fields(&[
+            ("hot_vkey", CborSchema::Any),
+            ("sequence_number", CborSchema::Any),
+            ("kes_period", CborSchema::Any),
+            ("sigma", CborSchema::Any),
+        ]),
+    ),
+    ("protocol_version", CborSchema::Fields(&[("major", CborSchema::Any), ("minor", CborSchema::Any)])),
+]);
+// headers before Babbage have two VRF results and inlined operational cert and protocol version
+const TPRAOS_HEADER_BODY: CborSchema = CborSchema::Fields(&[
+    ("block_number", CborSchema::Any),
+    ("slot", CborSchema::Any),
+    ("prev_hash", CborSchema::Any),
+    ("issuer_vkey", CborSchema::Any),
+    ("vrf_vkey", CborSchema::Any),
+    ("nonce_vrf", CborSchema::Any),
+    ("leader_vrf", CborSchema::Any),
+    ("block_body_size", CborSchema::Any),
+    ("block_body_hash", CborSchema::Any),
+    ("hot_vkey", CborSchema::Any),
+    ("sequence_number", CborSchema::Any),
+    ("kes_period", CborSchema::Any),
+    ("sigma", CborSchema::Any),
+    ("protocol_major", CborSchema::Any),
+    ("protocol_minor", CborSchema::Any),
+]);
+const LEGACY_OUTPUT_FIELDS: CborSchema =
+    CborSchema::Fields(&[("address", CborSchema::Any), ("amount", CborSchema::Any), ("datum_hash", CborSchema::Any)]);
+
+pub(super) fn annotate(node: &mut CborNode, schema: CborSchema) {
+    match schema {
+        CborSchema::Any => {}
+        CborSchema::Fields(fields) => {
+            if node.major_type == CborMajorType::Array {
+                for (child, (name, schema)) in node.children.iter_mut().zip(fields.iter()) {
+                    set_name(child, name, *schema);
+                }
+            }
+        }
+        CborSchema::ListOf(name, schema) => {
+            let list = without_set_tag(node);
+            if list.major_type == CborMajorType::Array || list.major_type == CborMajorType::Map {
+                for child in list.children.iter_mut() {
+                    set_name(child, name, *schema);
+                }
+            }
+        }
+        CborSchema::Transaction => annotate(
+            node,
+            CborSchema::Fields(&[
+                ("transaction_body", CborSchema::TxBody),
+                ("transaction_witness_set", CborSchema::WitnessSet),
+                ("is_valid", CborSchema::Any),
+                ("auxiliary_data", CborSchema::AuxiliaryData),
+            ]),
+        ),
+        CborSchema::VersionedBlock => {
+            // [era, block] as returned by the node, otherwise just the block
+            let is_versioned = node.children.len() == 2
+                && node.children[0].major_type == CborMajorType::UnsignedInteger;
+            if is_versioned {
+                annotate(node, CborSchema::Fields(&[("era", CborSchema::Any), ("block", BLOCK)]));
+            } else {
+                annotate(node, BLOCK);
+            }
+        }
+        CborSchema::HeaderBody => match node.children.len() {
+            15 => annotate(node, TPRAOS_HEADER_BODY),
+            _ => annotate(node, PRAOS_HEADER_BODY),
+        },
+        CborSchema::TxBody => annotate_map(node, |key| {
+            let field = TxBodyNames::from_u64(key)?;
+            let schema = match field {
+                TxBodyNames::Inputs | TxBodyNames::Collateral | TxBodyNames::ReferenceInputs => {
+                    CborSchema::ListOf("transaction_input", &INPUT)
+                }
+                TxBodyNames::Outputs => CborSchema::ListOf("transaction_output", &CborSchema::Output),
+                TxBodyNames::CollateralReturn => CborSchema::Output,
+                TxBodyNames::Certs => CborSchema::ListOf("certificate", &CborSchema::Certificate),
+                TxBodyNames::RequiredSigners => CborSchema::ListOf("required_signer", &CborSchema::Any),
+                TxBodyNames::Withdrawals => CborSchema::ListOf("withdrawal", &CborSchema::Any),
+                TxBodyNames::VotingProcedures => CborSchema::ListOf("voter_votes", &CborSchema::ListOf("voting_procedure", &CborSchema::Any)),
+                TxBodyNames::VotingProposals => CborSchema::ListOf("proposal_procedure", &PROPOSAL_PROCEDURE),
+                _ => CborSchema::Any,
+            };
+            Some((field.name(), schema))
+        }),
+        CborSchema::Output => match node.major_type {
+            CborMajorType::Array => annotate(node, LEGACY_OUTPUT_FIELDS),
+            _ => annotate_map(node, |key| match key {
+                0 => Some(("address", CborSchema::Any)),
+                1 => Some(("amount", CborSchema::Any)),
+                2 => Some(("datum_option", CborSchema::Any)),
+                3 => Some(("script_ref", CborSchema::Any)),
+                _ => None,
+            }),
+        },
+        CborSchema::Certificate => {
+            if let Some(kind) = first_uint(node).and_then(CertificateIndexNames::from_u64) {
+                node.name = Some(kind.name().to_string());
+            }
+        }
+        CborSchema::GovAction => {
+            if let Some(kind) = first_uint(node).and_then(VotingProposalIndexNames::from_u64) {
+                node.name = Some(kind.name().to_string());
+            }
+        }
+        CborSchema::WitnessSet => annotate_map(node, |key| {
+            let field = WitnessSetNames::from_u64(key)?;
+            let schema = match field {
+                WitnessSetNames::Vkeys => CborSchema::ListOf("vkeywitness", &VKEY_WITNESS),
+                WitnessSetNames::Bootstraps => CborSchema::ListOf("bootstrap_witness", &BOOTSTRAP_WITNESS),
+                WitnessSetNames::NativeScripts => CborSchema::ListOf("native_script", &CborSchema::Any),
+                WitnessSetNames::PlutusScriptsV1
+                | WitnessSetNames::PlutusScriptsV2
+                | WitnessSetNames::PlutusScriptsV3 => CborSchema::ListOf("plutus_script", &CborSchema::Any),
+                WitnessSetNames::PlutusData => CborSchema::ListOf("plutus_data", &CborSchema::Any),
+                WitnessSetNames::Redeemers => CborSchema::Redeemers,
+            };
+            Some((field.name(), schema))
+        }),
+        CborSchema::Redeemers => match node.major_type {
+            // Conway map of [tag, index] => [data, ex_units]
+            CborMajorType::Map => {
+                for child in node.children.iter_mut() {
+                    if let Some(key) = child.key.as_mut() {
+                        set_name(key, "redeemer_key", CborSchema::Fields(&[("tag", CborSchema::Any), ("index", CborSchema::Any)]));
+                    }
+                    set_name(child, "redeemer_value", CborSchema::Fields(&[("data", CborSchema::Any), ("ex_units", EX_UNITS)]));
+                }
+            }
+            _ => annotate(
+                node,
+                CborSchema::ListOf(
+                    "redeemer",
+                    &CborSchema::Fields(&[
+                        ("tag", CborSchema::Any),
+                        ("index", CborSchema::Any),
+                        ("data", CborSchema::Any),
+                        ("ex_units", EX_UNITS),
+                    ]),
+                ),
+            ),
+        },
+        CborSchema::AuxiliaryData => match node.major_type {
+            // Alonzo and later auxiliary data
+            CborMajorType::Tag if node.argument == Some(259) => {
+                if let Some(map) = node.children.first_mut() {
+                    annotate_map(map, |key| match key {
+                        0 => Some(("metadata", CborSchema::Any)),
+                        1 => Some(("native_scripts", CborSchema::Any)),
+                        2 => Some(("plutus_v1_scripts", CborSchema::Any)),
+                        3 => Some(("plutus_v2_scripts", CborSchema::Any)),
+                        4 => Some(("plutus_v3_scripts", CborSchema::Any)),
+                        _ => None,
+                    });
+                }
+            }
+            // Allegra auxiliary data, Shelley auxiliary data is just the metadata map
+            CborMajorType::Array => annotate(
+                node,
+                CborSchema::Fields(&[("metadata", CborSchema::Any), ("auxiliary_scripts", CborSchema::Any)]),
+            ),
+            _ => {}
+        },
+    }
+}
+
+fn set_name(node: &mut CborNode, name: &str, schema: CborSchema) {
+    node.name = Some(name.to_string());
+    annotate(node, schema);
+}
+
+fn annotate_map(node: &mut CborNode, field: impl Fn(u64) -> Option<(&'static str, CborSchema)>) {
+    if node.major_type != CborMajorType::Map {
+        return;
+    }
+    for child in node.children.iter_mut() {
+        let key = child
+            .key
+            .as_ref()
+            .filter(|key| key.major_type == CborMajorType::UnsignedInteger)
+            .and_then(|key| key.argument);
+        if let Some((name, schema)) = key.and_then(&field) {
+            set_name(child, name, schema);
+        }
+    }
+}
+
+// sets can be written with or without the 258 tag
+fn without_set_tag(node: &mut CborNode) -> &mut CborNode {
+    if node.major_type == CborMajorType::Tag && node.argument == Some(258) && node.children.len() == 1 {
+        return &mut node.children[0];
+    }
+    node
+}
+
+fn first_uint(node: &CborNode) -> Option<u64> {
+    node.children
+        .first()
+        .filter(|first| node.major_type == CborMajorType::Array && first.major_type == CborMajorType::UnsignedInteger)
+        .and_then(|first| first.argument)
+}
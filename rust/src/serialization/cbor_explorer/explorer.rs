@@ -0,0 +1,233 @@
+use super::cbor_schema::{annotate, CborSchema};
+use crate::*;
+
+// items are walked recursively, deeper ones get an error to keep the recursion from overflowing the stack
+pub(crate) const MAX_CBOR_DEPTH: usize = 256;
+
+/// Walks CBOR bytes into a tree of `CborNode`s for debugging.
+/// The bytes don't have to be valid: the tree stops at the first item that can't be read
+/// and that node gets an `error()`. Items that don't match the expected ledger structure
+/// are still shown, just without field names.
+#[wasm_bindgen]
+pub struct CborExplorer {}
+
+#[wasm_bindgen]
+impl CborExplorer {
+    /// Tree of any CBOR item without field names
+    pub fn explore(bytes: &[u8]) -> Result<CborNode, JsError> {
+        Self::explore_with_schema(bytes, None)
+    }
+
+    /// Tree of a transaction with the names of the body, witness set and auxiliary data fields
+    pub fn explore_transaction(bytes: &[u8]) -> Result<CborNode, JsError> {
+        Self::explore_with_schema(bytes, Some(CborSchema::Transaction))
+    }
+
+    /// Same as `explore_transaction` for a block, with or without the era wrapper
+    pub fn explore_block(bytes: &[u8]) -> Result<CborNode, JsError> {
+        Self::explore_with_schema(bytes, Some(CborSchema::VersionedBlock))
+    }
+}
+
+impl CborExplorer {
+    fn explore_with_schema(bytes: &[u8], schema: Option<CborSchema>) -> Result<CborNode, JsError> {
+        if bytes.is_empty() {
+            return Err(JsError::from_str("No bytes to explore"));
+        }
+        let mut walker = CborWalker {
+            bytes,
+            pos: 0,
+            depth: 0,
+            failed: false,
+        };
+        let mut root = walker.node();
+        if !walker.failed && walker.pos != bytes.len() {
+            root.error = Some(format!(
+                "{} unexpected bytes after the item at offset {}",
+                bytes.len() - walker.pos,
+                walker.pos
+            ));
+            root.trailing = true;
+        }
+        if let Some(schema) = schema {
+            annotate(&mut root, schema);
+        }
+        Ok(root)
+    }
+}
+
+struct CborWalker<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    // items around the one being read
+    depth: usize,
+    // set at the first error, nothing after it can be read reliably
+    failed: bool,
+}
+
+impl<'a> CborWalker<'a> {
+    fn empty_node(&self, offset: usize, major_type: CborMajorType) -> CborNode {
+        CborNode {
+            offset,
+            length: 0,
+            head_length: 0,
+            major_type,
+            name: None,
+            key: None,
+            argument: None,
+            value: None,
+            indefinite: false,
+            error: None,
+            trailing: false,
+            children: Vec::new(),
+        }
+    }
+
+    fn fail(&mut self, node: &mut CborNode, error: String) {
+        node.error = Some(error);
+        self.failed = true;
+    }
+
+    fn read(&mut self, len: u64) -> Result<&'a [u8], String> {
+        let end = (self.pos as u64)
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len() as u64)
+            .ok_or_else(|| format!("{} bytes expected but only {} left", len, self.bytes.len() - self.pos))?
+            as usize;
+        let bytes = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    // reads the head of an item, None as argument is an indefinite length
+    fn head(&mut self) -> Result<(u8, Option<u64>), String> {
+        let initial = self.read(1)?[0];
+        let major = initial >> 5;
+        let argument = match initial & 0x1f {
+            info @ 25..=27 if major == 7 => {
+                return Err(format!("floating point values are not supported (additional information {})", info))
+            }
+            info @ 0..=23 => Some(info as u64),
+            info @ 24..=27 => {
+                let size = 1u64 << (info - 24);
+                Some(self.read(size)?.iter().fold(0u64, |acc, byte| (acc << 8) | *byte as u64))
+            }
+            31 if (2..=5).contains(&major) => None,
+            31 if major == 7 => return Err("unexpected break".to_string()),
+            info => return Err(format!("invalid additional information {} for major type {}", info, major)),
+        };
+        Ok((major, argument))
+    }
+
+    fn is_break(&mut self) -> Result<bool, String> {
+        match self.bytes.get(self.pos) {
+            Some(0xff) => {
+                self.pos += 1;
+                Ok(true)
+            }
+            Some(_) => Ok(false),
+            None => Err("missing break of an indefinite length item".to_string()),
+        }
+    }
+
+    fn node(&mut self) -> CborNode {
+        let offset = self.pos;
+        let mut node = self.empty_node(offset, CborMajorType::Simple);
+        let (major, argument) = match self.head() {
+            Ok(head) => head,
+            Err(error) => {
+                self.fail(&mut node, error);
+                return node;
+            }
+        };
+        node.major_type = match major {
+            0 => CborMajorType::UnsignedInteger,
+            1 => CborMajorType::NegativeInteger,
+            2 => CborMajorType::Bytes,
+            3 => CborMajorType::Text,
+            4 => CborMajorType::Array,
+            5 => CborMajorType::Map,
+            6 => CborMajorType::Tag,
+            _ => CborMajorType::Simple,
+        };
+        node.head_length = self.pos - offset;
+        node.argument = argument;
+        node.indefinite = argument.is_none();
+        let content = if self.depth == MAX_CBOR_DEPTH {
+            Err(format!("items are nested deeper than {} levels", MAX_CBOR_DEPTH))
+        } else {
+            self.depth += 1;
+            let content = self.content(&mut node, major, argument);
+            self.depth -= 1;
+            content
+        };
+        if let Err(error) = content {
+            self.fail(&mut node, error);
+        }
+        node.length = self.pos - offset;
+        node
+    }
+
+    fn content(&mut self, node: &mut CborNode, major: u8, argument: Option<u64>) -> Result<(), String> {
+        match (major, argument) {
+            (2, None) | (3, None) => {
+                // chunks of an indefinite length string
+                while !self.is_break()? {
+                    let chunk = self.node();
+                    let is_valid = chunk.major_type == node.major_type && !chunk.indefinite;
+                    node.children.push(chunk);
+                    if self.failed {
+                        return Ok(());
+                    }
+                    if !is_valid {
+                        return Err("invalid chunk of an indefinite length string".to_string());
+                    }
+                }
+            }
+            (2, Some(len)) | (3, Some(len)) => {
+                self.read(len)?;
+            }
+            (4, len) => {
+                let mut count = 0;
+                while match len {
+                    Some(len) => count < len,
+                    None => !self.is_break()?,
+                } {
+                    node.children.push(self.node());
+                    if self.failed {
+                        return Ok(());
+                    }
+                    count += 1;
+                }
+            }
+            (5, len) => {
+                let mut count = 0;
+                while match len {
+                    Some(len) => count < len,
+                    None => !self.is_break()?,
+                } {
+                    let key = self.node();
+                    if self.failed {
+                        node.children.push(key);
+                        return Ok(());
+                    }
+                    let mut value = self.node();
+                    value.key = Some(Box::new(key));
+                    node.children.push(value);
+                    if self.failed {
+                        return Ok(());
+                    }
+                    count += 1;
+                }
+            }
+            (6, _) => {
+                node.children.push(self.node());
+            }
+            _ => {}
+        }
+        if node.children.is_empty() && major != 4 && major != 5 {
+            node.value = cbor_to_diagnostic(&self.bytes[node.offset..self.pos]).ok();
+        }
+        Ok(())
+    }
+}
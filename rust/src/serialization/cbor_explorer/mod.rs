@@ -0,0 +1,8 @@
+mod explorer;
+pub use explorer::*;
+
+mod cbor_node;
+pub use cbor_node::*;
+
+mod cbor_schema;
+pub(crate) use cbor_schema::{SET_FIELDS, STRUCT_MAPS};
@@ -0,0 +1,269 @@
+use crate::*;
+use std::collections::HashSet;
+use std::io::Cursor;
+
+// Rules of the Conway CDDL that aren't enforced by `Transaction::from_bytes`
+
+// nonempty maps and lists besides the sets, all sets except the inputs are nonempty as well
+const NONEMPTY_FIELDS: &[&str] = &["withdrawals", "mint", "voting_procedures", "redeemers"];
+
+// fields whose content isn't ledger structure, only their integers are checked
+const OPAQUE_FIELDS: &[&str] = &["plutus_data", "data", "datum_option", "script_ref", "metadata"];
+
+/// Checks transaction bytes against the Conway CDDL and reports every deviation with its byte offset:
+/// encodings the node rejects (duplicate or unknown keys, empty nonempty collections,
+/// duplicate set elements, zero amounts, ...) and encodings that are accepted but not canonical
+/// (sets without the 258 tag, non-shortest integers, indefinite lengths, unsorted map keys).
+/// Plutus data, metadata and datums are kept as they are written, so only their integers are checked.
+#[wasm_bindgen]
+pub fn check_conway_conformance(tx_bytes: &[u8]) -> ConformanceReport {
+    let mut checker = ConformanceChecker {
+        bytes: tx_bytes,
+        issues: Vec::new(),
+    };
+    checker.check();
+    ConformanceReport {
+        issues: ConformanceIssues(checker.issues),
+    }
+}
+
+struct ConformanceChecker<'a> {
+    bytes: &'a [u8],
+    issues: Vec<ConformanceIssue>,
+}
+
+impl<'a> ConformanceChecker<'a> {
+    fn check(&mut self) {
+        let root = match CborExplorer::explore_transaction(self.bytes) {
+            Ok(root) => root,
+            Err(_) => {
+                self.issues.push(ConformanceIssue {
+                    kind: ConformanceIssueKind::MalformedCbor,
+                    severity: ConformanceSeverity::Rejected,
+                    path: "transaction".to_string(),
+                    offset: 0,
+                    length: 0,
+                    message: "no bytes to check".to_string(),
+                });
+                return;
+            }
+        };
+        if self.report_malformed(&root, "transaction") {
+            return;
+        }
+        match (&root.error, root.trailing) {
+            (Some(error), true) => self.issues.push(ConformanceIssue {
+                kind: ConformanceIssueKind::TrailingBytes,
+                severity: ConformanceSeverity::Rejected,
+                path: "transaction".to_string(),
+                offset: root.length,
+                length: self.bytes.len() - root.length,
+                message: error.clone(),
+            }),
+            (Some(error), false) => {
+                let message = error.clone();
+                self.report(&root, "transaction", ConformanceIssueKind::MalformedCbor, message);
+                return;
+            }
+            (None, _) => {}
+        }
+        self.visit(&root, "transaction", root.name.as_deref(), false);
+
+        // structural errors not covered above, e.g. a field of the wrong type
+        let is_accepted = self.issues.iter().all(|issue| issue.severity != ConformanceSeverity::Rejected);
+        if is_accepted {
+            let mut raw = Deserializer::from(Cursor::new(&self.bytes[..root.length]));
+            if let Err(e) = Transaction::deserialize(&mut raw) {
+                self.report(&root, "transaction", ConformanceIssueKind::InvalidStructure, e.to_string());
+            }
+        }
+    }
+
+    fn report(&mut self, node: &CborNode, path: &str, kind: ConformanceIssueKind, message: String) {
+        let severity = match kind {
+            ConformanceIssueKind::MissingSetTag
+            | ConformanceIssueKind::NonCanonicalInteger
+            | ConformanceIssueKind::IndefiniteLength
+            | ConformanceIssueKind::UnsortedMapKeys => ConformanceSeverity::NonCanonical,
+            _ => ConformanceSeverity::Rejected,
+        };
+        self.issues.push(ConformanceIssue {
+            kind,
+            severity,
+            path: path.to_string(),
+            offset: node.offset,
+            length: node.length,
+            message,
+        });
+    }
+
+    // returns true when any node couldn't be read, the error of the root itself is left to the caller.
+    // The explorer stops at nesting too deep for the recursion, so the nodes are bounded
+    fn report_malformed(&mut self, node: &CborNode, path: &str) -> bool {
+        let mut found = false;
+        let paths = node.child_paths(path);
+        for (child, child_path) in node.children.iter().zip(paths.iter()) {
+            for item in child.key.iter().map(|key| key.as_ref()).chain(std::iter::once(child)) {
+                if let Some(error) = &item.error {
+                    self.report(item, child_path, ConformanceIssueKind::MalformedCbor, error.clone());
+                    found = true;
+                }
+                found |= self.report_malformed(item, child_path);
+            }
+        }
+        found
+    }
+
+    fn bytes_of(&self, node: &CborNode) -> &'a [u8] {
+        &self.bytes[node.offset..node.offset + node.length]
+    }
+
+    // `name` is the ledger name of the node and `opaque` is set inside plutus data, metadata and datums
+    fn visit(&mut self, node: &CborNode, path: &str, name: Option<&str>, opaque: bool) {
+        if let Some(message) = node.non_canonical_head() {
+            self.report(node, path, ConformanceIssueKind::NonCanonicalInteger, message);
+        }
+        if let (Some(name), false) = (name, opaque) {
+            self.check_field(node, path, name);
+        }
+        let opaque = opaque || matches!(name, Some(name) if OPAQUE_FIELDS.contains(&name))
+            // Shelley auxiliary data is only the metadata
+            || (name == Some("auxiliary_data") && node.major_type == CborMajorType::Map);
+        if !opaque {
+            if node.indefinite {
+                self.report(node, path, ConformanceIssueKind::IndefiniteLength, "indefinite length encoding".to_string());
+            }
+            if node.major_type == CborMajorType::Map {
+                self.check_map(node, path, name);
+            }
+        }
+
+        let paths = node.child_paths(path);
+        for (child, child_path) in node.children.iter().zip(paths.iter()) {
+            if let Some(key) = &child.key {
+                self.visit(key, child_path, None, opaque);
+            }
+            let child_name = match (node.major_type, node.argument, name) {
+                // the fields of Alonzo auxiliary data are in a map tagged 259
+                (CborMajorType::Tag, Some(259), Some("auxiliary_data")) => Some("auxiliary_data_map"),
+                _ => child.name.as_deref(),
+            };
+            self.visit(child, child_path, child_name, opaque);
+        }
+    }
+
+    fn check_field(&mut self, node: &CborNode, path: &str, name: &str) {
+        let is_set = SET_FIELDS.contains(&name);
+        let content = match node.major_type {
+            CborMajorType::Tag => &node.children[0],
+            _ => node,
+        };
+        if is_set {
+            match (node.major_type, node.argument) {
+                (CborMajorType::Tag, Some(258)) => {}
+                (CborMajorType::Tag, tag) => {
+                    let message = format!("{} has tag {} instead of the set tag 258", name, tag.unwrap_or_default());
+                    self.report(node, path, ConformanceIssueKind::WrongSetTag, message);
+                }
+                _ => {
+                    let message = format!("{} is a set without the 258 tag", name);
+                    self.report(node, path, ConformanceIssueKind::MissingSetTag, message);
+                }
+            }
+            let mut seen = HashSet::new();
+            let paths = content.child_paths(path);
+            for (element, element_path) in content.children.iter().zip(paths.iter()) {
+                if !seen.insert(element.canonical_bytes(self.bytes)) {
+                    let message = format!("{} contains this element more than once", name);
+                    self.report(element, element_path, ConformanceIssueKind::DuplicateSetElement, message);
+                }
+            }
+        }
+        let is_nonempty = (is_set && name != "inputs") || NONEMPTY_FIELDS.contains(&name);
+        let is_collection = content.major_type == CborMajorType::Array || content.major_type == CborMajorType::Map;
+        if is_nonempty && is_collection && content.children.is_empty() {
+            let message = format!("{} must not be empty", name);
+            self.report(node, path, ConformanceIssueKind::EmptyCollection, message);
+        }
+        match name {
+            "update" => {
+                let message = "protocol parameter updates were replaced by governance actions in Conway".to_string();
+                self.report(node, path, ConformanceIssueKind::FieldNotAllowed, message);
+            }
+            "donation" if node.argument == Some(0) => {
+                self.report(node, path, ConformanceIssueKind::ZeroAmount, "donation must be positive".to_string());
+            }
+            "mint" => self.check_multiasset(content, path, "mint"),
+            "amount" if node.major_type == CborMajorType::Array && node.children.len() == 2 => {
+                let assets_path = format!("{}[1]", path);
+                self.check_multiasset(&node.children[1], &assets_path, "amount");
+            }
+            _ => {}
+        }
+    }
+
+    // policies and asset maps must be nonempty and quantities non-zero
+    fn check_multiasset(&mut self, multiasset: &CborNode, path: &str, name: &str) {
+        if multiasset.major_type != CborMajorType::Map {
+            return;
+        }
+        if multiasset.children.is_empty() && name == "amount" {
+            let message = "multiasset must contain at least one policy".to_string();
+            self.report(multiasset, path, ConformanceIssueKind::EmptyCollection, message);
+        }
+        let paths = multiasset.child_paths(path);
+        for (assets, assets_path) in multiasset.children.iter().zip(paths.iter()) {
+            if assets.major_type == CborMajorType::Map && assets.children.is_empty() {
+                let message = "a policy must contain at least one asset".to_string();
+                self.report(assets, assets_path, ConformanceIssueKind::EmptyCollection, message);
+            }
+            let quantity_paths = assets.child_paths(assets_path);
+            for (quantity, quantity_path) in assets.children.iter().zip(quantity_paths.iter()) {
+                let is_zero = quantity.major_type == CborMajorType::UnsignedInteger && quantity.argument == Some(0);
+                if is_zero {
+                    let message = format!("{} quantities must not be zero", name);
+                    self.report(quantity, quantity_path, ConformanceIssueKind::ZeroAmount, message);
+                }
+            }
+        }
+    }
+
+    fn check_map(&mut self, map: &CborNode, path: &str, name: Option<&str>) {
+        let paths = map.child_paths(path);
+        let struct_map = STRUCT_MAPS.iter().find(|(struct_name, _)| Some(*struct_name) == name);
+        if let Some((struct_name, mandatory)) = struct_map {
+            for (value, value_path) in map.children.iter().zip(paths.iter()) {
+                if value.name.is_none() {
+                    let key = value.key.as_ref().and_then(|key| key.value.clone()).unwrap_or_default();
+                    let message = format!("unknown key {} in {}", key, struct_name);
+                    self.report(value, value_path, ConformanceIssueKind::UnknownKey, message);
+                }
+            }
+            for field in mandatory.iter() {
+                if !map.children.iter().any(|value| value.name.as_deref() == Some(*field)) {
+                    let message = format!("{} is missing {}", struct_name, field);
+                    self.report(map, path, ConformanceIssueKind::MissingField, message);
+                }
+            }
+        }
+
+        let keys: Vec<&CborNode> = map.children.iter().filter_map(|value| value.key.as_deref()).collect();
+        // keys are compared by value, `03` and `18 03` are the same key
+        let mut seen = HashSet::new();
+        for ((key, value), value_path) in keys.iter().zip(map.children.iter()).zip(paths.iter()) {
+            if !seen.insert(key.canonical_bytes(self.bytes)) {
+                let message = "the key is repeated".to_string();
+                self.report(value, value_path, ConformanceIssueKind::DuplicateKey, message);
+            }
+        }
+        // canonical CBOR sorts the keys by length and then bytewise
+        let keys: Vec<&[u8]> = keys.iter().map(|key| self.bytes_of(key)).collect();
+        let is_sorted = keys
+            .windows(2)
+            .all(|pair| (pair[0].len(), pair[0]) <= (pair[1].len(), pair[1]));
+        if !is_sorted {
+            let message = "map keys are not in canonical order".to_string();
+            self.report(map, path, ConformanceIssueKind::UnsortedMapKeys, message);
+        }
+    }
+}
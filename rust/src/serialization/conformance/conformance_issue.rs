@@ -0,0 +1,122 @@
+use crate::*;
+
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd, serde::Serialize)]
+pub enum ConformanceSeverity {
+    /// The node rejects the transaction
+    Rejected,
+    /// Accepted by the node but not the canonical encoding
+    NonCanonical,
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd, serde::Serialize)]
+pub enum ConformanceIssueKind {
+    MalformedCbor,
+    TrailingBytes,
+    /// The bytes don't decode to a transaction for another reason
+    InvalidStructure,
+    UnknownKey,
+    DuplicateKey,
+    MissingField,
+    /// A field that exists in older eras but not in Conway
+    FieldNotAllowed,
+    /// An empty set, list or map where the CDDL requires at least one element
+    EmptyCollection,
+    /// A zero amount where the CDDL requires a positive or non-zero one
+    ZeroAmount,
+    WrongSetTag,
+    DuplicateSetElement,
+    /// A set without the 258 tag, accepted in Conway but required afterwards
+    MissingSetTag,
+    NonCanonicalInteger,
+    IndefiniteLength,
+    UnsortedMapKeys,
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize)]
+pub struct ConformanceIssue {
+    pub(crate) kind: ConformanceIssueKind,
+    pub(crate) severity: ConformanceSeverity,
+    pub(crate) path: String,
+    pub(crate) offset: usize,
+    pub(crate) length: usize,
+    pub(crate) message: String,
+}
+
+#[wasm_bindgen]
+impl ConformanceIssue {
+    pub fn kind(&self) -> ConformanceIssueKind {
+        self.kind
+    }
+
+    pub fn severity(&self) -> ConformanceSeverity {
+        self.severity
+    }
+
+    /// Path of the item using the ledger CDDL names, e.g. `transaction.transaction_body.certs`
+    pub fn path(&self) -> String {
+        self.path.clone()
+    }
+
+    /// Offset of the first byte of the item
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Size of the item in bytes
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    pub fn message(&self) -> String {
+        self.message.clone()
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize)]
+pub struct ConformanceIssues(pub(crate) Vec<ConformanceIssue>);
+
+impl_vec_wrapper!(ConformanceIssues, ConformanceIssue);
+
+#[wasm_bindgen]
+impl ConformanceIssues {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn get(&self, index: usize) -> ConformanceIssue {
+        self.0[index].clone()
+    }
+}
+
+/// Result of `check_conway_conformance`
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize)]
+pub struct ConformanceReport {
+    pub(crate) issues: ConformanceIssues,
+}
+
+#[wasm_bindgen]
+impl ConformanceReport {
+    pub fn issues(&self) -> ConformanceIssues {
+        self.issues.clone()
+    }
+
+    /// No issue would make the node reject the transaction
+    pub fn is_accepted(&self) -> bool {
+        self.issues.0.iter().all(|issue| issue.severity != ConformanceSeverity::Rejected)
+    }
+
+    /// The transaction is in the canonical Conway encoding
+    pub fn is_canonical(&self) -> bool {
+        self.issues.0.is_empty()
+    }
+
+    pub fn to_json(&self) -> Result<String, JsError> {
+        serde_json::to_string_pretty(&self)
+            .map_err(|e| JsError::from_str(&format!("to_json: {}", e)))
+    }
+}
@@ -0,0 +1,5 @@
+mod conformance_checker;
+pub use conformance_checker::*;
+
+mod conformance_issue;
+pub use conformance_issue::*;
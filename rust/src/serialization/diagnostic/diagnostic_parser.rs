@@ -0,0 +1,342 @@
+use super::diagnostic_writer::shortest_additional_info;
+use crate::serialization::cbor_explorer::MAX_CBOR_DEPTH;
+
+// Parses the extended diagnostic notation written by `DiagnosticWriter` back to the exact CBOR bytes.
+// Besides the notation it writes, `/comments/` and single quoted byte strings ('text') are accepted.
+
+pub(super) struct DiagnosticParser<'a> {
+    text: &'a str,
+    pos: usize,
+    // items around the one being parsed
+    depth: usize,
+    out: Vec<u8>,
+}
+
+impl<'a> DiagnosticParser<'a> {
+    pub(super) fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            pos: 0,
+            depth: 0,
+            out: Vec::new(),
+        }
+    }
+
+    pub(super) fn parse(mut self) -> Result<Vec<u8>, String> {
+        self.item()?;
+        self.skip_whitespace()?;
+        if self.pos != self.text.len() {
+            return Err(self.error("unexpected text after the CBOR item"));
+        }
+        Ok(self.out)
+    }
+
+    fn error(&self, message: &str) -> String {
+        format!("{} at position {}", message, self.pos)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    fn peek_second(&self) -> Option<char> {
+        self.text[self.pos..].chars().nth(1)
+    }
+
+    // `_` followed by a digit starts an encoding indicator, otherwise it marks an indefinite length
+    fn at_indicator(&self) -> bool {
+        self.peek() == Some('_') && matches!(self.peek_second(), Some(c) if c.is_ascii_digit())
+    }
+
+    fn skip_while(&mut self, predicate: impl Fn(char) -> bool) {
+        while matches!(self.peek(), Some(c) if predicate(c)) {
+            self.next();
+        }
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        self.skip_whitespace()?;
+        match self.next() {
+            Some(c) if c == expected => Ok(()),
+            _ => Err(self.error(&format!("expected '{}'", expected))),
+        }
+    }
+
+    fn skip_whitespace(&mut self) -> Result<(), String> {
+        loop {
+            match self.peek() {
+                Some(c) if c.is_whitespace() => {
+                    self.next();
+                }
+                Some('/') => {
+                    self.next();
+                    while self.next().ok_or_else(|| self.error("unterminated comment"))? != '/' {}
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn write_head(&mut self, major: u8, argument: u64, indicator: Option<u8>) -> Result<(), String> {
+        let additional_info = match indicator {
+            Some(indicator) => 24 + indicator,
+            None => shortest_additional_info(argument),
+        };
+        let size = match additional_info {
+            0..=23 => 0,
+            _ => 1usize << (additional_info - 24),
+        };
+        if indicator.is_some() && size < 8 && argument >> (size * 8) != 0 {
+            return Err(self.error(&format!("{} doesn't fit the encoding indicator", argument)));
+        }
+        self.out.push(major << 5 | additional_info);
+        self.out.extend_from_slice(&argument.to_be_bytes()[8 - size..]);
+        Ok(())
+    }
+
+    // `_0` to `_3` after an item
+    fn indicator(&mut self) -> Result<Option<u8>, String> {
+        if !self.at_indicator() {
+            return Ok(None);
+        }
+        self.next();
+        match self.next() {
+            Some(digit @ '0'..='3') => Ok(Some(digit as u8 - b'0')),
+            _ => Err(self.error("only the encoding indicators _0, _1, _2 and _3 are supported")),
+        }
+    }
+
+    fn item(&mut self) -> Result<(), String> {
+        if self.depth == MAX_CBOR_DEPTH {
+            return Err(self.error(&format!("items are nested deeper than {} levels", MAX_CBOR_DEPTH)));
+        }
+        self.depth += 1;
+        let result = self.nested_item();
+        self.depth -= 1;
+        result
+    }
+
+    fn nested_item(&mut self) -> Result<(), String> {
+        self.skip_whitespace()?;
+        match self.peek() {
+            Some('[') => {
+                self.next();
+                self.container(4, ']', |parser| parser.item())
+            }
+            Some('{') => {
+                self.next();
+                self.container(5, '}', |parser| {
+                    parser.item()?;
+                    parser.expect(':')?;
+                    parser.item()
+                })
+            }
+            Some('(') => self.chunked_string(),
+            Some('h') if self.peek_second() == Some('\'') => self.string_item(),
+            Some('"') | Some('\'') => self.string_item(),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.number(),
+            Some(c) if c.is_ascii_alphabetic() => self.simple(),
+            _ => Err(self.error("expected a CBOR item")),
+        }
+    }
+
+    fn container(
+        &mut self,
+        major: u8,
+        close: char,
+        mut item: impl FnMut(&mut Self) -> Result<(), String>,
+    ) -> Result<(), String> {
+        self.skip_whitespace()?;
+        let (indefinite, indicator) = if self.at_indicator() {
+            (false, self.indicator()?)
+        } else if self.peek() == Some('_') {
+            self.next();
+            (true, None)
+        } else {
+            (false, None)
+        };
+        let header_pos = self.out.len();
+        self.out.push(major << 5 | 31);
+        let mut count = 0u64;
+        self.skip_whitespace()?;
+        if self.peek() == Some(close) {
+            self.next();
+        } else {
+            loop {
+                item(self)?;
+                count += 1;
+                self.skip_whitespace()?;
+                match self.next() {
+                    Some(',') => {}
+                    Some(c) if c == close => break,
+                    _ => return Err(self.error(&format!("expected ',' or '{}'", close))),
+                }
+            }
+        }
+        if indefinite {
+            self.out.push(0xff);
+        } else {
+            // the length is only known at the end, so the placeholder is replaced with the real header
+            let items = self.out.split_off(header_pos + 1);
+            self.out.truncate(header_pos);
+            self.write_head(major, count, indicator)?;
+            self.out.extend(items);
+        }
+        Ok(())
+    }
+
+    fn number(&mut self) -> Result<(), String> {
+        let negative = self.peek() == Some('-');
+        if negative {
+            self.next();
+        }
+        let start = self.pos;
+        self.skip_while(|c| c.is_ascii_digit());
+        let value: u128 = self.text[start..self.pos]
+            .parse()
+            .map_err(|_| self.error("invalid integer"))?;
+        let (major, argument) = if negative {
+            (1, value.checked_sub(1).ok_or_else(|| self.error("-0 is not a CBOR integer"))?)
+        } else {
+            (0, value)
+        };
+        if argument > u64::MAX as u128 {
+            return Err(self.error("integer doesn't fit into a CBOR head, use a bignum tag"));
+        }
+        let indicator = self.indicator()?;
+        self.skip_whitespace()?;
+        if !negative && self.peek() == Some('(') {
+            self.next();
+            self.write_head(6, argument as u64, indicator)?;
+            self.item()?;
+            return self.expect(')');
+        }
+        self.write_head(major, argument as u64, indicator)
+    }
+
+    // returns the major type and the content of a definite length string
+    fn string(&mut self) -> Result<(u8, Vec<u8>), String> {
+        match self.next() {
+            Some('h') => {
+                self.next();
+                let start = self.pos;
+                while self.next().ok_or_else(|| self.error("unterminated byte string"))? != '\'' {}
+                let hex_str: String = self.text[start..self.pos - 1]
+                    .chars()
+                    .filter(|c| !c.is_whitespace())
+                    .collect();
+                let bytes = hex::decode(hex_str).map_err(|e| self.error(&e.to_string()))?;
+                Ok((2, bytes))
+            }
+            Some(quote) if quote == '"' || quote == '\'' => {
+                let start = self.pos;
+                loop {
+                    match self.next() {
+                        Some('\\') => {
+                            self.next();
+                        }
+                        Some(c) if c == quote => break,
+                        Some(_) => {}
+                        None => return Err(self.error("unterminated string")),
+                    }
+                }
+                // the content is JSON escaped, single quoted strings are the bytes of their text
+                let mut content = String::new();
+                let mut chars = self.text[start..self.pos - 1].chars();
+                while let Some(c) = chars.next() {
+                    match (c, quote) {
+                        ('\\', _) => match chars.next() {
+                            Some('\'') => content.push('\''),
+                            Some(escaped) => {
+                                content.push('\\');
+                                content.push(escaped);
+                            }
+                            None => {}
+                        },
+                        ('"', '\'') => content.push_str("\\\""),
+                        (c, _) => content.push(c),
+                    }
+                }
+                let text: String = serde_json::from_str(&format!("\"{}\"", content))
+                    .map_err(|e| self.error(&format!("invalid string: {}", e)))?;
+                Ok((if quote == '"' { 3 } else { 2 }, text.into_bytes()))
+            }
+            _ => Err(self.error("expected a string")),
+        }
+    }
+
+    fn string_item(&mut self) -> Result<(), String> {
+        let (major, content) = self.string()?;
+        // ''_ and ""_ are empty indefinite length strings
+        if content.is_empty() && self.peek() == Some('_') && !self.at_indicator() {
+            self.next();
+            self.out.push(major << 5 | 31);
+            self.out.push(0xff);
+            return Ok(());
+        }
+        let indicator = self.indicator()?;
+        self.write_head(major, content.len() as u64, indicator)?;
+        self.out.extend(content);
+        Ok(())
+    }
+
+    fn chunked_string(&mut self) -> Result<(), String> {
+        self.next();
+        self.expect('_')?;
+        let header_pos = self.out.len();
+        let mut major = None;
+        loop {
+            self.skip_whitespace()?;
+            let (chunk_major, content) = self.string()?;
+            if *major.get_or_insert(chunk_major) != chunk_major {
+                return Err(self.error("byte and text string chunks can't be mixed"));
+            }
+            let indicator = self.indicator()?;
+            self.write_head(chunk_major, content.len() as u64, indicator)?;
+            self.out.extend(content);
+            self.skip_whitespace()?;
+            match self.next() {
+                Some(',') => {}
+                Some(')') => break,
+                _ => return Err(self.error("expected ',' or ')'")),
+            }
+        }
+        self.out.insert(header_pos, major.unwrap_or(2) << 5 | 31);
+        self.out.push(0xff);
+        Ok(())
+    }
+
+    fn simple(&mut self) -> Result<(), String> {
+        let start = self.pos;
+        self.skip_while(|c| c.is_ascii_alphabetic());
+        match &self.text[start..self.pos] {
+            "false" => self.out.push(0xf4),
+            "true" => self.out.push(0xf5),
+            "null" => self.out.push(0xf6),
+            "undefined" => self.out.push(0xf7),
+            "simple" => {
+                self.expect('(')?;
+                self.skip_whitespace()?;
+                let start = self.pos;
+                self.skip_while(|c| c.is_ascii_digit());
+                let value: u8 = self.text[start..self.pos]
+                    .parse()
+                    .map_err(|_| self.error("invalid simple value"))?;
+                match value {
+                    0..=19 => self.out.push(0xe0 | value),
+                    20..=31 => return Err(self.error("simple values 20-31 have no simple(n) notation")),
+                    _ => self.out.extend_from_slice(&[0xf8, value]),
+                }
+                self.expect(')')?;
+            }
+            other => return Err(self.error(&format!("unknown keyword '{}'", other))),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,247 @@
+// Writes CBOR as RFC 8949 extended diagnostic notation. Every detail of the encoding is kept:
+// indefinite lengths are written with `_` and arguments not using the shortest encoding get an
+// encoding indicator (`_0` for 1 byte, `_1` for 2 bytes, `_2` for 4 bytes and `_3` for 8 bytes).
+
+use crate::serialization::cbor_explorer::MAX_CBOR_DEPTH;
+
+const BREAK: u8 = 0xff;
+
+pub(super) struct DiagnosticWriter<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    // items around the one being written
+    depth: usize,
+    out: String,
+}
+
+struct Head {
+    major: u8,
+    additional_info: u8,
+    // None for indefinite lengths
+    argument: Option<u64>,
+}
+
+impl Head {
+    fn argument(&self) -> u64 {
+        self.argument.unwrap_or(0)
+    }
+
+    fn indicator(&self) -> String {
+        match self.argument {
+            Some(argument) if self.additional_info >= 24 && self.additional_info != shortest_additional_info(argument) => {
+                format!("_{}", self.additional_info - 24)
+            }
+            _ => String::new(),
+        }
+    }
+}
+
+pub(super) fn shortest_additional_info(argument: u64) -> u8 {
+    match argument {
+        0..=23 => argument as u8,
+        24..=0xff => 24,
+        0x100..=0xffff => 25,
+        0x1_0000..=0xffff_ffff => 26,
+        _ => 27,
+    }
+}
+
+impl<'a> DiagnosticWriter<'a> {
+    pub(super) fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            pos: 0,
+            depth: 0,
+            out: String::new(),
+        }
+    }
+
+    pub(super) fn write(mut self) -> Result<String, String> {
+        self.item()?;
+        if self.pos != self.bytes.len() {
+            return Err(format!("unexpected bytes after the CBOR item at offset {}", self.pos));
+        }
+        Ok(self.out)
+    }
+
+    fn byte(&mut self) -> Result<u8, String> {
+        let byte = *self
+            .bytes
+            .get(self.pos)
+            .ok_or_else(|| format!("unexpected end of CBOR at offset {}", self.pos))?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn take(&mut self, len: u64) -> Result<&'a [u8], String> {
+        let end = (self.pos as u64)
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len() as u64)
+            .ok_or_else(|| format!("unexpected end of CBOR at offset {}", self.pos))? as usize;
+        let bytes = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn is_break(&mut self) -> Result<bool, String> {
+        match self.bytes.get(self.pos) {
+            Some(&BREAK) => {
+                self.pos += 1;
+                Ok(true)
+            }
+            Some(_) => Ok(false),
+            None => Err(format!("missing break of an indefinite length item at offset {}", self.pos)),
+        }
+    }
+
+    fn head(&mut self) -> Result<Head, String> {
+        let offset = self.pos;
+        let initial = self.byte()?;
+        let major = initial >> 5;
+        let additional_info = initial & 0x1f;
+        let argument = match additional_info {
+            0..=23 => Some(additional_info as u64),
+            24..=27 => {
+                let size = 1u64 << (additional_info - 24);
+                Some(self.take(size)?.iter().fold(0u64, |acc, byte| (acc << 8) | *byte as u64))
+            }
+            31 if (2..=5).contains(&major) => None,
+            _ => {
+                return Err(format!(
+                    "invalid additional information {} for major type {} at offset {}",
+                    additional_info, major, offset
+                ))
+            }
+        };
+        Ok(Head {
+            major,
+            additional_info,
+            argument,
+        })
+    }
+
+    fn item(&mut self) -> Result<(), String> {
+        if self.depth == MAX_CBOR_DEPTH {
+            return Err(format!("items are nested deeper than {} levels at offset {}", MAX_CBOR_DEPTH, self.pos));
+        }
+        self.depth += 1;
+        let result = self.nested_item();
+        self.depth -= 1;
+        result
+    }
+
+    fn nested_item(&mut self) -> Result<(), String> {
+        let offset = self.pos;
+        let head = self.head()?;
+        match head.major {
+            0 => self.out.push_str(&format!("{}{}", head.argument(), head.indicator())),
+            1 => self.out.push_str(&format!("{}{}", -1 - head.argument() as i128, head.indicator())),
+            2 | 3 => match head.argument {
+                Some(len) => {
+                    let string = self.string(head.major, len, offset)?;
+                    self.out.push_str(&string);
+                    self.out.push_str(&head.indicator());
+                }
+                None => self.chunked_string(head.major)?,
+            },
+            4 => {
+                self.out.push('[');
+                self.out.push_str(&container_prefix(&head));
+                self.items(head.argument, |writer| writer.item())?;
+                self.out.push(']');
+            }
+            5 => {
+                self.out.push('{');
+                self.out.push_str(&container_prefix(&head));
+                self.items(head.argument, |writer| {
+                    writer.item()?;
+                    writer.out.push_str(": ");
+                    writer.item()
+                })?;
+                self.out.push('}');
+            }
+            6 => {
+                self.out.push_str(&format!("{}{}(", head.argument(), head.indicator()));
+                self.item()?;
+                self.out.push(')');
+            }
+            _ => self.simple(&head, offset)?,
+        }
+        Ok(())
+    }
+
+    fn items(
+        &mut self,
+        len: Option<u64>,
+        mut item: impl FnMut(&mut Self) -> Result<(), String>,
+    ) -> Result<(), String> {
+        let mut count = 0u64;
+        while match len {
+            Some(len) => count < len,
+            None => !self.is_break()?,
+        } {
+            if count > 0 {
+                self.out.push_str(", ");
+            }
+            item(self)?;
+            count += 1;
+        }
+        Ok(())
+    }
+
+    fn string(&mut self, major: u8, len: u64, offset: usize) -> Result<String, String> {
+        let bytes = self.take(len)?;
+        if major == 2 {
+            return Ok(format!("h'{}'", hex::encode(bytes)));
+        }
+        let text = std::str::from_utf8(bytes)
+            .map_err(|_| format!("invalid UTF-8 in the text string at offset {}", offset))?;
+        serde_json::to_string(text).map_err(|e| e.to_string())
+    }
+
+    fn chunked_string(&mut self, major: u8) -> Result<(), String> {
+        let mut chunks = Vec::new();
+        while !self.is_break()? {
+            let offset = self.pos;
+            let chunk = self.head()?;
+            match chunk.argument {
+                Some(len) if chunk.major == major => {
+                    let string = self.string(major, len, offset)?;
+                    chunks.push(format!("{}{}", string, chunk.indicator()));
+                }
+                _ => return Err(format!("invalid chunk of an indefinite length string at offset {}", offset)),
+            }
+        }
+        if chunks.is_empty() {
+            self.out.push_str(if major == 2 { "''_" } else { "\"\"_" });
+        } else {
+            self.out.push_str(&format!("(_ {})", chunks.join(", ")));
+        }
+        Ok(())
+    }
+
+    fn simple(&mut self, head: &Head, offset: usize) -> Result<(), String> {
+        let value = match (head.additional_info, head.argument()) {
+            (20, _) => "false".to_string(),
+            (21, _) => "true".to_string(),
+            (22, _) => "null".to_string(),
+            (23, _) => "undefined".to_string(),
+            (0..=19, value) => format!("simple({})", value),
+            (24, value) if value >= 32 => format!("simple({})", value),
+            (25..=27, _) => return Err(format!("floating point values are not supported (offset {})", offset)),
+            _ => return Err(format!("invalid simple value at offset {}", offset)),
+        };
+        self.out.push_str(&value);
+        Ok(())
+    }
+}
+
+fn container_prefix(head: &Head) -> String {
+    match head.argument {
+        None => "_ ".to_string(),
+        Some(_) => match head.indicator() {
+            indicator if indicator.is_empty() => indicator,
+            indicator => format!("{} ", indicator),
+        },
+    }
+}
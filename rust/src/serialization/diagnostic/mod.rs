@@ -0,0 +1,29 @@
+mod diagnostic_parser;
+mod diagnostic_writer;
+
+use crate::*;
+use diagnostic_parser::DiagnosticParser;
+use diagnostic_writer::DiagnosticWriter;
+
+/// Converts a single CBOR item to RFC 8949 extended diagnostic notation, e.g. `121_0([_ h'cafe', 1])`.
+/// Tags, indefinite lengths and non-shortest argument encodings are all written,
+/// so `encode_diagnostic_to_cbor` gives back the exact same bytes.
+#[wasm_bindgen]
+pub fn decode_cbor_to_diagnostic(bytes: &[u8]) -> Result<String, JsError> {
+    cbor_to_diagnostic(bytes).map_err(|e| JsError::from_str(&format!("Invalid CBOR: {}", e)))
+}
+
+/// Parses extended diagnostic notation back to CBOR bytes
+#[wasm_bindgen]
+pub fn encode_diagnostic_to_cbor(diagnostic: &str) -> Result<Vec<u8>, JsError> {
+    diagnostic_to_cbor(diagnostic)
+        .map_err(|e| JsError::from_str(&format!("Invalid diagnostic notation: {}", e)))
+}
+
+pub(crate) fn cbor_to_diagnostic(bytes: &[u8]) -> Result<String, String> {
+    DiagnosticWriter::new(bytes).write()
+}
+
+pub(crate) fn diagnostic_to_cbor(diagnostic: &str) -> Result<Vec<u8>, String> {
+    DiagnosticParser::new(diagnostic).parse()
+}
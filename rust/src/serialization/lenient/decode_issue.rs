@@ -0,0 +1,74 @@
+use crate::*;
+
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd, serde::Serialize)]
+pub enum DecodeIssueKind {
+    /// The bytes aren't valid CBOR, nothing after this point was decoded
+    MalformedCbor,
+    TrailingBytes,
+    /// A map key that isn't a field of the structure, the entry was skipped
+    UnknownKey,
+    /// A repeated map key, only the first entry was kept
+    DuplicateKey,
+    /// A set with a tag other than 258 or a 258 tag on a field that isn't a set, the tag was skipped
+    WrongSetTag,
+    /// An integer or length not written in its shortest form, the value was still decoded
+    NonCanonicalInteger,
+    /// An element of a list that couldn't be decoded, the element was skipped
+    InvalidElement,
+    /// A part of the transaction couldn't be decoded even after skipping the other issues
+    InvalidTransaction,
+}
+
+/// A problem found while decoding, with the path and byte offset of the item it concerns
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize)]
+pub struct DecodeIssue {
+    pub(crate) kind: DecodeIssueKind,
+    pub(crate) path: String,
+    pub(crate) offset: usize,
+    pub(crate) message: String,
+}
+
+#[wasm_bindgen]
+impl DecodeIssue {
+    pub fn kind(&self) -> DecodeIssueKind {
+        self.kind
+    }
+
+    /// Path of the item using the ledger CDDL names, e.g. `transaction.transaction_body.outputs[1]`
+    pub fn path(&self) -> String {
+        self.path.clone()
+    }
+
+    /// Offset of the item in the decoded bytes
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn message(&self) -> String {
+        self.message.clone()
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize)]
+pub struct DecodeIssues(pub(crate) Vec<DecodeIssue>);
+
+impl_vec_wrapper!(DecodeIssues, DecodeIssue);
+
+#[wasm_bindgen]
+impl DecodeIssues {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn get(&self, index: usize) -> DecodeIssue {
+        self.0[index].clone()
+    }
+
+    pub fn to_json(&self) -> Result<String, JsError> {
+        serde_json::to_string_pretty(&self)
+            .map_err(|e| JsError::from_str(&format!("to_json: {}", e)))
+    }
+}
@@ -0,0 +1,318 @@
+use crate::*;
+use std::collections::HashSet;
+use std::io::Cursor;
+
+/// Result of `Transaction::from_bytes_lenient`
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct LenientTransaction {
+    pub(crate) body: Option<TransactionBody>,
+    pub(crate) witness_set: Option<TransactionWitnessSet>,
+    pub(crate) auxiliary_data: Option<AuxiliaryData>,
+    pub(crate) transaction: Option<Transaction>,
+    pub(crate) issues: DecodeIssues,
+}
+
+#[wasm_bindgen]
+impl LenientTransaction {
+    /// The decoded transaction, None if any of its parts couldn't be decoded
+    pub fn transaction(&self) -> Option<Transaction> {
+        self.transaction.clone()
+    }
+
+    /// The body, even when other parts of the transaction couldn't be decoded
+    pub fn body(&self) -> Option<TransactionBody> {
+        self.body.clone()
+    }
+
+    /// The witness set, even when other parts of the transaction couldn't be decoded
+    pub fn witness_set(&self) -> Option<TransactionWitnessSet> {
+        self.witness_set.clone()
+    }
+
+    /// The auxiliary data, None when the transaction has none or it couldn't be decoded
+    pub fn auxiliary_data(&self) -> Option<AuxiliaryData> {
+        self.auxiliary_data.clone()
+    }
+
+    pub fn issues(&self) -> DecodeIssues {
+        self.issues.clone()
+    }
+
+    pub fn has_issues(&self) -> bool {
+        !self.issues.0.is_empty()
+    }
+}
+
+#[wasm_bindgen]
+impl Transaction {
+    /// Diagnostic decoding for transactions rejected by `from_bytes`.
+    /// Instead of stopping at the first error it skips unknown and duplicate map keys,
+    /// wrong set tags and list elements that can't be decoded, and reports each of them
+    /// together with non-canonical integers.
+    /// The body, witness set and auxiliary data are decoded independently,
+    /// so a part that can't be decoded doesn't hide the others.
+    /// When something was skipped the parts are decoded from repaired bytes,
+    /// so their hashes aren't the hashes of the original bytes.
+    pub fn from_bytes_lenient(bytes: &[u8]) -> LenientTransaction {
+        LenientDecoder {
+            bytes,
+            issues: Vec::new(),
+        }
+        .decode()
+    }
+}
+
+struct LenientDecoder<'a> {
+    bytes: &'a [u8],
+    issues: Vec<DecodeIssue>,
+}
+
+impl<'a> LenientDecoder<'a> {
+    fn decode(mut self) -> LenientTransaction {
+        let mut result = LenientTransaction {
+            body: None,
+            witness_set: None,
+            auxiliary_data: None,
+            transaction: None,
+            issues: DecodeIssues(Vec::new()),
+        };
+        if let Some(root) = self.explore() {
+            self.decode_parts(&root, &mut result);
+        }
+        result.issues = DecodeIssues(self.issues);
+        result
+    }
+
+    // the explored transaction, None when it isn't well formed CBOR.
+    // The explorer stops at nesting too deep for the recursion, so `repair` only walks bounded trees
+    fn explore(&mut self) -> Option<CborNode> {
+        let root = match CborExplorer::explore_transaction(self.bytes) {
+            Ok(root) => root,
+            Err(_) => {
+                self.issue(DecodeIssueKind::MalformedCbor, "transaction", 0, "no bytes to decode".to_string());
+                return None;
+            }
+        };
+        let has_malformed_items = self.report_malformed(&root, "transaction");
+        if let Some(error) = &root.error {
+            if !has_malformed_items && root.trailing {
+                self.issue(DecodeIssueKind::TrailingBytes, "transaction", root.length, error.clone());
+            } else {
+                self.issue(DecodeIssueKind::MalformedCbor, "transaction", root.offset, error.clone());
+                return None;
+            }
+        }
+        if has_malformed_items {
+            return None;
+        }
+        Some(root)
+    }
+
+    // [body, witness set, auxiliary data] before Alonzo and [body, witness set, is valid, auxiliary data] since
+    fn decode_parts(&mut self, root: &CborNode, result: &mut LenientTransaction) {
+        let parts = &root.children;
+        if root.major_type != CborMajorType::Array || !(3..=4).contains(&parts.len()) {
+            let message = "a transaction is an array of 3 or 4 items".to_string();
+            self.issue(DecodeIssueKind::InvalidTransaction, "transaction", root.offset, message);
+            return;
+        }
+        let paths = root.child_paths("transaction");
+        result.body = self.decode_part(&parts[0], &paths[0]);
+        result.witness_set = self.decode_part(&parts[1], &paths[1]);
+        let is_valid = match parts.len() {
+            4 => match (parts[2].major_type, parts[2].value.as_deref()) {
+                (CborMajorType::Simple, Some("true")) => Some(true),
+                (CborMajorType::Simple, Some("false")) => Some(false),
+                _ => {
+                    let message = "is_valid must be a bool".to_string();
+                    self.issue(DecodeIssueKind::InvalidTransaction, &paths[2], parts[2].offset, message);
+                    None
+                }
+            },
+            _ => Some(true),
+        };
+        let auxiliary_data = match &parts[parts.len() - 1] {
+            null if null.major_type == CborMajorType::Simple && null.value.as_deref() == Some("null") => {
+                Some(None)
+            }
+            node => self
+                .decode_part::<AuxiliaryData>(node, &paths[parts.len() - 1])
+                .map(Some),
+        };
+        result.auxiliary_data = auxiliary_data.clone().flatten();
+        if let (Some(body), Some(witness_set), Some(is_valid), Some(auxiliary_data)) =
+            (&result.body, &result.witness_set, is_valid, auxiliary_data)
+        {
+            let mut transaction = Transaction::new(body, witness_set, auxiliary_data);
+            transaction.set_is_valid(is_valid);
+            result.transaction = Some(transaction);
+        }
+    }
+
+    fn decode_part<T: Deserialize>(&mut self, node: &CborNode, path: &str) -> Option<T> {
+        let repaired = self.repair(node, path, node.name.as_deref());
+        match decode::<T>(&repaired) {
+            Ok(part) => Some(part),
+            Err(e) => {
+                self.issue(DecodeIssueKind::InvalidTransaction, path, node.offset, e.to_string());
+                None
+            }
+        }
+    }
+
+    fn issue(&mut self, kind: DecodeIssueKind, path: &str, offset: usize, message: String) {
+        self.issues.push(DecodeIssue {
+            kind,
+            path: path.to_string(),
+            offset,
+            message,
+        });
+    }
+
+    // reports the errors of the descendants, the error of the node itself is left to the caller
+    fn report_malformed(&mut self, node: &CborNode, path: &str) -> bool {
+        let mut found = false;
+        let paths = node.child_paths(path);
+        for (child, child_path) in node.children.iter().zip(paths.iter()) {
+            for item in child.key.iter().map(|key| key.as_ref()).chain(std::iter::once(child)) {
+                if let Some(error) = &item.error {
+                    self.issue(DecodeIssueKind::MalformedCbor, child_path, item.offset, error.clone());
+                    found = true;
+                }
+                found |= self.report_malformed(item, child_path);
+            }
+        }
+        found
+    }
+
+    // bytes of the node without the skipped items, `field` is the ledger name of the node
+    fn repair(&mut self, node: &CborNode, path: &str, field: Option<&str>) -> Vec<u8> {
+        self.check_canonical(node, path);
+        match node.major_type {
+            CborMajorType::Tag => {
+                let child = &node.children[0];
+                let mut out = self.bytes[node.offset..node.offset + node.head_length].to_vec();
+                out.extend(self.repair(child, path, field));
+                out
+            }
+            CborMajorType::Array => {
+                let check = field.and_then(element_check);
+                let mut count = 0;
+                let mut content = Vec::new();
+                let paths = node.child_paths(path);
+                for (child, child_path) in node.children.iter().zip(paths.iter()) {
+                    let bytes = self.repair(child, child_path, child.name.as_deref());
+                    if let Some(Err(e)) = check.map(|check| check(&bytes)) {
+                        self.issue(DecodeIssueKind::InvalidElement, child_path, child.offset, e.to_string());
+                        continue;
+                    }
+                    content.extend(bytes);
+                    count += 1;
+                }
+                self.container(node, count, content)
+            }
+            CborMajorType::Map => {
+                let is_struct = matches!(field, Some(field) if STRUCT_MAPS.iter().any(|(name, _)| *name == field));
+                let mut seen_keys = HashSet::new();
+                let mut count = 0;
+                let mut content = Vec::new();
+                let paths = node.child_paths(path);
+                for (child, child_path) in node.children.iter().zip(paths.iter()) {
+                    let key = match &child.key {
+                        Some(key) => key,
+                        None => continue,
+                    };
+                    if is_struct && child.name.is_none() {
+                        let message = format!(
+                            "unknown key {} in {}",
+                            key.value.clone().unwrap_or_default(),
+                            field.unwrap_or_default()
+                        );
+                        self.issue(DecodeIssueKind::UnknownKey, child_path, key.offset, message);
+                        continue;
+                    }
+                    if is_struct && !seen_keys.insert(key.argument) {
+                        let message = format!("key {} is repeated, the first entry is used", key.argument.unwrap_or_default());
+                        self.issue(DecodeIssueKind::DuplicateKey, child_path, key.offset, message);
+                        continue;
+                    }
+                    content.extend(self.repair(key, child_path, None));
+                    if is_struct {
+                        content.extend(self.repair_field(child, child_path));
+                    } else {
+                        content.extend(self.repair(child, child_path, None));
+                    }
+                    count += 1;
+                }
+                self.container(node, count, content)
+            }
+            _ => self.bytes[node.offset..node.offset + node.length].to_vec(),
+        }
+    }
+
+    // a field of a structure map, sets must have the 258 tag or no tag and other fields no 258 tag
+    fn repair_field(&mut self, node: &CborNode, path: &str) -> Vec<u8> {
+        let name = node.name.as_deref();
+        if let (CborMajorType::Tag, Some(inner), Some(name)) = (node.major_type, node.children.first(), name) {
+            let is_set = SET_FIELDS.contains(&name);
+            let tag = node.argument.unwrap_or_default();
+            if is_set != (tag == 258) && (is_set || inner.major_type == CborMajorType::Array) {
+                let message = if is_set {
+                    format!("{} is a set but has tag {} instead of 258", name, tag)
+                } else {
+                    format!("{} isn't a set but has the set tag 258", name)
+                };
+                self.issue(DecodeIssueKind::WrongSetTag, path, node.offset, message);
+                return self.repair(inner, path, Some(name));
+            }
+        }
+        self.repair(node, path, name)
+    }
+
+    fn check_canonical(&mut self, node: &CborNode, path: &str) {
+        if let Some(message) = node.non_canonical_head() {
+            self.issue(DecodeIssueKind::NonCanonicalInteger, path, node.offset, message);
+        }
+    }
+
+    fn container(&self, node: &CborNode, count: u64, content: Vec<u8>) -> Vec<u8> {
+        let mut out = if node.indefinite || node.argument == Some(count) {
+            self.bytes[node.offset..node.offset + node.head_length].to_vec()
+        } else {
+            write_head(node.major_type as u8, count)
+        };
+        out.extend(content);
+        if node.indefinite {
+            out.push(0xff);
+        }
+        out
+    }
+}
+
+type ElementCheck = fn(&[u8]) -> Result<(), DeserializeError>;
+
+// elements of these lists are decoded on their own so that a broken element can be skipped
+fn element_check(field: &str) -> Option<ElementCheck> {
+    match field {
+        "inputs" | "collateral" | "reference_inputs" => Some(check::<TransactionInput>),
+        "outputs" => Some(check::<TransactionOutput>),
+        "certs" => Some(check::<Certificate>),
+        "proposal_procedures" => Some(check::<VotingProposal>),
+        "required_signers" => Some(check::<Ed25519KeyHash>),
+        "vkeywitnesses" => Some(check::<Vkeywitness>),
+        "bootstrap_witnesses" => Some(check::<BootstrapWitness>),
+        "native_scripts" => Some(check::<NativeScript>),
+        "plutus_data" => Some(check::<PlutusData>),
+        _ => None,
+    }
+}
+
+fn check<T: Deserialize>(bytes: &[u8]) -> Result<(), DeserializeError> {
+    decode::<T>(bytes).map(|_| ())
+}
+
+fn decode<T: Deserialize>(bytes: &[u8]) -> Result<T, DeserializeError> {
+    let mut raw = Deserializer::from(Cursor::new(bytes));
+    T::deserialize(&mut raw)
+}
@@ -0,0 +1,5 @@
+mod decode_issue;
+pub use decode_issue::*;
+
+mod lenient_decoder;
+pub use lenient_decoder::*;
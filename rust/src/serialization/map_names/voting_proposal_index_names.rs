@@ -8,3 +8,18 @@ pub(crate) enum VotingProposalIndexNames {
     NewConstitutionAction = 5,
     InfoAction = 6,
 }
+
+impl VotingProposalIndexNames {
+    /// Name of the field in the ledger CDDL
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            VotingProposalIndexNames::ParameterChangeAction => "parameter_change_action",
+            VotingProposalIndexNames::HardForkInitiationAction => "hard_fork_initiation_action",
+            VotingProposalIndexNames::TreasuryWithdrawalsAction => "treasury_withdrawals_action",
+            VotingProposalIndexNames::NoConfidenceAction => "no_confidence_action",
+            VotingProposalIndexNames::UpdateCommitteeAction => "update_committee_action",
+            VotingProposalIndexNames::NewConstitutionAction => "new_constitution_action",
+            VotingProposalIndexNames::InfoAction => "info_action",
+        }
+    }
+}
@@ -9,3 +9,19 @@ pub(crate) enum WitnessSetNames {
     PlutusScriptsV2 = 6,
     PlutusScriptsV3 = 7,
 }
+
+impl WitnessSetNames {
+    /// Name of the field in the ledger CDDL
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            WitnessSetNames::Vkeys => "vkeywitnesses",
+            WitnessSetNames::NativeScripts => "native_scripts",
+            WitnessSetNames::Bootstraps => "bootstrap_witnesses",
+            WitnessSetNames::PlutusScriptsV1 => "plutus_v1_scripts",
+            WitnessSetNames::PlutusData => "plutus_data",
+            WitnessSetNames::Redeemers => "redeemers",
+            WitnessSetNames::PlutusScriptsV2 => "plutus_v2_scripts",
+            WitnessSetNames::PlutusScriptsV3 => "plutus_v3_scripts",
+        }
+    }
+}
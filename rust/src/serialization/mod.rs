@@ -5,6 +5,18 @@ pub(super) use traits::*;
 mod ser_info;
 pub use ser_info::*;
 
+mod diagnostic;
+pub use diagnostic::*;
+
+mod cbor_explorer;
+pub use cbor_explorer::*;
+
+mod lenient;
+pub use lenient::*;
+
+mod conformance;
+pub use conformance::*;
+
 mod general;
 mod serialization_macros;
 mod certificates;
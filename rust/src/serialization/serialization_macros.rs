@@ -94,6 +94,37 @@ macro_rules! to_hex {
     };
 }
 
+#[macro_export]
+macro_rules! to_from_diagnostic {
+    ($name:ident) => {
+        #[wasm_bindgen]
+        impl $name {
+            /// CBOR of this type in RFC 8949 extended diagnostic notation
+            pub fn to_diagnostic(&self) -> Result<String, JsError> {
+                decode_cbor_to_diagnostic(&self.to_bytes())
+            }
+        }
+        // wasm-exposed JsError return - JsError panics when used outside wasm
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten"), not(feature = "dont-expose-wasm")))]
+        #[wasm_bindgen]
+        impl $name {
+            pub fn from_diagnostic(diagnostic: &str) -> Result<$name, JsError> {
+                Ok(Self::from_bytes(encode_diagnostic_to_cbor(diagnostic)?)?)
+            }
+        }
+        // non-wasm exposed DeserializeError return
+        #[cfg(not(all(target_arch = "wasm32", not(target_os = "emscripten"), not(feature = "dont-expose-wasm"))))]
+        impl $name {
+            pub fn from_diagnostic(diagnostic: &str) -> Result<$name, DeserializeError> {
+                let bytes = diagnostic_to_cbor(diagnostic).map_err(|e| {
+                    DeserializeError::new("from_diagnostic", DeserializeFailure::CustomError(e))
+                })?;
+                Self::from_bytes(bytes)
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! to_from_bytes {
     ($name:ident) => {
@@ -101,6 +132,7 @@ macro_rules! to_from_bytes {
         from_bytes!($name);
         to_hex!($name);
         from_hex!($name);
+        to_from_diagnostic!($name);
     };
 }
 
@@ -693,4 +693,113 @@ fn byron_addr_type_pubkey() {
 fn byron_addr_type_redeem() {
     let byron_addr = ByronAddress::from_base58("Ae2tdPwUPEZ9vtyppa1FdJzvqJZkEcXgdHxVYAzTWcPaoNycVq5rc36LC1S").unwrap();
     assert_eq!(byron_addr.byron_address_kind(), ByronAddressType::ATRedeem);
-}
\ No newline at end of file
+}
+
+fn daedalus_root_key() -> LegacyDaedalusPrivateKey {
+    LegacyDaedalusPrivateKey::from_bytes(&root_key_12().as_bytes()).unwrap()
+}
+
+#[test]
+fn byron_daedalus_address_from_key() {
+    let root_key = daedalus_root_key();
+    let passphrase = DaedalusHDPassphrase::from_root_key(&root_key);
+    let mut path = DaedalusDerivationPath::new();
+    path.add(harden(0));
+    path.add(harden(3));
+    let key = root_key.derive(harden(0)).derive(harden(3)).to_public();
+    assert_eq!(root_key.derive_path(&path).to_public().as_bytes(), key.as_bytes());
+    // Daedalus keys don't follow BIP32-Ed25519
+    assert_ne!(key.as_bytes(), root_key_12().derive(harden(0)).derive(harden(3)).to_public().as_bytes());
+
+    let mainnet = ByronAddress::daedalus_from_key(&root_key, &path, NetworkInfo::mainnet().protocol_magic());
+    assert!(mainnet.to_base58().starts_with("DdzFF"));
+    let parsed = ByronAddress::from_base58(&mainnet.to_base58()).unwrap();
+    assert!(parsed.matches_key(&key));
+    assert!(!parsed.matches_key(&root_key.to_public()));
+    assert_eq!(passphrase.derivation_path(&parsed), Some(path.clone()));
+
+    let preprod =
+        ByronAddress::daedalus_from_key(&root_key, &path, NetworkInfo::testnet_preprod().protocol_magic());
+    assert_eq!(preprod.byron_protocol_magic(), NetworkInfo::testnet_preprod().protocol_magic());
+    assert!(preprod.matches_key(&key));
+
+    // historical address and its public key
+    let historical = ByronAddress::from_base58("DdzFFzCqrhsrcTVhLygT24QwTnNqQqQ8mZrq5jykUzMveU26sxaH529kMpo7VhPrt5pwW3dXeB2k3EEvKcNBRmzCfcQ7dTkyGzTs658C").unwrap();
+    let historical_key = Bip32PublicKey::from_bytes(&hex::decode("6a509689c653175865985ad1e0eb5ff9ada6997aa403e648614b3b78fcba9c27308228d9872af8b65b987ff23e1a20cd90d8346c31f0edb8998952dc67665580").unwrap()).unwrap();
+    assert!(historical.matches_key(&historical_key));
+    assert!(!historical.matches_key(&key));
+    assert!(!passphrase.is_own_address(&historical));
+    // same layout as the mainnet address: root, a payload of 2 encrypted indexes and no protocol magic
+    let (historical_bytes, mainnet_bytes) = (historical.to_bytes(), mainnet.to_bytes());
+    assert_eq!(mainnet_bytes.len(), historical_bytes.len());
+    assert_eq!(mainnet_bytes[..8], historical_bytes[..8]);
+    assert_eq!(mainnet_bytes[36..42], historical_bytes[36..42]);
+}
+
+#[test]
+fn byron_daedalus_address_is_spendable() {
+    // the path recovered from the address gives the key signing for it
+    let root_key = daedalus_root_key();
+    let mut path = DaedalusDerivationPath::new();
+    path.add(harden(1));
+    path.add(harden(42));
+    let address = ByronAddress::daedalus_from_key(&root_key, &path, NetworkInfo::mainnet().protocol_magic());
+    let recovered = DaedalusHDPassphrase::from_root_key(&root_key).derivation_path(&address).unwrap();
+    let key = root_key.derive_path(&recovered);
+
+    let tx_hash = TransactionHash::from([5; TransactionHash::BYTE_COUNT]);
+    let witness = make_daedalus_bootstrap_witness(&tx_hash, &address, &key);
+    assert!(witness.vkey().public_key().verify(&tx_hash.to_bytes(), &witness.signature()));
+    assert_eq!(witness.attributes(), address.attributes());
+    let witness_key = Bip32PublicKey::from_bytes(
+        &[witness.vkey().public_key().as_bytes(), witness.chain_code()].concat(),
+    )
+    .unwrap();
+    assert!(address.matches_key(&witness_key));
+}
+
+#[test]
+fn byron_redeem_address_from_key() {
+    let key = PrivateKey::from_normal_bytes(&[7; 32]).unwrap().to_public();
+    let other_key = PrivateKey::from_normal_bytes(&[8; 32]).unwrap().to_public();
+    let address = ByronAddress::redeem_from_key(&key, NetworkInfo::mainnet().protocol_magic());
+    let parsed = ByronAddress::from_base58(&address.to_base58()).unwrap();
+    assert_eq!(parsed, address);
+    assert_eq!(parsed.byron_address_kind(), ByronAddressType::ATRedeem);
+    assert!(parsed.matches_redeem_key(&key));
+    assert!(!parsed.matches_redeem_key(&other_key));
+    assert!(!parsed.matches_script(0, &key.as_bytes()));
+    assert_eq!(parsed.network_id().unwrap(), NetworkInfo::mainnet().network_id());
+}
+
+#[test]
+fn byron_script_address() {
+    let address = ByronAddress::from_script(0, &[1, 2, 3], NetworkInfo::testnet_preview().protocol_magic());
+    let parsed = ByronAddress::from_base58(&address.to_base58()).unwrap();
+    assert_eq!(parsed.byron_address_kind(), ByronAddressType::ATScript);
+    assert!(parsed.matches_script(0, &[1, 2, 3]));
+    assert!(!parsed.matches_script(1, &[1, 2, 3]));
+    assert!(!parsed.matches_script(0, &[1, 2]));
+    assert_eq!(parsed.network_id().unwrap(), NetworkInfo::testnet_preview().network_id());
+}
+
+#[test]
+fn byron_bootstrap_witness_checks_the_key() {
+    let root_key = root_key_15();
+    let key = root_key.derive(harden(44)).derive(harden(1815)).derive(harden(0)).derive(0).derive(0);
+    let tx_hash = TransactionHash::from([5; TransactionHash::BYTE_COUNT]);
+    let icarus = ByronAddress::icarus_from_key(&key.to_public(), NetworkInfo::mainnet().protocol_magic());
+    let witness = make_byron_bootstrap_witness(&tx_hash, &icarus, &key).unwrap();
+    assert_eq!(witness, make_icarus_bootstrap_witness(&tx_hash, &icarus, &key));
+    assert!(make_byron_bootstrap_witness(&tx_hash, &icarus, &root_key).is_err());
+
+    let daedalus_root = LegacyDaedalusPrivateKey::from_bytes(&root_key.as_bytes()).unwrap();
+    let mut path = DaedalusDerivationPath::new();
+    path.add(harden(0));
+    let daedalus = ByronAddress::daedalus_from_key(&daedalus_root, &path, NetworkInfo::mainnet().protocol_magic());
+    // the BIP32-Ed25519 child isn't the key of a Daedalus address
+    assert!(make_byron_bootstrap_witness(&tx_hash, &daedalus, &root_key.derive(harden(0))).is_err());
+
+    let redeem = ByronAddress::redeem_from_key(&key.to_raw_key().to_public(), NetworkInfo::mainnet().protocol_magic());
+    assert!(make_byron_bootstrap_witness(&tx_hash, &redeem, &key).is_err());
+}
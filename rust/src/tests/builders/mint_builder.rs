@@ -582,4 +582,67 @@ fn redeemer_tag_index_test() {
     assert_eq!(first_redeemer.index(), BigNum::from(first_index as u64));
     assert_eq!(second_redeemer.index(), BigNum::from(second_index as u64));
     assert_eq!(third_redeemer.index(), BigNum::from(third_index as u64));
-}
\ No newline at end of file
+}
+
+#[test]
+fn cip68_mint_test() {
+    let native_script = NativeScript::new_timelock_start(
+        &TimelockStart::new_timelockstart(&BigNum::from(100u64)),
+    );
+    let script_hash = native_script.hash();
+    let mint_witness = MintWitness::new_native_script(&NativeScriptSource::new(&native_script));
+    let datum = Cip68Datum::from_json_metadata(r#"{"name": "SpaceBud #1"}"#, 1, None).unwrap();
+    let reference_address = Address::from_bech32("addr_test1qpm5njmgzf4t7225v6j34wl30xfrufzt3jtqtdzf3en9ahpmnhtmynpasyc8fq75zv0uaj86vzsr7g3g8q5ypgu5fwtqr9zsgj").unwrap();
+    let data_cost = DataCost::new_coins_per_byte(&BigNum::from(4310u64));
+
+    let mut mint_builder = MintBuilder::new();
+    let output = mint_builder
+        .add_cip68_assets(
+            &mint_witness,
+            b"SpaceBud1".to_vec(),
+            &Cip67Label::fungible_token(),
+            &BigNum::from(1000u64),
+            &datum,
+            &reference_address,
+            &data_cost,
+        )
+        .unwrap();
+
+    let reference_name = Cip67Label::reference_nft().asset_name(b"SpaceBud1".to_vec()).unwrap();
+    let user_name = Cip67Label::fungible_token().asset_name(b"SpaceBud1".to_vec()).unwrap();
+    let mint = mint_builder.build().unwrap();
+    let policy_mints = mint.get(&script_hash).unwrap().get(0).unwrap();
+    assert_eq!(policy_mints.len(), 2);
+    assert_eq!(policy_mints.get(&reference_name).unwrap().to_str(), "1");
+    assert_eq!(policy_mints.get(&user_name).unwrap().to_str(), "1000");
+
+    assert_eq!(output.address(), reference_address);
+    assert_eq!(output.plutus_data(), Some(datum.to_plutus_data()));
+    let output_assets = output.amount().multiasset().unwrap();
+    assert_eq!(output_assets.get_asset(&script_hash, &reference_name), BigNum::one());
+    assert_eq!(output_assets.get_asset(&script_hash, &user_name), BigNum::zero());
+    let min_ada = min_ada_for_output(&output, &data_cost).unwrap();
+    assert_eq!(output.amount().coin(), min_ada);
+
+    let reference_label_res = mint_builder.add_cip68_assets(
+        &mint_witness,
+        b"SpaceBud2".to_vec(),
+        &Cip67Label::reference_nft(),
+        &BigNum::one(),
+        &datum,
+        &reference_address,
+        &data_cost,
+    );
+    assert!(reference_label_res.is_err());
+    let zero_amount_res = mint_builder.add_cip68_assets(
+        &mint_witness,
+        b"SpaceBud2".to_vec(),
+        &Cip67Label::nft(),
+        &BigNum::zero(),
+        &datum,
+        &reference_address,
+        &data_cost,
+    );
+    assert!(zero_amount_res.is_err());
+    assert_eq!(mint_builder.build().unwrap().get(&script_hash).unwrap().get(0).unwrap().len(), 2);
+}
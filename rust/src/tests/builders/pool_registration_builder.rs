@@ -0,0 +1,169 @@
+use crate::tests::fakes::{
+    fake_change_address, fake_key_hash, fake_reallistic_tx_builder, fake_reward_address,
+    fake_tx_input, fake_vrf_key_hash,
+};
+use crate::*;
+
+const METADATA: &str = r#"{"name":"Test Pool","description":"A pool for the tests","ticker":"TEST","homepage":"https://example.com"}"#;
+
+fn pool_builder() -> PoolRegistrationBuilder {
+    PoolRegistrationBuilder::new(
+        &fake_key_hash(1),
+        &fake_vrf_key_hash(2),
+        &BigNum(100_000_000_000),
+        &BigNum(340_000_000),
+        &UnitInterval::new(&BigNum(1), &BigNum(100)),
+        &fake_reward_address(3),
+    )
+    .unwrap()
+}
+
+#[test]
+fn pool_metadata_json() {
+    let metadata = PoolMetadataJson::from_json(METADATA).unwrap();
+    assert_eq!(metadata.name(), "Test Pool");
+    assert_eq!(metadata.ticker(), "TEST");
+    assert_eq!(metadata.description(), "A pool for the tests");
+    assert_eq!(metadata.homepage(), "https://example.com");
+    assert_eq!(metadata.to_json(), METADATA);
+    assert_eq!(metadata.hash().to_bytes(), blake2b256(METADATA.as_bytes()).to_vec());
+    // the hash is the one of the bytes as published
+    let pretty = format!("{}\n", METADATA);
+    assert_ne!(PoolMetadataJson::from_json(&pretty).unwrap().hash(), metadata.hash());
+
+    let with_ticker = |ticker: &str| METADATA.replace("\"TEST\"", ticker);
+    assert!(PoolMetadataJson::from_json(&with_ticker("\"AB\"")).is_err());
+    assert!(PoolMetadataJson::from_json(&with_ticker("\"ABCDEF\"")).is_err());
+    assert!(PoolMetadataJson::from_json(&with_ticker("\"ÅBÇ\"")).is_ok());
+    assert!(PoolMetadataJson::from_json(&with_ticker("5")).is_err());
+    let long_name = METADATA.replace("Test Pool", &"n".repeat(51));
+    assert!(PoolMetadataJson::from_json(&long_name).is_err());
+    let long_homepage = METADATA.replace("example.com", &"e".repeat(60));
+    assert!(PoolMetadataJson::from_json(&long_homepage).is_err());
+    let long_description = METADATA.replace("A pool for the tests", &"d".repeat(256));
+    assert!(PoolMetadataJson::from_json(&long_description).is_err());
+    let extended = METADATA.replace("}", r#","extended":"https://example.com/x.json"}"#);
+    assert!(PoolMetadataJson::from_json(&extended).is_ok());
+    let too_big = METADATA.replace("}", &format!(r#","extra":"{}"}}"#, "x".repeat(400)));
+    assert!(PoolMetadataJson::from_json(&too_big).is_err());
+    assert!(PoolMetadataJson::from_json(r#"{"name":"Test Pool"}"#).is_err());
+    assert!(PoolMetadataJson::from_json("[]").is_err());
+    assert!(PoolMetadataJson::from_json("{").is_err());
+}
+
+#[test]
+fn pool_registration_relays() {
+    let mut builder = pool_builder();
+    let ipv4 = Ipv4::new(vec![10, 0, 0, 1]).unwrap();
+    builder
+        .add_relay(&Relay::new_single_host_addr(&SingleHostAddr::new(Some(3001), Some(ipv4.clone()), None)))
+        .unwrap();
+    assert!(builder
+        .add_relay(&Relay::new_single_host_addr(&SingleHostAddr::new(Some(3001), Some(ipv4), None)))
+        .is_err());
+    assert!(builder
+        .add_relay(&Relay::new_single_host_addr(&SingleHostAddr::new(Some(3001), None, None)))
+        .is_err());
+
+    let host = |name: &str| {
+        Relay::new_single_host_name(&SingleHostName::new(
+            Some(3001),
+            &DNSRecordAorAAAA::new(name.to_string()).unwrap(),
+        ))
+    };
+    builder.add_relay(&host("relay-1.example.com")).unwrap();
+    builder.add_relay(&host("relay2.example.com.")).unwrap();
+    assert!(builder.add_relay(&host("")).is_err());
+    assert!(builder.add_relay(&host("relay_1.example.com")).is_err());
+    assert!(builder.add_relay(&host("-relay.example.com")).is_err());
+    assert!(builder.add_relay(&host("relay..example.com")).is_err());
+    assert!(builder.add_relay(&host("relay example.com")).is_err());
+    assert!(builder.add_relay(&host(&format!("{}.com", "r".repeat(64)))).is_err());
+    assert!(builder.add_relay(&host(&"r.".repeat(40))).is_err());
+
+    let srv = |name: &str| {
+        Relay::new_multi_host_name(&MultiHostName::new(&DNSRecordSRV::new(name.to_string()).unwrap()))
+    };
+    builder.add_relay(&srv("_cardano._tcp.example.com")).unwrap();
+    assert!(builder.add_relay(&srv("_._tcp.example.com")).is_err());
+
+    let registration = builder.build();
+    assert_eq!(registration.pool_params().relays().len(), 4);
+}
+
+#[test]
+fn pool_registration_certificate_and_tx() {
+    let mut builder = pool_builder();
+    builder.add_owner(&fake_key_hash(4)).unwrap();
+    builder.add_owner(&fake_key_hash(5)).unwrap();
+    assert!(builder.add_owner(&fake_key_hash(4)).is_err());
+    let metadata = PoolMetadataJson::from_json(METADATA).unwrap();
+    let url = URL::new("https://example.com/pool.json".to_string()).unwrap();
+    builder.set_metadata(&url, &metadata).unwrap();
+    let long_url = URL::new(format!("https://example.com/{}.json", "p".repeat(50))).unwrap();
+    assert!(builder.set_metadata(&long_url, &metadata).is_err());
+
+    let params = builder.build().pool_params();
+    assert_eq!(params.operator(), fake_key_hash(1));
+    assert_eq!(params.pool_owners().len(), 2);
+    assert_eq!(params.pool_metadata(), Some(PoolMetadata::new(&url, &metadata.hash())));
+
+    let certificates = builder.build_certificates().unwrap();
+    assert_eq!(
+        certificates.build().get(0).as_pool_registration(),
+        Some(builder.build())
+    );
+    let signers = certificates.get_required_signers();
+    assert!(signers.contains(&fake_key_hash(1)));
+    assert!(signers.contains(&fake_key_hash(5)));
+
+    let mut tx_builder = fake_reallistic_tx_builder();
+    tx_builder.add_key_input(
+        &fake_key_hash(6),
+        &fake_tx_input(1),
+        &Value::new(&BigNum(1_000_000_000)),
+    );
+    tx_builder.set_certs_builder(&certificates);
+    tx_builder.add_change_if_needed(&fake_change_address()).unwrap();
+    assert_eq!(tx_builder.get_deposit().unwrap(), BigNum(500_000_000));
+    let body = tx_builder.build().unwrap();
+    let outputs: u64 = (0..body.outputs().len()).map(|i| body.outputs().get(i).amount().coin().0).sum();
+    assert_eq!(outputs + body.fee().0 + 500_000_000, 1_000_000_000);
+
+    // an update of the registered pool takes no deposit
+    let update = builder.build_certificates_for_update().unwrap();
+    assert_eq!(update.build(), certificates.build());
+    let mut tx_builder = fake_reallistic_tx_builder();
+    tx_builder.add_key_input(
+        &fake_key_hash(6),
+        &fake_tx_input(1),
+        &Value::new(&BigNum(1_000_000_000)),
+    );
+    tx_builder.set_certs_builder(&update);
+    tx_builder.add_change_if_needed(&fake_change_address()).unwrap();
+    assert_eq!(tx_builder.get_deposit().unwrap(), BigNum(0));
+    let body = tx_builder.build().unwrap();
+    let outputs: u64 = (0..body.outputs().len()).map(|i| body.outputs().get(i).amount().coin().0).sum();
+    assert_eq!(outputs + body.fee().0, 1_000_000_000);
+    assert_eq!(body.certs().unwrap().get(0).as_pool_registration(), Some(builder.build()));
+    // the body alone doesn't tell an update from a new registration
+    let (pool_deposit, key_deposit) = (BigNum(500_000_000), BigNum(2_000_000));
+    assert_eq!(get_deposit(&body, &pool_deposit, &key_deposit).unwrap(), pool_deposit);
+    let mut registered_pools = Ed25519KeyHashes::new();
+    registered_pools.add(&fake_key_hash(1));
+    assert_eq!(
+        get_deposit_with_registered_pools(&body, &pool_deposit, &key_deposit, &registered_pools)
+            .unwrap(),
+        BigNum(0)
+    );
+
+    assert!(PoolRegistrationBuilder::new(
+        &fake_key_hash(1),
+        &fake_vrf_key_hash(2),
+        &BigNum(0),
+        &BigNum(340_000_000),
+        &UnitInterval::new(&BigNum(2), &BigNum(1)),
+        &fake_reward_address(3),
+    )
+    .is_err());
+}
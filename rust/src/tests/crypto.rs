@@ -84,3 +84,341 @@ fn private_key_from_bech32() {
     let er = PrivateKey::from_bech32("qwe");
     assert!(er.is_err());
 }
+
+fn daedalus_root_key() -> LegacyDaedalusPrivateKey {
+    let entropy = [
+        0x0c, 0xcb, 0x74, 0xf3, 0x6b, 0x7d, 0xa1, 0x64, 0x9a, 0x81, 0x44, 0x67, 0x55, 0x22, 0xd4,
+        0xd8,
+    ];
+    let key = Bip32PrivateKey::from_bip39_entropy(&entropy, &[]);
+    LegacyDaedalusPrivateKey::from_bytes(&key.as_bytes()).unwrap()
+}
+
+fn daedalus_address(root_key: &LegacyDaedalusPrivateKey, payload: &[u8]) -> ByronAddress {
+    let xpub = root_key.0.to_public().0;
+    let attributes = legacy_address::Attributes::new_bootstrap_era(
+        Some(legacy_address::wrap_payload(payload)),
+        None,
+    );
+    ByronAddress(legacy_address::ExtendedAddr::new(&xpub, attributes))
+}
+
+#[test]
+fn daedalus_derivation_path_encryption() {
+    let passphrase = DaedalusHDPassphrase::from_root_key(&daedalus_root_key());
+    let mut path = DaedalusDerivationPath::new();
+    path.add(0x80000000);
+    path.add(0x80000001);
+
+    let payload = passphrase.encrypt_derivation_path(&path);
+    // 2 hardened indexes in an indefinite array, and the tag
+    assert_eq!(payload.len(), 12 + 16);
+    assert_eq!(passphrase.decrypt_derivation_path(&payload).unwrap(), path);
+
+    let other = DaedalusHDPassphrase::from_root_public_key(
+        &Bip32PrivateKey::from_bip39_entropy(&[0; 16], &[]).to_public(),
+    );
+    assert!(other.decrypt_derivation_path(&payload).is_err());
+
+    let mut tampered = payload.clone();
+    tampered[0] ^= 1;
+    assert!(passphrase.decrypt_derivation_path(&tampered).is_err());
+    assert!(passphrase.decrypt_derivation_path(&payload[..8]).is_err());
+}
+
+#[test]
+fn daedalus_address_recovery() {
+    let root_key = daedalus_root_key();
+    let passphrase = DaedalusHDPassphrase::from_root_key(&root_key);
+    let public_passphrase = DaedalusHDPassphrase::from_root_public_key(
+        &Bip32PublicKey::from_bytes(root_key.0.to_public().as_ref()).unwrap(),
+    );
+    let mut path = DaedalusDerivationPath::new();
+    path.add(0x80000000);
+    path.add(0x80000005);
+
+    let address = daedalus_address(&root_key, &passphrase.encrypt_derivation_path(&path));
+    let address = ByronAddress::from_base58(&address.to_base58()).unwrap();
+    assert!(address.to_base58().starts_with("DdzFF"));
+    assert_eq!(passphrase.derivation_path(&address), Some(path.clone()));
+    assert_eq!(public_passphrase.derivation_path(&address), Some(path));
+
+    let foreign = ByronAddress::from_base58("DdzFFzCqrht5sQ6gwv7tcc7JKPph7uP4JCB1GNeAN5XTmadgF9t1E4A1vhEZ5Ef8k3wRpDroes5pUQmWhUDkFYSJpbczr4QmJkqtRMVJ").unwrap();
+    assert!(!passphrase.is_own_address(&foreign));
+    let icarus = ByronAddress::from_base58("Ae2tdPwUPEZ6r6zbg4ibhFrNnyKHg7SYuPSfDpjKxgvwFX9LquRep7gj7FQ").unwrap();
+    assert!(!passphrase.is_own_address(&icarus));
+    // the attribute must hold exactly the announced bytes
+    let truncated = daedalus_address(&root_key, &[]);
+    assert!(!passphrase.is_own_address(&truncated));
+    assert_eq!(legacy_address::unwrap_payload(&[0x58, 0xff, 0x00]), None);
+    assert_eq!(legacy_address::unwrap_payload(&[0x43, 1, 2, 3]), Some(vec![1, 2, 3]));
+}
+
+#[test]
+fn vrf_signing_key_text_envelope() {
+    let key = VRFSigningKey::from_seed(&[5; 32]).unwrap();
+    let vkey = key.to_public();
+    let cert = key.prove(b"slot input");
+    assert!(cert.verify(&vkey, b"slot input"));
+    assert!(!cert.verify(&vkey, b"other input"));
+    assert_eq!(vkey.hash().to_bytes(), blake2b256(&vkey.to_bytes()).to_vec());
+
+    let envelope = TextEnvelope::from_json(&key.to_text_envelope().to_json().unwrap()).unwrap();
+    assert_eq!(envelope.envelope_type(), "VrfSigningKey_PraosVRF");
+    assert_eq!(envelope.description(), "VRF Signing Key");
+    // CBOR header of 64 bytes
+    assert_eq!(&envelope.cbor().unwrap()[..2], &[0x58, 0x40]);
+    let restored = VRFSigningKey::from_text_envelope(&envelope).unwrap();
+    assert_eq!(restored.to_bytes(), key.to_bytes());
+    assert!(VRFVKey::from_text_envelope(&envelope).is_err());
+    assert_eq!(
+        VRFVKey::from_text_envelope(&vkey.to_text_envelope()).unwrap(),
+        vkey
+    );
+    let not_bytes = TextEnvelope::new("VrfVerificationKey_PraosVRF", "", &[0x01]);
+    assert!(VRFVKey::from_text_envelope(&not_bytes).is_err());
+
+    let mut bytes = key.to_bytes();
+    bytes[40] ^= 1;
+    assert!(VRFSigningKey::from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn kes_signing_key_evolution() {
+    let mut key = KESSigningKey::from_seed(&[7; 32]).unwrap();
+    let vkey = key.to_public();
+    let envelope = key.to_text_envelope();
+    assert_eq!(envelope.envelope_type(), "KesSigningKey_ed25519_kes_2^6");
+    assert_eq!(
+        KESVKey::from_text_envelope(&vkey.to_text_envelope()).unwrap(),
+        vkey
+    );
+
+    key.evolve_to(10).unwrap();
+    assert_eq!(key.period(), 10);
+    assert_eq!(key.to_public(), vkey);
+    let signature = key.sign(b"header body");
+    assert!(signature.verify(&vkey, 10, b"header body"));
+    assert!(!signature.verify(&vkey, 9, b"header body"));
+    assert!(key.evolve_to(9).is_err());
+    assert!(key.evolve_to(64).is_err());
+
+    let mut restored = KESSigningKey::from_text_envelope(&envelope, 0).unwrap();
+    restored.evolve_to(10).unwrap();
+    assert_eq!(restored.to_bytes(), key.to_bytes());
+
+    key.evolve_to(63).unwrap();
+    assert!(key.sign(b"last").verify(&vkey, 63, b"last"));
+    assert!(key.evolve().is_err());
+}
+
+#[test]
+fn operational_cert_issue() {
+    let cold_key = PrivateKey::from_normal_bytes(&[3; 32]).unwrap();
+    let cold_vkey = Vkey::new(&cold_key.to_public());
+    let hot_vkey = KESSigningKey::from_seed(&[4; 32]).unwrap().to_public();
+
+    let mut counter = OperationalCertIssueCounter::new(0, &cold_vkey);
+    assert_eq!(counter.pool_id(), cold_key.to_public().hash());
+    let cert = counter.issue(&hot_vkey, 425, &cold_key).unwrap();
+    assert_eq!(cert.sequence_number(), 0);
+    assert_eq!(cert.kes_period(), 425);
+    assert!(cert.verify(&cold_vkey));
+    assert_eq!(counter.counter(), 1);
+    let other_key = PrivateKey::from_normal_bytes(&[8; 32]).unwrap();
+    assert!(counter.issue(&hot_vkey, 425, &other_key).is_err());
+    assert_eq!(counter.counter(), 1);
+
+    let envelope = counter.to_text_envelope();
+    assert_eq!(envelope.description(), "Next certificate issue number: 1");
+    assert_eq!(
+        OperationalCertIssueCounter::from_text_envelope(&envelope).unwrap(),
+        counter
+    );
+
+    let envelope = cert.to_text_envelope(&cold_vkey);
+    assert_eq!(envelope.envelope_type(), "NodeOperationalCertificate");
+    assert_eq!(OperationalCert::from_text_envelope(&envelope).unwrap(), cert);
+    let forged = cert.to_text_envelope(&Vkey::new(&other_key.to_public()));
+    assert!(OperationalCert::from_text_envelope(&forged).is_err());
+}
+
+#[test]
+fn stake_pool_key_text_envelope() {
+    let cold_key = PrivateKey::from_normal_bytes(&[3; 32]).unwrap();
+    let envelope = cold_key.to_stake_pool_text_envelope().unwrap();
+    assert_eq!(envelope.envelope_type(), "StakePoolSigningKey_ed25519");
+    assert_eq!(
+        PrivateKey::from_stake_pool_text_envelope(&envelope)
+            .unwrap()
+            .as_bytes(),
+        cold_key.as_bytes()
+    );
+    let vkey_envelope = cold_key.to_public().to_stake_pool_text_envelope();
+    assert_eq!(
+        PublicKey::from_stake_pool_text_envelope(&vkey_envelope).unwrap(),
+        cold_key.to_public()
+    );
+    assert!(PublicKey::from_stake_pool_text_envelope(&envelope).is_err());
+    assert!(PrivateKey::generate_ed25519extended()
+        .unwrap()
+        .to_stake_pool_text_envelope()
+        .is_err());
+}
+
+#[test]
+fn asset_fingerprint_cip14() {
+    let policy_id =
+        PolicyID::from_hex("7eae28af2208be856f7a119668ae52a49b73725e326dc16579dcc373").unwrap();
+    let fingerprint = AssetFingerprint::new(&policy_id, &AssetName::new(vec![]).unwrap());
+    assert_eq!(
+        fingerprint.to_cip5_bech32(),
+        "asset1rjklcrnsdzqp65wjgrg55sy9723kw09mlgvlc3"
+    );
+    let fingerprint =
+        AssetFingerprint::new(&policy_id, &AssetName::new(b"PATATE".to_vec()).unwrap());
+    assert_eq!(
+        fingerprint.to_cip5_bech32(),
+        "asset13n25uv0yaf5kus35fm2k86cqy60z58d9xmde92"
+    );
+    assert_eq!(
+        AssetFingerprint::from_cip5_bech32("asset13n25uv0yaf5kus35fm2k86cqy60z58d9xmde92").unwrap(),
+        fingerprint
+    );
+    assert!(AssetFingerprint::from_cip5_bech32(&fingerprint.to_bech32("pool").unwrap()).is_err());
+}
+
+#[test]
+fn cip5_key_prefixes() {
+    let pool_id =
+        Ed25519KeyHash::from_hex("0f292fcaa02b8b2f9b3c8f9fd8e0bb21abedb692a6d5058df3ef2735")
+            .unwrap();
+    let bech32 = pool_id.to_cip5_bech32(KeyRole::Pool).unwrap();
+    assert_eq!(
+        bech32,
+        "pool1pu5jlj4q9w9jlxeu370a3c9myx47md5j5m2str0naunn2q3lkdy"
+    );
+    assert_eq!(
+        Ed25519KeyHash::from_cip5_bech32(&bech32, KeyRole::Pool).unwrap(),
+        pool_id
+    );
+    assert!(Ed25519KeyHash::from_cip5_bech32(&bech32, KeyRole::Stake).is_err());
+    assert!(pool_id
+        .to_cip5_bech32(KeyRole::Stake)
+        .unwrap()
+        .starts_with("stake_vkh1"));
+    assert!(pool_id
+        .to_cip5_bech32(KeyRole::ReqSigner)
+        .unwrap()
+        .starts_with("req_signer_vkh1"));
+    assert!(pool_id.to_cip5_bech32(KeyRole::Acct).is_err());
+
+    let key = PrivateKey::from_normal_bytes(&[5; 32]).unwrap();
+    let bech32 = key.to_cip5_bech32(KeyRole::Pool).unwrap();
+    assert!(bech32.starts_with("pool_sk1"));
+    assert_eq!(
+        PrivateKey::from_cip5_bech32(&bech32, KeyRole::Pool)
+            .unwrap()
+            .as_bytes(),
+        key.as_bytes()
+    );
+    let extended = PrivateKey::generate_ed25519extended().unwrap();
+    let bech32 = extended.to_cip5_bech32(KeyRole::Addr).unwrap();
+    assert!(bech32.starts_with("addr_sk1"));
+    assert_eq!(
+        PrivateKey::from_cip5_bech32(&bech32, KeyRole::Addr)
+            .unwrap()
+            .as_bytes(),
+        extended.as_bytes()
+    );
+    let bech32 = key.to_public().to_cip5_bech32(KeyRole::AddrShared).unwrap();
+    assert!(bech32.starts_with("addr_shared_vk1"));
+    assert_eq!(
+        PublicKey::from_cip5_bech32(&bech32, KeyRole::AddrShared).unwrap(),
+        key.to_public()
+    );
+    assert!(PublicKey::from_cip5_bech32(&bech32, KeyRole::Addr).is_err());
+
+    let root = Bip32PrivateKey::from_bip39_entropy(&[0; 16], &[]);
+    let bech32 = root.to_cip5_bech32(KeyRole::Root).unwrap();
+    assert!(bech32.starts_with("root_xsk1"));
+    assert_eq!(
+        Bip32PrivateKey::from_cip5_bech32(&bech32, KeyRole::Root)
+            .unwrap()
+            .as_bytes(),
+        root.as_bytes()
+    );
+    let bech32 = root.to_public().to_cip5_bech32(KeyRole::Acct).unwrap();
+    assert!(bech32.starts_with("acct_xvk1"));
+    assert_eq!(
+        Bip32PublicKey::from_cip5_bech32(&bech32, KeyRole::Acct)
+            .unwrap()
+            .as_bytes(),
+        root.to_public().as_bytes()
+    );
+    assert!(root.to_cip5_bech32(KeyRole::CVote).is_err());
+}
+
+#[test]
+fn cip5_hash_prefixes() {
+    let script_hash = ScriptHash::from([1; 28]);
+    let bech32 = script_hash.to_cip5_bech32();
+    assert!(bech32.starts_with("script1"));
+    assert_eq!(ScriptHash::from_cip5_bech32(&bech32).unwrap(), script_hash);
+    assert!(DataHash::from([1; 32])
+        .to_cip5_bech32()
+        .starts_with("datum1"));
+    assert!(ScriptDataHash::from([1; 32])
+        .to_cip5_bech32()
+        .starts_with("script_data1"));
+
+    let vrf_key = VRFSigningKey::from_seed(&[2; 32]).unwrap();
+    let bech32 = vrf_key.to_cip5_bech32();
+    assert!(bech32.starts_with("vrf_sk1"));
+    assert_eq!(
+        VRFSigningKey::from_cip5_bech32(&bech32).unwrap().to_bytes(),
+        vrf_key.to_bytes()
+    );
+    let bech32 = vrf_key.to_public().to_cip5_bech32();
+    assert!(bech32.starts_with("vrf_vk1"));
+    assert_eq!(
+        VRFVKey::from_cip5_bech32(&bech32).unwrap(),
+        vrf_key.to_public()
+    );
+    assert!(vrf_key
+        .to_public()
+        .hash()
+        .to_cip5_bech32()
+        .starts_with("vrf_vkh1"));
+    assert!(VRFKeyHash::from_cip5_bech32(&bech32).is_err());
+
+    let kes_key = KESSigningKey::from_seed(&[3; 32]).unwrap();
+    let bech32 = kes_key.to_cip5_bech32();
+    assert!(bech32.starts_with("kes_sk1"));
+    let decoded = KESSigningKey::from_cip5_bech32(&bech32, 4).unwrap();
+    assert_eq!(decoded.to_bytes(), kes_key.to_bytes());
+    assert_eq!(decoded.period(), 4);
+    assert!(kes_key.to_public().to_cip5_bech32().starts_with("kes_vk1"));
+}
+
+#[test]
+fn daedalus_key_derivation() {
+    let root_key = daedalus_root_key();
+    let mut path = DaedalusDerivationPath::new();
+    path.add(0x80000000);
+    path.add(0x80000002);
+    let key = root_key.derive_path(&path);
+    assert_eq!(key.as_bytes(), root_key.derive(0x80000000).derive(0x80000002).as_bytes());
+    assert_ne!(root_key.derive(0).as_bytes(), root_key.derive(0x80000000).as_bytes());
+    // V1 isn't BIP32-Ed25519, the same path gives another key
+    let bip32 = Bip32PrivateKey::from_bytes(&root_key.as_bytes()).unwrap();
+    assert_ne!(key.as_bytes(), bip32.derive(0x80000000).derive(0x80000002).as_bytes());
+
+    let tx_hash = TransactionHash::from([1; TransactionHash::BYTE_COUNT]);
+    let payload = DaedalusHDPassphrase::from_root_key(&root_key).encrypt_derivation_path(&path);
+    let address = daedalus_address(&root_key, &payload);
+    let witness = make_daedalus_bootstrap_witness(&tx_hash, &address, &key);
+    assert_eq!(witness.vkey().public_key().as_bytes(), key.to_public().to_raw_key().as_bytes());
+    assert_eq!(witness.chain_code(), key.chaincode());
+    assert!(witness.vkey().public_key().verify(&tx_hash.to_bytes(), &witness.signature()));
+}
@@ -0,0 +1,133 @@
+use crate::tests::fakes::fake_default_tx_builder;
+use crate::*;
+
+#[test]
+fn mainnet_era_boundaries() {
+    let history = EraHistory::mainnet();
+    // Shelley hard fork, 2020-07-29T21:44:51Z
+    assert_eq!(
+        history.slot_to_posix_millis(&BigNum(4492800)).unwrap(),
+        BigNum(1596059091000)
+    );
+    assert_eq!(
+        history.slot_to_posix_millis(&BigNum(4492799)).unwrap(),
+        BigNum(1596059071000)
+    );
+    assert_eq!(history.slot_to_epoch(&BigNum(4492799)).unwrap(), 207);
+    assert_eq!(history.slot_to_epoch(&BigNum(4492800)).unwrap(), 208);
+    assert_eq!(history.epoch_first_slot(208).unwrap(), BigNum(4492800));
+    assert_eq!(history.epoch_first_slot(209).unwrap(), BigNum(4924800));
+    assert_eq!(history.epoch_first_slot(1).unwrap(), BigNum(21600));
+    assert_eq!(history.slot_length_millis(&BigNum(0)), BigNum(20000));
+    assert_eq!(history.epoch_length(300), 432000);
+
+    let slot = BigNum(100_000_000);
+    let time = history.slot_to_posix_millis(&slot).unwrap();
+    assert_eq!(time, BigNum((1596059091 + 100_000_000 - 4492800) * 1000));
+    assert_eq!(history.posix_millis_to_slot(&time).unwrap(), slot);
+    assert_eq!(history.posix_millis_to_slot(&BigNum(time.0 + 999)).unwrap(), slot);
+    // a Byron slot is 20 seconds long
+    assert_eq!(
+        history.posix_millis_to_slot(&BigNum(1506203091000 + 39999)).unwrap(),
+        BigNum(1)
+    );
+    assert!(history.posix_millis_to_slot(&BigNum(1506203090999)).is_err());
+}
+
+#[test]
+fn testnet_presets() {
+    let preprod = EraHistory::from_network_info(&NetworkInfo::testnet_preprod()).unwrap();
+    assert_eq!(
+        preprod.slot_to_posix_millis(&BigNum(86400)).unwrap(),
+        BigNum(1655769600000)
+    );
+    assert_eq!(preprod.slot_to_epoch(&BigNum(86400)).unwrap(), 4);
+
+    let preview = EraHistory::from_network_info(&NetworkInfo::testnet_preview()).unwrap();
+    assert_eq!(preview.slot_to_posix_millis(&BigNum(0)).unwrap(), BigNum(1666656000000));
+    assert_eq!(preview.slot_to_epoch(&BigNum(86400)).unwrap(), 1);
+
+    assert_eq!(
+        EraHistory::from_network_info(&NetworkInfo::mainnet()),
+        Some(EraHistory::mainnet())
+    );
+    assert!(EraHistory::from_network_info(&NetworkInfo::new(0, 42)).is_none());
+}
+
+#[test]
+fn custom_era_history() {
+    let mut history = EraHistory::new(&BigNum(1_000_000), &BigNum(100), 10).unwrap();
+    history.add_era(2, &BigNum(1000), 50).unwrap();
+    assert!(history.add_era(2, &BigNum(1000), 50).is_err());
+    assert!(EraHistory::new(&BigNum(0), &BigNum(0), 10).is_err());
+
+    assert_eq!(history.epoch_first_slot(2).unwrap(), BigNum(20));
+    assert_eq!(history.slot_to_posix_millis(&BigNum(20)).unwrap(), BigNum(1_002_000));
+    assert_eq!(history.slot_to_posix_millis(&BigNum(21)).unwrap(), BigNum(1_003_000));
+    assert_eq!(history.slot_to_epoch(&BigNum(70)).unwrap(), 3);
+    assert_eq!(history.posix_millis_to_slot(&BigNum(1_001_950)).unwrap(), BigNum(19));
+}
+
+#[test]
+fn tx_builder_validity_from_time() {
+    let history = EraHistory::testnet_preview();
+    let mut tx_builder = fake_default_tx_builder();
+    tx_builder
+        .set_ttl_from_time(&BigNum(1666656010500), &history)
+        .unwrap();
+    assert_eq!(tx_builder.ttl, Some(BigNum(10)));
+    tx_builder
+        .set_validity_start_interval_from_time(&BigNum(1666656000500), &history)
+        .unwrap();
+    assert_eq!(tx_builder.validity_start_interval, Some(BigNum(1)));
+    tx_builder
+        .set_validity_start_interval_from_time(&BigNum(1666656000000), &history)
+        .unwrap();
+    assert_eq!(tx_builder.validity_start_interval, Some(BigNum(0)));
+    assert!(tx_builder
+        .set_ttl_from_time(&BigNum(1000), &history)
+        .is_err());
+
+    let start = TimelockStart::new_from_time(&BigNum(1666656000500), &history).unwrap();
+    assert_eq!(start.slot_bignum(), BigNum(1));
+    let expiry = TimelockExpiry::new_from_time(&BigNum(1666656000500), &history).unwrap();
+    assert_eq!(expiry.slot_bignum(), BigNum(0));
+}
+
+#[test]
+fn era_history_from_genesis() {
+    let byron = ByronGenesis::from_json(
+        r#"{
+            "blockVersionData": { "slotDuration": "20000" },
+            "protocolConsts": { "k": 2160, "protocolMagic": 1 },
+            "startTime": 1654041600
+        }"#,
+    )
+    .unwrap();
+    let mut shelley = ShelleyGenesis::from_json(&fake_shelley_genesis()).unwrap();
+    let history = EraHistory::from_genesis(Some(byron), &shelley, 4).unwrap();
+    assert_eq!(history, EraHistory::testnet_preprod());
+
+    shelley.system_start_millis = 1666656000000;
+    shelley.epoch_length = 86400;
+    let history = EraHistory::from_genesis(None, &shelley, 0).unwrap();
+    assert_eq!(history, EraHistory::testnet_preview());
+}
+
+fn fake_shelley_genesis() -> String {
+    r#"{
+        "activeSlotsCoeff": 0.05,
+        "epochLength": 432000,
+        "maxKESEvolutions": 62,
+        "maxLovelaceSupply": 45000000000000000,
+        "networkId": "Testnet",
+        "networkMagic": 1,
+        "protocolParams": {},
+        "securityParam": 2160,
+        "slotLength": 1,
+        "slotsPerKESPeriod": 129600,
+        "systemStart": "2022-06-01T00:00:00Z",
+        "updateQuorum": 5
+    }"#
+    .to_string()
+}
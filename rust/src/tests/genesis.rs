@@ -0,0 +1,319 @@
+use crate::legacy_address::ByronAddressType;
+use crate::*;
+
+const BYRON_GENESIS: &str = r#"{
+    "avvmDistr": {
+        "-0BJDi-gauylk4LptQTgjMeo7kY9lTCbZv12vwOSTZk=": "9999300000000"
+    },
+    "blockVersionData": {
+        "maxTxSize": "4096",
+        "slotDuration": "20000"
+    },
+    "nonAvvmBalances": {
+        "Ae2tdPwUPEZ4YjgvykNpoFeYUxoyhNj2kg8KfKWN2FizsSpLUPv68MpTVDo": "1000000"
+    },
+    "protocolConsts": {
+        "k": 2160,
+        "protocolMagic": 1
+    },
+    "startTime": 1654041600
+}"#;
+
+const SHELLEY_GENESIS: &str = r#"{
+    "activeSlotsCoeff": 0.05,
+    "epochLength": 432000,
+    "genDelegs": {
+        "637f2e950b0fd8f8e3e811c5fbeb19e411e7a2bf37272b84b29c1a0b": {
+            "delegate": "aae9293510344ddd636364c2673e34e03e79e3eefa8dbaa70e326f7d",
+            "vrf": "227116365af2ed943f1a8b5e6557bfaa34996f1578eec667a5e2b361c51e4ce7"
+        }
+    },
+    "initialFunds": {
+        "60e0f7c1d1e2a5a9b3f7f0a5e2b1c5d6e7f8091a2b3c4d5e6f708192a3": 45000000000000
+    },
+    "maxKESEvolutions": 62,
+    "maxLovelaceSupply": 45000000000000000,
+    "networkId": "Testnet",
+    "networkMagic": 42,
+    "protocolParams": {
+        "a0": 0.3,
+        "decentralisationParam": 1,
+        "eMax": 18,
+        "extraEntropy": { "tag": "NeutralNonce" },
+        "keyDeposit": 2000000,
+        "maxBlockBodySize": 65536,
+        "maxBlockHeaderSize": 1100,
+        "maxTxSize": 16384,
+        "minFeeA": 44,
+        "minFeeB": 155381,
+        "minPoolCost": 340000000,
+        "nOpt": 150,
+        "poolDeposit": 500000000,
+        "protocolVersion": { "major": 6, "minor": 0 },
+        "rho": 0.003,
+        "tau": 0.2
+    },
+    "securityParam": 2160,
+    "slotLength": 0.2,
+    "slotsPerKESPeriod": 129600,
+    "staking": {
+        "pools": {
+            "9a4b8c4ec5bd4dff0b3e0c5b0e7b3b2e1c5f3ee2d1c4b5a697887766": {
+                "cost": 340000000,
+                "margin": 0.025,
+                "metadata": null,
+                "owners": [],
+                "pledge": 0,
+                "publicKey": "9a4b8c4ec5bd4dff0b3e0c5b0e7b3b2e1c5f3ee2d1c4b5a697887766",
+                "relays": [
+                    { "single host address": { "IPv4": "127.0.0.1", "IPv6": null, "port": 3001 } },
+                    { "single host name": { "dnsName": "relay.example.com", "port": 3001 } }
+                ],
+                "rewardAccount": {
+                    "credential": { "keyHash": "11223344556677889900aabbccddeeff00112233445566778899aabb" },
+                    "network": "Testnet"
+                },
+                "vrf": "227116365af2ed943f1a8b5e6557bfaa34996f1578eec667a5e2b361c51e4ce7"
+            }
+        },
+        "stake": {
+            "11223344556677889900aabbccddeeff00112233445566778899aabb": "9a4b8c4ec5bd4dff0b3e0c5b0e7b3b2e1c5f3ee2d1c4b5a697887766"
+        }
+    },
+    "systemStart": "2022-10-25T00:00:00Z",
+    "updateQuorum": 5
+}"#;
+
+const ALONZO_GENESIS: &str = r#"{
+    "lovelacePerUTxOWord": 34482,
+    "executionPrices": {
+        "prSteps": { "numerator": 721, "denominator": 10000000 },
+        "prMem": { "numerator": 577, "denominator": 10000 }
+    },
+    "maxTxExUnits": { "exUnitsMem": 10000000, "exUnitsSteps": 10000000000 },
+    "maxBlockExUnits": { "exUnitsMem": 50000000, "exUnitsSteps": 40000000000 },
+    "maxValueSize": 5000,
+    "collateralPercentage": 150,
+    "maxCollateralInputs": 3,
+    "costModels": {
+        "PlutusV1": { "b-cost": 2, "a-cost": 1, "c-cost": -3 },
+        "PlutusV2": [4, 5, 6]
+    }
+}"#;
+
+const CONWAY_GENESIS: &str = r#"{
+    "poolVotingThresholds": {
+        "committeeNormal": 0.51,
+        "committeeNoConfidence": 0.51,
+        "hardForkInitiation": 0.51,
+        "motionNoConfidence": 0.51,
+        "ppSecurityGroup": 0.51
+    },
+    "dRepVotingThresholds": {
+        "motionNoConfidence": 0.67,
+        "committeeNormal": 0.67,
+        "committeeNoConfidence": 0.6,
+        "updateToConstitution": 0.75,
+        "hardForkInitiation": 0.6,
+        "ppNetworkGroup": 0.67,
+        "ppEconomicGroup": 0.67,
+        "ppTechnicalGroup": 0.67,
+        "ppGovGroup": 0.75,
+        "treasuryWithdrawal": 0.67
+    },
+    "committeeMinSize": 7,
+    "committeeMaxTermLength": 146,
+    "govActionLifetime": 6,
+    "govActionDeposit": 100000000000,
+    "dRepDeposit": 500000000,
+    "dRepActivity": 20,
+    "minFeeRefScriptCostPerByte": 15,
+    "plutusV3CostModel": [100788, 420, 1, 1],
+    "constitution": {
+        "anchor": {
+            "dataHash": "ca41a91f399259bcefe57f9858e91f6d00e1a38d6d9c63d4052914ea7bd70cb2",
+            "url": "ipfs://bafkreifnwj6zpu3ixa4siz2lndqybyc5wnnt3jkwyutci4e2tmbnj3xrdm"
+        },
+        "script": "fa24fb305126805cf2164c161d852a0e7330cf988f1fe558cf7d4a64"
+    },
+    "committee": {
+        "members": {
+            "scriptHash-df0e83bde65416dade5b1f97e7f115cc1ff999550ad968850783fe50": 580,
+            "keyHash-11223344556677889900aabbccddeeff00112233445566778899aabb": 300
+        },
+        "threshold": { "numerator": 2, "denominator": 3 }
+    }
+}"#;
+
+fn rational(numerator: u64, denominator: u64) -> UnitInterval {
+    UnitInterval::new(&BigNum(numerator), &BigNum(denominator))
+}
+
+#[test]
+fn byron_genesis_balances() {
+    let genesis = ByronGenesis::from_json(BYRON_GENESIS).unwrap();
+    assert_eq!(genesis.protocol_magic(), 1);
+    assert_eq!(genesis.start_time(), BigNum(1654041600));
+    assert_eq!(genesis.slot_duration_millis(), BigNum(20000));
+    assert_eq!(genesis.epoch_length(), 21600);
+
+    let avvm = genesis.avvm_balances().get(0);
+    assert_eq!(avvm.amount(), BigNum(9999300000000));
+    assert_eq!(avvm.address().0.addr_type, ByronAddressType::ATRedeem);
+    assert_eq!(avvm.address().byron_protocol_magic(), 1);
+    let base58 = avvm.address().to_base58();
+    assert_eq!(ByronAddress::from_base58(&base58).unwrap(), avvm.address());
+
+    let non_avvm = genesis.non_avvm_balances().get(0);
+    assert_eq!(
+        non_avvm.address().to_base58(),
+        "Ae2tdPwUPEZ4YjgvykNpoFeYUxoyhNj2kg8KfKWN2FizsSpLUPv68MpTVDo"
+    );
+
+    let utxos = genesis.initial_utxos();
+    assert_eq!(utxos.len(), 2);
+    let utxo = utxos.get(1);
+    let address = non_avvm.address().to_address();
+    assert_eq!(
+        utxo.input().transaction_id(),
+        TransactionHash::from(blake2b256(&address.to_bytes()))
+    );
+    assert_eq!(utxo.input().index(), 0);
+    assert_eq!(utxo.output().amount().coin(), BigNum(1000000));
+}
+
+#[test]
+fn byron_genesis_mainnet_redeem_address() {
+    let json = BYRON_GENESIS.replace("\"protocolMagic\": 1", "\"protocolMagic\": 764824073");
+    let genesis = ByronGenesis::from_json(&json).unwrap();
+    let avvm = genesis.avvm_balances().get(0).address();
+    // mainnet redeem addresses have no attributes, like this one from the mainnet chain
+    let mainnet_redeem = ByronAddress::from_base58("Ae2tdPwUPEZ3MHKkpT5Bpj549vrRH7nBqYjNXnCV8G2Bc2YxNcGHEa8ykDp").unwrap();
+    assert_eq!(mainnet_redeem.byron_address_kind(), ByronAddressType::ATRedeem);
+    assert_eq!(avvm.byron_address_kind(), ByronAddressType::ATRedeem);
+    assert_eq!(avvm.to_bytes().len(), mainnet_redeem.to_bytes().len());
+    assert_eq!(avvm.to_bytes()[..7], mainnet_redeem.to_bytes()[..7]);
+    // root computed independently as blake2b224(sha3_256([2, [2, key], {}]))
+    assert_eq!(
+        hex::encode(avvm.to_bytes()),
+        "82d818582183581cccdf735b7d5cafe44e65e75a82fd4305fe7712924a8e1196fefdbddfa0021a9cbc2ffe"
+    );
+    assert_eq!(avvm.to_base58(), "Ae2tdPwUPEZHFQnrr2dYB4GEQ8WVKspEyrg29pJ3f7qdjzaxjeShEEokF5f");
+    assert_eq!(avvm.byron_protocol_magic(), NetworkInfo::mainnet().protocol_magic());
+}
+
+#[test]
+fn byron_genesis_rejects_invalid_avvm_key() {
+    let json = BYRON_GENESIS.replace("-0BJDi-gauylk4LptQTgjMeo7kY9lTCbZv12vwOSTZk=", "not-a-key");
+    assert!(ByronGenesis::from_json(&json).is_err());
+}
+
+#[test]
+fn shelley_genesis_parameters() {
+    let genesis = ShelleyGenesis::from_json(SHELLEY_GENESIS).unwrap();
+    assert_eq!(genesis.network_magic(), 42);
+    assert_eq!(genesis.network_id(), NetworkId::testnet());
+    assert_eq!(genesis.system_start_millis(), BigNum(1666656000000));
+    assert_eq!(genesis.slot_length_millis(), BigNum(200));
+    assert_eq!(genesis.epoch_length(), 432000);
+    assert_eq!(genesis.active_slots_coeff(), rational(1, 20));
+    assert_eq!(genesis.max_lovelace_supply(), BigNum(45000000000000000));
+
+    let params = genesis.protocol_params();
+    assert_eq!(params.minfee_a(), Some(BigNum(44)));
+    assert_eq!(params.pool_pledge_influence(), Some(rational(3, 10)));
+    assert_eq!(params.expansion_rate(), Some(rational(3, 1000)));
+    assert_eq!(params.d, Some(rational(1, 1)));
+    assert_eq!(params.extra_entropy, Some(Nonce::new_identity()));
+    assert_eq!(params.protocol_version(), Some(ProtocolVersion::new(6, 0)));
+
+    let genesis_hash = genesis.genesis_hashes().get(0);
+    let delegation = genesis.genesis_key_delegation(&genesis_hash).unwrap();
+    assert_eq!(
+        delegation.genesis_delegate_hash().to_hex(),
+        "aae9293510344ddd636364c2673e34e03e79e3eefa8dbaa70e326f7d"
+    );
+}
+
+#[test]
+fn shelley_genesis_funds_and_staking() {
+    let genesis = ShelleyGenesis::from_json(SHELLEY_GENESIS).unwrap();
+    let utxo = genesis.initial_utxos().get(0);
+    let address = genesis.initial_funds().get(0).address();
+    assert_eq!(
+        utxo.input().transaction_id(),
+        TransactionHash::from(blake2b256(&address.to_bytes()))
+    );
+    assert_eq!(utxo.output().amount().coin(), BigNum(45000000000000));
+
+    let pool_id = genesis.pool_ids().get(0);
+    let pool = genesis.pool_params(&pool_id).unwrap();
+    assert_eq!(pool.operator(), pool_id);
+    assert_eq!(pool.margin(), rational(1, 40));
+    assert_eq!(pool.relays().len(), 2);
+    assert_eq!(pool.reward_account().network_id(), 0);
+    assert!(pool.pool_metadata().is_none());
+    let staked = genesis.staked_keys().get(0);
+    assert_eq!(genesis.stake_delegation(&staked), Some(pool_id));
+}
+
+#[test]
+fn alonzo_genesis_cost_models() {
+    let genesis = AlonzoGenesis::from_json(ALONZO_GENESIS).unwrap();
+    assert_eq!(genesis.coins_per_utxo_byte(), BigNum(4310));
+    assert_eq!(genesis.execution_prices().step_price(), rational(721, 10000000));
+    assert_eq!(genesis.max_tx_ex_units().steps(), BigNum(10000000000));
+
+    let cost_models = genesis.cost_models();
+    let v1 = cost_models.get(&Language::new_plutus_v1()).unwrap();
+    assert_eq!(v1, CostModel::from(vec![1, 2, -3]));
+    let v2 = cost_models.get(&Language::new_plutus_v2()).unwrap();
+    assert_eq!(v2, CostModel::from(vec![4, 5, 6]));
+
+    let json = ALONZO_GENESIS.replace(
+        r#""prSteps": { "numerator": 721, "denominator": 10000000 },
+        "prMem": { "numerator": 577, "denominator": 10000 }"#,
+        r#""priceSteps": 7.21e-5, "priceMemory": 0.0577"#,
+    );
+    let genesis_with_decimal_prices = AlonzoGenesis::from_json(&json).unwrap();
+    assert_eq!(genesis_with_decimal_prices.execution_prices(), genesis.execution_prices());
+}
+
+#[test]
+fn conway_genesis_governance() {
+    let genesis = ConwayGenesis::from_json(CONWAY_GENESIS).unwrap();
+    assert_eq!(genesis.drep_voting_thresholds().update_constitution(), rational(3, 4));
+    assert_eq!(genesis.gov_action_deposit(), BigNum(100000000000));
+    assert_eq!(genesis.min_fee_ref_script_cost_per_byte(), rational(15, 1));
+    assert_eq!(genesis.plutus_v3_cost_model().unwrap().len(), 4);
+
+    let constitution = genesis.constitution().unwrap();
+    assert_eq!(
+        constitution.script_hash().unwrap().to_hex(),
+        "fa24fb305126805cf2164c161d852a0e7330cf988f1fe558cf7d4a64"
+    );
+    let committee = genesis.committee().unwrap();
+    assert_eq!(committee.quorum_threshold(), rational(2, 3));
+    let member = Credential::from_scripthash(
+        &ScriptHash::from_hex("df0e83bde65416dade5b1f97e7f115cc1ff999550ad968850783fe50").unwrap(),
+    );
+    assert_eq!(committee.get_member_epoch(&member), Some(580));
+    assert_eq!(committee.members_keys().len(), 2);
+}
+
+#[test]
+fn tx_builder_config_from_genesis() {
+    let shelley = ShelleyGenesis::from_json(SHELLEY_GENESIS).unwrap();
+    let alonzo = AlonzoGenesis::from_json(ALONZO_GENESIS).unwrap();
+    let conway = ConwayGenesis::from_json(CONWAY_GENESIS).unwrap();
+    let builder = TransactionBuilderConfigBuilder::from_genesis(&shelley, &alonzo, Some(&conway));
+    let config = builder.build().unwrap();
+    assert_eq!(config.fee_algo, LinearFee::new(&BigNum(44), &BigNum(155381)));
+    assert_eq!(config.pool_deposit, BigNum(500000000));
+    assert_eq!(config.max_tx_size, 16384);
+    assert_eq!(config.max_value_size, 5000);
+    assert_eq!(config.ref_script_coins_per_byte, Some(rational(15, 1)));
+
+    let builder = TransactionBuilderConfigBuilder::from_genesis(&shelley, &alonzo, None);
+    assert!(builder.build().unwrap().ref_script_coins_per_byte.is_none());
+}
@@ -11,4 +11,6 @@ mod crypto;
 mod utils;
 mod fees;
 mod emip3;
-mod pointer;
\ No newline at end of file
+mod pointer;
+mod genesis;
+mod era_history;
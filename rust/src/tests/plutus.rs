@@ -214,6 +214,82 @@ pub fn plutus_datum_from_json_detailed() {
     assert_eq!(datum, datum2);
 }
 
+#[test]
+pub fn plutus_datum_lossless_json_round_trip() {
+    let cbor = "d8799fa30102030401058201 02d866 8218c880d866 82039f01ffc249010000000000000000 1805bf0102ffff"
+        .replace(' ', "");
+    let datum = PlutusData::from_bytes(hex::decode(&cbor).unwrap()).unwrap();
+    let json = datum.to_json(PlutusDatumSchema::LosslessSchema).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let fields = &value["fields"];
+    assert_eq!(value["constructor"], 0);
+    // duplicate keys stay in the encoded order
+    let keys: Vec<_> = fields[0]["map"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry["k"]["int"].clone())
+        .collect();
+    assert_eq!(keys, vec![1, 3, 1]);
+    assert_eq!(fields[1]["definite"], true);
+    assert_eq!(fields[2]["constructor"], 200);
+    assert!(fields[2].get("general_form").is_none());
+    assert_eq!(fields[3]["general_form"], true);
+    assert_eq!(fields[4]["int"], "18446744073709551616");
+    assert_eq!(fields[5]["int"], 5);
+    assert_eq!(fields[5]["cbor"], "1805");
+    assert_eq!(fields[6]["definite"], false);
+
+    let datum2 = PlutusData::from_json(&json, PlutusDatumSchema::LosslessSchema).unwrap();
+    assert_eq!(hex::encode(datum2.to_bytes()), cbor);
+    assert_eq!(hash_plutus_data(&datum2), hash_plutus_data(&datum));
+}
+
+#[test]
+pub fn plutus_datum_lossless_json_big_values() {
+    let json = "{\"constructor\": 1000, \"fields\": [
+            {\"int\": \"-340282366920938463463374607431768211456\"},
+            {\"int\": 9007199254740993},
+            {\"list\": [], \"definite\": false}
+        ]}";
+    let datum = PlutusData::from_json(json, PlutusDatumSchema::LosslessSchema).unwrap();
+    let constr = datum.as_constr_plutus_data().unwrap();
+    assert_eq!(constr.alternative(), BigNum(1000));
+    assert_eq!(
+        constr.data().get(0).as_integer().unwrap().to_str(),
+        "-340282366920938463463374607431768211456"
+    );
+    assert_eq!(constr.data().get(1).as_integer().unwrap().to_str(), "9007199254740993");
+    assert_eq!(hex::encode(constr.data().get(2).to_bytes()), "9fff");
+
+    let value: serde_json::Value =
+        serde_json::from_str(&datum.to_json(PlutusDatumSchema::LosslessSchema).unwrap()).unwrap();
+    assert_eq!(value["fields"][1]["int"], "9007199254740993");
+    assert_eq!(value["fields"][2]["definite"], false);
+}
+
+#[test]
+pub fn plutus_datum_lossless_json_errors() {
+    let from_json = |json: &str| PlutusData::from_json(json, PlutusDatumSchema::LosslessSchema);
+    assert!(from_json("{\"int\": 5, \"cbor\": \"1805\"}").is_ok());
+    // the exact bytes must match the described datum
+    assert!(from_json("{\"int\": 6, \"cbor\": \"1805\"}").is_err());
+    assert!(from_json("{\"int\": 1.5}").is_err());
+    assert!(from_json("{\"int\": 5, \"bytes\": \"00\"}").is_err());
+    assert!(from_json("{\"list\": [], \"definite\": 1}").is_err());
+    assert!(from_json("{\"map\": [{\"k\": {\"int\": 1}}]}").is_err());
+    assert!(from_json("[]").is_err());
+    // the exact bytes are decoded before they are compared with the datum
+    let nested = |lists: usize| hex::encode([vec![0x81; lists], vec![0x00]].concat());
+    let nested_json = |lists: usize| {
+        format!("{}{{\"int\": 0}}{}", "{\"list\": [".repeat(lists), "]}".repeat(lists))
+    };
+    let with_cbor = |lists: usize| format!("{{\"list\": [], \"cbor\": \"{}\"}}", nested(lists));
+    assert!(from_json(&with_cbor(100_000)).unwrap_err().to_string().contains("nested deeper than 128 levels"));
+    assert!(!from_json(&with_cbor(100)).unwrap_err().to_string().contains("nested deeper"));
+    assert!(from_json(&nested_json(40)).is_ok());
+}
+
 #[test]
 pub fn test_cost_model() {
     let arr = vec![
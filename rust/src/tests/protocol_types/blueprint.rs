@@ -0,0 +1,242 @@
+use crate::*;
+
+const COMPILED_CODE: &str = "4e4d01000033222220051200120011";
+
+fn fake_blueprint(hash: &str) -> String {
+    format!(
+        r##"{{
+  "preamble": {{
+    "title": "acme/marketplace",
+    "version": "0.1.0",
+    "plutusVersion": "v2"
+  }},
+  "validators": [
+    {{
+      "title": "marketplace.spend",
+      "datum": {{ "title": "datum", "schema": {{ "$ref": "#/definitions/marketplace~1Listing" }} }},
+      "redeemer": {{ "title": "action", "schema": {{ "$ref": "#/definitions/marketplace~1Action" }} }},
+      "parameters": [
+        {{ "title": "fee", "schema": {{ "$ref": "#/definitions/Int" }} }},
+        {{ "title": "admin", "schema": {{ "$ref": "#/definitions/ByteArray" }} }}
+      ],
+      "compiledCode": "{}",
+      "hash": "{}"
+    }}
+  ],
+  "definitions": {{
+    "Int": {{ "dataType": "integer" }},
+    "ByteArray": {{ "dataType": "bytes" }},
+    "Bool": {{
+      "anyOf": [
+        {{ "title": "False", "dataType": "constructor", "index": 0, "fields": [] }},
+        {{ "title": "True", "dataType": "constructor", "index": 1, "fields": [] }}
+      ]
+    }},
+    "Data": {{ "title": "Data", "description": "Any Plutus data." }},
+    "Option$ByteArray": {{
+      "anyOf": [
+        {{ "title": "Some", "dataType": "constructor", "index": 0, "fields": [{{ "$ref": "#/definitions/ByteArray" }}] }},
+        {{ "title": "None", "dataType": "constructor", "index": 1, "fields": [] }}
+      ]
+    }},
+    "List$Int": {{ "dataType": "list", "items": {{ "$ref": "#/definitions/Int" }} }},
+    "Pairs$ByteArray_Int": {{
+      "dataType": "map",
+      "keys": {{ "$ref": "#/definitions/ByteArray" }},
+      "values": {{ "$ref": "#/definitions/Int" }}
+    }},
+    "Tuple$Int_ByteArray": {{
+      "dataType": "list",
+      "items": [{{ "$ref": "#/definitions/Int" }}, {{ "$ref": "#/definitions/ByteArray" }}]
+    }},
+    "marketplace/Listing": {{
+      "title": "Listing",
+      "anyOf": [
+        {{
+          "title": "Listing",
+          "dataType": "constructor",
+          "index": 0,
+          "fields": [
+            {{ "title": "seller", "$ref": "#/definitions/ByteArray" }},
+            {{ "title": "price", "$ref": "#/definitions/Int" }},
+            {{ "title": "royalty", "$ref": "#/definitions/Option$ByteArray" }},
+            {{ "title": "active", "$ref": "#/definitions/Bool" }},
+            {{ "title": "tags", "$ref": "#/definitions/Pairs$ByteArray_Int" }},
+            {{ "title": "extra", "$ref": "#/definitions/Data" }}
+          ]
+        }}
+      ]
+    }},
+    "marketplace/Action": {{
+      "title": "Action",
+      "anyOf": [
+        {{ "title": "Buy", "dataType": "constructor", "index": 0, "fields": [] }},
+        {{
+          "title": "Update",
+          "dataType": "constructor",
+          "index": 1,
+          "fields": [{{ "$ref": "#/definitions/Int" }}, {{ "$ref": "#/definitions/List$Int" }}]
+        }}
+      ]
+    }}
+  }}
+}}"##,
+        COMPILED_CODE, hash
+    )
+}
+
+fn fake_script_hash_hex() -> String {
+    PlutusScript::new_v2(hex::decode(COMPILED_CODE).unwrap())
+        .hash()
+        .to_hex()
+}
+
+#[test]
+fn blueprint_validator_lookup_test() {
+    let blueprint = PlutusBlueprint::from_json(&fake_blueprint(&fake_script_hash_hex())).unwrap();
+    assert_eq!(blueprint.title(), Some("acme/marketplace".to_string()));
+    assert_eq!(blueprint.plutus_version(), Some(Language::new_plutus_v2()));
+    assert_eq!(blueprint.validators().len(), 1);
+    assert!(blueprint.validator("missing.spend").is_none());
+
+    let validator = blueprint.validator("marketplace.spend").unwrap();
+    assert!(validator.has_datum());
+    assert_eq!(validator.parameters_len(), 2);
+    assert_eq!(validator.parameter_title(1), Some("admin".to_string()));
+    let script = validator.script().unwrap();
+    assert_eq!(script.language_version(), Language::new_plutus_v2());
+    assert_eq!(script.bytes(), hex::decode(COMPILED_CODE).unwrap());
+    assert_eq!(validator.hash(), Some(script.hash()));
+
+    let params = validator.encode_parameters(r#"[5, "abcd"]"#).unwrap();
+    assert_eq!(params.len(), 2);
+    assert_eq!(params.get(0), PlutusData::new_integer(&BigInt::from(5)));
+    assert_eq!(params.get(1), PlutusData::new_bytes(vec![0xab, 0xcd]));
+    assert!(validator.encode_parameters(r#"[5]"#).is_err());
+    assert!(validator.encode_parameter(1, r#"5"#).is_err());
+}
+
+#[test]
+fn blueprint_hash_mismatch_test() {
+    let blueprint = PlutusBlueprint::from_json(&fake_blueprint(&"00".repeat(28))).unwrap();
+    let validator = blueprint.validator("marketplace.spend").unwrap();
+    assert!(validator.script().is_err());
+}
+
+#[test]
+fn blueprint_datum_round_trip_test() {
+    let blueprint = PlutusBlueprint::from_json(&fake_blueprint(&fake_script_hash_hex())).unwrap();
+    let validator = blueprint.validator("marketplace.spend").unwrap();
+    let json = r#"{
+        "seller": "deadbeef",
+        "price": "123456789012345678901234567890",
+        "royalty": {"Some": ["cafe"]},
+        "active": true,
+        "tags": [["01", 1], ["01", 2]],
+        "extra": {"list": [{"int": 1}]}
+    }"#;
+    let datum = validator.encode_datum(json).unwrap();
+
+    let constr = datum.as_constr_plutus_data().unwrap();
+    assert_eq!(constr.alternative(), BigNum::zero());
+    let fields = constr.data();
+    assert_eq!(fields.len(), 6);
+    assert_eq!(fields.get(0), PlutusData::new_bytes(hex::decode("deadbeef").unwrap()));
+    assert_eq!(
+        fields.get(1).as_integer().unwrap().to_str(),
+        "123456789012345678901234567890"
+    );
+    let royalty = fields.get(2).as_constr_plutus_data().unwrap();
+    assert_eq!(royalty.alternative(), BigNum::zero());
+    assert_eq!(royalty.data().get(0), PlutusData::new_bytes(vec![0xca, 0xfe]));
+    assert_eq!(
+        fields.get(3),
+        PlutusData::new_empty_constr_plutus_data(&BigNum::one())
+    );
+    // duplicate keys are preserved
+    assert_eq!(fields.get(4).as_map().unwrap().total_len(), 2);
+
+    let decoded: serde_json::Value =
+        serde_json::from_str(&validator.decode_datum(&datum).unwrap()).unwrap();
+    let expected: serde_json::Value = serde_json::from_str(
+        r#"{
+        "seller": "deadbeef",
+        "price": 123456789012345678901234567890,
+        "royalty": {"Some": ["cafe"]},
+        "active": true,
+        "tags": [["01", 1], ["01", 2]],
+        "extra": {"list": [{"int": 1}]}
+    }"#,
+    )
+    .unwrap();
+    assert_eq!(decoded, expected);
+    assert_eq!(validator.encode_datum(&decoded.to_string()).unwrap(), datum);
+}
+
+#[test]
+fn blueprint_redeemer_test() {
+    let blueprint = PlutusBlueprint::from_json(&fake_blueprint(&fake_script_hash_hex())).unwrap();
+    let validator = blueprint.validator("marketplace.spend").unwrap();
+
+    let buy = validator.encode_redeemer(r#""Buy""#).unwrap();
+    assert_eq!(buy, PlutusData::new_empty_constr_plutus_data(&BigNum::zero()));
+    assert_eq!(validator.decode_redeemer(&buy).unwrap(), r#""Buy""#);
+
+    let update = validator.encode_redeemer(r#"{"Update": [10, [1, 2, 3]]}"#).unwrap();
+    let constr = update.as_constr_plutus_data().unwrap();
+    assert_eq!(constr.alternative(), BigNum::one());
+    assert_eq!(constr.data().get(1).as_list().unwrap().len(), 3);
+    assert_eq!(
+        validator.decode_redeemer(&update).unwrap(),
+        r#"{"Update":[10,[1,2,3]]}"#
+    );
+}
+
+#[test]
+fn blueprint_error_path_test() {
+    let blueprint = PlutusBlueprint::from_json(&fake_blueprint(&fake_script_hash_hex())).unwrap();
+    let validator = blueprint.validator("marketplace.spend").unwrap();
+
+    let err = validator
+        .encode_redeemer(r#"{"Update": [10, [1, "x", 3]]}"#)
+        .unwrap_err();
+    assert!(format!("{:?}", err).contains("$.Update[1][1]: expected an integer"));
+
+    let err = validator.encode_redeemer(r#""Sell""#).unwrap_err();
+    assert!(format!("{:?}", err).contains("unknown constructor"));
+
+    let err = validator
+        .encode_datum(r#"{"seller": "zz", "price": 1, "royalty": "None", "active": false, "tags": [], "extra": {"int": 1}}"#)
+        .unwrap_err();
+    assert!(format!("{:?}", err).contains("$.seller: expected a hex string"));
+
+    let err = validator
+        .encode_datum(r#"{"seller": "00", "price": 1, "active": false, "tags": [], "extra": {"int": 1}}"#)
+        .unwrap_err();
+    assert!(format!("{:?}", err).contains("$.royalty: missing field"));
+
+    let wrong_constr = PlutusData::new_empty_constr_plutus_data(&BigNum::from(5u64));
+    assert!(validator.decode_redeemer(&wrong_constr).is_err());
+}
+
+#[test]
+fn blueprint_definitions_test() {
+    let blueprint = PlutusBlueprint::from_json(&fake_blueprint(&fake_script_hash_hex())).unwrap();
+    assert!(!blueprint.definition_names().0.is_empty());
+
+    let tuple = blueprint
+        .encode_json("Tuple$Int_ByteArray", r#"[1, "ff"]"#)
+        .unwrap();
+    assert_eq!(tuple.as_list().unwrap().len(), 2);
+    assert!(blueprint.encode_json("Tuple$Int_ByteArray", r#"[1]"#).is_err());
+    assert_eq!(
+        blueprint.decode_to_json("Tuple$Int_ByteArray", &tuple).unwrap(),
+        r#"[1,"ff"]"#
+    );
+
+    let map = blueprint
+        .encode_json("Pairs$ByteArray_Int", r#"{"aa": 1, "bb": 2}"#)
+        .unwrap();
+    assert_eq!(map.as_map().unwrap().len(), 2);
+    assert!(blueprint.encode_json("Unknown", "1").is_err());
+}
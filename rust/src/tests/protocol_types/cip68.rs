@@ -0,0 +1,81 @@
+use crate::*;
+
+#[test]
+fn cip67_label_prefix_test() {
+    assert_eq!(hex::encode(Cip67Label::reference_nft().to_bytes()), "000643b0");
+    assert_eq!(hex::encode(Cip67Label::nft().to_bytes()), "000de140");
+    assert_eq!(hex::encode(Cip67Label::fungible_token().to_bytes()), "0014df10");
+    assert_eq!(hex::encode(Cip67Label::rich_fungible_token().to_bytes()), "001bc280");
+    assert_eq!(hex::encode(Cip67Label::new(0).to_bytes()), "00000000");
+    assert_eq!(hex::encode(Cip67Label::new(1).to_bytes()), "00001070");
+    assert_eq!(hex::encode(Cip67Label::new(65535).to_bytes()), "0ffff240");
+
+    let label = Cip67Label::from_bytes(hex::decode("000de140").unwrap()).unwrap();
+    assert_eq!(label.label(), 222);
+    assert!(Cip67Label::from_bytes(hex::decode("000de150").unwrap()).is_err());
+    assert!(Cip67Label::from_bytes(hex::decode("100de140").unwrap()).is_err());
+    assert!(Cip67Label::from_bytes(hex::decode("000de1").unwrap()).is_err());
+}
+
+#[test]
+fn cip67_asset_name_test() {
+    let name = Cip67Label::nft().asset_name(b"SpaceBud".to_vec()).unwrap();
+    assert_eq!(hex::encode(name.name()), "000de1405370616365427564");
+    assert_eq!(Cip67Label::from_asset_name(&name), Some(Cip67Label::nft()));
+    assert_eq!(Cip67Label::asset_name_body(&name), Some(b"SpaceBud".to_vec()));
+
+    let plain_name = AssetName::new(b"SpaceBud".to_vec()).unwrap();
+    assert_eq!(Cip67Label::from_asset_name(&plain_name), None);
+    assert_eq!(Cip67Label::asset_name_body(&plain_name), None);
+
+    assert!(Cip67Label::nft().asset_name(vec![0; 28]).is_ok());
+    assert!(Cip67Label::nft().asset_name(vec![0; 29]).is_err());
+}
+
+#[test]
+fn cip68_datum_json_roundtrip_test() {
+    let json = r#"{"name":"SpaceBud #1","image":"ipfs://abc","traits":["hat","glasses"],"power":9000,"raw":"0xff00"}"#;
+    let datum = Cip68Datum::from_json_metadata(json, 1, None).unwrap();
+    assert_eq!(datum.version(), BigInt::from(1));
+    assert_eq!(
+        datum.extra(),
+        PlutusData::new_empty_constr_plutus_data(&BigNum::zero())
+    );
+    assert_eq!(
+        datum.get("name"),
+        Some(PlutusData::new_bytes(b"SpaceBud #1".to_vec()))
+    );
+    assert_eq!(datum.get("raw"), Some(PlutusData::new_bytes(vec![0xff, 0x00])));
+    assert_eq!(datum.get("missing"), None);
+
+    let json_value: serde_json::Value = serde_json::from_str(json).unwrap();
+    let roundtrip: serde_json::Value =
+        serde_json::from_str(&datum.metadata_json().unwrap()).unwrap();
+    assert_eq!(roundtrip, json_value);
+
+    let data = datum.to_plutus_data();
+    let constr = data.as_constr_plutus_data().unwrap();
+    assert_eq!(constr.alternative(), BigNum::zero());
+    assert_eq!(constr.data().len(), 3);
+    assert_eq!(Cip68Datum::from_plutus_data(&data).unwrap(), datum);
+    let from_bytes = PlutusData::from_bytes(data.to_bytes()).unwrap();
+    assert_eq!(Cip68Datum::from_plutus_data(&from_bytes).unwrap(), datum);
+}
+
+#[test]
+fn cip68_datum_parsing_errors_test() {
+    assert!(Cip68Datum::from_json_metadata("[1, 2]", 1, None).is_err());
+    assert!(Cip68Datum::from_json_metadata(r#"{"a": true}"#, 1, None).is_err());
+
+    let mut fields = PlutusList::new();
+    fields.add(&PlutusData::new_integer(&BigInt::from(1)));
+    fields.add(&PlutusData::new_integer(&BigInt::from(1)));
+    let not_map = PlutusData::new_constr_plutus_data(&ConstrPlutusData::new(&BigNum::zero(), &fields));
+    assert!(Cip68Datum::from_plutus_data(&not_map).is_err());
+    assert!(Cip68Datum::from_plutus_data(&PlutusData::new_integer(&BigInt::from(1))).is_err());
+
+    let datum = Cip68Datum::new(&PlutusMap::new(), 2);
+    let mut wrong_alternative = datum.to_plutus_data().as_constr_plutus_data().unwrap();
+    wrong_alternative.alternative = BigNum::one();
+    assert!(Cip68Datum::from_plutus_data(&PlutusData::new_constr_plutus_data(&wrong_alternative)).is_err());
+}
@@ -305,4 +305,109 @@ fn drep_bech32_to_bech_cip_129() {
     let drep2 = DRep::from_bech32("drep_script1dja6lg0xdt4tfrd7r2svc3ywh5xqrl6w85axjp0gtdu6xw6h2wn").unwrap();
     assert_eq!(drep1.to_bech32(true).unwrap(), "drep1ytsg4j3k9sgsxye6uu3trxpcqcq6h7t8p9e42e6wgjzt5yggls86y");
     assert_eq!(drep2.to_bech32(true).unwrap(), "drep1ydkthtapue4w4dydhcd2pnzy367scq0lfc7n56g9apdhngcaf8d6w");
-}
\ No newline at end of file
+}
+
+#[test]
+fn governance_identifier_drep_test() {
+    let cip_129 = "drep1ytsg4j3k9sgsxye6uu3trxpcqcq6h7t8p9e42e6wgjzt5yggls86y";
+    let cip_105 = "drep_vkh1uz9v5d3vzyp3xwh8y2ceswqxqx4ljecfwd2kwnjysjapz3kx3ey";
+    let legacy = "drep1uz9v5d3vzyp3xwh8y2ceswqxqx4ljecfwd2kwnjysjapzh3avs7";
+    let id1 = GovernanceIdentifier::from_bech32(cip_129).unwrap();
+    let id2 = GovernanceIdentifier::from_bech32(cip_105).unwrap();
+    let id3 = GovernanceIdentifier::from_bech32(legacy).unwrap();
+    assert_eq!(id1.kind(), GovernanceIdentifierKind::DRep);
+    assert_eq!(id1, id2);
+    assert_eq!(id1, id3);
+    assert_eq!(id1.as_drep(), Some(DRep::from_bech32(cip_129).unwrap()));
+    assert_eq!(id1.as_governance_action_id(), None);
+    assert_eq!(id1.to_bech32(true).unwrap(), cip_129);
+    assert_eq!(id1.to_bech32(false).unwrap(), cip_105);
+
+    let script_id = GovernanceIdentifier::from_bech32(
+        "drep_script1dja6lg0xdt4tfrd7r2svc3ywh5xqrl6w85axjp0gtdu6xw6h2wn",
+    )
+    .unwrap();
+    assert_eq!(
+        script_id.to_bech32(true).unwrap(),
+        "drep1ydkthtapue4w4dydhcd2pnzy367scq0lfc7n56g9apdhngcaf8d6w"
+    );
+    assert_eq!(
+        GovernanceIdentifier::new_drep(&script_id.as_drep().unwrap()).unwrap(),
+        script_id
+    );
+    assert!(GovernanceIdentifier::new_drep(&DRep::new_always_abstain()).is_err());
+}
+
+#[test]
+fn governance_identifier_committee_test() {
+    let hot = Credential::from_keyhash(&fake_key_hash(1));
+    let cold = Credential::from_scripthash(&fake_script_hash(2));
+    let hot_id = GovernanceIdentifier::new_committee_hot_credential(&hot);
+    let cold_id = GovernanceIdentifier::new_committee_cold_credential(&cold);
+
+    let hot_bech32 = hot_id.to_bech32(true).unwrap();
+    let cold_bech32 = cold_id.to_bech32(true).unwrap();
+    assert!(hot_bech32.starts_with("cc_hot1"));
+    assert!(cold_bech32.starts_with("cc_cold1"));
+    assert_eq!(hot_id.to_bytes().unwrap()[0], 0x02);
+    assert_eq!(cold_id.to_bytes().unwrap()[0], 0x13);
+
+    let parsed_hot = GovernanceIdentifier::from_bech32(&hot_bech32).unwrap();
+    assert_eq!(parsed_hot.kind(), GovernanceIdentifierKind::CommitteeHotCredential);
+    assert_eq!(parsed_hot.as_credential(), Some(hot.clone()));
+    assert_eq!(parsed_hot.as_drep(), None);
+
+    let parsed_cold = GovernanceIdentifier::from_bech32(&cold_bech32).unwrap();
+    assert_eq!(parsed_cold.kind(), GovernanceIdentifierKind::CommitteeColdCredential);
+    assert_eq!(parsed_cold.as_credential(), Some(cold));
+
+    let legacy_hot = hot_id.to_bech32(false).unwrap();
+    assert!(legacy_hot.starts_with("cc_hot_vkh1"));
+    assert_eq!(GovernanceIdentifier::from_bech32(&legacy_hot).unwrap(), hot_id);
+    let legacy_cold = cold_id.to_bech32(false).unwrap();
+    assert!(legacy_cold.starts_with("cc_cold_script1"));
+    assert_eq!(GovernanceIdentifier::from_bech32(&legacy_cold).unwrap(), cold_id);
+
+    assert_eq!(
+        GovernanceIdentifier::from_hex(&hot_id.to_hex().unwrap()).unwrap(),
+        hot_id
+    );
+    assert!(DRep::from_bech32(&hot_bech32).is_err());
+}
+
+#[test]
+fn governance_identifier_header_mismatch_test() {
+    let drep_id = GovernanceIdentifier::new_drep(&DRep::new_key_hash(&fake_key_hash(1))).unwrap();
+    let mut bytes = drep_id.to_bytes().unwrap();
+    bytes[0] = 0x02;
+    let mismatched = bech32::encode("drep", bech32::ToBase32::to_base32(&bytes)).unwrap();
+    assert!(GovernanceIdentifier::from_bech32(&mismatched).is_err());
+}
+
+#[test]
+fn governance_identifier_gov_action_test() {
+    let action_id = GovernanceActionId::new(&TransactionHash::from([0u8; 32]), 17);
+    let bech32 = "gov_action1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpzklpgpf";
+    assert_eq!(action_id.to_bech32().unwrap(), bech32);
+    assert_eq!(GovernanceActionId::from_bech32(bech32).unwrap(), action_id);
+
+    let id = GovernanceIdentifier::from_bech32(bech32).unwrap();
+    assert_eq!(id.kind(), GovernanceIdentifierKind::GovernanceActionId);
+    assert_eq!(id.as_governance_action_id(), Some(action_id));
+    assert_eq!(id.as_credential(), None);
+    assert!(id.to_bech32(false).is_err());
+
+    let big_index_id = GovernanceActionId::new(&fake_tx_hash(1), 300);
+    let big_index_bech32 = big_index_id.to_bech32().unwrap();
+    assert_eq!(
+        GovernanceActionId::from_bech32(&big_index_bech32).unwrap(),
+        big_index_id
+    );
+    let identifier = GovernanceIdentifier::new_governance_action_id(&big_index_id);
+    assert_eq!(identifier.to_bytes().unwrap().len(), 34);
+    assert_eq!(
+        GovernanceIdentifier::from_bytes(identifier.to_bytes().unwrap()).unwrap(),
+        identifier
+    );
+    assert!(GovernanceActionId::new(&fake_tx_hash(1), 70000).to_bech32().is_err());
+}
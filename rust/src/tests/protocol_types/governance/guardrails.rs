@@ -0,0 +1,173 @@
+use crate::tests::fakes::fake_cost_models;
+use crate::*;
+
+fn unit(numerator: u64, denominator: u64) -> UnitInterval {
+    UnitInterval::new(&BigNum(numerator), &BigNum(denominator))
+}
+
+fn rule_ids(violations: &GuardrailViolations) -> Vec<String> {
+    violations.into_iter().filter_map(|v| v.rule_id()).collect()
+}
+
+#[test]
+fn guardrails_valid_update_test() {
+    let mut update = ProtocolParamUpdate::new();
+    update.set_minfee_a(&Coin::from(44u32));
+    update.set_minfee_b(&Coin::from(155381u32));
+    update.set_pool_pledge_influence(&unit(3, 10));
+    update.set_execution_costs(&ExUnitPrices::new(&unit(577, 10000), &unit(721, 10000000)));
+    update.set_max_tx_ex_units(&ExUnits::new(&BigNum(14000000), &BigNum(10000000000)));
+    update.set_cost_models(&fake_cost_models());
+    update.set_governance_action_deposit(&Coin::from(100_000_000_000u64));
+
+    let ruleset = GuardrailsRuleset::conway_default();
+    let violations = ruleset.check_protocol_param_update(&update);
+    assert_eq!(violations.len(), 0);
+
+    let action = ParameterChangeAction::new(&update);
+    assert_eq!(ruleset.check_parameter_change_action(&action).len(), 0);
+}
+
+#[test]
+fn guardrails_integer_violations_test() {
+    let mut update = ProtocolParamUpdate::new();
+    update.set_minfee_a(&Coin::zero());
+    update.set_max_tx_size(40000);
+    update.set_collateral_percentage(150);
+
+    let violations = GuardrailsRuleset::conway_default().check_protocol_param_update(&update);
+    assert_eq!(rule_ids(&violations), vec!["TFPB-01", "TFPB-03", "MTS-01"]);
+
+    let minfee_violations = violations.for_param(0);
+    assert_eq!(minfee_violations.len(), 2);
+    assert_eq!(minfee_violations.get(0).param_name(), "minfee_a");
+    assert_eq!(
+        minfee_violations.get(0).kind(),
+        GuardrailViolationKind::PredicateFailed
+    );
+}
+
+#[test]
+fn guardrails_list_violations_test() {
+    let mut update = ProtocolParamUpdate::new();
+    update.set_execution_costs(&ExUnitPrices::new(&unit(1, 10000), &unit(721, 10000000)));
+    let mut thresholds = crate::tests::fakes::fake_drep_voting_thresholds();
+    thresholds.set_motion_no_confidence(&unit(67, 100));
+    thresholds.set_committee_normal(&unit(67, 100));
+    thresholds.set_committee_no_confidence(&unit(6, 10));
+    thresholds.set_update_constitution(&unit(1, 2));
+    thresholds.set_hard_fork_initiation(&unit(6, 10));
+    thresholds.set_pp_network_group(&unit(67, 100));
+    thresholds.set_pp_economic_group(&unit(67, 100));
+    thresholds.set_pp_technical_group(&unit(67, 100));
+    thresholds.set_pp_governance_group(&unit(75, 100));
+    thresholds.set_treasury_withdrawal(&unit(67, 100));
+    update.set_drep_voting_thresholds(&thresholds);
+
+    let violations = GuardrailsRuleset::conway_default().check_protocol_param_update(&update);
+    assert_eq!(violations.len(), 2);
+    assert_eq!(violations.get(0).param_key(), 19);
+    assert_eq!(violations.get(0).param_name(), "execution_costs.mem_price");
+    assert_eq!(violations.get(0).rule_id(), Some("EIUP-PM-02".to_string()));
+    assert_eq!(violations.get(1).param_key(), 26);
+    assert_eq!(
+        violations.get(1).param_name(),
+        "drep_voting_thresholds.update_constitution"
+    );
+    assert_eq!(violations.get(1).rule_id(), Some("VT-DCON-01".to_string()));
+}
+
+#[test]
+fn guardrails_not_allowed_param_test() {
+    let mut update = ProtocolParamUpdate::new();
+    update.set_protocol_version(&ProtocolVersion::new(10, 0));
+    update.set_minfee_a(&Coin::from(44u32));
+
+    let violations = GuardrailsRuleset::conway_default().check_protocol_param_update(&update);
+    assert_eq!(violations.len(), 1);
+    assert_eq!(
+        violations.get(0).kind(),
+        GuardrailViolationKind::ParameterNotAllowed
+    );
+    assert_eq!(violations.get(0).param_key(), 14);
+    assert_eq!(violations.get(0).rule_id(), None);
+}
+
+#[test]
+fn guardrails_custom_ruleset_test() {
+    let json = r#"{
+        "1": [{
+            "type": "integer",
+            "predicates": [{ "minValue": 10, "maxValue": 20, "$comment": "in range (R-01)" }]
+        }],
+        "33": {
+            "type": "unit",
+            "predicates": [{ "notEqual": { "numerator": 0, "denominator": 1 } }]
+        }
+    }"#;
+    let ruleset = GuardrailsRuleset::from_json(json).unwrap();
+    assert!(ruleset.has_param(1));
+    assert!(!ruleset.has_param(0));
+
+    let mut update = ProtocolParamUpdate::new();
+    update.set_minfee_b(&Coin::from(21u32));
+    update.set_ref_script_coins_per_byte(&unit(0, 5));
+    let violations = ruleset.check_protocol_param_update(&update);
+    assert_eq!(violations.len(), 2);
+    assert_eq!(violations.get(0).rule_id(), Some("R-01".to_string()));
+    assert_eq!(violations.get(0).description(), "in range (R-01)");
+    assert_eq!(violations.get(1).rule_id(), None);
+    assert_eq!(violations.get(1).param_name(), "ref_script_coins_per_byte");
+}
+
+#[test]
+fn guardrails_cost_models_test() {
+    let ruleset = GuardrailsRuleset::conway_default();
+    let mut update = ProtocolParamUpdate::new();
+    update.set_cost_models(&TxBuilderConstants::plutus_conway_cost_models());
+    assert_eq!(ruleset.check_protocol_param_update(&update).len(), 0);
+
+    let mut cost_models = TxBuilderConstants::plutus_conway_cost_models();
+    let mut v2 = cost_models.get(&Language::new_plutus_v2()).unwrap();
+    v2.0.pop();
+    cost_models.insert(&Language::new_plutus_v2(), &v2);
+    update.set_cost_models(&cost_models);
+    let violations = ruleset.check_protocol_param_update(&update);
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations.get(0).param_key(), 18);
+    assert_eq!(violations.get(0).param_name(), "cost_models.plutus_v2");
+    assert_eq!(
+        violations.get(0).description(),
+        "PlutusV2 cost model must have 175 parameters"
+    );
+
+    let custom = GuardrailsRuleset::from_json(
+        r#"{"18": {"type": "costModels", "languages": {"0": {"lengths": [166]}}}}"#,
+    )
+    .unwrap();
+    let violations = custom.check_protocol_param_update(&update);
+    assert_eq!(violations.len(), 2);
+    assert_eq!(
+        violations.get(0).description(),
+        "cost_models.plutus_v2 is not an allowed cost model language"
+    );
+}
+
+#[test]
+fn guardrails_invalid_ruleset_test() {
+    assert!(GuardrailsRuleset::from_json("[]").is_err());
+    assert!(GuardrailsRuleset::from_json(r#"{"15": {"type": "integer"}}"#).is_err());
+    assert!(GuardrailsRuleset::from_json(r#"{"9": {"type": "integer"}}"#).is_err());
+    assert!(GuardrailsRuleset::from_json(
+        r#"{"19": {"type": "list", "elements": [{"type": "unit"}]}}"#
+    )
+    .is_err());
+    assert!(GuardrailsRuleset::from_json(
+        r#"{"0": {"type": "integer", "predicates": [{"minValue": 1.5}]}}"#
+    )
+    .is_err());
+    assert!(GuardrailsRuleset::from_json(
+        r#"{"18": {"type": "costModels", "languages": {"0": {"lengths": ["166"]}}}}"#
+    )
+    .is_err());
+}
@@ -3,3 +3,9 @@ mod fixed_tx;
 mod governance;
 mod protocol_param_update;
 mod fixed_block;
+mod nonce_evolution;
+mod cip68;
+mod nft_metadata;
+mod tx_messages;
+mod blueprint;
+mod uplc;
@@ -0,0 +1,216 @@
+use crate::tests::fakes::{fake_base_address, fake_policy_id};
+use crate::*;
+
+#[test]
+fn cip25_v1_round_trip_test() {
+    let policy_id = fake_policy_id(1);
+    let asset_name = AssetName::new(b"SpaceBud1".to_vec()).unwrap();
+    let long_image = format!("ipfs://{}", "Q".repeat(100));
+
+    let mut asset = Cip25AssetMetadata::new("SpaceBud #1", &long_image);
+    asset.set_media_type("image/png");
+    asset.set_description("A bud in space");
+    let mut file = Cip25File::new("image/png", &long_image);
+    file.set_name("full");
+    asset.add_file(&file);
+    asset
+        .set_property("traits", &TransactionMetadatum::new_text("hat".to_string()).unwrap())
+        .unwrap();
+
+    let mut metadata = Cip25Metadata::new(Cip25Version::V1);
+    metadata.insert(&policy_id, &asset_name, &asset);
+
+    let metadatum = metadata.to_metadatum().unwrap();
+    let root = metadatum.as_map().unwrap();
+    let policy_map = root.get_str(&policy_id.to_hex()).unwrap().as_map().unwrap();
+    let asset_map = policy_map.get_str("SpaceBud1").unwrap().as_map().unwrap();
+    let image = asset_map.get_str("image").unwrap().as_list().unwrap();
+    assert_eq!(image.len(), 2);
+    assert_eq!(image.get(0).as_text().unwrap().len(), 64);
+
+    let decoded = Cip25Metadata::from_metadatum(&metadatum).unwrap();
+    assert_eq!(decoded, metadata);
+    let decoded_asset = decoded.get(&policy_id, &asset_name).unwrap();
+    assert_eq!(decoded_asset.image(), long_image);
+    assert_eq!(decoded_asset.files().get(0).name(), Some("full".to_string()));
+    assert_eq!(decoded_asset.properties().len(), 1);
+}
+
+#[test]
+fn cip25_v2_uses_bytes_test() {
+    let policy_id = fake_policy_id(2);
+    let asset_name = AssetName::new(vec![0xff, 0x00, 0x01]).unwrap();
+    let mut metadata = Cip25Metadata::new(Cip25Version::V2);
+    metadata.insert(&policy_id, &asset_name, &Cip25AssetMetadata::new("Token", "ipfs://x"));
+
+    let mut aux = AuxiliaryData::new();
+    metadata.add_to_auxiliary_data(&mut aux).unwrap();
+    let root = aux
+        .metadata()
+        .unwrap()
+        .get(&BigNum::from(721u64))
+        .unwrap()
+        .as_map()
+        .unwrap();
+    assert_eq!(root.get_str("version").unwrap().as_int().unwrap().as_i32_or_nothing(), Some(2));
+    let policy_map = root
+        .get(&TransactionMetadatum::new_bytes(policy_id.to_bytes()).unwrap())
+        .unwrap()
+        .as_map()
+        .unwrap();
+    assert!(policy_map.has(&TransactionMetadatum::new_bytes(asset_name.name()).unwrap()));
+
+    let decoded = Cip25Metadata::from_auxiliary_data(&aux).unwrap().unwrap();
+    assert_eq!(decoded.version(), Cip25Version::V2);
+    assert_eq!(decoded, metadata);
+    assert!(Cip25Metadata::from_auxiliary_data(&AuxiliaryData::new())
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn cip25_parse_from_json_test() {
+    let policy_id = fake_policy_id(3);
+    let json = format!(
+        r#"{{"{}": {{"NFT1": {{"name": "NFT 1", "image": ["ipfs://abc", "def"], "mediaType": "image/png"}}}}}}"#,
+        policy_id.to_hex()
+    );
+    let metadatum =
+        encode_json_str_to_metadatum(json, MetadataJsonSchema::NoConversions).unwrap();
+    let metadata = Cip25Metadata::from_metadatum(&metadatum).unwrap();
+    assert_eq!(metadata.version(), Cip25Version::V1);
+    let asset = metadata
+        .get(&policy_id, &AssetName::new(b"NFT1".to_vec()).unwrap())
+        .unwrap();
+    assert_eq!(asset.name(), "NFT 1");
+    assert_eq!(asset.image(), "ipfs://abcdef");
+    assert_eq!(asset.media_type(), Some("image/png".to_string()));
+
+    let missing_image = format!(
+        r#"{{"{}": {{"NFT1": {{"name": "NFT 1"}}}}}}"#,
+        policy_id.to_hex()
+    );
+    let metadatum =
+        encode_json_str_to_metadatum(missing_image, MetadataJsonSchema::NoConversions).unwrap();
+    assert!(Cip25Metadata::from_metadatum(&metadatum).is_err());
+
+    let bad_policy = r#"{"abcd": {"NFT1": {"name": "NFT 1", "image": "ipfs://x"}}}"#;
+    let metadatum =
+        encode_json_str_to_metadatum(bad_policy.to_string(), MetadataJsonSchema::NoConversions)
+            .unwrap();
+    assert!(Cip25Metadata::from_metadatum(&metadatum).is_err());
+}
+
+#[test]
+fn cip25_binary_asset_name_test() {
+    let policy_id = fake_policy_id(4);
+    let asset_name = AssetName::new(vec![0x00, 0x0d, 0xe1, 0x40, 0xff]).unwrap();
+    let asset = Cip25AssetMetadata::new("Token", "ipfs://x");
+
+    let mut metadata = Cip25Metadata::new(Cip25Version::V2);
+    metadata.insert(&policy_id, &asset_name, &asset);
+    let decoded = Cip25Metadata::from_metadatum(&metadata.to_metadatum().unwrap()).unwrap();
+    assert_eq!(decoded.get(&policy_id, &asset_name), Some(asset.clone()));
+    assert_eq!(decoded, metadata);
+
+    let mut v1 = Cip25Metadata::new(Cip25Version::V1);
+    v1.insert(&policy_id, &asset_name, &asset);
+    assert!(v1.to_metadatum().is_err());
+}
+
+#[test]
+fn cip25_version_key_shape_test() {
+    let policy_id = fake_policy_id(5);
+    let asset = Cip25AssetMetadata::new("Token", "ipfs://x").to_metadatum().unwrap();
+    let metadatum = |version: Option<i32>, policy_key: TransactionMetadatum, asset_key| {
+        let mut assets = MetadataMap::new();
+        assets.insert(&asset_key, &asset);
+        let mut root = MetadataMap::new();
+        root.insert(&policy_key, &TransactionMetadatum::new_map(&assets));
+        if let Some(version) = version {
+            root.insert_str("version", &TransactionMetadatum::new_int(&Int::new_i32(version)))
+                .unwrap();
+        }
+        TransactionMetadatum::new_map(&root)
+    };
+    let text = |s: &str| TransactionMetadatum::new_text(s.to_string()).unwrap();
+    let bytes = |b: Vec<u8>| TransactionMetadatum::new_bytes(b).unwrap();
+
+    let v1 = metadatum(None, text(&policy_id.to_hex()), text("NFT"));
+    assert!(Cip25Metadata::from_metadatum(&v1).is_ok());
+    let v2 = metadatum(Some(2), bytes(policy_id.to_bytes()), bytes(b"NFT".to_vec()));
+    assert!(Cip25Metadata::from_metadatum(&v2).is_ok());
+
+    let v1_bytes_policy = metadatum(None, bytes(policy_id.to_bytes()), text("NFT"));
+    assert!(Cip25Metadata::from_metadatum(&v1_bytes_policy).is_err());
+    let v1_bytes_name = metadatum(None, text(&policy_id.to_hex()), bytes(b"NFT".to_vec()));
+    assert!(Cip25Metadata::from_metadatum(&v1_bytes_name).is_err());
+    let v2_text_policy = metadatum(Some(2), text(&policy_id.to_hex()), bytes(b"NFT".to_vec()));
+    assert!(Cip25Metadata::from_metadatum(&v2_text_policy).is_err());
+    let v2_text_name = metadatum(Some(2), bytes(policy_id.to_bytes()), text("NFT"));
+    assert!(Cip25Metadata::from_metadatum(&v2_text_name).is_err());
+}
+
+#[test]
+fn cip25_reserved_property_test() {
+    let mut asset = Cip25AssetMetadata::new("Token", "ipfs://x");
+    let value = TransactionMetadatum::new_text("y".to_string()).unwrap();
+    assert!(asset.set_property("image", &value).is_err());
+    assert!(asset.set_property("website", &value).is_ok());
+}
+
+#[test]
+fn cip27_royalty_test() {
+    let address = fake_base_address(0);
+    let royalty = Cip27Royalty::new("0.2", &address).unwrap();
+
+    let mut aux = AuxiliaryData::new();
+    royalty.add_to_auxiliary_data(&mut aux).unwrap();
+    let map = aux
+        .metadata()
+        .unwrap()
+        .get(&BigNum::from(777u64))
+        .unwrap()
+        .as_map()
+        .unwrap();
+    assert_eq!(map.get_str("rate").unwrap().as_text().unwrap(), "0.2");
+    assert_eq!(map.get_str("addr").unwrap().as_list().unwrap().len(), 2);
+
+    let decoded = Cip27Royalty::from_auxiliary_data(&aux).unwrap().unwrap();
+    assert_eq!(decoded, royalty);
+    assert_eq!(decoded.address(), address);
+
+    assert!(Cip27Royalty::new("1", &address).is_ok());
+    assert!(Cip27Royalty::new("1.000", &address).is_ok());
+    assert!(Cip27Royalty::new("0", &address).is_ok());
+    assert!(Cip27Royalty::new("1.01", &address).is_err());
+    assert!(Cip27Royalty::new("-0.1", &address).is_err());
+    assert!(Cip27Royalty::new(".5", &address).is_err());
+    assert!(Cip27Royalty::new("0.", &address).is_err());
+    assert!(Cip27Royalty::new("abc", &address).is_err());
+}
+
+#[test]
+fn cip27_legacy_pct_test() {
+    let address = fake_base_address(1);
+    let mut map = MetadataMap::new();
+    map.insert_str("pct", &TransactionMetadatum::new_text("0.05".to_string()).unwrap())
+        .unwrap();
+    map.insert_str(
+        "addr",
+        &TransactionMetadatum::new_text(address.to_bech32(None).unwrap()[..50].to_string())
+            .unwrap(),
+    )
+    .unwrap();
+    assert!(Cip27Royalty::from_metadatum(&TransactionMetadatum::new_map(&map)).is_err());
+
+    let mut list = MetadataList::new();
+    let bech32 = address.to_bech32(None).unwrap();
+    list.add(&TransactionMetadatum::new_text(bech32[..50].to_string()).unwrap());
+    list.add(&TransactionMetadatum::new_text(bech32[50..].to_string()).unwrap());
+    map.insert_str("addr", &TransactionMetadatum::new_list(&list))
+        .unwrap();
+    let royalty = Cip27Royalty::from_metadatum(&TransactionMetadatum::new_map(&map)).unwrap();
+    assert_eq!(royalty.rate(), "0.05");
+    assert_eq!(royalty.address(), address);
+}
@@ -0,0 +1,159 @@
+use crate::*;
+
+fn header_body(slot: u64, prev_hash: Option<[u8; 32]>, vrf_output: u8) -> HeaderBody {
+    HeaderBody::new_headerbody(
+        1,
+        &BigNum(slot),
+        prev_hash.map(BlockHash::from),
+        &Vkey::new(&PrivateKey::from_normal_bytes(&[1; 32]).unwrap().to_public()),
+        &VRFVKey::from([2; 32]),
+        &VRFCert::new(vec![vrf_output; 64], vec![0; 80]).unwrap(),
+        0,
+        &BlockHash::from([3; 32]),
+        &OperationalCert::new(
+            &KESVKey::from([4; 32]),
+            0,
+            0,
+            &Ed25519Signature::from_bytes(vec![5; 64]).unwrap(),
+        ),
+        &ProtocolVersion::new(9, 0),
+    )
+}
+
+fn nonce(byte: u8) -> Nonce {
+    Nonce::new_from_hash(vec![byte; 32]).unwrap()
+}
+
+#[test]
+fn nonce_combine() {
+    let identity = Nonce::new_identity();
+    assert_eq!(nonce(1).combine(&identity), nonce(1));
+    assert_eq!(identity.combine(&nonce(1)), nonce(1));
+    assert_eq!(identity.combine(&identity), identity);
+    let mut bytes = vec![1; 32];
+    bytes.extend_from_slice(&[2; 32]);
+    assert_eq!(
+        nonce(1).combine(&nonce(2)).get_hash(),
+        Some(blake2b256(&bytes).to_vec())
+    );
+    assert_ne!(nonce(1).combine(&nonce(2)), nonce(2).combine(&nonce(1)));
+}
+
+#[test]
+fn header_vrf_nonce() {
+    let mut tagged = b"N".to_vec();
+    tagged.extend_from_slice(&[7; 64]);
+    assert_eq!(
+        header_body(1, None, 7).vrf_nonce().get_hash(),
+        Some(blake2b256(&blake2b256(&tagged)).to_vec())
+    );
+
+    let mut tpraos = header_body(1, None, 7);
+    tpraos.leader_cert = HeaderLeaderCertEnum::NonceAndLeader(
+        VRFCert::new(vec![8; 64], vec![0; 80]).unwrap(),
+        VRFCert::new(vec![9; 64], vec![0; 80]).unwrap(),
+    );
+    assert_eq!(
+        tpraos.vrf_nonce().get_hash(),
+        Some(blake2b256(&[8; 64]).to_vec())
+    );
+}
+
+#[test]
+fn stability_windows() {
+    let f = UnitInterval::new(&BigNum(1), &BigNum(20));
+    assert_eq!(NonceEvolution::stability_window(2160, &f).unwrap(), BigNum(129600));
+    assert_eq!(
+        NonceEvolution::randomness_stabilisation_window(2160, &f).unwrap(),
+        BigNum(172800)
+    );
+    let f = UnitInterval::new(&BigNum(1), &BigNum(3));
+    assert_eq!(NonceEvolution::stability_window(1, &f).unwrap(), BigNum(9));
+    let f = UnitInterval::new(&BigNum(2), &BigNum(3));
+    assert_eq!(NonceEvolution::stability_window(1, &f).unwrap(), BigNum(5));
+    let f = UnitInterval::new(&BigNum(0), &BigNum(1));
+    assert!(NonceEvolution::stability_window(2160, &f).is_err());
+}
+
+#[test]
+fn nonce_evolution_epochs() {
+    // epochs of 100 slots, the candidate nonce being frozen 30 slots before their end
+    let history = EraHistory::new(&BigNum(0), &BigNum(1000), 100).unwrap();
+    let initial = nonce(0);
+    let mut state = NonceEvolution::new(&history, &BigNum(30), 0, &initial);
+    assert_eq!(state.epoch_nonce(), initial);
+
+    let first = header_body(10, None, 1);
+    let second = header_body(50, Some([11; 32]), 2);
+    let third = header_body(80, Some([12; 32]), 3);
+    for header in [&first, &second, &third] {
+        state.apply_header(header).unwrap();
+    }
+    let before_window = initial.combine(&first.vrf_nonce()).combine(&second.vrf_nonce());
+    let evolving = before_window.combine(&third.vrf_nonce());
+    assert_eq!(state.evolving_nonce(), evolving);
+    assert_eq!(state.candidate_nonce(), before_window);
+    assert_eq!(state.lab_nonce(), nonce(12));
+    assert_eq!(state.epoch_nonce(), initial);
+    assert_eq!(state.next_epoch_nonce(), before_window);
+
+    let fourth = header_body(120, Some([13; 32]), 4);
+    state.apply_header(&fourth).unwrap();
+    assert_eq!(state.epoch(), 1);
+    assert_eq!(state.epoch_nonce(), before_window);
+    assert_eq!(state.last_epoch_block_nonce(), nonce(12));
+    assert_eq!(state.evolving_nonce(), evolving.combine(&fourth.vrf_nonce()));
+    assert_eq!(state.candidate_nonce(), state.evolving_nonce());
+    assert_eq!(
+        state.next_epoch_nonce(),
+        state.candidate_nonce().combine(&nonce(12))
+    );
+    assert!(state.apply_header(&third).is_err());
+
+    // an epoch without blocks, and the extra entropy of TPraos
+    state.set_extra_entropy(&nonce(14));
+    let candidate = state.candidate_nonce();
+    state.tick_epoch(2);
+    assert_eq!(state.epoch_nonce(), candidate.combine(&nonce(12)).combine(&nonce(14)));
+    assert_eq!(state.last_epoch_block_nonce(), nonce(13));
+    state.tick_epoch(2);
+    assert_eq!(state.epoch(), 2);
+    state.apply_header(&header_body(320, Some([15; 32]), 5)).unwrap();
+    assert_eq!(
+        state.epoch_nonce(),
+        candidate.combine(&nonce(13)).combine(&nonce(14))
+    );
+
+    let mut restored = NonceEvolution::new(&history, &BigNum(30), 1, &initial);
+    restored.set_nonces(&before_window, &evolving, &before_window, &nonce(12), &nonce(12));
+    restored.apply_header(&fourth).unwrap();
+    assert_eq!(restored.evolving_nonce(), evolving.combine(&fourth.vrf_nonce()));
+    assert_eq!(restored.epoch_nonce(), before_window);
+}
+
+#[test]
+fn nonce_evolution_mainnet_shelley_start() {
+    // the nonce of epoch 208, the first Shelley epoch of mainnet, is the hash of the Shelley genesis
+    let genesis_hash = "1a3be38bcbb7911969283716ad7aa550250226b76a61fc51cc9a9a35d9276d81";
+    let initial = Nonce::new_from_hash(hex::decode(genesis_hash).unwrap()).unwrap();
+    let f = UnitInterval::new(&BigNum(1), &BigNum(20));
+    let window = NonceEvolution::stability_window(2160, &f).unwrap();
+    let mut state = NonceEvolution::new(&EraHistory::mainnet(), &window, 208, &initial);
+    assert_eq!(state.epoch_nonce().get_hash(), Some(hex::decode(genesis_hash).unwrap()));
+
+    // epoch 208 spans the slots 4492800 to 4924799, the candidate nonce is frozen from 4795200
+    let last_candidate = header_body(4795199, Some([1; 32]), 1);
+    state.apply_header(&last_candidate).unwrap();
+    let candidate = initial.combine(&last_candidate.vrf_nonce());
+    assert_eq!(state.candidate_nonce(), candidate);
+    let frozen = header_body(4795200, Some([2; 32]), 2);
+    state.apply_header(&frozen).unwrap();
+    assert_eq!(state.candidate_nonce(), candidate);
+    assert_eq!(state.evolving_nonce(), candidate.combine(&frozen.vrf_nonce()));
+
+    state.apply_header(&header_body(4924800, Some([3; 32]), 3)).unwrap();
+    // the state starts without the block hash of a previous epoch
+    assert_eq!(state.epoch(), 209);
+    assert_eq!(state.epoch_nonce(), candidate);
+    assert_eq!(state.last_epoch_block_nonce(), nonce(2));
+}
@@ -0,0 +1,128 @@
+use crate::tests::fakes::fake_default_tx_builder;
+use crate::*;
+
+#[test]
+fn cip20_message_chunking_test() {
+    let long_line = "é".repeat(40);
+    let message = Cip20Message::from_text(&format!("Invoice #42\n{}", long_line));
+    assert_eq!(message.len(), 3);
+    assert_eq!(message.get(0), "Invoice #42");
+    assert_eq!(message.get(1).len(), 64);
+    assert_eq!(format!("{}{}", message.get(1), message.get(2)), long_line);
+
+    let metadatum = message.to_metadatum().unwrap();
+    let lines = metadatum.as_map().unwrap().get_str("msg").unwrap().as_list().unwrap();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(Cip20Message::from_metadatum(&metadatum).unwrap(), message);
+}
+
+#[test]
+fn cip20_message_from_json_test() {
+    let json = r#"{"msg": ["Happy birthday", "from Alice"]}"#;
+    let metadatum =
+        encode_json_str_to_metadatum(json.to_string(), MetadataJsonSchema::NoConversions).unwrap();
+    let message = Cip20Message::from_metadatum(&metadatum).unwrap();
+    assert_eq!(message.text(), "Happy birthday\nfrom Alice");
+
+    let invalid = r#"{"msg": "not a list"}"#;
+    let metadatum =
+        encode_json_str_to_metadatum(invalid.to_string(), MetadataJsonSchema::NoConversions)
+            .unwrap();
+    assert!(Cip20Message::from_metadatum(&metadatum).is_err());
+}
+
+#[test]
+fn tx_builder_message_test() {
+    let mut tx_builder = fake_default_tx_builder();
+    assert!(tx_builder.get_message().unwrap().is_none());
+
+    let message = Cip20Message::from_text("Thanks for the coffee");
+    tx_builder.add_message(&message).unwrap();
+    assert_eq!(tx_builder.get_message().unwrap().unwrap(), message);
+
+    let aux = tx_builder.get_auxiliary_data().unwrap();
+    assert_eq!(Cip20Message::from_auxiliary_data(&aux).unwrap().unwrap(), message);
+    assert!(Cip83EncryptedMessage::from_auxiliary_data(&aux).is_err());
+}
+
+#[test]
+fn cip83_encrypted_message_round_trip_test() {
+    let message = Cip20Message::from_text("Secret\nmessage");
+    let encrypted = Cip83EncryptedMessage::encrypt(&message, None).unwrap();
+
+    let mut tx_builder = fake_default_tx_builder();
+    tx_builder.add_encrypted_message(&encrypted).unwrap();
+    let aux = tx_builder.get_auxiliary_data().unwrap();
+    assert!(tx_builder.get_message().is_err());
+
+    let metadatum = aux.metadata().unwrap().get(&BigNum::from(674u64)).unwrap();
+    let map = metadatum.as_map().unwrap();
+    assert_eq!(map.get_str("enc").unwrap().as_text().unwrap(), "basic");
+    let chunks = map.get_str("msg").unwrap().as_list().unwrap();
+    for i in 0..chunks.len() {
+        assert!(chunks.get(i).as_text().unwrap().len() <= 64);
+    }
+
+    let parsed = Cip83EncryptedMessage::from_auxiliary_data(&aux).unwrap().unwrap();
+    assert_eq!(parsed, encrypted);
+    assert_eq!(parsed.decrypt(None).unwrap(), message);
+    assert!(parsed.decrypt(Some("wrong".to_string())).is_err());
+
+    let with_passphrase =
+        Cip83EncryptedMessage::encrypt(&message, Some("hunter2".to_string())).unwrap();
+    assert!(with_passphrase.decrypt(None).is_err());
+    assert_eq!(
+        with_passphrase.decrypt(Some("hunter2".to_string())).unwrap(),
+        message
+    );
+}
+
+// produced with `openssl enc -e -aes-256-cbc -pbkdf2 -iter 10000 -md sha256 -a -k cardano`
+// from the CIP-83 example message
+#[test]
+fn cip83_openssl_vector_test() {
+    let encoded = "U2FsdGVkX19leEESeQel9l0YMsyT6tKsN7ENLVokwRFALmSuTNUMyahenhToBUZF7k/u2p63HT0PHO4JxvKz8bqxQ40gslLeaRRFgCrXvoE=";
+    let mut map = MetadataMap::new();
+    map.insert_str("enc", &TransactionMetadatum::new_text("basic".to_string()).unwrap())
+        .unwrap();
+    let mut lines = MetadataList::new();
+    lines.add(&TransactionMetadatum::new_text(encoded[..64].to_string()).unwrap());
+    lines.add(&TransactionMetadatum::new_text(encoded[64..].to_string()).unwrap());
+    map.insert_str("msg", &TransactionMetadatum::new_list(&lines))
+        .unwrap();
+    let metadatum = TransactionMetadatum::new_map(&map);
+    let encrypted = Cip83EncryptedMessage::from_metadatum(&metadatum).unwrap();
+
+    let message = encrypted.decrypt(None).unwrap();
+    assert_eq!(message.len(), 2);
+    assert_eq!(message.get(0), "Invoice-No: 1234567890");
+    assert_eq!(message.get(1), "Customer-No: 555-1234");
+    assert!(encrypted.decrypt(Some("hunter2".to_string())).is_err());
+
+    let salt: [u8; 8] = encrypted.encrypted_bytes()[8..16].try_into().unwrap();
+    let reencrypted = Cip83EncryptedMessage::encrypt_with_salt(&message, None, &salt).unwrap();
+    assert_eq!(reencrypted, encrypted);
+    assert_eq!(reencrypted.to_metadatum().unwrap(), metadatum);
+}
+
+#[test]
+fn cip83_base64_test() {
+    let mut map = MetadataMap::new();
+    map.insert_str("enc", &TransactionMetadatum::new_text("basic".to_string()).unwrap())
+        .unwrap();
+    let mut lines = MetadataList::new();
+    lines.add(&TransactionMetadatum::new_text("AAEC/w".to_string()).unwrap());
+    lines.add(&TransactionMetadatum::new_text("==".to_string()).unwrap());
+    map.insert_str("msg", &TransactionMetadatum::new_list(&lines))
+        .unwrap();
+    let message = Cip83EncryptedMessage::from_metadatum(&TransactionMetadatum::new_map(&map)).unwrap();
+    assert_eq!(message.encrypted_bytes(), vec![0, 1, 2, 255]);
+
+    let metadatum = message.to_metadatum().unwrap();
+    let lines = metadatum.as_map().unwrap().get_str("msg").unwrap().as_list().unwrap();
+    assert_eq!(lines.get(0).as_text().unwrap(), "AAEC/w==");
+
+    map.insert_str("enc", &TransactionMetadatum::new_text("aes".to_string()).unwrap())
+        .unwrap();
+    assert!(Cip83EncryptedMessage::from_metadatum(&TransactionMetadatum::new_map(&map)).is_err());
+}
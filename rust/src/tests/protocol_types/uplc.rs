@@ -0,0 +1,215 @@
+use crate::*;
+
+// marketplace validator from mainnet, "cborHex" form with the script bytes wrapped twice
+const MARKETPLACE_SCRIPT_HEX: &str = "590e6f590e6c0100003323332223322333222332232332233223232333222323332223233333333222222223233322232333322223232332232323332223232332233223232333332222233223322332233223322332222323232232232325335303233300a3333573466e1cd55cea8042400046664446660a40060040026eb4d5d0a8041bae35742a00e66a05046666ae68cdc39aab9d37540029000102b11931a982599ab9c04f04c04a049357426ae89401c8c98d4c124cd5ce0268250240239999ab9a3370ea0089001102b11999ab9a3370ea00a9000102c11931a982519ab9c04e04b0490480473333573466e1cd55cea8012400046601a64646464646464646464646666ae68cdc39aab9d500a480008cccccccccc06ccd40a48c8c8cccd5cd19b8735573aa0049000119810981c9aba15002302e357426ae8940088c98d4c164cd5ce02e82d02c02b89aab9e5001137540026ae854028cd40a40a8d5d0a804999aa8183ae502f35742a010666aa060eb940bcd5d0a80399a8148211aba15006335029335505304b75a6ae854014c8c8c8cccd5cd19b8735573aa0049000119a8119919191999ab9a3370e6aae7540092000233502b33504175a6ae854008c118d5d09aba25002232635305d3357380c20bc0b80b626aae7940044dd50009aba150023232323333573466e1cd55cea80124000466a05266a082eb4d5d0a80118231aba135744a004464c6a60ba66ae7018417817016c4d55cf280089baa001357426ae8940088c98d4c164cd5ce02e82d02c02b89aab9e5001137540026ae854010cd40a5d71aba15003335029335505375c40026ae854008c0e0d5d09aba2500223263530553357380b20ac0a80a626ae8940044d5d1280089aba25001135744a00226ae8940044d5d1280089aba25001135744a00226aae7940044dd50009aba150023232323333573466e1d4005200623020303a357426aae79400c8cccd5cd19b875002480108c07cc110d5d09aab9e500423333573466e1d400d20022301f302f357426aae7940148cccd5cd19b875004480008c088dd71aba135573ca00c464c6a60a066ae7015014413c13813413012c4d55cea80089baa001357426ae8940088c98d4c124cd5ce026825024023882489931a982419ab9c4910350543500049047135573ca00226ea80044d55ce9baa001135744a00226aae7940044dd50009109198008018011000911111111109199999999980080580500480400380300280200180110009109198008018011000891091980080180109000891091980080180109000891091980080180109000909111180200290911118018029091111801002909111180080290008919118011bac0013200135503c2233335573e0024a01c466a01a60086ae84008c00cd5d100101811919191999ab9a3370e6aae75400d200023330073232323333573466e1cd55cea8012400046601a605c6ae854008cd404c0a8d5d09aba25002232635303433573807006a06606426aae7940044dd50009aba150033335500b75ca0146ae854008cd403dd71aba135744a004464c6a606066ae700d00c40bc0b84d5d1280089aab9e5001137540024442466600200800600440024424660020060044002266aa002eb9d6889119118011bab00132001355036223233335573e0044a012466a01066aa05c600c6aae754008c014d55cf280118021aba200302b1357420022244004244244660020080062400224464646666ae68cdc3a800a400046a05e600a6ae84d55cf280191999ab9a3370ea00490011281791931a981399ab9c02b028026025024135573aa00226ea80048c8c8cccd5cd19b8735573aa004900011980318039aba15002375a6ae84d5d1280111931a981219ab9c028025023022135573ca00226ea80048848cc00400c00880048c8cccd5cd19b8735573aa002900011bae357426aae7940088c98d4c080cd5ce01201080f80f09baa00112232323333573466e1d400520042500723333573466e1d4009200223500a3006357426aae7940108cccd5cd19b87500348000940288c98d4c08ccd5ce01381201101081000f89aab9d50011375400224244460060082244400422444002240024646666ae68cdc3a800a4004400c46666ae68cdc3a80124000400c464c6a603666ae7007c0700680640604d55ce9baa0011220021220012001232323232323333573466e1d4005200c200b23333573466e1d4009200a200d23333573466e1d400d200823300b375c6ae854014dd69aba135744a00a46666ae68cdc3a8022400c46601a6eb8d5d0a8039bae357426ae89401c8cccd5cd19b875005480108cc048c050d5d0a8049bae357426ae8940248cccd5cd19b875006480088c050c054d5d09aab9e500b23333573466e1d401d2000230133016357426aae7940308c98d4c080cd5ce01201080f80f00e80e00d80d00c80c09aab9d5004135573ca00626aae7940084d55cf280089baa00121222222230070082212222222330060090082122222223005008122222220041222222200322122222223300200900822122222223300100900820012323232323333573466e1d400520022333008375a6ae854010dd69aba15003375a6ae84d5d1280191999ab9a3370ea00490001180518059aba135573ca00c464c6a602266ae7005404804003c0384d55cea80189aba25001135573ca00226ea80048488c00800c888488ccc00401401000c80048c8c8cccd5cd19b875001480088c018dd71aba135573ca00646666ae68cdc3a80124000460106eb8d5d09aab9e5004232635300b33573801e01801401201026aae7540044dd5000909118010019091180080190008891119191999ab9a3370e6aae75400920002335500b300635742a004600a6ae84d5d1280111931a980419ab9c00c009007006135573ca00226ea800526120012001112212330010030021120014910350543100222123330010040030022001121223002003112200112001120012001122002122001200111232300100122330033002002001332323233322233322233223332223322332233322233223322332233223233322232323322323232323333222232332232323222323222325335301a5335301a333573466e1cc8cccd54c05048004c8cd406488ccd406400c004008d4058004cd4060888c00cc008004800488cdc0000a40040029000199aa98068900091299a980e299a9a81a1a98169a98131a9812001110009110019119a98188011281c11a81c8009080f880e899a8148010008800a8141a981028009111111111005240040380362038266ae712413c53686f756c642062652065786163746c79206f6e652073637269707420696e70757420746f2061766f696420646f75626c65207361742069737375650001b15335303500315335301a5335301a333573466e20ccc064ccd54c03448005402540a0cc020d4c0c00188880094004074074cdc09a9818003111001a80200d80e080e099ab9c49010f73656c6c6572206e6f7420706169640001b15335301a333573466e20ccc064cc88ccd54c03c48005402d40a8cc028004009400401c074075401006c07040704cd5ce24810d66656573206e6f7420706169640001b101b15335301a3322353022002222222222253353503e33355301f1200133502322533535040002210031001503f253353027333573466e3c0300040a40a04d41040045410000c840a4409d4004d4c0c001888800840704cd5ce2491c4f6e6c792073656c6c65722063616e2063616e63656c206f666665720001b101b135301d00122002153353016333573466e2540040d406005c40d4540044cdc199b8235302b001222003480c920d00f2235301a0012222222222333553011120012235302a002222353034003223353038002253353026333573466e3c0500040a009c4cd40cc01401c401c801d40b0024488cd54c02c480048d4d5408c00488cd54098008cd54c038480048d4d5409800488cd540a4008ccd4d540340048cc0e12000001223303900200123303800148000004cd54c02c480048d4d5408c00488cd54098008ccd4d540280048cd54c03c480048d4d5409c00488cd540a8008d5404400400488ccd5540200580080048cd54c03c480048d4d5409c00488cd540a8008d5403c004004ccd55400c044008004444888ccd54c018480054080cd54c02c480048d4d5408c00488cd54098008d54034004ccd54c0184800488d4d54090008894cd4c05cccd54c04048004c8cd405488ccd4d402c00c88008008004d4d402400488004cd4024894cd4c064008406c40040608d4d5409c00488cc028008014018400c4cd409001000d4084004cd54c02c480048d4d5408c00488c8cd5409c00cc004014c8004d540d8894cd4d40900044d5403400c884d4d540a4008894cd4c070cc0300080204cd5404801c0044c01800c00848848cc00400c00848004c8004d540b488448894cd4d40780044008884cc014008ccd54c01c480040140100044484888c00c01044884888cc0080140104484888c004010448004c8004d540a08844894cd4d406000454068884cd406cc010008cd54c01848004010004c8004d5409c88448894cd4d40600044d401800c884ccd4024014c010008ccd54c01c4800401401000448d4d400c0048800448d4d40080048800848848cc00400c0084800488ccd5cd19b8f002001006005222323230010053200135502522335350130014800088d4d54060008894cd4c02cccd5cd19b8f00200900d00c13007001130060033200135502422335350120014800088d4d5405c008894cd4c028ccd5cd19b8f00200700c00b10011300600312200212200120014881002212330010030022001222222222212333333333300100b00a009008007006005004003002200122123300100300220012221233300100400300220011122002122122330010040031200111221233001003002112001221233001003002200121223002003212230010032001222123330010040030022001121223002003112200112001122002122001200122337000040029040497a0088919180080091198019801001000a4411c28f07a93d7715db0bdc1766c8bd5b116602b105c02c54fc3bcd0d4680001";
+
+// (program 1.0.0 (lam x x))
+const IDENTITY_FLAT_HEX: &str = "010000200101";
+
+#[test]
+fn uplc_flat_round_trip_test() {
+    let script = PlutusScript::from_bytes(hex::decode(MARKETPLACE_SCRIPT_HEX).unwrap()).unwrap();
+    let program = UplcProgram::from_plutus_script(&script).unwrap();
+    assert_eq!(program.version(), "1.0.0");
+
+    let flat = program.to_flat_bytes();
+    assert_eq!(&script.bytes()[3..], flat.as_slice());
+    assert_eq!(UplcProgram::from_flat_bytes(flat).unwrap(), program);
+
+    let reencoded = program.to_plutus_script(&Language::new_plutus_v1());
+    assert_eq!(reencoded, script);
+    assert_eq!(reencoded.hash(), script.hash());
+}
+
+#[test]
+fn uplc_double_cbor_test() {
+    let script = PlutusScript::from_bytes(hex::decode(MARKETPLACE_SCRIPT_HEX).unwrap()).unwrap();
+    // bytes passed with one more CBOR wrapping than needed
+    let double_wrapped = PlutusScript::new(hex::decode(MARKETPLACE_SCRIPT_HEX).unwrap());
+    let program = UplcProgram::from_plutus_script(&double_wrapped).unwrap();
+    assert_eq!(program, UplcProgram::from_plutus_script(&script).unwrap());
+
+    let raw_flat = PlutusScript::new(hex::decode(IDENTITY_FLAT_HEX).unwrap());
+    assert!(UplcProgram::from_plutus_script(&raw_flat).is_ok());
+}
+
+#[test]
+fn uplc_invalid_flat_test() {
+    assert!(UplcProgram::from_flat_bytes(vec![]).is_err());
+    assert!(UplcProgram::from_flat_bytes(hex::decode("0100002001").unwrap()).is_err());
+    assert!(UplcProgram::from_flat_bytes(hex::decode("01000020010100").unwrap()).is_err());
+    // unknown term tag 15
+    assert!(UplcProgram::from_flat_bytes(hex::decode("010000f001").unwrap()).is_err());
+}
+
+// (program 1.0.0 (delay (delay ... (error))))
+fn nested_delays_flat(depth: usize) -> Vec<u8> {
+    let filler = if depth & 1 == 0 { "1" } else { "01" };
+    hex::decode(format!("010000{}6{}", "1".repeat(depth), filler)).unwrap()
+}
+
+#[test]
+fn uplc_nesting_limit_test() {
+    let program = UplcProgram::from_flat_bytes(nested_delays_flat(100_000)).unwrap();
+    assert_eq!(program.to_flat_bytes(), nested_delays_flat(100_000));
+    assert_eq!(program.clone(), program);
+    assert!(program.to_pretty_string().is_ok());
+    assert_eq!(program.stats().unwrap().depth(), 100_001);
+    assert!(UplcProgram::from_flat_bytes(nested_delays_flat(100_001)).is_err());
+
+    // applying the program nests it deeper than printing and measuring accept
+    let applied = program.apply_data(&PlutusData::new_integer(&BigInt::from(42)));
+    assert!(applied.to_pretty_string().is_err());
+    assert!(applied.stats().is_err());
+    assert!(UplcProgram::from_flat_bytes(nested_delays_flat(1_000_000)).is_err());
+}
+
+// (program 1.0.0 [(lam i_0 [(lam i_1 ... i_<lets - 1>) (error)]) (error)]), every let binds an error
+fn let_chain_flat(lets: usize) -> Vec<u8> {
+    // applications and lambdas of the lets, the variable 1 and the errors bound by the lets
+    let mut bits = "00110010".repeat(lets);
+    bits.push_str("000000000001");
+    bits.push_str(&"0110".repeat(lets));
+    bits.push_str(&"0".repeat(7 - bits.len() % 8));
+    bits.push('1');
+    let mut bytes = vec![1, 0, 0];
+    bytes.extend(
+        bits.as_bytes()
+            .chunks(8)
+            .map(|byte| u8::from_str_radix(std::str::from_utf8(byte).unwrap(), 2).unwrap()),
+    );
+    bytes
+}
+
+#[test]
+fn uplc_let_chain_test() {
+    let program = UplcProgram::from_flat_bytes(let_chain_flat(1)).unwrap();
+    assert_eq!(
+        program.to_pretty_string().unwrap(),
+        "(program 1.0.0\n  [\n    (lam i_0 i_0)\n    (error)\n  ]\n)"
+    );
+
+    let flat = let_chain_flat(50_000);
+    let program = UplcProgram::from_flat_bytes(flat.clone()).unwrap();
+    assert_eq!(program.to_flat_bytes(), flat);
+    assert_eq!(program.clone(), program);
+    let stats = program.stats().unwrap();
+    assert_eq!(stats.depth(), 100_001);
+    assert_eq!(stats.term_count(UplcTermKind::Lambda), 50_000);
+    assert_eq!(stats.term_count(UplcTermKind::Error), 50_000);
+}
+
+#[test]
+fn apply_params_test() {
+    let program = UplcProgram::from_flat_bytes(hex::decode(IDENTITY_FLAT_HEX).unwrap()).unwrap();
+    let script = program.to_plutus_script(&Language::new_plutus_v2());
+    assert_eq!(hex::encode(script.bytes()), format!("46{}", IDENTITY_FLAT_HEX));
+
+    let mut params = PlutusList::new();
+    params.add(&PlutusData::new_integer(&BigInt::from(42)));
+    let applied = script.apply_params(&params).unwrap();
+    assert_eq!(applied.language_version(), Language::new_plutus_v2());
+    // (program 1.0.0 [(lam x x) (con data (I 42))])
+    assert_eq!(hex::encode(applied.bytes()), "4c010000320014c102182a0001");
+    assert_ne!(applied.hash(), script.hash());
+
+    // applying with no parameters keeps the script
+    assert_eq!(script.apply_params(&PlutusList::new()).unwrap(), script);
+}
+
+#[test]
+fn apply_params_order_test() {
+    let script = PlutusScript::from_bytes_v2(hex::decode(MARKETPLACE_SCRIPT_HEX).unwrap()).unwrap();
+    let first = PlutusData::new_bytes(vec![1, 2, 3]);
+    let second = PlutusData::new_empty_constr_plutus_data(&BigNum::one());
+    let mut params = PlutusList::new();
+    params.add(&first);
+    params.add(&second);
+
+    let applied = script.apply_params(&params).unwrap();
+    let expected = UplcProgram::from_plutus_script(&script)
+        .unwrap()
+        .apply_data(&first)
+        .apply_data(&second);
+    assert_eq!(UplcProgram::from_plutus_script(&applied).unwrap(), expected);
+
+    let mut one_by_one = PlutusList::new();
+    one_by_one.add(&first);
+    let partially_applied = script.apply_params(&one_by_one).unwrap();
+    let mut rest = PlutusList::new();
+    rest.add(&second);
+    assert_eq!(partially_applied.apply_params(&rest).unwrap().hash(), applied.hash());
+}
+
+#[test]
+fn uplc_pretty_print_test() {
+    let program = UplcProgram::from_flat_bytes(hex::decode(IDENTITY_FLAT_HEX).unwrap()).unwrap();
+    assert_eq!(program.to_pretty_string().unwrap(), "(program 1.0.0\n  (lam i_0 i_0)\n)");
+
+    let applied = program.apply_data(&PlutusData::new_integer(&BigInt::from(42)));
+    assert_eq!(
+        applied.to_pretty_string().unwrap(),
+        "(program 1.0.0\n  [\n    (lam i_0 i_0)\n    (con data (I 42))\n  ]\n)"
+    );
+
+    // (program 1.0.0 (builtin serialiseData))
+    let builtin = UplcProgram::from_flat_bytes(hex::decode("0100007661").unwrap()).unwrap();
+    assert_eq!(builtin.to_pretty_string().unwrap(), "(program 1.0.0\n  (builtin serialiseData)\n)");
+}
+
+#[test]
+fn uplc_stats_test() {
+    let script = PlutusScript::from_bytes(hex::decode(MARKETPLACE_SCRIPT_HEX).unwrap()).unwrap();
+    let stats = UplcProgram::from_plutus_script(&script).unwrap().stats().unwrap();
+    assert_eq!(stats.flat_size(), script.bytes().len() - 3);
+    assert!(
+        stats.size()
+            > stats.term_count(UplcTermKind::Lambda) + stats.term_count(UplcTermKind::Apply)
+    );
+    assert!(stats.depth() < stats.size());
+    assert!(stats.builtin_size("trace") > 0);
+    assert_eq!(stats.builtin_size("serialiseData"), 0);
+    assert_eq!(stats.builtin_size("unknown"), 0);
+    let names = stats.builtin_names();
+    assert!((0..names.len()).any(|i| names.get(i) == "equalsByteString"));
+    assert!(!stats.uses_sums_of_products());
+    assert_eq!(stats.minimum_language(), Language::new_plutus_v1());
+
+    let identity = UplcProgram::from_flat_bytes(hex::decode(IDENTITY_FLAT_HEX).unwrap())
+        .unwrap()
+        .stats()
+        .unwrap();
+    assert_eq!(identity.flat_size(), 6);
+    assert_eq!(identity.size(), 2);
+    assert_eq!(identity.depth(), 2);
+    assert_eq!(identity.term_count(UplcTermKind::Lambda), 1);
+    assert_eq!(identity.term_count(UplcTermKind::Var), 1);
+    assert_eq!(identity.term_count(UplcTermKind::Constant), 0);
+    assert_eq!(identity.builtin_names().len(), 0);
+
+    // [(builtin serialiseData) (con data (I 42))]
+    let call = UplcProgram::from_flat_bytes(hex::decode("0100007661").unwrap())
+        .unwrap()
+        .apply_data(&PlutusData::new_integer(&BigInt::from(42)));
+    let call = call.stats().unwrap();
+    assert_eq!(call.size(), 3);
+    assert_eq!(call.builtin_size("serialiseData"), 3);
+}
+
+#[test]
+fn uplc_minimum_language_test() {
+    let language = |flat: &str| {
+        UplcProgram::from_flat_bytes(hex::decode(flat).unwrap())
+            .unwrap()
+            .stats()
+            .unwrap()
+            .minimum_language()
+    };
+    assert_eq!(language(IDENTITY_FLAT_HEX), Language::new_plutus_v1());
+    // (program 1.1.0 (lam x x))
+    assert_eq!(language("010100200101"), Language::new_plutus_v3());
+    // (program 1.0.0 (builtin serialiseData))
+    assert_eq!(language("0100007661"), Language::new_plutus_v2());
+    // (program 1.0.0 (builtin bls12_381_G1_add))
+    assert_eq!(language("01000076c1"), Language::new_plutus_v3());
+}
@@ -0,0 +1,125 @@
+use crate::tests::fakes::{fake_signature, fake_tx_input, fake_vkey};
+use crate::*;
+
+const SIGNED_TX: &str = "84a400d90102818258203b40265111d8bb3c3c608d95b3a0bf83461ace32d79336579a1939b3aad1c0b700018182581d611c616f1acb460668a9b2f123c80372c2adad3583b9c6cd2b1deeed1c01021a00016f32030aa100d9010281825820f9aa3fccb7fe539e471188ccc9ee65514c5961c070b06ca185962484a4813bee58406d68d8b7b2ee54f1f46b64e3f61a14f840be2ec125c858ec917f634a1eb898a51660654839226016a2588d39920e6dfe1b66d917027f198b5eb887d20f4ac805f5f6";
+
+#[test]
+fn explore_transaction_names_fields() {
+    let bytes = hex::decode(SIGNED_TX).unwrap();
+    let root = CborExplorer::explore_transaction(&bytes).unwrap();
+    assert_eq!(root.offset(), 0);
+    assert_eq!(root.length(), bytes.len());
+    assert_eq!(root.error(), None);
+
+    let body = root.find("transaction_body").unwrap();
+    assert_eq!(body.major_type(), CborMajorType::Map);
+    assert_eq!(body.offset(), 1);
+
+    // the 258 set tag with the input list inside
+    let inputs = body.find("inputs").unwrap();
+    assert_eq!(inputs.offset(), 3);
+    assert_eq!(inputs.major_type(), CborMajorType::Tag);
+    assert_eq!(inputs.argument(), Some(258));
+    assert_eq!(inputs.key().unwrap().argument(), Some(0));
+    let input = inputs.find("transaction_input").unwrap();
+    assert_eq!(input.offset(), 7);
+    assert_eq!(input.length(), 36);
+    assert_eq!(input.find("transaction_id").unwrap().length(), 34);
+
+    let fee = body.find("fee").unwrap();
+    assert_eq!(fee.value(), Some("94002".to_string()));
+    assert_eq!(body.find("ttl").unwrap().argument(), Some(10));
+    assert_eq!(root.find("transaction_output").unwrap().children().len(), 2);
+
+    let witness = root.find("vkeywitness").unwrap();
+    assert_eq!(witness.find("signature").unwrap().length(), 66);
+    assert_eq!(root.find("is_valid").unwrap().value(), Some("true".to_string()));
+    assert_eq!(root.find("auxiliary_data").unwrap().value(), Some("null".to_string()));
+}
+
+#[test]
+fn explore_transaction_names_certificates() {
+    let mut inputs = TransactionInputs::new();
+    inputs.add(&fake_tx_input(1));
+    let mut body = TransactionBody::new_tx_body(&inputs, &TransactionOutputs::new(), &Coin::from(1000u64));
+    let mut certs = Certificates::new();
+    certs.add(&Certificate::new_stake_registration(&StakeRegistration::new(
+        &Credential::from_keyhash(&fake_vkey().public_key().hash()),
+    )));
+    body.set_certs(&certs);
+    let mut witness_set = TransactionWitnessSet::new();
+    let mut vkeys = Vkeywitnesses::new();
+    vkeys.add(&Vkeywitness::new(&fake_vkey(), &fake_signature(1)));
+    witness_set.set_vkeys(&vkeys);
+    let tx = Transaction::new(&body, &witness_set, None);
+
+    let root = CborExplorer::explore_transaction(&tx.to_bytes()).unwrap();
+    let certs = root.find("certs").unwrap();
+    assert_eq!(certs.find("stake_registration").unwrap().children().len(), 2);
+    assert!(root.find("vkeywitnesses").is_some());
+
+    let annotated = root.to_annotated_string();
+    assert!(annotated.contains("# transaction_body"));
+    assert!(annotated.contains("0: tag(258) # inputs"));
+    assert!(annotated.contains("# stake_registration"));
+}
+
+#[test]
+fn explore_malformed_bytes() {
+    // array of 3 with the last item missing
+    let root = CborExplorer::explore(&hex::decode("830102").unwrap()).unwrap();
+    assert_eq!(root.children().len(), 3);
+    assert_eq!(root.children().get(1).value(), Some("2".to_string()));
+    let missing = root.children().get(2);
+    assert_eq!(missing.offset(), 3);
+    assert!(missing.error().is_some());
+
+    // byte string longer than the input
+    let root = CborExplorer::explore(&hex::decode("8201450102").unwrap()).unwrap();
+    assert_eq!(root.error(), None);
+    let bytes = root.children().get(1);
+    assert_eq!(bytes.offset(), 2);
+    assert!(bytes.error().unwrap().contains("5 bytes expected"));
+
+    let root = CborExplorer::explore(&hex::decode("0102").unwrap()).unwrap();
+    assert_eq!(root.length(), 1);
+    assert!(root.error().unwrap().contains("unexpected bytes"));
+
+    assert!(CborExplorer::explore(&hex::decode("f93c00").unwrap()).unwrap().error().is_some());
+    assert!(CborExplorer::explore(&[]).is_err());
+}
+
+#[test]
+fn explore_without_schema_has_no_names() {
+    let bytes = hex::decode(SIGNED_TX).unwrap();
+    let root = CborExplorer::explore(&bytes).unwrap();
+    assert_eq!(root.name(), None);
+    assert!(root.find("transaction_body").is_none());
+    assert_eq!(root.children().len(), 4);
+    let json = root.to_json().unwrap();
+    assert!(json.contains("\"major_type\": \"Map\""));
+}
+
+#[test]
+fn explore_deeply_nested_bytes() {
+    // arrays of one item around an integer
+    let nested = |arrays: usize| [vec![0x81; arrays], vec![0x00]].concat();
+    let innermost = |root: CborNode| {
+        let mut node = root;
+        while let Some(child) = node.children.first().cloned() {
+            node = child;
+        }
+        node
+    };
+
+    let root = CborExplorer::explore(&nested(255)).unwrap();
+    let integer = innermost(root.clone());
+    assert_eq!(integer.error(), None);
+    assert_eq!(integer.value(), Some("0".to_string()));
+    assert!(root.to_json().is_ok());
+
+    let root = CborExplorer::explore(&nested(100_000)).unwrap();
+    let deepest = innermost(root);
+    assert_eq!(deepest.offset(), 256);
+    assert!(deepest.error().unwrap().contains("nested deeper than 256 levels"));
+}
@@ -0,0 +1,157 @@
+use crate::*;
+
+const SIGNED_TX: &str = "84a400d90102818258203b40265111d8bb3c3c608d95b3a0bf83461ace32d79336579a1939b3aad1c0b700018182581d611c616f1acb460668a9b2f123c80372c2adad3583b9c6cd2b1deeed1c01021a00016f32030aa100d9010281825820f9aa3fccb7fe539e471188ccc9ee65514c5961c070b06ca185962484a4813bee58406d68d8b7b2ee54f1f46b64e3f61a14f840be2ec125c858ec917f634a1eb898a51660654839226016a2588d39920e6dfe1b66d917027f198b5eb887d20f4ac805f5f6";
+const UNTAGGED_TX: &str = "84a400818258203b40265111d8bb3c3c608d95b3a0bf83461ace32d79336579a1939b3aad1c0b700018182581d611c616f1acb460668a9b2f123c80372c2adad3583b9c6cd2b1deeed1c01021a00016f32030aa10081825820f9aa3fccb7fe539e471188ccc9ee65514c5961c070b06ca185962484a4813bee5840fae5de40c94d759ce13bf9886262159c4f26a289fd192e165995b785259e503f6887bf39dfa23a47cf163784c6eee23f61440e749bc1df3c73975f5231aeda0ff5f6";
+
+fn issue_kinds(report: &ConformanceReport) -> Vec<(ConformanceIssueKind, String)> {
+    report.issues().into_iter().map(|issue| (issue.kind(), issue.path())).collect()
+}
+
+fn tx_from_diagnostic(body: &str) -> Vec<u8> {
+    let input = format!("[h'{}', 0]", "aa".repeat(32));
+    encode_diagnostic_to_cbor(&format!("[{}, {{}}, true, null]", body.replace("INPUT", &input))).unwrap()
+}
+
+#[test]
+fn canonical_tx_conforms() {
+    let report = check_conway_conformance(&hex::decode(SIGNED_TX).unwrap());
+    assert!(report.is_canonical());
+    assert!(report.is_accepted());
+}
+
+#[test]
+fn untagged_sets_are_accepted_but_not_canonical() {
+    let report = check_conway_conformance(&hex::decode(UNTAGGED_TX).unwrap());
+    assert!(report.is_accepted());
+    assert!(!report.is_canonical());
+    assert_eq!(
+        issue_kinds(&report),
+        vec![
+            (ConformanceIssueKind::MissingSetTag, "transaction.transaction_body.inputs".to_string()),
+            (ConformanceIssueKind::MissingSetTag, "transaction.transaction_witness_set.vkeywitnesses".to_string()),
+        ]
+    );
+    assert_eq!(report.issues().get(0).severity(), ConformanceSeverity::NonCanonical);
+    assert_eq!(report.issues().get(0).offset(), 3);
+}
+
+#[test]
+fn rejected_body_encodings() {
+    // repeated ttl, unknown key 30 and empty certs
+    let hex_str = SIGNED_TX
+        .replacen("a400d90102", "a700d90102", 1)
+        .replacen("6f32030a", "6f32030a030b181e0004d9010280", 1);
+    let report = check_conway_conformance(&hex::decode(hex_str).unwrap());
+    assert!(!report.is_accepted());
+    assert_eq!(
+        issue_kinds(&report),
+        vec![
+            (ConformanceIssueKind::UnknownKey, "transaction.transaction_body[30]".to_string()),
+            (ConformanceIssueKind::DuplicateKey, "transaction.transaction_body.ttl".to_string()),
+            (ConformanceIssueKind::UnsortedMapKeys, "transaction.transaction_body".to_string()),
+            (ConformanceIssueKind::EmptyCollection, "transaction.transaction_body.certs".to_string()),
+        ]
+    );
+    let certs = report.issues().get(3);
+    assert_eq!(certs.offset(), 92);
+    assert_eq!(certs.length(), 4);
+}
+
+#[test]
+fn rejected_amounts_and_fields() {
+    let policy = "01".repeat(28);
+    let body = format!(
+        "{{0: 258([INPUT, INPUT]), 1: [_ [h'{}', [1000, {{h'{}': {{h'': 0}}}}]]], 2: 100, 22: 0, 6: [{{}}, 1]}}",
+        "61".repeat(29),
+        policy
+    );
+    let report = check_conway_conformance(&tx_from_diagnostic(&body));
+    let kinds: Vec<ConformanceIssueKind> = report.issues().into_iter().map(|issue| issue.kind()).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            ConformanceIssueKind::UnsortedMapKeys,
+            ConformanceIssueKind::DuplicateSetElement,
+            ConformanceIssueKind::IndefiniteLength,
+            ConformanceIssueKind::ZeroAmount,
+            ConformanceIssueKind::ZeroAmount,
+            ConformanceIssueKind::FieldNotAllowed,
+        ]
+    );
+    assert_eq!(report.issues().get(1).path(), "transaction.transaction_body.inputs[1]");
+    assert_eq!(
+        report.issues().get(3).path(),
+        format!("transaction.transaction_body.outputs[0].amount[1][h'{}'][h'']", policy)
+    );
+    assert_eq!(report.issues().get(4).path(), "transaction.transaction_body.donation");
+}
+
+#[test]
+fn structure_and_cbor_errors() {
+    let report = check_conway_conformance(&tx_from_diagnostic("{0: 258([INPUT]), 1: [], 2: \"fee\"}"));
+    assert_eq!(
+        issue_kinds(&report),
+        vec![(ConformanceIssueKind::InvalidStructure, "transaction".to_string())]
+    );
+
+    let report = check_conway_conformance(&tx_from_diagnostic("{0: 258([INPUT]), 1: []}"));
+    assert_eq!(
+        issue_kinds(&report),
+        vec![(ConformanceIssueKind::MissingField, "transaction.transaction_body".to_string())]
+    );
+
+    let bytes = hex::decode(SIGNED_TX).unwrap();
+    let report = check_conway_conformance(&bytes[..40]);
+    assert_eq!(report.issues().get(0).kind(), ConformanceIssueKind::MalformedCbor);
+    assert!(!report.is_accepted());
+}
+
+#[test]
+fn duplicates_are_compared_by_value() {
+    // the second input has the index 0 in two bytes and the ttl key 3 is repeated in two bytes
+    let input = format!("[h'{}', 0_0]", "aa".repeat(32));
+    let body = format!("{{0: 258([INPUT, {}]), 1: [], 2: 100, 3: 5, 3_0: 6}}", input);
+    let report = check_conway_conformance(&tx_from_diagnostic(&body));
+    assert_eq!(
+        issue_kinds(&report),
+        vec![
+            (ConformanceIssueKind::DuplicateKey, "transaction.transaction_body.ttl".to_string()),
+            (ConformanceIssueKind::DuplicateSetElement, "transaction.transaction_body.inputs[1]".to_string()),
+            (ConformanceIssueKind::NonCanonicalInteger, "transaction.transaction_body.inputs[1].index".to_string()),
+            (ConformanceIssueKind::NonCanonicalInteger, "transaction.transaction_body.ttl".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn trailing_bytes_and_truncated_items() {
+    let mut bytes = hex::decode(SIGNED_TX).unwrap();
+    bytes.push(0);
+    let report = check_conway_conformance(&bytes);
+    assert_eq!(
+        issue_kinds(&report),
+        vec![(ConformanceIssueKind::TrailingBytes, "transaction".to_string())]
+    );
+    assert_eq!(report.issues().get(0).offset(), bytes.len() - 1);
+    assert_eq!(report.issues().get(0).length(), 1);
+
+    // an integer of 8 bytes with only 1 byte
+    let report = check_conway_conformance(&hex::decode("1b00").unwrap());
+    assert_eq!(
+        issue_kinds(&report),
+        vec![(ConformanceIssueKind::MalformedCbor, "transaction".to_string())]
+    );
+    // an indefinite length array without its break
+    let report = check_conway_conformance(&hex::decode("9f01").unwrap());
+    assert_eq!(report.issues().get(0).kind(), ConformanceIssueKind::MalformedCbor);
+}
+
+#[test]
+fn deeply_nested_items_are_malformed() {
+    let bytes = [vec![0x81; 100_000], vec![0x00]].concat();
+    let report = check_conway_conformance(&bytes);
+    assert!(!report.is_accepted());
+    let issue = report.issues().get(0);
+    assert_eq!(issue.kind(), ConformanceIssueKind::MalformedCbor);
+    assert!(issue.message().contains("nested deeper than 256 levels"));
+}
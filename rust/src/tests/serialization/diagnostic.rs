@@ -0,0 +1,72 @@
+use crate::tests::fakes::fake_tx_input;
+use crate::*;
+
+#[test]
+fn plutus_data_diagnostic_round_trip() {
+    let cbor = "d8799f42cafe1805d8668218c880d9050080c249010000000000000000ff";
+    let datum = PlutusData::from_hex(cbor).unwrap();
+    let diagnostic = datum.to_diagnostic().unwrap();
+    assert_eq!(
+        diagnostic,
+        "121([_ h'cafe', 5_0, 102([200, []]), 1280([]), 2(h'010000000000000000')])"
+    );
+    let parsed = PlutusData::from_diagnostic(&diagnostic).unwrap();
+    assert_eq!(parsed, datum);
+    assert_eq!(hex::encode(parsed.to_bytes()), cbor);
+}
+
+#[test]
+fn metadatum_diagnostic_round_trip() {
+    let cbor = "a3636b65792401830102030541ab";
+    let metadatum = TransactionMetadatum::from_hex(cbor).unwrap();
+    let diagnostic = metadatum.to_diagnostic().unwrap();
+    assert_eq!(diagnostic, "{\"key\": -5, 1: [1, 2, 3], 5: h'ab'}");
+    let parsed = TransactionMetadatum::from_diagnostic(&diagnostic).unwrap();
+    assert_eq!(hex::encode(parsed.to_bytes()), cbor);
+}
+
+#[test]
+fn diagnostic_round_trip_for_any_type() {
+    let input = fake_tx_input(1);
+    let parsed = TransactionInput::from_diagnostic(&input.to_diagnostic().unwrap()).unwrap();
+    assert_eq!(parsed, input);
+    assert!(TransactionInput::from_diagnostic("[h'00', 0]").is_err());
+}
+
+#[test]
+fn diagnostic_notation_test() {
+    let to_hex = |diagnostic: &str| hex::encode(encode_diagnostic_to_cbor(diagnostic).unwrap());
+    assert_eq!(to_hex(" [ 1 , /comment/ -1 , 24_1 ] "), "83012019 0018".replace(' ', ""));
+    assert_eq!(to_hex("{_ \"a\": null, 'b': [_ ], h'': ''_}"), "bf6161f64162 9fff 40 5fff ff".replace(' ', ""));
+    assert_eq!(to_hex("[true, false, undefined, simple(16), simple(255)]"), "85f5f4f7f0f8ff");
+    assert_eq!(to_hex("(_ \"a\", \"b\")"), "7f61616162ff");
+    assert_eq!(to_hex("-18446744073709551616"), "3bffffffffffffffff");
+
+    let text = "{\"q\\\"\\u00e9\": 1_3}";
+    let cbor = encode_diagnostic_to_cbor(text).unwrap();
+    assert_eq!(decode_cbor_to_diagnostic(&cbor).unwrap(), "{\"q\\\"é\": 1_3}");
+
+    assert!(encode_diagnostic_to_cbor("[1, 2").is_err());
+    assert!(encode_diagnostic_to_cbor("5_4").is_err());
+    assert!(encode_diagnostic_to_cbor("256_0").is_err());
+    assert!(encode_diagnostic_to_cbor("1.5").is_err());
+    assert!(encode_diagnostic_to_cbor("(_ h'01', \"a\")").is_err());
+    assert!(encode_diagnostic_to_cbor("1 2").is_err());
+    // floats and truncated items
+    assert!(decode_cbor_to_diagnostic(&hex::decode("f93e00").unwrap()).is_err());
+    assert!(decode_cbor_to_diagnostic(&hex::decode("8201").unwrap()).is_err());
+    assert!(decode_cbor_to_diagnostic(&hex::decode("0101").unwrap()).is_err());
+}
+
+#[test]
+fn deeply_nested_diagnostic_notation() {
+    let nested_cbor = |arrays: usize| [vec![0x81; arrays], vec![0x00]].concat();
+    let nested_text = |arrays: usize| format!("{}0{}", "[".repeat(arrays), "]".repeat(arrays));
+    assert_eq!(cbor_to_diagnostic(&nested_cbor(255)).unwrap(), nested_text(255));
+    assert_eq!(encode_diagnostic_to_cbor(&nested_text(255)).unwrap(), nested_cbor(255));
+
+    assert!(cbor_to_diagnostic(&nested_cbor(100_000)).unwrap_err().contains("nested deeper than 256 levels"));
+    assert!(diagnostic_to_cbor(&nested_text(100_000)).unwrap_err().contains("nested deeper than 256 levels"));
+    assert!(decode_cbor_to_diagnostic(&nested_cbor(256)).is_err());
+    assert!(PlutusData::from_diagnostic(&"[".repeat(100_000)).is_err());
+}
@@ -0,0 +1,106 @@
+use crate::*;
+
+const SIGNED_TX: &str = "84a400d90102818258203b40265111d8bb3c3c608d95b3a0bf83461ace32d79336579a1939b3aad1c0b700018182581d611c616f1acb460668a9b2f123c80372c2adad3583b9c6cd2b1deeed1c01021a00016f32030aa100d9010281825820f9aa3fccb7fe539e471188ccc9ee65514c5961c070b06ca185962484a4813bee58406d68d8b7b2ee54f1f46b64e3f61a14f840be2ec125c858ec917f634a1eb898a51660654839226016a2588d39920e6dfe1b66d917027f198b5eb887d20f4ac805f5f6";
+
+fn replace_once(hex: &str, from: &str, to: &str) -> Vec<u8> {
+    assert_eq!(hex.matches(from).count(), 1);
+    hex::decode(hex.replacen(from, to, 1)).unwrap()
+}
+
+fn issue_kinds(result: &LenientTransaction) -> Vec<(DecodeIssueKind, String)> {
+    result.issues().into_iter().map(|issue| (issue.kind(), issue.path())).collect()
+}
+
+#[test]
+fn lenient_decode_of_valid_tx_has_no_issues() {
+    let bytes = hex::decode(SIGNED_TX).unwrap();
+    let result = Transaction::from_bytes_lenient(&bytes);
+    assert!(!result.has_issues());
+    assert_eq!(result.transaction(), Some(Transaction::from_bytes(bytes).unwrap()));
+}
+
+#[test]
+fn lenient_decode_collects_all_issues() {
+    // 6 body fields: inputs with tag 259, ttl as a non-canonical 10, a repeated ttl and an unknown key 30
+    let hex_str = hex::encode(replace_once(SIGNED_TX, "a400d90102", "a600d90103"));
+    let bytes = replace_once(&hex_str, "6f32030a", "6f3203180a030b181e00");
+    assert!(Transaction::from_bytes(bytes.clone()).is_err());
+
+    let result = Transaction::from_bytes_lenient(&bytes);
+    assert_eq!(
+        issue_kinds(&result),
+        vec![
+            (DecodeIssueKind::WrongSetTag, "transaction.transaction_body.inputs".to_string()),
+            (DecodeIssueKind::NonCanonicalInteger, "transaction.transaction_body.ttl".to_string()),
+            (DecodeIssueKind::DuplicateKey, "transaction.transaction_body.ttl".to_string()),
+            (DecodeIssueKind::UnknownKey, "transaction.transaction_body[30]".to_string()),
+        ]
+    );
+    assert_eq!(result.issues().get(0).offset(), 3);
+    let tx = result.transaction().unwrap();
+    assert_eq!(tx.body().inputs().len(), 1);
+    assert_eq!(tx.body().ttl_bignum(), Some(BigNum(10)));
+    assert_eq!(tx.witness_set().vkeys().unwrap().len(), 1);
+}
+
+#[test]
+fn lenient_decode_skips_invalid_elements() {
+    // a second output that is just the integer 5
+    let hex_str = hex::encode(replace_once(SIGNED_TX, "018182581d61", "018282581d61"));
+    let bytes = replace_once(&hex_str, "1c01021a", "1c0105021a");
+    let result = Transaction::from_bytes_lenient(&bytes);
+    let issues = result.issues();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues.get(0).kind(), DecodeIssueKind::InvalidElement);
+    assert_eq!(issues.get(0).path(), "transaction.transaction_body.outputs[1]");
+    assert_eq!(result.transaction().unwrap().body().outputs().len(), 1);
+}
+
+#[test]
+fn lenient_decode_of_broken_bytes() {
+    let bytes = hex::decode(SIGNED_TX).unwrap();
+    let result = Transaction::from_bytes_lenient(&bytes[..50]);
+    assert!(result.transaction().is_none());
+    assert_eq!(result.issues().get(0).kind(), DecodeIssueKind::MalformedCbor);
+
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    let result = Transaction::from_bytes_lenient(&trailing);
+    assert_eq!(issue_kinds(&result), vec![(DecodeIssueKind::TrailingBytes, "transaction".to_string())]);
+    assert_eq!(result.issues().get(0).offset(), bytes.len());
+    assert!(result.transaction().is_some());
+
+    // a truncated integer and items nested too deep are malformed, not followed by trailing bytes
+    let result = Transaction::from_bytes_lenient(&hex::decode("1b00").unwrap());
+    assert_eq!(issue_kinds(&result), vec![(DecodeIssueKind::MalformedCbor, "transaction".to_string())]);
+    let nested = [vec![0x81; 100_000], vec![0x00]].concat();
+    let result = Transaction::from_bytes_lenient(&nested);
+    assert!(result.transaction().is_none());
+    let issue = result.issues().get(0);
+    assert_eq!(issue.kind(), DecodeIssueKind::MalformedCbor);
+    assert!(issue.message().contains("nested deeper than 256 levels"));
+
+    // valid CBOR that isn't a transaction
+    let result = Transaction::from_bytes_lenient(&[0x80]);
+    assert_eq!(result.issues().get(0).kind(), DecodeIssueKind::InvalidTransaction);
+    assert!(Transaction::from_bytes_lenient(&[]).transaction().is_none());
+}
+
+#[test]
+fn lenient_decode_keeps_the_parts_that_decode() {
+    // the witness set replaced by an empty array
+    let witness_set_start = SIGNED_TX.find("a100d9010281825820f9aa").unwrap();
+    let hex_str = format!("{}80f5f6", &SIGNED_TX[..witness_set_start]);
+    let bytes = hex::decode(hex_str).unwrap();
+    let result = Transaction::from_bytes_lenient(&bytes);
+    assert_eq!(
+        issue_kinds(&result),
+        vec![(DecodeIssueKind::InvalidTransaction, "transaction.transaction_witness_set".to_string())]
+    );
+    assert_eq!(result.issues().get(0).offset(), witness_set_start / 2);
+    assert!(result.transaction().is_none());
+    assert!(result.witness_set().is_none());
+    assert!(result.auxiliary_data().is_none());
+    let body = result.body().unwrap();
+    assert_eq!(body, Transaction::from_bytes(hex::decode(SIGNED_TX).unwrap()).unwrap().body());
+}
@@ -3,3 +3,8 @@ pub mod governance;
 pub mod transaction_body;
 pub mod protocol_param_update;
 pub mod general;
+pub mod diagnostic;
+pub mod cbor_explorer;
+pub mod lenient;
+pub mod conformance;
+pub mod round_trip;
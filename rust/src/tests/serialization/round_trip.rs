@@ -0,0 +1,120 @@
+use crate::arbitrary::*;
+use crate::*;
+use quickcheck::TestResult;
+
+#[quickcheck]
+fn plutus_data_round_trip(data: PlutusData) -> TestResult {
+    check_round_trip(&data)
+}
+
+#[quickcheck]
+fn native_script_round_trip(script: NativeScript) -> TestResult {
+    check_round_trip(&script)
+}
+
+#[quickcheck]
+fn script_ref_round_trip(script_ref: ScriptRef) -> TestResult {
+    check_round_trip(&script_ref)
+}
+
+#[quickcheck]
+fn redeemers_round_trip(redeemers: Redeemers) -> TestResult {
+    check_round_trip(&redeemers)
+}
+
+#[quickcheck]
+fn address_round_trip(address: Address) -> TestResult {
+    check_round_trip(&address)
+}
+
+#[quickcheck]
+fn value_round_trip(value: Value) -> TestResult {
+    check_round_trip(&value)
+}
+
+#[quickcheck]
+fn transaction_output_round_trip(output: TransactionOutput) -> TestResult {
+    check_round_trip(&output)
+}
+
+#[quickcheck]
+fn certificate_round_trip(certificate: Certificate) -> TestResult {
+    check_round_trip(&certificate)
+}
+
+#[quickcheck]
+fn governance_action_round_trip(action: GovernanceAction) -> TestResult {
+    check_round_trip(&action)
+}
+
+#[quickcheck]
+fn voting_proposal_round_trip(proposal: VotingProposal) -> TestResult {
+    check_round_trip(&proposal)
+}
+
+#[quickcheck]
+fn voting_procedures_round_trip(procedures: VotingProcedures) -> TestResult {
+    check_round_trip(&procedures)
+}
+
+#[quickcheck]
+fn protocol_param_update_round_trip(update: ProtocolParamUpdate) -> TestResult {
+    check_round_trip(&update)
+}
+
+#[quickcheck]
+fn transaction_body_round_trip(body: TransactionBody) -> TestResult {
+    check_round_trip(&body)
+}
+
+#[quickcheck]
+fn witness_set_round_trip(witness_set: TransactionWitnessSet) -> TestResult {
+    check_round_trip(&witness_set)
+}
+
+#[quickcheck]
+fn auxiliary_data_round_trip(auxiliary_data: AuxiliaryData) -> TestResult {
+    check_round_trip(&auxiliary_data)
+}
+
+#[quickcheck]
+fn transaction_round_trip(tx: Transaction) -> TestResult {
+    check_round_trip(&tx)
+}
+
+#[quickcheck]
+fn fixed_transaction_keeps_bytes(tx: Transaction) -> TestResult {
+    check_bytes_round_trip::<FixedTransaction>(&tx.to_bytes())
+}
+
+// a changed byte usually still decodes, but no longer in the way this crate would encode it
+#[quickcheck]
+fn fixed_transaction_keeps_changed_bytes(tx: Transaction, position: usize, byte: u8) -> TestResult {
+    let mut bytes = tx.to_bytes();
+    let position = position % bytes.len();
+    bytes[position] = byte;
+    check_bytes_round_trip::<FixedTransaction>(&bytes)
+}
+
+#[quickcheck]
+fn plutus_data_keeps_changed_bytes(data: PlutusData, position: usize, byte: u8) -> TestResult {
+    let mut bytes = data.to_bytes();
+    let position = position % bytes.len();
+    bytes[position] = byte;
+    check_bytes_round_trip::<PlutusData>(&bytes)
+}
+
+#[quickcheck]
+fn fixed_transaction_body_keeps_bytes(body: TransactionBody) -> bool {
+    let bytes = body.to_bytes();
+    let fixed = FixedTransactionBody::from_bytes(bytes.clone()).unwrap();
+    fixed.original_bytes() == bytes && fixed.transaction_body() == body && fixed.tx_hash() == TransactionHash::from(blake2b256(&bytes))
+}
+
+#[quickcheck]
+fn transaction_decoded_from_changed_bytes_round_trip(tx: Transaction, position: usize, byte: u8) -> TestResult {
+    let mut bytes = tx.to_bytes();
+    let position = position % bytes.len();
+    bytes[position] = byte;
+    check_decoded_round_trip::<Transaction>(&bytes)
+}
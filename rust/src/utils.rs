@@ -647,6 +647,27 @@ pub fn make_icarus_bootstrap_witness(
     BootstrapWitness::new(&vkey, &signature, chain_code, addr.attributes())
 }
 
+/// Bootstrap witness of any public key Byron address, icarus or daedalus style,
+/// failing when `key` isn't the key of `addr`.
+/// Redeem and script addresses can't be witnessed since the Shelley era.
+#[wasm_bindgen]
+pub fn make_byron_bootstrap_witness(
+    tx_body_hash: &TransactionHash,
+    addr: &ByronAddress,
+    key: &Bip32PrivateKey,
+) -> Result<BootstrapWitness, JsError> {
+    if addr.byron_address_kind() != legacy_address::ByronAddressType::ATPubKey {
+        return Err(JsError::from_str(&format!(
+            "{} addresses can't be spent with a bootstrap witness",
+            addr.byron_address_kind()
+        )));
+    }
+    if !addr.matches_key(&key.to_public()) {
+        return Err(JsError::from_str("Key doesn't match the address"));
+    }
+    Ok(make_icarus_bootstrap_witness(tx_body_hash, addr, key))
+}
+
 #[wasm_bindgen]
 pub fn make_vkey_witness(tx_body_hash: &TransactionHash, sk: &PrivateKey) -> Vkeywitness {
     let sig = sk.sign(tx_body_hash.0.as_ref());
@@ -740,37 +761,59 @@ pub fn internal_get_implicit_input(
     ))
 }
 
+// deposit taken by a certificate, registrations updating an already registered pool take none
+pub(crate) fn certificate_deposit(
+    cert: &Certificate,
+    pool_deposit: &BigNum,
+    key_deposit: &BigNum,
+    is_pool_update: bool,
+) -> Coin {
+    match &cert.0 {
+        CertificateEnum::PoolRegistration(_) if !is_pool_update => *pool_deposit,
+        CertificateEnum::StakeRegistration(cert) => cert.coin.unwrap_or(*key_deposit),
+        CertificateEnum::DRepRegistration(cert) => cert.coin,
+        CertificateEnum::StakeRegistrationAndDelegation(cert) => cert.coin,
+        CertificateEnum::VoteRegistrationAndDelegation(cert) => cert.coin,
+        CertificateEnum::StakeVoteRegistrationAndDelegation(cert) => cert.coin,
+        _ => Coin::zero(),
+    }
+}
+
 pub fn internal_get_deposit(
     certs: &Option<Certificates>,
     pool_deposit: &BigNum, // // protocol parameter
     key_deposit: &BigNum,  // protocol parameter
 ) -> Result<Coin, JsError> {
-    let certificate_deposit = match &certs {
-        None => BigNum::zero(),
-        Some(certs) => certs
-            .certs
-            .iter()
-            .try_fold(BigNum::zero(), |acc, ref cert| match &cert.0 {
-                CertificateEnum::PoolRegistration(_) => acc.checked_add(&pool_deposit),
-                CertificateEnum::StakeRegistration(cert) => {
-                    if let Some(coin) = cert.coin {
-                        acc.checked_add(&coin)
-                    } else {
-                        acc.checked_add(&key_deposit)
-                    }
-                }
-                CertificateEnum::DRepRegistration(cert) => acc.checked_add(&cert.coin),
-                CertificateEnum::StakeRegistrationAndDelegation(cert) => {
-                    acc.checked_add(&cert.coin)
-                }
-                CertificateEnum::VoteRegistrationAndDelegation(cert) => acc.checked_add(&cert.coin),
-                CertificateEnum::StakeVoteRegistrationAndDelegation(cert) => {
-                    acc.checked_add(&cert.coin)
-                }
-                _ => Ok(acc),
-            })?,
-    };
-    Ok(certificate_deposit)
+    internal_get_deposit_with_registered_pools(
+        certs,
+        pool_deposit,
+        key_deposit,
+        &Ed25519KeyHashes::new(),
+    )
+}
+
+fn internal_get_deposit_with_registered_pools(
+    certs: &Option<Certificates>,
+    pool_deposit: &BigNum,
+    key_deposit: &BigNum,
+    registered_pools: &Ed25519KeyHashes,
+) -> Result<Coin, JsError> {
+    let mut deposit = Coin::zero();
+    for cert in certs.iter().flat_map(|certs| certs.certs.iter()) {
+        let is_pool_update = match &cert.0 {
+            CertificateEnum::PoolRegistration(registration) => {
+                registered_pools.contains(&registration.pool_params.operator)
+            }
+            _ => false,
+        };
+        deposit = deposit.checked_add(&certificate_deposit(
+            cert,
+            pool_deposit,
+            key_deposit,
+            is_pool_update,
+        ))?;
+    }
+    Ok(deposit)
 }
 
 #[wasm_bindgen]
@@ -796,6 +839,23 @@ pub fn get_deposit(
     internal_get_deposit(&txbody.certs, &pool_deposit, &key_deposit)
 }
 
+/// Like `get_deposit`, with the registrations of the pools in `registered_pools` counted as
+/// updates, which take no deposit, as those added with `CertificatesBuilder.add_pool_update`
+#[wasm_bindgen]
+pub fn get_deposit_with_registered_pools(
+    txbody: &TransactionBody,
+    pool_deposit: &BigNum, // protocol parameter
+    key_deposit: &BigNum,  // protocol parameter
+    registered_pools: &Ed25519KeyHashes,
+) -> Result<Coin, JsError> {
+    internal_get_deposit_with_registered_pools(
+        &txbody.certs,
+        pool_deposit,
+        key_deposit,
+        registered_pools,
+    )
+}
+
 #[derive(Debug, Clone, Eq, Ord, PartialEq, PartialOrd)]
 pub struct MinOutputAdaCalculator {
     output: TransactionOutput,
@@ -1327,3 +1387,51 @@ pub(crate) fn has_transaction_witnesses_set_tag(witness_set: &TransactionWitness
         (false, false) => None,
     }
 }
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+pub(crate) fn base64_encode(bytes: &[u8]) -> String {
+    let mut result = String::with_capacity(bytes.len() * 4 / 3 + 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                result.push(BASE64_ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                result.push('=');
+            }
+        }
+    }
+    result
+}
+
+pub(crate) fn base64_decode(text: &str) -> Option<Vec<u8>> {
+    let chunks = text.as_bytes().chunks_exact(4);
+    if !chunks.remainder().is_empty() {
+        return None;
+    }
+    let count = chunks.len();
+    let mut result = Vec::with_capacity(count * 3);
+    for (i, chunk) in chunks.enumerate() {
+        let is_last = i + 1 == count;
+        let padding = chunk.iter().rev().take_while(|c| **c == b'=').count();
+        if padding > 2 || (padding > 0 && !is_last) {
+            return None;
+        }
+        let mut n = 0u32;
+        for c in &chunk[..4 - padding] {
+            let value = BASE64_ALPHABET.iter().position(|a| a == c)?;
+            n = (n << 6) | value as u32;
+        }
+        n <<= 6 * padding as u32;
+        let bytes = [(n >> 16) as u8, (n >> 8) as u8, n as u8];
+        result.extend_from_slice(&bytes[..3 - padding]);
+    }
+    Some(result)
+}